@@ -26,7 +26,258 @@ mod musl_reference_tests {
 
     // These files are all internal functions or otherwise miscellaneous, not
     // defining a function we want to test.
-    const IGNORED_FILES: &[&str] = &["fenv.rs"];
+    const IGNORED_FILES: &[&str] = &[
+        "fenv.rs",
+        // C leaves out-of-range conversions unspecified, and musl's results
+        // for NaN and infinities differ from the saturating `as` casts.
+        "llrint.rs",
+        "llrintf.rs",
+        "llround.rs",
+        "llroundf.rs",
+        "lrint.rs",
+        "lrintf.rs",
+        "lround.rs",
+        "lroundf.rs",
+        // musl ignores the payload string.
+        "nan.rs",
+        "nanf.rs",
+        // The direction is a `long double` in C.
+        "nexttoward.rs",
+        "nexttowardf.rs",
+        // Not part of musl.
+        "acosd.rs",
+        "acosdf.rs",
+        "acosf128.rs",
+        "acospi.rs",
+        "acospif.rs",
+        "asind.rs",
+        "asindf.rs",
+        "asinf128.rs",
+        "asinpi.rs",
+        "asinpif.rs",
+        "atan2d.rs",
+        "atan2df.rs",
+        "atan2f128.rs",
+        "atan2pi.rs",
+        "atan2pif.rs",
+        "atand.rs",
+        "atandf.rs",
+        "atanf128.rs",
+        "atanpi.rs",
+        "atanpif.rs",
+        "bf16.rs",
+        "bf16_erf.rs",
+        "bf16_exp.rs",
+        "bf16_log.rs",
+        "bf16_rsqrt.rs",
+        "bf16_sigmoid.rs",
+        "bf16_sqrt.rs",
+        "bf16_tanh.rs",
+        "cabs.rs",
+        "cabsf.rs",
+        "cacos.rs",
+        "cacosf.rs",
+        "cacosh.rs",
+        "cacoshf.rs",
+        "canonicalize.rs",
+        "canonicalizef.rs",
+        "carg.rs",
+        "cargf.rs",
+        "casin.rs",
+        "casinf.rs",
+        "casinh.rs",
+        "casinhf.rs",
+        "catan.rs",
+        "catanf.rs",
+        "catanh.rs",
+        "catanhf.rs",
+        "cbrtf128.rs",
+        "ccos.rs",
+        "ccosf.rs",
+        "ccosh.rs",
+        "ccoshf.rs",
+        "ceilf128.rs",
+        "ceilf16.rs",
+        "cexp.rs",
+        "cexpf.rs",
+        "checked.rs",
+        "clamp.rs",
+        "clampf.rs",
+        "clog.rs",
+        "clogf.rs",
+        "complex.rs",
+        "complexf.rs",
+        "compoundn.rs",
+        "compoundnf.rs",
+        "conj.rs",
+        "conjf.rs",
+        "convert.rs",
+        "copysignf128.rs",
+        "copysignf16.rs",
+        "cosd.rs",
+        "cosdf.rs",
+        "cosf128.rs",
+        "cosf16.rs",
+        "coshf128.rs",
+        "cospi.rs",
+        "cospif.rs",
+        "cpow.rs",
+        "cpowf.rs",
+        "cproj.rs",
+        "cprojf.rs",
+        "csin.rs",
+        "csinf.rs",
+        "csinh.rs",
+        "csinhf.rs",
+        "csqrt.rs",
+        "csqrtf.rs",
+        "ctan.rs",
+        "ctanf.rs",
+        "ctanh.rs",
+        "ctanhf.rs",
+        "div_euclid.rs",
+        "div_euclidf.rs",
+        "exp10m1.rs",
+        "exp10m1f.rs",
+        "exp2f128.rs",
+        "exp2f16.rs",
+        "exp2m1.rs",
+        "exp2m1f.rs",
+        "expf128.rs",
+        "expf16.rs",
+        "expm1f128.rs",
+        "f128.rs",
+        "f16.rs",
+        "fabsf128.rs",
+        "fabsf16.rs",
+        "fadd.rs",
+        "fdiv.rs",
+        "ffma.rs",
+        "flags.rs",
+        "floorf128.rs",
+        "floorf16.rs",
+        "fmaf128.rs",
+        "fmaf16.rs",
+        "fmaxf16.rs",
+        "fmaximum.rs",
+        "fmaximum_mag.rs",
+        "fmaximum_mag_num.rs",
+        "fmaximum_mag_numf.rs",
+        "fmaximum_magf.rs",
+        "fmaximum_num.rs",
+        "fmaximum_numf.rs",
+        "fmaximumf.rs",
+        "fminf16.rs",
+        "fminimum.rs",
+        "fminimum_mag.rs",
+        "fminimum_mag_num.rs",
+        "fminimum_mag_numf.rs",
+        "fminimum_magf.rs",
+        "fminimum_num.rs",
+        "fminimum_numf.rs",
+        "fminimumf.rs",
+        "fmodf128.rs",
+        "fmul.rs",
+        "fract.rs",
+        "fractf.rs",
+        "frexpf128.rs",
+        "frexpf16.rs",
+        "fromfp.rs",
+        "fromfpf.rs",
+        "fsqrt.rs",
+        "fsub.rs",
+        "getpayload.rs",
+        "getpayloadf.rs",
+        "hypotf128.rs",
+        "ilogbf128.rs",
+        "interval.rs",
+        "intervalf.rs",
+        "issignaling.rs",
+        "issignalingf.rs",
+        "k_catrig.rs",
+        "k_catrigf.rs",
+        "k_cexp.rs",
+        "k_cexpf.rs",
+        "k_cosf128.rs",
+        "k_expf128.rs",
+        "k_logf128.rs",
+        "k_sinf128.rs",
+        "ldexpf128.rs",
+        "ldexpf16.rs",
+        "log10f128.rs",
+        "log10p1.rs",
+        "log10p1f.rs",
+        "log1pf128.rs",
+        "log2f128.rs",
+        "log2f16.rs",
+        "log2p1.rs",
+        "log2p1f.rs",
+        "logf128.rs",
+        "logf16.rs",
+        "nextafterf128.rs",
+        "nextdown.rs",
+        "nextdownf.rs",
+        "nextup.rs",
+        "nextupf.rs",
+        "powf128.rs",
+        "powi.rs",
+        "powif.rs",
+        "pown.rs",
+        "pownf.rs",
+        "powr.rs",
+        "powrf.rs",
+        "recip.rs",
+        "recipf.rs",
+        "rem_euclid.rs",
+        "rem_euclidf.rs",
+        "rem_pio2f128.rs",
+        "rintf16.rs",
+        "rootn.rs",
+        "rootnf.rs",
+        "round_ops.rs",
+        "round_opsf.rs",
+        "roundeven.rs",
+        "roundevenf.rs",
+        "roundf128.rs",
+        "roundf16.rs",
+        "rsqrt.rs",
+        "rsqrtf.rs",
+        "scalbnf128.rs",
+        "setpayload.rs",
+        "setpayloadf.rs",
+        "setpayloadsig.rs",
+        "setpayloadsigf.rs",
+        "signum.rs",
+        "signumf.rs",
+        "sind.rs",
+        "sindf.rs",
+        "sinf128.rs",
+        "sinf16.rs",
+        "sinhf128.rs",
+        "sinpi.rs",
+        "sinpif.rs",
+        "sqrtf128.rs",
+        "sqrtf16.rs",
+        "tand.rs",
+        "tandf.rs",
+        "tanf128.rs",
+        "tanhf128.rs",
+        "tanhf16.rs",
+        "tanpi.rs",
+        "tanpif.rs",
+        "to_degrees.rs",
+        "to_degreesf.rs",
+        "to_int.rs",
+        "to_intf.rs",
+        "to_radians.rs",
+        "to_radiansf.rs",
+        "totalorder.rs",
+        "totalorderf.rs",
+        "totalordermag.rs",
+        "totalordermagf.rs",
+        "truncf128.rs",
+        "truncf16.rs",
+    ];
 
     struct Function {
         name: String,
@@ -39,6 +290,7 @@ mod musl_reference_tests {
         F32,
         F64,
         I32,
+        I64,
         Bool,
     }
 
@@ -112,6 +364,7 @@ mod musl_reference_tests {
                 "f32" => Ty::F32,
                 "f64" => Ty::F64,
                 "i32" => Ty::I32,
+                "i64" => Ty::I64,
                 "bool" => Ty::Bool,
                 other => panic!("unknown type `{}`", other),
             }
@@ -200,6 +453,13 @@ mod musl_reference_tests {
                         r.gen::<i32>().into()
                     }
                 }
+                Ty::I64 => {
+                    if r.gen_range(0, 10) < 1 {
+                        *[i64::max_value(), 0, i64::min_value()].choose(r).unwrap()
+                    } else {
+                        r.gen::<i64>()
+                    }
+                }
                 Ty::Bool => r.gen::<bool>() as i64,
             };
         }
@@ -209,6 +469,7 @@ mod musl_reference_tests {
                 Ty::F32 => "f32",
                 Ty::F64 => "f64",
                 Ty::I32 => "i32",
+                Ty::I64 => "i64",
                 Ty::Bool => "i32",
             }
         }
@@ -218,6 +479,7 @@ mod musl_reference_tests {
                 Ty::F32 => "*mut f32",
                 Ty::F64 => "*mut f64",
                 Ty::I32 => "*mut i32",
+                Ty::I64 => "*mut i64",
                 Ty::Bool => "*mut i32",
             }
         }
@@ -227,6 +489,7 @@ mod musl_reference_tests {
                 Ty::F32 => "0_f32",
                 Ty::F64 => "0_f64",
                 Ty::I32 => "0_i32",
+                Ty::I64 => "0_i64",
                 Ty::Bool => "false",
             }
         }
@@ -236,6 +499,7 @@ mod musl_reference_tests {
                 Ty::F32 => ".to_bits() as i64",
                 Ty::F64 => ".to_bits() as i64",
                 Ty::I32 => " as i64",
+                Ty::I64 => "",
                 Ty::Bool => " as i64",
             }
         }
@@ -298,6 +562,7 @@ mod musl_reference_tests {
                     Ty::F32 => format!("f32::from_bits(test[{}] as u32)", i),
                     Ty::F64 => format!("f64::from_bits(test[{}] as u64)", i),
                     Ty::I32 => format!("test[{}] as i32", i),
+                    Ty::I64 => format!("test[{}]", i),
                     Ty::Bool => format!("test[{}] as i32", i),
                 });
                 src.push_str(",");
@@ -405,6 +670,7 @@ mod musl_reference_tests {
                     Ty::F32 => format!("f32::from_bits(test[{}] as u32)", i),
                     Ty::F64 => format!("f64::from_bits(test[{}] as u64)", i),
                     Ty::I32 => format!("test[{}] as i32", i),
+                    Ty::I64 => format!("test[{}]", i),
                     Ty::Bool => format!("test[{}] as i32", i),
                 });
                 src.push_str(",");
@@ -421,6 +687,7 @@ mod musl_reference_tests {
                     Ty::F32 => format!("if _eqf(output{}, f32::from_bits(expected[{}] as u32)).is_ok() {{ continue }}", get, i),
                     Ty::F64 => format!("if _eq(output{}, f64::from_bits(expected[{}] as u64)).is_ok() {{ continue }}", get, i),
                     Ty::I32 => format!("if output{} as i64 == expected[{}] {{ continue }}", get, i),
+                    Ty::I64 => format!("if output{} == expected[{}] {{ continue }}", get, i),
                     Ty::Bool => unreachable!(),
                 }));
             }
@@ -33,6 +33,7 @@
  * Function needed: sqrt
  */
 
+use super::fenv::{feraiseexcept, FE_INVALID};
 use super::sqrt;
 
 const PIO2_HI: f64 = 1.57079632679489655800e+00; /* 0x3FF921FB, 0x54442D18 */
@@ -83,6 +84,9 @@ pub fn acos(x: f64) -> f64 {
             }
             return 0.;
         }
+        if !x.is_nan() {
+            feraiseexcept(FE_INVALID);
+        }
         return 0. / (x - x);
     }
     /* |x| < 0.5 */
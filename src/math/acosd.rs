@@ -0,0 +1,36 @@
+use super::{acos, rad_to_deg};
+
+/// Arccosine in degrees (f64)
+///
+/// Computes `acos(x) * 180/pi`, the arccosine of `x` in degrees, in the range
+/// `[0, 180]`. Returns NaN if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosd(x: f64) -> f64 {
+    if x == -1.0 {
+        return 180.0;
+    }
+    if x == 0.0 {
+        return 90.0;
+    }
+    if x == 0.5 || x == -0.5 {
+        return 90.0 - x * 60.0;
+    }
+    rad_to_deg(acos(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::acosd;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(acosd(1.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(acosd(-1.0), 180.0);
+        assert_eq!(acosd(0.0), 90.0);
+        assert_eq!(acosd(-0.0), 90.0);
+        assert_eq!(acosd(0.5), 60.0);
+        assert_eq!(acosd(-0.5), 120.0);
+        assert_eq!(acosd(0.6), 53.13010235415598);
+        assert!(acosd(-1.5).is_nan());
+    }
+}
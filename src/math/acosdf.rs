@@ -0,0 +1,11 @@
+use super::acosd;
+use super::fenv::narrow;
+
+/// Arccosine in degrees (f32)
+///
+/// Computes the arccosine of `x` in degrees. The computation is carried out in double
+/// precision, which also makes the exact cases of `acosd` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosdf(x: f32) -> f32 {
+    narrow(acosd(x as f64))
+}
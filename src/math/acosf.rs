@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_INVALID};
 use super::sqrtf::sqrtf;
 
 const PIO2_HI: f32 = 1.5707962513e+00; /* 0x3fc90fda */
@@ -51,6 +52,9 @@ pub fn acosf(x: f32) -> f32 {
             }
             return 0.;
         }
+        if !x.is_nan() {
+            feraiseexcept(FE_INVALID);
+        }
         return 0. / (x - x);
     }
     /* |x| < 0.5 */
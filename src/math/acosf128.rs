@@ -0,0 +1,161 @@
+/* acos(x) = atan2(sqrt(1 - x^2), x); within 1 ulp */
+
+use super::f128::{exponent, invalid, quiet_nan, BIAS};
+use super::{atan2f128, fmaf128, sqrtf128, F128};
+
+/// Arccosine (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if exponent(x) >= BIAS && x != F128::ONE && x != -F128::ONE {
+        /* |x| > 1 */
+        return invalid();
+    }
+    /* acos(x) = atan2(sqrt(1 - x^2), x), where 1 - x^2 is rounded once */
+    atan2f128(sqrtf128(fmaf128(-x, x, F128::ONE)), x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 0.5 and -0.75 */
+        assert!(
+            ulps(
+                acosf128(f(0x3ffe0000000000000000000000000000)),
+                0x3fff0c152382d73658465bb32e0f567b
+            ) <= 2
+        );
+        assert!(
+            ulps(
+                acosf128(f(0xbffe8000000000000000000000000000)),
+                0x4000359d26f93b6c32551ad5cf63b655
+            ) <= 2
+        );
+        assert_eq!(acosf128(F128::ONE), F128::ZERO);
+        assert_eq!(
+            acosf128(-F128::ONE).to_bits(),
+            0x4000921fb54442d18469898cc51701b8
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(acosf128(F128::from(2)).is_nan());
+        assert!(acosf128(F128::NEG_INFINITY).is_nan());
+        assert!(acosf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fefb9ac3dce29d6d73cfa63d30f0005,
+            0x3fff921dfb9805027f756bc60c11ac88,
+        ),
+        (
+            0x3ff8e1f7b54eedc74cbe2b3f179fe1dc,
+            0x3fff8e5bbcf3a3b682fd8d59de05a8b7,
+        ),
+        (
+            0x3ffca0bebcab71f5687c9fa4719cfa56,
+            0x3fff5daa12b84062c665532aae2afe15,
+        ),
+        (
+            0x3ffd91ce94284bf26425271445a6a7e5,
+            0x3fff2ae5c01a9b6671cbf4ea8cac9866,
+        ),
+        (
+            0xbfecb0547e8611cab85ba277eb142f69,
+            0x3fff921feb4ed2a24709a0cb110055c8,
+        ),
+        (
+            0xbfed1aca943a150ae78672b18a7663a0,
+            0x3fff921ffbf6e7e00a9250e26d5aa736,
+        ),
+        (
+            0xbfef083fe23e7a1a881e1bdf5dfbc06e,
+            0x3fff9220bd8425102d7112116fc4acbf,
+        ),
+        (
+            0xbfefca48214b7b0b088ee69112cc4f3d,
+            0x3fff92217f8c641df43ae4fa19079e6e,
+        ),
+        (
+            0xbfefff3de3fa0f902dfefb5c18521f8f,
+            0x3fff9221b48226cce7cb67f37949461f,
+        ),
+        (
+            0xbff00510d06eec8817412eeb11461351,
+            0x3fff9221bf65e3b0c779a171485d26a0,
+        ),
+        (
+            0xbff391bb88cd1e38cde6a3db7b540a04,
+            0x3fff9238d0fcd9f18f07dd0a083a5eab,
+        ),
+        (
+            0xbff4f336831e5889876a082d2e6f6678,
+            0x3fff925e1c1544ceda87d7d3a5b99a6d,
+        ),
+        (
+            0xbff5d9783b0d22691c027b58a8a4826d,
+            0x3fff929613573dcf17bf183112bffb42,
+        ),
+        (
+            0xbff6160e3ee28f6803382dd2eb3d9076,
+            0x3fff92aabc6a899a2314104c7218ebd0,
+        ),
+        (
+            0xbff6da7b849b5a03ac4c73b426ea8c77,
+            0x3fff930cf32885bd52a0d75c96aed68c,
+        ),
+        (
+            0xbff745a20fdd6ea530652acaab3ddd38,
+            0x3fff936557abf064a05949f0b5c1c7b9,
+        ),
+        (
+            0xbff7b2fcf294ba6f0c7d18d2e8a4e8db,
+            0x3fff93d2b3082950535a3d72a7d1799a,
+        ),
+        (
+            0xbff8a1aa97e256cfa066efb3f3c5c1a2,
+            0x3fff9563103e79dfdeea5fc2cd48d678,
+        ),
+        (
+            0xbff9397773acf681ea37e3e0b6fe2f2c,
+            0x3fff9705a6a913d632a60c8fca238e56,
+        ),
+        (
+            0xbff93f65fe390eaced166d05353157b1,
+            0x3fff971d61f55ebbe8a120a36763b2e7,
+        ),
+        (
+            0xbff9ec33f74af4ba144998e303280fd3,
+            0x3fff99d0d0f95a37f51b88e833024017,
+        ),
+        (
+            0xbffc3038e10cc7057a2dea7e3489bfcb,
+            0x3fffb84afb0559322db38c0ed7632d1f,
+        ),
+        (
+            0xbffd1a55420f0e4079eb1c718a38805c,
+            0x3fffd9a229f445a646d39d1e46041573,
+        ),
+        (
+            0xbffd49d52dc4f7d3b022c504bda0eafc,
+            0x3fffe614311425d0897e404c542f8ca3,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(acosf128, &REFERENCE, 1);
+    }
+}
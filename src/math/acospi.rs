@@ -0,0 +1,30 @@
+use super::{acos, div_pi};
+
+/// Arccosine divided by pi (f64)
+///
+/// Computes `acos(x) / pi`, the arccosine of `x` in half-revolutions, in the
+/// range `[0, 1]`. Returns NaN if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospi(x: f64) -> f64 {
+    if x == -1.0 {
+        return 1.0;
+    }
+    if x == 0.0 {
+        return 0.5;
+    }
+    div_pi(acos(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::acospi;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(acospi(1.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(acospi(-1.0), 1.0);
+        assert_eq!(acospi(0.0), 0.5);
+        assert_eq!(acospi(-0.0), 0.5);
+        assert!(acospi(-1.5).is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+use super::acospi;
+use super::fenv::narrow;
+
+/// Arccosine divided by pi (f32)
+///
+/// Computes the arccosine of `x` in half-revolutions. The computation is carried out in
+/// double precision, which also makes the exact cases of `acospi` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospif(x: f32) -> f32 {
+    narrow(acospi(x as f64))
+}
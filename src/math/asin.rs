@@ -39,6 +39,7 @@
  *
  */
 
+use super::fenv::{feraiseexcept, FE_INVALID};
 use super::{fabs, get_high_word, get_low_word, sqrt, with_set_low_word};
 
 const PIO2_HI: f64 = 1.57079632679489655800e+00; /* 0x3FF921FB, 0x54442D18 */
@@ -84,6 +85,9 @@ pub fn asin(mut x: f64) -> f64 {
             /* asin(1) = +-pi/2 with inexact */
             return x * PIO2_HI + f64::from_bits(0x3870000000000000);
         } else {
+            if !x.is_nan() {
+                feraiseexcept(FE_INVALID);
+            }
             return 0.0 / (x - x);
         }
     }
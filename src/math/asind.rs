@@ -0,0 +1,34 @@
+use super::{asin, rad_to_deg};
+
+/// Arcsine in degrees (f64)
+///
+/// Computes `asin(x) * 180/pi`, the arcsine of `x` in degrees, in the range
+/// `[-90, 90]`. Returns NaN if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asind(x: f64) -> f64 {
+    if x == 1.0 || x == -1.0 {
+        return x * 90.0;
+    }
+    if x == 0.5 || x == -0.5 {
+        return x * 60.0;
+    }
+    rad_to_deg(asin(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::asind;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(asind(1.0), 90.0);
+        assert_eq!(asind(-1.0), -90.0);
+        assert_eq!(asind(0.5), 30.0);
+        assert_eq!(asind(-0.5), -30.0);
+        assert_eq!(asind(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(asind(0.6), 36.86989764584402);
+        assert!(asind(1.5).is_nan());
+        assert!(asind(NAN).is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+use super::asind;
+use super::fenv::narrow;
+
+/// Arcsine in degrees (f32)
+///
+/// Computes the arcsine of `x` in degrees. The computation is carried out in double
+/// precision, which also makes the exact cases of `asind` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asindf(x: f32) -> f32 {
+    narrow(asind(x as f64))
+}
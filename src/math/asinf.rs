@@ -14,6 +14,7 @@
  */
 
 use super::fabsf::fabsf;
+use super::fenv::{feraiseexcept, FE_INVALID};
 use super::sqrt::sqrt;
 
 const PIO2: f64 = 1.570796326794896558e+00;
@@ -48,6 +49,9 @@ pub fn asinf(mut x: f32) -> f32 {
             /* |x| == 1 */
             return ((x as f64) * PIO2 + x1p_120) as f32; /* asin(+-1) = +-pi/2 with inexact */
         }
+        if !x.is_nan() {
+            feraiseexcept(FE_INVALID);
+        }
         return 0. / (x - x); /* asin(|x|>1) is NaN */
     }
 
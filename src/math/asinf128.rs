@@ -0,0 +1,168 @@
+/* asin(x) = atan2(x, sqrt(1 - x^2)); within 2 ulp */
+
+use super::f128::{exponent, inexact_tiny, invalid, quiet_nan, BIAS};
+use super::{atan2f128, fmaf128, sqrtf128, F128};
+
+/// Arcsine (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if exponent(x) >= BIAS && x != F128::ONE && x != -F128::ONE {
+        /* |x| > 1 */
+        return invalid();
+    }
+    if exponent(x) < BIAS - 57 {
+        /* |x| < 2^-57 */
+        return inexact_tiny(x);
+    }
+    /* asin(x) = atan2(x, sqrt(1 - x^2)), where 1 - x^2 is rounded once */
+    atan2f128(x, sqrtf128(fmaf128(-x, x, F128::ONE)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 0.5 and -0.75 */
+        assert!(
+            ulps(
+                asinf128(f(0x3ffe0000000000000000000000000000)),
+                0x3ffe0c152382d73658465bb32e0f567b
+            ) <= 2
+        );
+        assert!(
+            ulps(
+                asinf128(f(0xbffe8000000000000000000000000000)),
+                0xbffeb235315c680dc081583db360d5e2
+            ) <= 2
+        );
+        assert_eq!(
+            asinf128(F128::ONE).to_bits(),
+            0x3fff921fb54442d18469898cc51701b8
+        );
+        assert_eq!(
+            asinf128(-F128::ONE).to_bits(),
+            0x3fff921fb54442d18469898cc51701b8 | 1 << 127
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(asinf128(F128::from(2)).is_nan());
+        assert!(asinf128(F128::NEG_INFINITY).is_nan());
+        assert!(asinf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fec310c5e0431349a2fc69ca93ef456,
+            0x3fec310c5e0432555c58878f5bb4de15,
+        ),
+        (
+            0x3fef47d1b8fe150a6ba17f7831ebeba0,
+            0x3fef47d1b8fe6ea21f9fae5da68d1016,
+        ),
+        (
+            0x3ff278f3e2f0670001a5573c3a5047ce,
+            0x3ff278f3e31274d92fffbfad41583e5f,
+        ),
+        (
+            0x3ff3d66da8f389272d4f6d7cb18ec7f5,
+            0x3ff3d66da9fc4b3d7e6c57f7c2121c2d,
+        ),
+        (
+            0x3ffaf10aefd9a7f697fcd08a6ae32939,
+            0x3ffaf15923382dbf3b8b5e0d642b7e7b,
+        ),
+        (
+            0x3ffb0f5d96ff7f62fc1aa33b4fd9191c,
+            0x3ffb0f9082a2d1a2b5a1ec883fe0d10d,
+        ),
+        (
+            0x3ffb76c7896b6cd2535e8294ac2ece8b,
+            0x3ffb774deaea54c4e87d20b075a4e61b,
+        ),
+        (
+            0x3ffdbc4bc673147094f8c6657304ee58,
+            0x3ffdcb91ae7c1f14ad2e6df5511d2374,
+        ),
+        (
+            0x3ffeb47f98d0170027ff95e3bf58772e,
+            0x3fff0554587be825995579f7e186a779,
+        ),
+        (
+            0x3ffed7672fb910a82dc22f9bbca54889,
+            0x3fff2b7e27899c2354086febce1240cd,
+        ),
+        (
+            0xbfebaec9b5dcd40c72e23145164beea4,
+            0xbfebaec9b5dcd4d7c252aa29aaeac9fe,
+        ),
+        (
+            0xbfec3a694b618b9336c268ece86fcc44,
+            0xbfec3a694b618ccf62c4605a63b0322c,
+        ),
+        (
+            0xbfedd6de72e84a1c73f6180b464c9d88,
+            0xbfedd6de72e85ab47f0c77af7bb1b135,
+        ),
+        (
+            0xbfedef55bba729efdb10ccdee4dffc7c,
+            0xbfedef55bba73d41134172f2b858053f,
+        ),
+        (
+            0xbfedfba12deaf7f929514647b35ffc56,
+            0xbfedfba12deb0cc3d5205c662da8c1c6,
+        ),
+        (
+            0xbff302026ce59b70ec082568fab88c46,
+            0xbff302026d1149585468e23960fc9d3f,
+        ),
+        (
+            0xbff50fabf895b9ca05de263f7d41a039,
+            0xbff50fabfbc5994094295ca5fe8eac99,
+        ),
+        (
+            0xbff601ef4ed4b108f87ab16d07905a6a,
+            0xbff601ef59bdbee4b5c5348ce0446684,
+        ),
+        (
+            0xbffa098119cc9da3d9868635e3dc0c58,
+            0xbffa098d0179bee47dc5927bc9454eaa,
+        ),
+        (
+            0xbffa4b3857b9cabe89328b2b5d65d6ab,
+            0xbffa4b4f76519b8c95ec568256a49092,
+        ),
+        (
+            0xbffb0f5e5f47952d472fc64c2237f147,
+            0xbffb0f914b5bccc5461d01fe45882c27,
+        ),
+        (
+            0xbffb2ded66ec6f2e09d29d8df23fbc70,
+            0xbffb2e3391fa796f9222511c15a07f40,
+        ),
+        (
+            0xbffcf1bfc530226249e724f6c7b29aa6,
+            0xbffcf6c8cea31e0d2d8adbc7cd821e5b,
+        ),
+        (
+            0xbffdacbdcede01e8e4a7f4b14d28c7eb,
+            0xbffdba5fa030266c82850d997c81f18b,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(asinf128, &REFERENCE, 2);
+    }
+}
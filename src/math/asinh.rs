@@ -1,3 +1,4 @@
+use super::fenv::{feraiseexcept, FE_INEXACT};
 use super::{log, log1p, sqrt};
 
 const LN2: f64 = 0.693147180559945309417232121458176568; /* 0x3fe62e42,  0xfefa39ef*/
@@ -27,8 +28,9 @@ pub fn asinh(mut x: f64) -> f64 {
         x = log1p(x + x * x / (sqrt(x * x + 1.0) + 1.0));
     } else {
         /* |x| < 0x1p-26, raise inexact if x != 0 */
-        let x1p120 = f64::from_bits(0x4770000000000000);
-        force_eval!(x + x1p120);
+        if x != 0.0 {
+            feraiseexcept(FE_INEXACT);
+        }
     }
 
     if sign {
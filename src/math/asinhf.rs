@@ -1,3 +1,4 @@
+use super::fenv::{feraiseexcept, FE_INEXACT};
 use super::{log1pf, logf, sqrtf};
 
 const LN2: f32 = 0.693147180559945309417232121458176568;
@@ -26,8 +27,9 @@ pub fn asinhf(mut x: f32) -> f32 {
         x = log1pf(x + x * x / (sqrtf(x * x + 1.0) + 1.0));
     } else {
         /* |x| < 0x1p-12, raise inexact if x!=0 */
-        let x1p120 = f32::from_bits(0x7b800000);
-        force_eval!(x + x1p120);
+        if x != 0.0 {
+            feraiseexcept(FE_INEXACT);
+        }
     }
 
     if sign {
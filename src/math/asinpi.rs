@@ -0,0 +1,28 @@
+use super::{asin, div_pi};
+
+/// Arcsine divided by pi (f64)
+///
+/// Computes `asin(x) / pi`, the arcsine of `x` in half-revolutions, in the
+/// range `[-0.5, 0.5]`. Returns NaN if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpi(x: f64) -> f64 {
+    if x == 1.0 || x == -1.0 {
+        return x * 0.5;
+    }
+    div_pi(asin(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::asinpi;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(asinpi(1.0), 0.5);
+        assert_eq!(asinpi(-1.0), -0.5);
+        assert_eq!(asinpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(asinpi(1.5).is_nan());
+        assert!(asinpi(NAN).is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+use super::asinpi;
+use super::fenv::narrow;
+
+/// Arcsine divided by pi (f32)
+///
+/// Computes the arcsine of `x` in half-revolutions. The computation is carried out in
+/// double precision, which also makes the exact cases of `asinpi` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpif(x: f32) -> f32 {
+    narrow(asinpi(x as f64))
+}
@@ -30,6 +30,7 @@
  */
 
 use super::fabs;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_UNDERFLOW};
 use core::f64;
 
 const ATANHI: [f64; 4] = [
@@ -83,9 +84,9 @@ pub fn atan(x: f64) -> f64 {
         /* |x| < 0.4375 */
         if ix < 0x3e40_0000 {
             /* |x| < 2^-27 */
-            if ix < 0x0010_0000 {
+            if ix < 0x0010_0000 && x != 0.0 {
                 /* raise underflow for subnormal x */
-                force_eval!(x as f32);
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             }
 
             return x;
@@ -0,0 +1,65 @@
+use super::{atan2, fabs, rad_to_deg};
+
+/// Arctangent of y/x in degrees (f64)
+///
+/// Computes `atan2(y, x) * 180/pi`, the angle of the point `(x, y)` in
+/// degrees, in the range `[-180, 180]`. Results that are multiples of 45, such
+/// as those for zero or infinite arguments or for `|y| == |x|`, are exact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2d(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let ax = fabs(x);
+    let ay = fabs(y);
+    let negx = x.is_sign_negative();
+    /* the result has the sign of y */
+    let signed = |r: f64| if y.is_sign_negative() { -r } else { r };
+
+    if ay == ax {
+        return signed(match (ax == 0.0, negx) {
+            (true, false) => 0.0,
+            (true, true) => 180.0,
+            (false, false) => 45.0,
+            (false, true) => 135.0,
+        });
+    }
+    if ay == 0.0 || ax.is_infinite() {
+        return signed(if negx { 180.0 } else { 0.0 });
+    }
+    if ax == 0.0 || ay.is_infinite() {
+        return signed(90.0);
+    }
+    rad_to_deg(atan2(y, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atan2d;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(atan2d(0.0, 0.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(atan2d(-0.0, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(atan2d(0.0, -0.0), 180.0);
+        assert_eq!(atan2d(-0.0, -0.0), -180.0);
+        assert_eq!(atan2d(0.0, -2.0), 180.0);
+        assert_eq!(atan2d(-3.0, 0.0), -90.0);
+        assert_eq!(atan2d(2.0, 2.0), 45.0);
+        assert_eq!(atan2d(-2.0, -2.0), -135.0);
+        assert_eq!(atan2d(INFINITY, NEG_INFINITY), 135.0);
+        assert_eq!(atan2d(NEG_INFINITY, INFINITY), -45.0);
+        assert_eq!(atan2d(1.0, NEG_INFINITY), 180.0);
+        assert_eq!(atan2d(-1.0, INFINITY).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(atan2d(INFINITY, -1.0), 90.0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(atan2d(-3.0, -4.0), -143.13010235415598);
+        assert!(atan2d(NAN, 1.0).is_nan());
+        assert!(atan2d(1.0, NAN).is_nan());
+    }
+}
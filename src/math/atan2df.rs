@@ -0,0 +1,12 @@
+use super::atan2d;
+use super::fenv::narrow;
+
+/// Arctangent of y/x in degrees (f32)
+///
+/// Computes `atan2(y, x) * 180/pi`, the angle of the point `(x, y)` in
+/// degrees. The computation is carried out in double precision, which also
+/// makes the exact cases of `atan2d` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2df(y: f32, x: f32) -> f32 {
+    narrow(atan2d(y as f64, x as f64))
+}
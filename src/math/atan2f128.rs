@@ -0,0 +1,205 @@
+/* The quadrants of the f64 atan2, around atanf128(|y/x|); within 1 ulp */
+
+use super::atanf128::{PIO2_HI, PIO2_LO};
+use super::f128::{exponent, is_zero, quiet_nan, SIGN_MASK};
+use super::{atanf128, F128};
+
+/* pi, pi/4 and 3pi/4 as pairs hi, lo */
+const PI_HI: F128 = F128::from_bits(0x4000921fb54442d18469898cc51701b8);
+const PI_LO: F128 = F128::from_bits(0x3f8dcd129024e088a67cc74020bbea64);
+const PIO4_HI: F128 = F128::from_bits(0x3ffe921fb54442d18469898cc51701b8);
+const PIO4_LO: F128 = F128::from_bits(0x3f8bcd129024e088a67cc74020bbea64);
+const PI3O4_HI: F128 = F128::from_bits(0x40002d97c7f3321d234f272993d1414a);
+const PI3O4_LO: F128 = F128::from_bits(0x3f8d59cdec1ba8667cdd9570188cefcb);
+
+/// Arctangent of y/x (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2f128(y: F128, x: F128) -> F128 {
+    if x.is_nan() || y.is_nan() {
+        return quiet_nan(y, x);
+    }
+    if x == F128::ONE {
+        return atanf128(y);
+    }
+    /* 2*sign(x)+sign(y) */
+    let m = (y.is_sign_negative() as u32) | (x.is_sign_negative() as u32) << 1;
+    let ex = exponent(x);
+    let ey = exponent(y);
+
+    /* when y = 0 */
+    if is_zero(y) {
+        return match m {
+            0 | 1 => y,            /* atan(+-0,+anything)=+-0 */
+            2 => PI_HI + PI_LO,    /* atan(+0,-anything) = PI */
+            _ => -(PI_HI + PI_LO), /* atan(-0,-anything) =-PI */
+        };
+    }
+    let pio2 = PIO2_HI + PIO2_LO;
+    /* when x = 0 */
+    if is_zero(x) {
+        return if m & 1 != 0 { -pio2 } else { pio2 };
+    }
+    /* when x is INF */
+    if x.is_infinite() {
+        if y.is_infinite() {
+            return match m {
+                0 => PIO4_HI + PIO4_LO,      /* atan(+INF,+INF) */
+                1 => -(PIO4_HI + PIO4_LO),   /* atan(-INF,+INF) */
+                2 => PI3O4_HI + PI3O4_LO,    /* atan(+INF,-INF) */
+                _ => -(PI3O4_HI + PI3O4_LO), /* atan(-INF,-INF) */
+            };
+        } else {
+            return match m {
+                0 => F128::ZERO,       /* atan(+...,+INF) */
+                1 => -F128::ZERO,      /* atan(-...,+INF) */
+                2 => PI_HI + PI_LO,    /* atan(+...,-INF) */
+                _ => -(PI_HI + PI_LO), /* atan(-...,-INF) */
+            };
+        }
+    }
+    /* |y/x| > 0x1p120 */
+    if ex + 120 < ey || y.is_infinite() {
+        return if m & 1 != 0 { -pio2 } else { pio2 };
+    }
+
+    /* z = atan(|y/x|) without spurious underflow */
+    let z = if (m & 2 != 0) && ey + 120 < ex {
+        /* |y/x| < 0x1p-120, x<0 */
+        F128::ZERO
+    } else {
+        atanf128(F128::from_bits((y / x).to_bits() & !SIGN_MASK))
+    };
+    match m {
+        0 => z,                   /* atan(+,+) */
+        1 => -z,                  /* atan(-,+) */
+        2 => PI_HI - (z - PI_LO), /* atan(+,-) */
+        _ => (z - PI_LO) - PI_HI, /* atan(-,-) */
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference2, ulps};
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        /* atan2(1, -1) = 3pi/4 and atan2(-2, 3) */
+        assert!(
+            ulps(
+                atan2f128(F128::ONE, -F128::ONE),
+                0x40002d97c7f3321d234f272993d1414a
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                atan2f128(F128::from(-2), F128::from(3)),
+                0xbffe2d0ead60663956d2220a2c148bdd
+            ) <= 1
+        );
+        assert_eq!(atan2f128(F128::ONE, F128::ONE), atanf128(F128::ONE));
+    }
+
+    #[test]
+    fn special_values() {
+        let pi = 0x4000921fb54442d18469898cc51701b8;
+        assert_eq!(atan2f128(F128::ZERO, -F128::ONE).to_bits(), pi);
+        assert_eq!(atan2f128(-F128::ZERO, -F128::ZERO).to_bits(), pi | 1 << 127);
+        assert_eq!(atan2f128(-F128::ZERO, F128::ONE).to_bits(), 1 << 127);
+        assert_eq!(atan2f128(F128::ONE, F128::ZERO), PIO2_HI);
+        assert_eq!(
+            atan2f128(F128::INFINITY, F128::NEG_INFINITY).to_bits(),
+            0x40002d97c7f3321d234f272993d1414a
+        );
+        assert_eq!(atan2f128(F128::ONE, F128::INFINITY), F128::ZERO);
+        assert!(atan2f128(F128::NAN, F128::ONE).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128, u128); 16] = [
+        (
+            0x3ff573d7e67eb1ee5a217841ee39f70d,
+            0x3ffe9b4abc4e10ac38548214b7bd278d,
+            0x3ff5cee4432f8d9535ecf5b8a1f67b75,
+        ),
+        (
+            0x3ffa0041136ec98a4b9a5bfefef93699,
+            0xc0041267c66b8b31c8a2cbb882adb067,
+            0x40009201d325b3c01dddaab6d3c944a9,
+        ),
+        (
+            0x3ffa5a5261b293d14dbc4f16bec322b6,
+            0xbff9ce06f0b378219878af20d3aa3691,
+            0x4000145c3106176ad62bd40e7a8e85f1,
+        ),
+        (
+            0x3ffae479b97802b599036e49ebbcfff0,
+            0x3ff853ec2564713bc119ccc504279719,
+            0x3fff65abf8f376813b32e53d774bbada,
+        ),
+        (
+            0x40057a304d7fd7e7318dc2a0ebfc3188,
+            0x40038b1d5a66b07c499918f2eb49e21c,
+            0x3fff50b87c40cf670fe7327ad011fad8,
+        ),
+        (
+            0x40071cdc0c1b14835001a8c91482626a,
+            0x3ff65b57498032d60cd6dd890f234733,
+            0x3fff921f1930e46b3b60262a785a53fb,
+        ),
+        (
+            0x400842195e5e2d8d640328b19bf45296,
+            0x3ff91379bc1b94cc6ceb9a8d1ad510e5,
+            0x3fff921dff60d2420a60856568f4d576,
+        ),
+        (
+            0xbff5e0384ca71f0000ea084cd465913e,
+            0xbff7ec3ab4455f37837dfcfe8b0ef849,
+            0xc0007380a06fc6231f01e4e9e8c25640,
+        ),
+        (
+            0xbff912a55bb6cec00ee91a7403d0135d,
+            0xbff54c6dc7353773ba71c553b4a2e1dd,
+            0xbfffa5740ad827142e3cd867ac001262,
+        ),
+        (
+            0xbffccbb46b333965e4a2f850e4a9a657,
+            0xbffc86621b8e3d1a1eb01db3b45527e0,
+            0xc000232d924c6e29b09390308d2b3717,
+        ),
+        (
+            0xbffeb067801c0f7d1862470459b64823,
+            0xc002e2993524da2b5d5f69cf2783795f,
+            0xc0008af6a19bd23bc9f6b4130060d29a,
+        ),
+        (
+            0xbfffe4d9867868b67bb9a74e4db675e7,
+            0xbffadf924bd01acd248c87f4cb68a9b8,
+            0xbfff9a08c4a167a3094463b0521c9f15,
+        ),
+        (
+            0xc00008f8399a5a582baefbbd15ecf8ec,
+            0x4008e61f6c9600693a037c1e9b901629,
+            0xbff61712f4f7bed2112f9ad4d25dac09,
+        ),
+        (
+            0xc0007b9a61b3670701b95d51155b2009,
+            0xc003bae5be1fc321d21d94d2233914e7,
+            0xc000847668208cd69a723e6f91e849e8,
+        ),
+        (
+            0xc001b8c091003d545a4f0aba87dbe82e,
+            0x4009d7005bb2e4d632ecef820218749f,
+            0xbff6df1d9951574b15853db4df0809cf,
+        ),
+        (
+            0xc009228b8bf2644fd0cc66b8ca7b7347,
+            0xbff9a9c4f3adbf490c11b8460a221677,
+            0xbfff92212c69bdbc8fa4a27b5e4bbe17,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference2(atan2f128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,65 @@
+use super::{atan2, div_pi, fabs};
+
+/// Arctangent of y/x divided by pi (f64)
+///
+/// Computes `atan2(y, x) / pi`, the angle of the point `(x, y)` in
+/// half-revolutions, in the range `[-1, 1]`. Results that are multiples of
+/// 1/4, such as those for zero or infinite arguments or for `|y| == |x|`, are
+/// exact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pi(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+
+    let ax = fabs(x);
+    let ay = fabs(y);
+    let negx = x.is_sign_negative();
+    /* the result has the sign of y */
+    let signed = |r: f64| if y.is_sign_negative() { -r } else { r };
+
+    if ay == ax {
+        return signed(match (ax == 0.0, negx) {
+            (true, false) => 0.0,
+            (true, true) => 1.0,
+            (false, false) => 0.25,
+            (false, true) => 0.75,
+        });
+    }
+    if ay == 0.0 || ax.is_infinite() {
+        return signed(if negx { 1.0 } else { 0.0 });
+    }
+    if ax == 0.0 || ay.is_infinite() {
+        return signed(0.5);
+    }
+    div_pi(atan2(y, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atan2pi;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(atan2pi(0.0, 0.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(atan2pi(-0.0, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(atan2pi(0.0, -0.0), 1.0);
+        assert_eq!(atan2pi(-0.0, -0.0), -1.0);
+        assert_eq!(atan2pi(0.0, -2.0), 1.0);
+        assert_eq!(atan2pi(-3.0, 0.0), -0.5);
+        assert_eq!(atan2pi(2.0, 2.0), 0.25);
+        assert_eq!(atan2pi(-2.0, -2.0), -0.75);
+        assert_eq!(atan2pi(INFINITY, NEG_INFINITY), 0.75);
+        assert_eq!(atan2pi(NEG_INFINITY, INFINITY), -0.25);
+        assert_eq!(atan2pi(1.0, NEG_INFINITY), 1.0);
+        assert_eq!(atan2pi(-1.0, INFINITY).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(atan2pi(INFINITY, -1.0), 0.5);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert!(atan2pi(NAN, 1.0).is_nan());
+        assert!(atan2pi(1.0, NAN).is_nan());
+    }
+}
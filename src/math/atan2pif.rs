@@ -0,0 +1,12 @@
+use super::atan2pi;
+use super::fenv::narrow;
+
+/// Arctangent of y/x divided by pi (f32)
+///
+/// Computes `atan2(y, x) / pi`, the angle of the point `(x, y)` in
+/// half-revolutions. The computation is carried out in double precision,
+/// which also makes the exact cases of `atan2pi` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pif(y: f32, x: f32) -> f32 {
+    narrow(atan2pi(y as f64, x as f64))
+}
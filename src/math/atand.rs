@@ -0,0 +1,33 @@
+use super::{atan, rad_to_deg};
+
+/// Arctangent in degrees (f64)
+///
+/// Computes `atan(x) * 180/pi`, the arctangent of `x` in degrees, in the range
+/// `[-90, 90]`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atand(x: f64) -> f64 {
+    if x.is_infinite() {
+        return if x > 0.0 { 90.0 } else { -90.0 };
+    }
+    if x == 1.0 || x == -1.0 {
+        return x * 45.0;
+    }
+    rad_to_deg(atan(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atand;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(atand(1.0), 45.0);
+        assert_eq!(atand(-1.0), -45.0);
+        assert_eq!(atand(0.75), 36.86989764584402);
+        assert_eq!(atand(INFINITY), 90.0);
+        assert_eq!(atand(NEG_INFINITY), -90.0);
+        assert_eq!(atand(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(atand(NAN).is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+use super::atand;
+use super::fenv::narrow;
+
+/// Arctangent in degrees (f32)
+///
+/// Computes the arctangent of `x` in degrees. The computation is carried out in double
+/// precision, which also makes the exact cases of `atand` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atandf(x: f32) -> f32 {
+    narrow(atand(x as f64))
+}
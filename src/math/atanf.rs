@@ -14,6 +14,7 @@
  */
 
 use super::fabsf;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_UNDERFLOW};
 
 const ATAN_HI: [f32; 4] = [
     4.6364760399e-01, /* atan(0.5)hi 0x3eed6338 */
@@ -63,9 +64,9 @@ pub fn atanf(mut x: f32) -> f32 {
         /* |x| < 0.4375 */
         if ix < 0x39800000 {
             /* |x| < 2**-12 */
-            if ix < 0x00800000 {
+            if ix < 0x00800000 && ix != 0 {
                 /* raise underflow for subnormal x */
-                force_eval!(x * x);
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             }
             return x;
         }
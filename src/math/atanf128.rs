@@ -0,0 +1,261 @@
+/* atan(x) = atan(j/8) + atan(t), from a table and a series; within 1 ulp */
+
+use super::f128::{exponent, inexact_tiny, quiet_nan, to_int, two_sum, BIAS, SIGN_MASK};
+use super::{fmaf128, roundf128, F128};
+
+/* atan(j/8) for j = 1..8, as pairs hi, lo */
+const ATAN_TBL: [u128; 16] = [
+    0x3ffbfd5ba9aac2f6dc65912f313e7d11,
+    0x3f87def1672afb2bb35b245d926aefbf,
+    0x3ffcf5b75f92c80dd62adb8f3debef44,
+    0x3f897e5aa7fa90388b3836b7a3a767c9,
+    0x3ffd6f61941e4def08e715464245b9fd,
+    0xbf8bdbe613de34428d5d519dc660d1ae,
+    0x3ffddac670561bb4f68adfc88bd97875,
+    0x3f89a06dc282b0e4c39be01c59e2dcdd,
+    0x3ffe1e00babdefeb3f36b906bc2ccb88,
+    0x3f8cba3c8c533f032959bae7a9ed2369,
+    0x3ffe4978fa3269ee12483350fe548afb,
+    0x3f8c64f71f84344f7595cf390a43332c,
+    0x3ffe700a7c5784633ce7965b4aa42149,
+    0xbf8cde16142899f759c32bfaff6f5505,
+    0x3ffe921fb54442d18469898cc51701b8,
+    0x3f8bcd129024e088a67cc74020bbea64,
+];
+
+/* (-1)^n/(2n+1) for n = 1..15, enough for |t| <= 1/16 */
+const AT: [u128; 15] = [
+    0xbffd5555555555555555555555555555, /* -1/3 */
+    0x3ffc999999999999999999999999999a, /* 1/5 */
+    0xbffc2492492492492492492492492492, /* -1/7 */
+    0x3ffbc71c71c71c71c71c71c71c71c71c, /* 1/9 */
+    0xbffb745d1745d1745d1745d1745d1746, /* -1/11 */
+    0x3ffb3b13b13b13b13b13b13b13b13b14, /* 1/13 */
+    0xbffb1111111111111111111111111111, /* -1/15 */
+    0x3ffae1e1e1e1e1e1e1e1e1e1e1e1e1e2, /* 1/17 */
+    0xbffaaf286bca1af286bca1af286bca1b, /* -1/19 */
+    0x3ffa8618618618618618618618618618, /* 1/21 */
+    0xbffa642c8590b21642c8590b21642c86, /* -1/23 */
+    0x3ffa47ae147ae147ae147ae147ae147b, /* 1/25 */
+    0xbffa2f684bda12f684bda12f684bda13, /* -1/27 */
+    0x3ffa1a7b9611a7b9611a7b9611a7b961, /* 1/29 */
+    0xbffa0842108421084210842108421084, /* -1/31 */
+];
+
+/* pi/2 as a pair hi, lo */
+pub(crate) const PIO2_HI: F128 = F128::from_bits(0x3fff921fb54442d18469898cc51701b8);
+pub(crate) const PIO2_LO: F128 = F128::from_bits(0x3f8ccd129024e088a67cc74020bbea64);
+
+/* atan(t) - t for |t| <= 1/16 */
+#[inline]
+fn atan_tail(t: F128) -> F128 {
+    let z = t * t;
+    let mut p = F128::from_bits(AT[AT.len() - 1]);
+    for &c in AT.iter().rev().skip(1) {
+        p = p * z + F128::from_bits(c);
+    }
+    t * z * p
+}
+
+/// Arctangent (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanf128(x: F128) -> F128 {
+    let ax = F128::from_bits(x.to_bits() & !SIGN_MASK);
+    let e = exponent(x);
+
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if e < BIAS - 57 {
+        /* |x| < 2^-57 */
+        return inexact_tiny(x);
+    }
+    let z = if e >= BIAS + 114 {
+        /* |x| >= 2^114, also inf */
+        PIO2_HI + PIO2_LO
+    } else if ax <= F128::ONE {
+        /* atan(x) = atan(c) + atan(t), with c = j/8 the nearest to x and
+         * t = (x - c)/(1 + x*c) */
+        let j = to_int(roundf128(ax * F128::from(8)));
+        let c = F128::from(j) / F128::from(8);
+        let t = (ax - c) / fmaf128(ax, c, F128::ONE);
+        if j == 0 {
+            t + atan_tail(t)
+        } else {
+            let i = (2 * (j as usize - 1)) & 15;
+            F128::from_bits(ATAN_TBL[i]) + (F128::from_bits(ATAN_TBL[i + 1]) + (t + atan_tail(t)))
+        }
+    } else {
+        /* atan(x) = pi/2 - atan(1/x), where atan(1/x) = atan(c) + atan(t) with
+         * c = j/8 the nearest to 1/x and t = (1 - x*c)/(x + c) */
+        let j = to_int(roundf128(F128::from(8) / ax));
+        let c = F128::from(j) / F128::from(8);
+        let t = fmaf128(-ax, c, F128::ONE) / (ax + c);
+        if j == 0 {
+            PIO2_HI - ((t + atan_tail(t)) - PIO2_LO)
+        } else {
+            let i = (2 * (j as usize - 1)) & 15;
+            let (hi, lo) = two_sum(PIO2_HI, -F128::from_bits(ATAN_TBL[i]));
+            hi + (lo + (PIO2_LO - (F128::from_bits(ATAN_TBL[i + 1]) + (t + atan_tail(t)))))
+        }
+    };
+    if x.is_sign_negative() {
+        -z
+    } else {
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1, 0.5, -3, 1e6 and 0.0625 */
+        assert!(ulps(atanf128(F128::ONE), 0x3ffe921fb54442d18469898cc51701b8) <= 1);
+        assert!(
+            ulps(
+                atanf128(f(0x3ffe0000000000000000000000000000)),
+                0x3ffddac670561bb4f68adfc88bd97875
+            ) <= 1
+        );
+        assert!(ulps(atanf128(F128::from(-3)), 0xbfff3fc176b7a855ffd77cb88581def9) <= 1);
+        assert!(
+            ulps(
+                atanf128(F128::from(1000000)),
+                0x3fff921fa47d4b30ce822275563fcb9a
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                atanf128(f(0x3ffb0000000000000000000000000000)),
+                0x3ffaff55bb72cfde9c6d964f25b81c5c
+            ) <= 1
+        );
+        let z = atanf128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(
+            atanf128(F128::INFINITY).to_bits(),
+            0x3fff921fb54442d18469898cc51701b8
+        );
+        assert_eq!(
+            atanf128(F128::NEG_INFINITY).to_bits(),
+            0xbfff921fb54442d18469898cc51701b8
+        );
+        assert!(atanf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3ff51628578ee9bcbcbb8fb80f721b6f,
+            0x3ff5162850b77d431131551bb940eba3,
+        ),
+        (
+            0x3ff52aea3a76183cef485079469b7b0c,
+            0x3ff52aea31f896012b0b618938ba4721,
+        ),
+        (
+            0x3ff672308b0bb9d60362860569831b25,
+            0x3ff672304a89d59b9bd6e947e5c97b04,
+        ),
+        (
+            0x3ff7a4d57da57c8a0cea6c57b87654f3,
+            0x3ff7a4d402935a5db8d7bf50e064986a,
+        ),
+        (
+            0x3ffb384bc50bb295050b4014275fa0b0,
+            0x3ffb37b16425dd027f7b84e5eee4acda,
+        ),
+        (
+            0x3ffb934c4236d27b1613d1ae0f77411f,
+            0x3ffb92008c117733b1cee115200cc0ca,
+        ),
+        (
+            0x3ffc5474f377773a6bfebad807031754,
+            0x3ffc515f230152c9d7562dc597897de7,
+        ),
+        (
+            0x3ffc6e2b50c7b58db995a24e3276f9a6,
+            0x3ffc6a57301dfdcc9977c166300547df,
+        ),
+        (
+            0x3ffca96a89f8b11290505e6fbd0544f0,
+            0x3ffca3737d3ac3074d392ccb88ad53ff,
+        ),
+        (
+            0x3ffe4045f69fd4177b2287613cfb0e7f,
+            0x3ffe1e330733cb70c6f128227d3af485,
+        ),
+        (
+            0x3ffe97fcc60112327a5bdc3d24a3dc96,
+            0x3ffe587b88c4302dfa27307c6a25ab16,
+        ),
+        (
+            0x3ffee0e9ae6a2fe0823e380ca53f03bf,
+            0x3ffe82194e1796d2aa27c8571083d862,
+        ),
+        (
+            0x3ffef7c410d48a71e1d64e0602d39f25,
+            0x3ffe8df9386dec7494f29bb2d6bfc4b5,
+        ),
+        (
+            0x40024e43298179c3a812544d37546bf0,
+            0x3fff79b0ccc78d7900073baa889d2b57,
+        ),
+        (
+            0xbff775d1acab7cae5e8b49b5c916c1d7,
+            0xbff775d0a2faf1815c57c2db0f8cfefb,
+        ),
+        (
+            0xbff918dd914f2678f9ea1d77d1032634,
+            0xbff918d6868d1f7fa4b3de632b7e60fd,
+        ),
+        (
+            0xbffb1dc78b1d8400498870a80dfabf08,
+            0xbffb1d512d7d4cd9b8a4db77b428bce1,
+        ),
+        (
+            0xbffbdd54dc29b9d598ec7dd637093fe6,
+            0xbffbdb3027546921b35f9d7203ed1703,
+        ),
+        (
+            0xbffc618228b44108676637755ec06b8a,
+            0xbffc5e0f1a3d4e0593bf56f8e2cacde5,
+        ),
+        (
+            0xbfff060a5787598a9ea98dfda2bd75b9,
+            0xbffe9817f342d4df4e6b7158f7c0fa6e,
+        ),
+        (
+            0xc00003f06e099eeb7dc5bef391435716,
+            0xbfff1cfc8f6f52c63cbf9dd7238af409,
+        ),
+        (
+            0xc001761a6a654ba6d355280f3ff5a8b1,
+            0xbfff66bf94770debc665f1c4713e5525,
+        ),
+        (
+            0xc0063bff987677445b54cd072167caaa,
+            0xbfff9080ed3e00f458dc444e0091e648,
+        ),
+        (
+            0xc006b96265a5e1711ac52c4cd9ded6f3,
+            0xbfff90f6c0e5b2581c3f64f0bf9a8497,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(atanf128, &REFERENCE, 1);
+    }
+}
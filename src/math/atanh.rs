@@ -1,3 +1,4 @@
+use super::fenv::{feraiseexcept, FE_DIVBYZERO, FE_INEXACT, FE_UNDERFLOW};
 use super::log1p;
 
 /* atanh(x) = log((1+x)/(1-x))/2 = log1p(2x/(1-x))/2 ~= x + x^3/3 + o(x^5) */
@@ -16,14 +17,18 @@ pub fn atanh(x: f64) -> f64 {
     if e < 0x3ff - 1 {
         if e < 0x3ff - 32 {
             /* handle underflow */
-            if e == 0 {
-                force_eval!(y as f32);
+            if e == 0 && y != 0.0 {
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             }
         } else {
             /* |x| < 0.5, up to 1.7ulp error */
             y = 0.5 * log1p(2.0 * y + 2.0 * y * y / (1.0 - y));
         }
     } else {
+        if y == 1.0 {
+            /* atanh(+-1) = +-inf */
+            feraiseexcept(FE_DIVBYZERO);
+        }
         /* avoid overflow */
         y = 0.5 * log1p(2.0 * (y / (1.0 - y)));
     }
@@ -0,0 +1,32 @@
+use super::{atan, div_pi};
+
+/// Arctangent divided by pi (f64)
+///
+/// Computes `atan(x) / pi`, the arctangent of `x` in half-revolutions, in the
+/// range `[-0.5, 0.5]`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpi(x: f64) -> f64 {
+    if x.is_infinite() {
+        return if x > 0.0 { 0.5 } else { -0.5 };
+    }
+    if x == 1.0 || x == -1.0 {
+        return x * 0.25;
+    }
+    div_pi(atan(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::atanpi;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(atanpi(1.0), 0.25);
+        assert_eq!(atanpi(-1.0), -0.25);
+        assert_eq!(atanpi(INFINITY), 0.5);
+        assert_eq!(atanpi(NEG_INFINITY), -0.5);
+        assert_eq!(atanpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(atanpi(NAN).is_nan());
+    }
+}
@@ -0,0 +1,11 @@
+use super::atanpi;
+use super::fenv::narrow;
+
+/// Arctangent divided by pi (f32)
+///
+/// Computes the arctangent of `x` in half-revolutions. The computation is carried out in
+/// double precision, which also makes the exact cases of `atanpi` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpif(x: f32) -> f32 {
+    narrow(atanpi(x as f64))
+}
@@ -0,0 +1,248 @@
+use super::convert::f64_to_f32;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_UNDERFLOW};
+use super::Round;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Neg;
+
+/// A bfloat16 floating-point number
+///
+/// The top half of an `f32`: an 8 bit significand with the 8 bit exponent of
+/// `f32`, stored as its bits. It converts exactly to `f32` and `f64`, and
+/// from them by rounding to nearest, ties to even, whatever the rounding
+/// mode. The functions on it, such as `bf16_exp`, compute in `f64` and round
+/// once, so that their results are correctly rounded to nearest. Comparisons
+/// follow IEEE 754: a NaN is unordered, also with itself, and `-0 == +0`.
+///
+/// C has no names for bfloat16 functions, and a `bf16` suffix would make
+/// `logbf16` read as `logb`, so they take a `bf16_` prefix instead.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct BF16(u16);
+
+pub(crate) const SIG_BITS: u32 = 7;
+pub(crate) const SIGN_MASK: u16 = 1 << 15;
+pub(crate) const SIG_MASK: u16 = (1 << SIG_BITS) - 1;
+pub(crate) const QUIET_BIT: u16 = 1 << (SIG_BITS - 1);
+const INF_BITS: u16 = 0xff << SIG_BITS;
+
+impl BF16 {
+    /// Positive zero
+    pub const ZERO: BF16 = BF16(0);
+    /// One
+    pub const ONE: BF16 = BF16(0x7f << SIG_BITS);
+    /// Positive infinity
+    pub const INFINITY: BF16 = BF16(INF_BITS);
+    /// Negative infinity
+    pub const NEG_INFINITY: BF16 = BF16(SIGN_MASK | INF_BITS);
+    /// A quiet NaN
+    pub const NAN: BF16 = BF16(INF_BITS | QUIET_BIT);
+    /// The largest finite value, `(2 - 2^-7) * 2^127`
+    pub const MAX: BF16 = BF16(INF_BITS - 1);
+    /// The smallest positive normal value, `2^-126`
+    pub const MIN_POSITIVE: BF16 = BF16(1 << SIG_BITS);
+    /// The difference between 1 and the next larger value, `2^-7`
+    pub const EPSILON: BF16 = BF16((0x7f - SIG_BITS as u16) << SIG_BITS);
+
+    /// Returns the number with the bit representation `bits`.
+    pub const fn from_bits(bits: u16) -> BF16 {
+        BF16(bits)
+    }
+
+    /// Returns the bit representation of the number.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if the number is a NaN.
+    pub fn is_nan(self) -> bool {
+        self.0 & !SIGN_MASK > INF_BITS
+    }
+
+    /// Returns `true` if the number is positive or negative infinity.
+    pub fn is_infinite(self) -> bool {
+        self.0 & !SIGN_MASK == INF_BITS
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.0 & !SIGN_MASK < INF_BITS
+    }
+
+    /// Returns `true` if the sign bit is set, also for `-0` and NaNs.
+    pub fn is_sign_negative(self) -> bool {
+        self.0 & SIGN_MASK != 0
+    }
+
+    /// Rounds `x` to bfloat16, to nearest with ties to even, raising
+    /// overflow, underflow and inexact as needed. NaNs are quieted, and keep
+    /// the top of their payload.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f32(x: f32) -> BF16 {
+        let bits = x.to_bits();
+        if x.is_nan() {
+            if bits & 1 << 22 == 0 {
+                feraiseexcept(FE_INVALID);
+            }
+            return BF16((bits >> 16) as u16 | QUIET_BIT);
+        }
+        let rem = bits & 0xffff;
+        /* adding just below half, plus the last kept bit, rounds to even, and
+         * a carry into the exponent field is the right result up to inf */
+        let z = (bits + 0x7fff + (bits >> 16 & 1)) >> 16;
+        if rem != 0 {
+            let mag = z as u16 & !SIGN_MASK;
+            if mag == INF_BITS {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            } else if bits & 0x7f80_0000 == 0 {
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+            } else {
+                feraiseexcept(FE_INEXACT);
+            }
+        }
+        BF16(z as u16)
+    }
+
+    /// Rounds `x` to bfloat16, to nearest with ties to even, with a single
+    /// rounding, raising the exceptions as `from_f32`. NaNs are quieted, and
+    /// keep the top of their payload.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f64(x: f64) -> BF16 {
+        if x.is_nan() {
+            /* as f32 could quiet it */
+            let bits = x.to_bits();
+            if bits & 1 << 51 == 0 {
+                feraiseexcept(FE_INVALID);
+            }
+            let sign = (bits >> 48) as u16 & SIGN_MASK;
+            return BF16(sign | INF_BITS | QUIET_BIT | (bits >> (52 - SIG_BITS)) as u16 & SIG_MASK);
+        }
+        /* rounding to odd first keeps the second rounding correct */
+        BF16::from_f32(f64_to_f32(x, Round::Odd))
+    }
+}
+
+impl fmt::Debug for BF16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BF16({:#06x})", self.0)
+    }
+}
+
+impl PartialEq for BF16 {
+    fn eq(&self, other: &BF16) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BF16 {
+    fn partial_cmp(&self, other: &BF16) -> Option<Ordering> {
+        f32::from(*self).partial_cmp(&f32::from(*other))
+    }
+}
+
+impl Neg for BF16 {
+    type Output = BF16;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn neg(self) -> BF16 {
+        BF16(self.0 ^ SIGN_MASK)
+    }
+}
+
+impl From<BF16> for f32 {
+    /// Converts `x` exactly, NaN payloads included.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn from(x: BF16) -> f32 {
+        f32::from_bits((x.0 as u32) << 16)
+    }
+}
+
+impl From<BF16> for f64 {
+    /// Converts `x` exactly, NaN payloads included.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn from(x: BF16) -> f64 {
+        if x.is_nan() {
+            /* as f64 could quiet it */
+            let sign = ((x.0 >> 15) as u64) << 63;
+            return f64::from_bits(
+                sign | 0x7ff << 52 | ((x.0 & SIG_MASK) as u64) << (52 - SIG_BITS),
+            );
+        }
+        f32::from(x) as f64
+    }
+}
+
+/* Checks `f` against the f32 function `g` on every input: the result must be
+ * `g` rounded to bfloat16, except where g(x) is within two ulps of `f32` of
+ * a midpoint of bfloat16 values, beyond the accuracy of g, where it may be
+ * either neighbor. */
+#[cfg(test)]
+pub(crate) fn check_all(f: fn(BF16) -> BF16, g: fn(f32) -> f32) {
+    for bits in 0..=u16::MAX {
+        let x = BF16(bits);
+        let z = f(x);
+        let r = g(f32::from(x));
+        if r.is_nan() {
+            assert!(z.is_nan(), "{:?}", x);
+            continue;
+        }
+        let rb = r.to_bits();
+        if (rb & 0xffff).abs_diff(0x8000) > 2 {
+            assert_eq!(z.0, BF16::from_f32(r).0, "{:?}", x);
+        } else {
+            let below = (rb >> 16) as u16;
+            assert!(z.0 == below || z.0 == below + 1, "{:?}", x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        for bits in 0..=u16::MAX {
+            let x = BF16(bits);
+            let y = f32::from(x);
+            if x.is_nan() {
+                assert!(y.is_nan() && f64::from(x).is_nan());
+                /* quieted, with the payload kept */
+                assert_eq!(BF16::from_f32(y).0, bits | QUIET_BIT);
+                assert_eq!(BF16::from_f64(f64::from(x)).0, bits | QUIET_BIT);
+            } else {
+                assert_eq!(f64::from(x), y as f64);
+                assert_eq!(BF16::from_f32(y).0, bits);
+                assert_eq!(BF16::from_f64(y as f64).0, bits);
+            }
+        }
+        assert_eq!(f32::from(BF16::MAX), f32::from_bits(0x7f7f0000));
+    }
+
+    #[test]
+    fn rounding() {
+        /* 1 + 2^-8 is a tie, 1 + 2^-8 + 2^-23 is not */
+        assert_eq!(BF16::from_f32(f32::from_bits(0x3f808000)), BF16::ONE);
+        assert_eq!(BF16::from_f32(f32::from_bits(0x3f808001)).0, 0x3f81);
+        assert_eq!(BF16::from_f32(f32::from_bits(0x3f818000)).0, 0x3f82);
+        assert_eq!(BF16::from_f32(f32::MAX), BF16::INFINITY);
+        assert_eq!(BF16::from_f32(f32::from_bits(0x7f7f7fff)), BF16::MAX);
+        assert_eq!(BF16::from_f32(-f32::from_bits(0x00008000)).0, 0x8000);
+        assert_eq!(BF16::from_f32(f32::from_bits(0x00018000)).0, 0x0002);
+        /* a single rounding from f64, where rounding through f32 is wrong */
+        let x = f64::from_bits(0x3ff0100000000001);
+        assert_eq!((x as f32).to_bits(), 0x3f808000);
+        assert_eq!(BF16::from_f64(x).0, 0x3f81);
+        assert_eq!(BF16::from_f64(1e300), BF16::INFINITY);
+        assert_eq!(BF16::from_f64(-1e-300).0, 0x8000);
+    }
+
+    #[test]
+    fn comparisons() {
+        assert!(BF16::ONE < BF16::from_f32(2.0) && -BF16::ONE > BF16::from_f32(-2.0));
+        assert!(BF16::NEG_INFINITY < -BF16::MAX && BF16::MAX < BF16::INFINITY);
+        assert_eq!(BF16::ZERO, -BF16::ZERO);
+        assert!(BF16::NAN != BF16::NAN);
+        assert!(BF16(1) > -BF16::ZERO);
+    }
+}
@@ -0,0 +1,39 @@
+use super::{erf, BF16};
+
+/// Error function (BF16)
+///
+/// Calculates an approximation to the “error function”, which estimates
+/// the probability that an observation will fall within x standard
+/// deviations of the mean (assuming a normal distribution).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_erf(x: BF16) -> BF16 {
+    BF16::from_f64(erf(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::erff;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_erf(BF16::from_bits(0x3f80)).to_bits(), 0x3f58);
+        assert_eq!(bf16_erf(BF16::from_bits(0xbf00)).to_bits(), 0xbf05);
+        assert_eq!(bf16_erf(BF16::from_bits(0x4000)).to_bits(), 0x3f7f);
+        assert_eq!(bf16_erf(BF16::from_bits(0x0080)).to_bits(), 0x0090);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_erf(BF16::INFINITY), BF16::ONE);
+        assert_eq!(bf16_erf(BF16::NEG_INFINITY), -BF16::ONE);
+        assert!(bf16_erf(-BF16::ZERO).is_sign_negative());
+        assert!(bf16_erf(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_erf, erff);
+    }
+}
@@ -0,0 +1,37 @@
+use super::{exp, BF16};
+
+/// Exponential, base *e* (BF16)
+///
+/// Calculate the exponential of `x`, that is, *e* raised to the power `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_exp(x: BF16) -> BF16 {
+    BF16::from_f64(exp(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::expf;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_exp(BF16::from_bits(0x3f80)).to_bits(), 0x402e);
+        assert_eq!(bf16_exp(BF16::from_bits(0xc2b0)).to_bits(), 0x0042);
+        assert_eq!(bf16_exp(BF16::from_bits(0x42b0)).to_bits(), 0x7ef9);
+        assert_eq!(bf16_exp(BF16::from_bits(0x3c00)).to_bits(), 0x3f81);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_exp(BF16::from_f32(89.0)), BF16::INFINITY);
+        assert_eq!(bf16_exp(BF16::NEG_INFINITY), BF16::ZERO);
+        assert_eq!(bf16_exp(BF16::ZERO), BF16::ONE);
+        assert!(bf16_exp(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_exp, expf);
+    }
+}
@@ -0,0 +1,35 @@
+use super::{log, BF16};
+
+/// The natural logarithm of `x` (BF16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_log(x: BF16) -> BF16 {
+    BF16::from_f64(log(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::logf;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_log(BF16::from_bits(0x4000)).to_bits(), 0x3f31);
+        assert_eq!(bf16_log(BF16::from_bits(0x7f7f)).to_bits(), 0x42b1);
+        assert_eq!(bf16_log(BF16::from_bits(0x0001)).to_bits(), 0xc2b8);
+        assert_eq!(bf16_log(BF16::from_bits(0x3f81)).to_bits(), 0x3bff);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_log(BF16::ZERO), BF16::NEG_INFINITY);
+        assert!(bf16_log(-BF16::ONE).is_nan());
+        assert_eq!(bf16_log(BF16::ONE), BF16::ZERO);
+        assert!(bf16_log(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_log, logf);
+    }
+}
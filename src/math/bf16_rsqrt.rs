@@ -0,0 +1,37 @@
+use super::{rsqrt, BF16};
+
+/// Reciprocal square root (BF16)
+///
+/// Returns the correctly rounded `1 / sqrt(x)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_rsqrt(x: BF16) -> BF16 {
+    BF16::from_f64(rsqrt(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::rsqrtf;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_rsqrt(BF16::from_bits(0x4000)).to_bits(), 0x3f35);
+        assert_eq!(bf16_rsqrt(BF16::from_bits(0x4040)).to_bits(), 0x3f14);
+        assert_eq!(bf16_rsqrt(BF16::from_bits(0x0001)).to_bits(), 0x60b5);
+        assert_eq!(bf16_rsqrt(BF16::from_bits(0x7f7f)).to_bits(), 0x1f80);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_rsqrt(BF16::INFINITY), BF16::ZERO);
+        assert_eq!(bf16_rsqrt(-BF16::ZERO), BF16::NEG_INFINITY);
+        assert!(bf16_rsqrt(-BF16::ONE).is_nan());
+        assert!(bf16_rsqrt(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_rsqrt, rsqrtf);
+    }
+}
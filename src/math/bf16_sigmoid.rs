@@ -0,0 +1,50 @@
+use super::{exp, BF16};
+
+/// Logistic sigmoid (BF16)
+///
+/// Computes `1 / (1 + exp(-x))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_sigmoid(x: BF16) -> BF16 {
+    let y = f64::from(x);
+    if y < 0.0 {
+        /* exp(-x) could overflow */
+        let e = exp(y);
+        return BF16::from_f64(e / (1.0 + e));
+    }
+    BF16::from_f64(1.0 / (1.0 + exp(-y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::expf;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_sigmoid(BF16::from_bits(0x3f80)).to_bits(), 0x3f3b);
+        assert_eq!(bf16_sigmoid(BF16::from_bits(0xc000)).to_bits(), 0x3df4);
+        assert_eq!(bf16_sigmoid(BF16::from_bits(0xc2b0)).to_bits(), 0x0042);
+        assert_eq!(bf16_sigmoid(BF16::from_bits(0x4100)).to_bits(), 0x3f80);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_sigmoid(BF16::INFINITY), BF16::ONE);
+        assert_eq!(bf16_sigmoid(BF16::NEG_INFINITY), BF16::ZERO);
+        assert_eq!(bf16_sigmoid(BF16::ZERO).to_bits(), 0x3f00);
+        assert!(bf16_sigmoid(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_sigmoid, |x| {
+            if x < 0.0 {
+                let e = expf(x);
+                e / (1.0 + e)
+            } else {
+                1.0 / (1.0 + expf(-x))
+            }
+        });
+    }
+}
@@ -0,0 +1,37 @@
+use super::{sqrt, BF16};
+
+/// Square root (BF16)
+///
+/// Returns the correctly rounded square root of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_sqrt(x: BF16) -> BF16 {
+    BF16::from_f64(sqrt(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::sqrtf;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_sqrt(BF16::from_bits(0x4000)).to_bits(), 0x3fb5);
+        assert_eq!(bf16_sqrt(BF16::from_bits(0x4080)).to_bits(), 0x4000);
+        assert_eq!(bf16_sqrt(BF16::from_bits(0x0001)).to_bits(), 0x1e35);
+        assert_eq!(bf16_sqrt(BF16::from_bits(0x7f7f)).to_bits(), 0x5f7f);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_sqrt(BF16::INFINITY), BF16::INFINITY);
+        assert!(bf16_sqrt(-BF16::ONE).is_nan());
+        assert!(bf16_sqrt(-BF16::ZERO).is_sign_negative());
+        assert!(bf16_sqrt(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_sqrt, sqrtf);
+    }
+}
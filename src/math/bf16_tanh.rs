@@ -0,0 +1,37 @@
+use super::{tanh, BF16};
+
+/// Hyperbolic tangent (BF16)
+///
+/// Computes the hyperbolic tangent of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_tanh(x: BF16) -> BF16 {
+    BF16::from_f64(tanh(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::bf16::check_all;
+    use super::super::tanhf;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(bf16_tanh(BF16::from_bits(0x3f80)).to_bits(), 0x3f43);
+        assert_eq!(bf16_tanh(BF16::from_bits(0xbe80)).to_bits(), 0xbe7b);
+        assert_eq!(bf16_tanh(BF16::from_bits(0x4080)).to_bits(), 0x3f80);
+        assert_eq!(bf16_tanh(BF16::from_bits(0x3c00)).to_bits(), 0x3c00);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(bf16_tanh(BF16::INFINITY), BF16::ONE);
+        assert_eq!(bf16_tanh(BF16::NEG_INFINITY), -BF16::ONE);
+        assert!(bf16_tanh(-BF16::ZERO).is_sign_negative());
+        assert!(bf16_tanh(BF16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(bf16_tanh, tanhf);
+    }
+}
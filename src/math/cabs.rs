@@ -0,0 +1,9 @@
+use super::{hypot, Complex};
+
+/// Complex absolute value (f64)
+///
+/// Returns the modulus of `z`, computed without undue overflow or underflow.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cabs(z: Complex<f64>) -> f64 {
+    hypot(z.re, z.im)
+}
@@ -0,0 +1,9 @@
+use super::{hypotf, Complex};
+
+/// Complex absolute value (f32)
+///
+/// Returns the modulus of `z`, computed without undue overflow or underflow.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cabsf(z: Complex<f32>) -> f32 {
+    hypotf(z.re, z.im)
+}
@@ -0,0 +1,104 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrigf.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::k_catrigf::{
+    clog_for_large_values, do_hard_work, M_LN2, PIO2_HI, PIO2_LO, RECIP_EPSILON, SQRT_6_EPSILON,
+};
+use super::{acosf, atan2f, fabsf, Complex};
+use core::f32;
+
+/// Complex arc cosine (f32)
+///
+/// Returns `PI/2 - casinf(z)`, computed so that it is accurate near 1, with
+/// branch cuts on the real axis outside `[-1, 1]` and the special values of
+/// C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacosf(z: Complex<f32>) -> Complex<f32> {
+    /*
+     * cacosf(z) = PI/2 - z + O(z^3)   as z -> 0
+     *
+     * cacosf(z) = -sign(y)*I*clog(z) + O(1/z^2)   as z -> infinity
+     * The above formula works for the real part as well, because
+     * Re(cacosf(z)) = atan2f(fabsf(y), x) + O(y/z^3)
+     *    as z -> infinity, uniformly in y
+     */
+    let x = z.re;
+    let y = z.im;
+    let sx = x.is_sign_negative();
+    let sy = y.is_sign_negative();
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+
+    if x.is_nan() || y.is_nan() {
+        /* cacosf(+-Inf + I*NaN) = NaN + I*opt(-)Inf */
+        if x.is_infinite() {
+            return Complex::new(y + y, f32::NEG_INFINITY);
+        }
+        /* cacosf(NaN + I*+-Inf) = NaN + I*-+Inf */
+        if y.is_infinite() {
+            return Complex::new(x + x, -y);
+        }
+        /* cacosf(0 + I*NaN) = PI/2 + I*NaN with inexact */
+        if x == 0.0 {
+            return Complex::new(PIO2_HI + PIO2_LO, y + y);
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        /* clog...() will raise inexact unless x or y is infinite. */
+        let w = clog_for_large_values(z);
+        let rx = fabsf(w.im);
+        let ry = w.re + M_LN2;
+        return Complex::new(rx, if sy { ry } else { -ry });
+    }
+
+    /* Avoid spuriously raising inexact for z = 1. */
+    if x == 1.0 && y == 0.0 {
+        return Complex::new(0.0, -y);
+    }
+
+    /* All remaining cases are inexact. */
+    feraiseexcept(FE_INEXACT);
+
+    if ax < SQRT_6_EPSILON / 4.0 && ay < SQRT_6_EPSILON / 4.0 {
+        return Complex::new(PIO2_HI - (x - PIO2_LO), -y);
+    }
+
+    let (ry, b, sqrt_a2mx2, new_x) = do_hard_work(ay, ax);
+    let rx = match b {
+        Some(b) => acosf(if sx { -b } else { b }),
+        None => atan2f(sqrt_a2mx2, if sx { -new_x } else { new_x }),
+    };
+    Complex::new(rx, if sy { ry } else { -ry })
+}
@@ -0,0 +1,86 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrig.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::{cacos, copysign, fabs, Complex};
+
+/// Complex inverse hyperbolic cosine (f64)
+///
+/// Returns `log(z + sqrt(z + 1) sqrt(z - 1))`, with a branch cut on the real
+/// axis below 1 and the special values of C Annex G. The real part is never
+/// negative.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacosh(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * cacosh(z) = I*cacos(z) or -I*cacos(z)
+     * where the sign is chosen so Re(cacosh(z)) >= 0.
+     */
+    let w = cacos(z);
+    let rx = w.re;
+    let ry = w.im;
+    /* cacosh(NaN + I*NaN) = NaN + I*NaN */
+    if rx.is_nan() && ry.is_nan() {
+        return Complex::new(ry, rx);
+    }
+    /* cacosh(NaN + I*+-Inf) = +Inf + I*NaN */
+    /* cacosh(+-Inf + I*NaN) = +Inf + I*NaN */
+    if rx.is_nan() {
+        return Complex::new(fabs(ry), rx);
+    }
+    /* cacosh(0 + I*NaN) = NaN + I*NaN */
+    if ry.is_nan() {
+        return Complex::new(ry, ry);
+    }
+    Complex::new(fabs(ry), copysign(rx, z.im))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::FRAC_PI_2;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(
+            cacosh(Complex::new(0.0, -0.0)),
+            Complex::new(0.0, -FRAC_PI_2)
+        );
+        let z = cacosh(Complex::new(1.0, INFINITY));
+        assert!(z.re == INFINITY && z.im == FRAC_PI_2);
+        let z = cacosh(Complex::new(NEG_INFINITY, NAN));
+        assert!(z.re == INFINITY && z.im.is_nan());
+        let z = cacosh(Complex::new(0.0, NAN));
+        assert!(z.re.is_nan() && z.im.is_nan());
+    }
+
+    #[test]
+    fn values() {
+        let z = cacosh(Complex::new(1.0, 2.0));
+        assert!((z.re / 1.5285709194809982 - 1.0).abs() < 1e-15);
+        assert!((z.im / 1.1437177404024206 - 1.0).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,58 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrigf.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::{cacosf, copysignf, fabsf, Complex};
+
+/// Complex inverse hyperbolic cosine (f32)
+///
+/// Returns `logf(z + sqrtf(z + 1) sqrtf(z - 1))`, with a branch cut on the real
+/// axis below 1 and the special values of C Annex G. The real part is never
+/// negative.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cacoshf(z: Complex<f32>) -> Complex<f32> {
+    /*
+     * cacoshf(z) = I*cacosf(z) or -I*cacosf(z)
+     * where the sign is chosen so Re(cacoshf(z)) >= 0.
+     */
+    let w = cacosf(z);
+    let rx = w.re;
+    let ry = w.im;
+    /* cacoshf(NaN + I*NaN) = NaN + I*NaN */
+    if rx.is_nan() && ry.is_nan() {
+        return Complex::new(ry, rx);
+    }
+    /* cacoshf(NaN + I*+-Inf) = +Inf + I*NaN */
+    /* cacoshf(+-Inf + I*NaN) = +Inf + I*NaN */
+    if rx.is_nan() {
+        return Complex::new(fabsf(ry), rx);
+    }
+    /* cacoshf(0 + I*NaN) = NaN + I*NaN */
+    if ry.is_nan() {
+        return Complex::new(ry, ry);
+    }
+    Complex::new(fabsf(ry), copysignf(rx, z.im))
+}
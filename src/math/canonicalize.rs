@@ -0,0 +1,13 @@
+/// Canonical encoding (f64)
+///
+/// Returns the canonical encoding of `x`. Every binary64 encoding is already
+/// canonical, so this is the identity except that a signaling NaN is turned
+/// into a quiet one, raising the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn canonicalize(x: f64) -> f64 {
+    if x.is_nan() {
+        x + x
+    } else {
+        x
+    }
+}
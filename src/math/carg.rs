@@ -0,0 +1,10 @@
+use super::{atan2, Complex};
+
+/// Complex argument (f64)
+///
+/// Returns the argument of `z`, in `[-pi, pi]`, with the branch cut along the
+/// negative real axis.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn carg(z: Complex<f64>) -> f64 {
+    atan2(z.im, z.re)
+}
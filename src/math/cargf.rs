@@ -0,0 +1,10 @@
+use super::{atan2f, Complex};
+
+/// Complex argument (f32)
+///
+/// Returns the argument of `z`, in `[-pi, pi]`, with the branch cut along the
+/// negative real axis.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cargf(z: Complex<f32>) -> f32 {
+    atan2f(z.im, z.re)
+}
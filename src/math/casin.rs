@@ -0,0 +1,13 @@
+use super::{casinh, Complex};
+
+/// Complex arc sine (f64)
+///
+/// Returns `-I casinh(I z)`, with branch cuts on the real axis outside
+/// `[-1, 1]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casin(z: Complex<f64>) -> Complex<f64> {
+    /* casin(z) = reverse(casinh(reverse(z))), where
+     * reverse(x + I*y) = y + I*x = I*conj(x + I*y) */
+    let w = casinh(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
@@ -0,0 +1,13 @@
+use super::{casinhf, Complex};
+
+/// Complex arc sine (f32)
+///
+/// Returns `-I casinhf(I z)`, with branch cuts on the real axis outside
+/// `[-1, 1]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinf(z: Complex<f32>) -> Complex<f32> {
+    /* casinf(z) = reverse(casinhf(reverse(z))), where
+     * reverse(x + I*y) = y + I*x = I*conj(x + I*y) */
+    let w = casinhf(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
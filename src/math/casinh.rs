@@ -0,0 +1,131 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrig.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::k_catrig::{clog_for_large_values, do_hard_work, M_LN2, RECIP_EPSILON, SQRT_6_EPSILON};
+use super::{asin, atan2, copysign, fabs, Complex};
+
+/// Complex inverse hyperbolic sine (f64)
+///
+/// Returns `log(z + sqrt(z*z + 1))`, with branch cuts on the imaginary axis
+/// outside `[-I, I]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn casinh(z: Complex<f64>) -> Complex<f64> {
+    /*
+     * casinh(z) = z + O(z^3)   as z -> 0
+     *
+     * casinh(z) = sign(x)*clog(sign(x)*z) + O(1/z^2)   as z -> infinity
+     * The above formula works for the imaginary part as well, because
+     * Im(casinh(z)) = sign(x)*atan2(sign(x)*y, fabs(x)) + O(y/z^3)
+     *    as z -> infinity, uniformly in y
+     */
+    let x = z.re;
+    let y = z.im;
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    if x.is_nan() || y.is_nan() {
+        /* casinh(+-Inf + I*NaN) = +-Inf + I*NaN */
+        if x.is_infinite() {
+            return Complex::new(x, y + y);
+        }
+        /* casinh(NaN + I*+-Inf) = opt(+-)Inf + I*NaN */
+        if y.is_infinite() {
+            return Complex::new(y, x + x);
+        }
+        /* casinh(NaN + I*0) = NaN + I*0 */
+        if y == 0.0 {
+            return Complex::new(x + x, y);
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        /* clog...() will raise inexact unless x or y is infinite. */
+        let w = if x.is_sign_negative() {
+            clog_for_large_values(-z)
+        } else {
+            clog_for_large_values(z)
+        };
+        return Complex::new(copysign(w.re + M_LN2, x), copysign(w.im, y));
+    }
+
+    /* Avoid spuriously raising inexact for z = 0. */
+    if x == 0.0 && y == 0.0 {
+        return z;
+    }
+
+    /* All remaining cases are inexact. */
+    feraiseexcept(FE_INEXACT);
+
+    if ax < SQRT_6_EPSILON / 4.0 && ay < SQRT_6_EPSILON / 4.0 {
+        return z;
+    }
+
+    let (rx, b, sqrt_a2my2, new_y) = do_hard_work(ax, ay);
+    let ry = match b {
+        Some(b) => asin(b),
+        None => atan2(new_y, sqrt_a2my2),
+    };
+    Complex::new(copysign(rx, x), copysign(ry, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(casinh(Complex::new(-0.0, 0.0)), Complex::new(-0.0, 0.0));
+        let z = casinh(Complex::new(1.0, INFINITY));
+        assert!(z.re == INFINITY && z.im == consts::FRAC_PI_2);
+        let z = casinh(Complex::new(INFINITY, -INFINITY));
+        assert!(z.re == INFINITY && z.im == -consts::FRAC_PI_4);
+        let z = casinh(Complex::new(NEG_INFINITY, NAN));
+        assert!(z.re == NEG_INFINITY && z.im.is_nan());
+        let z = casinh(Complex::new(NAN, 0.0));
+        assert!(z.re.is_nan() && z.im == 0.0);
+    }
+
+    #[test]
+    fn values() {
+        let z = casinh(Complex::new(1.0, 2.0));
+        assert!((z.re / 1.4693517443681852 - 1.0).abs() < 1e-15);
+        assert!((z.im / 1.0634400235777521 - 1.0).abs() < 1e-15);
+        /* the branch cut on the imaginary axis */
+        let z = casinh(Complex::new(0.0, 2.0));
+        assert!((z.re / 1.3169578969248168 - 1.0).abs() < 1e-15 && z.im == consts::FRAC_PI_2);
+        let z = casinh(Complex::new(-0.0, 2.0));
+        assert!((z.re / -1.3169578969248168 - 1.0).abs() < 1e-15 && z.im == consts::FRAC_PI_2);
+    }
+}
@@ -0,0 +1,13 @@
+use super::{catanh, Complex};
+
+/// Complex arc tangent (f64)
+///
+/// Returns `-I catanh(I z)`, with branch cuts on the imaginary axis outside
+/// `[-I, I]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catan(z: Complex<f64>) -> Complex<f64> {
+    /* catan(z) = reverse(catanh(reverse(z))), where
+     * reverse(x + I*y) = y + I*x = I*conj(x + I*y) */
+    let w = catanh(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
@@ -0,0 +1,13 @@
+use super::{catanhf, Complex};
+
+/// Complex arc tangent (f32)
+///
+/// Returns `-I catanhf(I z)`, with branch cuts on the imaginary axis outside
+/// `[-I, I]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanf(z: Complex<f32>) -> Complex<f32> {
+    /* catanf(z) = reverse(catanhf(reverse(z))), where
+     * reverse(x + I*y) = y + I*x = I*conj(x + I*y) */
+    let w = catanhf(Complex::new(z.im, z.re));
+    Complex::new(w.im, w.re)
+}
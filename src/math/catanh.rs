@@ -0,0 +1,190 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrig.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * catanh(z) = log((1+z)/(1-z)) / 2
+ *           = log1p(4*x / |z-1|^2) / 4
+ *             + I * atan2(2*y, (1-x)*(1+x)-y*y) / 2
+ *
+ * catanh(z) = z + O(z^3)   as z -> 0
+ *
+ * catanh(z) = 1/z + sign(y)*I*PI/2 + O(1/z^3)   as z -> infinity
+ * The above formula works for the real part as well, because
+ * Re(catanh(z)) = x/|z|^2 + O(x/z^4)
+ *    as z -> infinity, uniformly in x
+ */
+
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::k_catrig::{M_LN2, PIO2_HI, PIO2_LO, RECIP_EPSILON, SQRT_3_EPSILON, SQRT_MIN};
+use super::{atan, atan2, atanh, copysign, fabs, log, log1p, Complex};
+use core::f64;
+
+/*
+ * sum_squares(x,y) = x*x + y*y (or just x*x if y*y would underflow).
+ * Assumes x*x and y*y will not overflow.
+ * Assumes x and y are finite.
+ * Assumes y is non-negative.
+ * Assumes fabs(x) >= DBL_EPSILON.
+ */
+#[inline]
+fn sum_squares(x: f64, y: f64) -> f64 {
+    /* Avoid underflow when y is small. */
+    if y < SQRT_MIN {
+        return x * x;
+    }
+    x * x + y * y
+}
+
+/*
+ * real_part_reciprocal(x, y) = Re(1/(x+I*y)) = x/(x*x + y*y).
+ * Assumes x and y are not NaN, and one of x and y is larger than
+ * RECIP_EPSILON.  We avoid unwarranted underflow.  It is important to not use
+ * the code (1/z).re, because the imaginary part may produce an unwanted
+ * underflow.
+ * This is only called in a context where inexact is always raised before
+ * the call, so no effort is made to avoid or force inexact.
+ */
+#[inline]
+fn real_part_reciprocal(x: f64, y: f64) -> f64 {
+    const BIAS: i32 = 1023; /* DBL_MAX_EXP - 1 */
+    const CUTOFF: i32 = 27; /* DBL_MANT_DIG / 2 + 1: just half or 1 guard digit */
+
+    let ix = ((x.to_bits() >> 32) as u32 & 0x7ff00000) as i32;
+    let iy = ((y.to_bits() >> 32) as u32 & 0x7ff00000) as i32;
+    if ix - iy >= CUTOFF << 20 || x.is_infinite() {
+        return 1.0 / x; /* +-Inf -> +-0 is special */
+    }
+    if iy - ix >= CUTOFF << 20 {
+        return x / y / y; /* should avoid double div, but hard */
+    }
+    if ix <= (BIAS + 1024 / 2 - CUTOFF) << 20 {
+        return x / (x * x + y * y);
+    }
+    let scale = f64::from_bits(((0x7ff00000 - ix) as u64) << 32); /* 2**(1-ilogb(x)) */
+    let x = x * scale;
+    let y = y * scale;
+    x / (x * x + y * y) * scale
+}
+
+/// Complex inverse hyperbolic tangent (f64)
+///
+/// Returns `log((1 + z) / (1 - z)) / 2`, with branch cuts on the real axis
+/// outside `[-1, 1]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let ax = fabs(x);
+    let ay = fabs(y);
+
+    /* This helps handle many cases. */
+    if y == 0.0 && ax <= 1.0 {
+        return Complex::new(atanh(x), y);
+    }
+
+    /* To ensure the same accuracy as atan(), and to filter out z = 0. */
+    if x == 0.0 {
+        return Complex::new(x, atan(y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        /* catanh(+-Inf + I*NaN) = +-0 + I*NaN */
+        if x.is_infinite() {
+            return Complex::new(copysign(0.0, x), y + y);
+        }
+        /* catanh(NaN + I*+-Inf) = sign(NaN)0 + I*+-PI/2 */
+        if y.is_infinite() {
+            return Complex::new(copysign(0.0, x), copysign(PIO2_HI + PIO2_LO, y));
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        return Complex::new(real_part_reciprocal(x, y), copysign(PIO2_HI + PIO2_LO, y));
+    }
+
+    if ax < SQRT_3_EPSILON / 2.0 && ay < SQRT_3_EPSILON / 2.0 {
+        /*
+         * z = 0 was filtered out above.  All other cases must raise
+         * inexact, but this is the only case that needs to do it
+         * explicitly.
+         */
+        feraiseexcept(FE_INEXACT);
+        return z;
+    }
+
+    let rx = if ax == 1.0 && ay < f64::EPSILON {
+        (M_LN2 - log(ay)) / 2.0
+    } else {
+        log1p(4.0 * ax / sum_squares(ax - 1.0, ay)) / 4.0
+    };
+
+    let ry = if ax == 1.0 {
+        atan2(2.0, -ay) / 2.0
+    } else if ay < f64::EPSILON {
+        atan2(2.0 * ay, (1.0 - ax) * (1.0 + ax)) / 2.0
+    } else {
+        atan2(2.0 * ay, (1.0 - ax) * (1.0 + ax) - ay * ay) / 2.0
+    };
+
+    Complex::new(copysign(rx, x), copysign(ry, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::FRAC_PI_2;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(catanh(Complex::new(-0.0, 0.0)), Complex::new(-0.0, 0.0));
+        assert_eq!(catanh(Complex::new(1.0, 0.0)), Complex::new(INFINITY, 0.0));
+        let z = catanh(Complex::new(1.0, INFINITY));
+        assert!(z.re == 0.0 && z.im == FRAC_PI_2);
+        let z = catanh(Complex::new(NEG_INFINITY, 1.0));
+        assert!(z.re == 0.0 && z.re.is_sign_negative() && z.im == FRAC_PI_2);
+        let z = catanh(Complex::new(INFINITY, NAN));
+        assert!(z.re == 0.0 && z.im.is_nan());
+        let z = catanh(Complex::new(NAN, -INFINITY));
+        assert!(z.re == 0.0 && z.im == -FRAC_PI_2);
+    }
+
+    #[test]
+    fn values() {
+        let z = catanh(Complex::new(1.0, 2.0));
+        assert!((z.re / 0.17328679513998632 - 1.0).abs() < 1e-15);
+        assert!((z.im / 1.1780972450961724 - 1.0).abs() < 1e-15);
+        let z = catanh(Complex::new(1e300, 1e300));
+        assert!((z.re / 5e-301 - 1.0).abs() < 1e-15 && z.im == FRAC_PI_2);
+    }
+}
@@ -0,0 +1,175 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrigf.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * catanhf(z) = logf((1+z)/(1-z)) / 2
+ *           = log1pf(4*x / |z-1|^2) / 4
+ *             + I * atan2f(2*y, (1-x)*(1+x)-y*y) / 2
+ *
+ * catanhf(z) = z + O(z^3)   as z -> 0
+ *
+ * catanhf(z) = 1/z + sign(y)*I*PI/2 + O(1/z^3)   as z -> infinity
+ * The above formula works for the real part as well, because
+ * Re(catanhf(z)) = x/|z|^2 + O(x/z^4)
+ *    as z -> infinity, uniformly in x
+ */
+
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::k_catrigf::{M_LN2, PIO2_HI, PIO2_LO, RECIP_EPSILON, SQRT_3_EPSILON, SQRT_MIN};
+use super::{atan2f, atanf, atanhf, copysignf, fabsf, log1pf, logf, Complex};
+use core::f32;
+
+/*
+ * sum_squares(x,y) = x*x + y*y (or just x*x if y*y would underflow).
+ * Assumes x*x and y*y will not overflow.
+ * Assumes x and y are finite.
+ * Assumes y is non-negative.
+ * Assumes fabsf(x) >= FLT_EPSILON.
+ */
+#[inline]
+fn sum_squares(x: f32, y: f32) -> f32 {
+    /* Avoid underflow when y is small. */
+    if y < SQRT_MIN {
+        return x * x;
+    }
+    x * x + y * y
+}
+
+/*
+ * real_part_reciprocal(x, y) = Re(1/(x+I*y)) = x/(x*x + y*y).
+ * Assumes x and y are not NaN, and one of x and y is larger than
+ * RECIP_EPSILON.  We avoid unwarranted underflow.  It is important to not use
+ * the code (1/z).re, because the imaginary part may produce an unwanted
+ * underflow.
+ * This is only called in a context where inexact is always raised before
+ * the call, so no effort is made to avoid or force inexact.
+ */
+#[inline]
+fn real_part_reciprocal(x: f32, y: f32) -> f32 {
+    const BIAS: i32 = 127; /* FLT_MAX_EXP - 1 */
+    const CUTOFF: i32 = 13; /* FLT_MANT_DIG / 2 + 1: just half or 1 guard digit */
+
+    let ix = (x.to_bits() & 0x7f800000) as i32;
+    let iy = (y.to_bits() & 0x7f800000) as i32;
+    if ix - iy >= CUTOFF << 23 || x.is_infinite() {
+        return 1.0 / x; /* +-Inf -> +-0 is special */
+    }
+    if iy - ix >= CUTOFF << 23 {
+        return x / y / y; /* should avoid double div, but hard */
+    }
+    if ix <= (BIAS + 128 / 2 - CUTOFF) << 23 {
+        return x / (x * x + y * y);
+    }
+    let scale = f32::from_bits((0x7f800000 - ix) as u32); /* 2**(1-ilogb(x)) */
+    let x = x * scale;
+    let y = y * scale;
+    x / (x * x + y * y) * scale
+}
+
+/// Complex inverse hyperbolic tangent (f32)
+///
+/// Returns `logf((1 + z) / (1 - z)) / 2`, with branch cuts on the real axis
+/// outside `[-1, 1]` and the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn catanhf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re;
+    let y = z.im;
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+
+    /* This helps handle many cases. */
+    if y == 0.0 && ax <= 1.0 {
+        return Complex::new(atanhf(x), y);
+    }
+
+    /* To ensure the same accuracy as atanf(), and to filter out z = 0. */
+    if x == 0.0 {
+        return Complex::new(x, atanf(y));
+    }
+
+    if x.is_nan() || y.is_nan() {
+        /* catanhf(+-Inf + I*NaN) = +-0 + I*NaN */
+        if x.is_infinite() {
+            return Complex::new(copysignf(0.0, x), y + y);
+        }
+        /* catanhf(NaN + I*+-Inf) = sign(NaN)0 + I*+-PI/2 */
+        if y.is_infinite() {
+            return Complex::new(copysignf(0.0, x), copysignf(PIO2_HI + PIO2_LO, y));
+        }
+        /*
+         * All other cases involving NaN return NaN + I*NaN.
+         * C99 leaves it optional whether to raise invalid if one of
+         * the arguments is not NaN, so we opt not to raise it.
+         */
+        return Complex::new(x + y, x + y);
+    }
+
+    if ax > RECIP_EPSILON || ay > RECIP_EPSILON {
+        return Complex::new(real_part_reciprocal(x, y), copysignf(PIO2_HI + PIO2_LO, y));
+    }
+
+    if ax < SQRT_3_EPSILON / 2.0 && ay < SQRT_3_EPSILON / 2.0 {
+        /*
+         * z = 0 was filtered out above.  All other cases must raise
+         * inexact, but this is the only case that needs to do it
+         * explicitly.
+         */
+        feraiseexcept(FE_INEXACT);
+        return z;
+    }
+
+    let rx = if ax == 1.0 && ay < f32::EPSILON {
+        (M_LN2 - logf(ay)) / 2.0
+    } else {
+        log1pf(4.0 * ax / sum_squares(ax - 1.0, ay)) / 4.0
+    };
+
+    let ry = if ax == 1.0 {
+        atan2f(2.0, -ay) / 2.0
+    } else if ay < f32::EPSILON {
+        atan2f(2.0 * ay, (1.0 - ax) * (1.0 + ax)) / 2.0
+    } else {
+        atan2f(2.0 * ay, (1.0 - ax) * (1.0 + ax) - ay * ay) / 2.0
+    };
+
+    Complex::new(copysignf(rx, x), copysignf(ry, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        let z = catanhf(Complex::new(1.0, 2.0));
+        assert!((z.re / 0.1732868 - 1.0).abs() < 1e-6);
+        assert!((z.im / 1.1780972 - 1.0).abs() < 1e-6);
+        let z = catanhf(Complex::new(1e30, -1e30));
+        assert!((z.re / 5e-31 - 1.0).abs() < 1e-6);
+        assert_eq!(z.im, -f32::consts::FRAC_PI_2);
+    }
+}
@@ -0,0 +1,183 @@
+/* Newton steps from the f64 cube root, the last one in double-F128
+ * arithmetic; within 1 ulp */
+
+use super::f128::{dd_add, dd_mul, is_zero, quiet_nan, two_prod, SIGN_MASK};
+use super::{cbrt, frexpf128, scalbnf128, F128};
+
+/// Cube root (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cbrtf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    /* cbrt(+-0) = +-0, cbrt(+-inf) = +-inf */
+    if x.is_infinite() || is_zero(x) {
+        return x;
+    }
+
+    /* |x| = v * 2^(3q) with v in [0.5, 4) */
+    let (m, e) = frexpf128(F128::from_bits(x.to_bits() & !SIGN_MASK));
+    let q = e.div_euclid(3);
+    let v = scalbnf128(m, e.rem_euclid(3));
+
+    /* a 53-bit approximation, then a Newton step to about 106 bits */
+    let t = F128::from(cbrt(v.to_f64()));
+    let t = t - (t * t * t - v) / (F128::from(3) * t * t);
+
+    /* a last Newton step with the residual t^3 - v in double-F128 arithmetic,
+     * which leaves an error far below an ulp before the final rounding */
+    let t2 = two_prod(t, t);
+    let r = dd_add(dd_mul(t2, (t, F128::ZERO)), (-v, F128::ZERO));
+    let t = t - r.0 / (F128::from(3) * t2.0);
+
+    let z = scalbnf128(t, q);
+    if x.is_sign_negative() {
+        -z
+    } else {
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(cbrtf128(F128::from(27)), F128::from(3));
+        assert_eq!(cbrtf128(F128::from(-8)), F128::from(-2));
+        /* 2, 10 and 2^-15872 */
+        assert_eq!(
+            cbrtf128(F128::from(2)).to_bits(),
+            0x3fff428a2f98d728ae223ddab715be25
+        );
+        assert_eq!(
+            cbrtf128(F128::from(10)).to_bits(),
+            0x400013c484138704eca8a22d724dea09
+        );
+        assert_eq!(
+            cbrtf128(f(0x01ff0000000000000000000000000000)).to_bits(),
+            0x2b54428a2f98d728ae223ddab715be25
+        );
+        /* a subnormal argument */
+        assert_eq!(cbrtf128(f(1)).to_bits(), 0x2a850000000000000000000000000000);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(cbrtf128(F128::INFINITY), F128::INFINITY);
+        assert_eq!(cbrtf128(F128::NEG_INFINITY), F128::NEG_INFINITY);
+        let z = cbrtf128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        assert!(cbrtf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3f6119ecca045931e6f2c4ed62263dc3,
+            0x3fca4d1455b836bc1a9de48cf72ec581,
+        ),
+        (
+            0x3f6913c5fac97cc0e54ca0325089de39,
+            0x3fcd066da831568b569652cabaf2aebe,
+        ),
+        (
+            0x3f8de22f54f8928bf42c68e864f8a16e,
+            0x3fd93c27448d11bc4644a7fbb9cc78d9,
+        ),
+        (
+            0x3fc9ce11da9992fc67e46c8750a59ed9,
+            0x3fed37b1cb6fec1eb81340d9918bcb45,
+        ),
+        (
+            0x3feed6e299a1f73f1ad97ccc3c2a9b11,
+            0x3ff98b3130ba4a66b47b443ea98830a7,
+        ),
+        (
+            0x3ff0ba64abe74857df4d4b9694667307,
+            0x3ffa3334b236fda1ee830266ec8dd7ff,
+        ),
+        (
+            0x406cd0eade5864742645f0d1020b49bc,
+            0x40238983fcba0b5b808684de62ba11ec,
+        ),
+        (
+            0x40796c56781016c2463dda695ee3c66f,
+            0x4027c91a89f2d664f9388b7b49180071,
+        ),
+        (
+            0x40913b6af961b98fe36fcf17b1b4f93c,
+            0x402fb3a7848e9a4434adffda57d8a131,
+        ),
+        (
+            0x40bd31723c688edebbda71c2836e2843,
+            0x403e56189fb9a4d71e34ecde3aa00882,
+        ),
+        (
+            0xbfa4ff5726b0543d3d29e76919c59bd5,
+            0xbfe0ffc7b15f16eafdb6a9b7ed1fa540,
+        ),
+        (
+            0xbfc3d398c870bee4a18859253f216699,
+            0xbfeb38eeafd13d8436705858bf402393,
+        ),
+        (
+            0xbfc67a7a571ad7c3865dab0adaa172ac,
+            0xbfec23a2a595b4203e15921b9fa8ea5e,
+        ),
+        (
+            0xbfd01275489a2914313fe757712fd846,
+            0xbfef4a1ccefd5dd9a0ba646b8546b5b7,
+        ),
+        (
+            0xc001da8b0a9e4ada53a7e42a73dc80cf,
+            0xbffff3326762587984dfe38a1c6f0733,
+        ),
+        (
+            0xc010e2aea4cf7e4efce4b97a43f4b332,
+            0xc004f608e2153ae1dffc0bad497cd913,
+        ),
+        (
+            0xc016a825fa7dbed3e976c53dbbe0916e,
+            0xc006e0dc535060272b748b76a1c47481,
+        ),
+        (
+            0xc025c33f5018a3f3575988b220060e74,
+            0xc00beae40861f4f7e44e3f850d43d630,
+        ),
+        (
+            0xc0319285273d48a8165fac667b461839,
+            0xc00fd88b55ce638a6bfab845a1ee9583,
+        ),
+        (
+            0xc0354c347853389b9b622a45d32ad82a,
+            0xc011173adeafe73622c30c28eaeb47c4,
+        ),
+        (
+            0xc04975712069afd6c4114aae7faa4909,
+            0xc017cce1394ff54e7098bc0234e08673,
+        ),
+        (
+            0xc09bbb6d5fb046c93f460b3509cfb404,
+            0xc0333371eb798ca33f11edef2171f720,
+        ),
+        (
+            0xc09c994ec1ca41e0ac68423e3c23ad63,
+            0xc0337927b15e930efe12ced75a741c62,
+        ),
+        (
+            0xc0b922373795a0471f61e678fd147ac7,
+            0xc03d0aee88c923dc0b24f02f8b2732a2,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(cbrtf128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,9 @@
+use super::{ccosh, Complex};
+
+/// Complex cosine (f64)
+///
+/// Returns `ccosh(I z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccos(z: Complex<f64>) -> Complex<f64> {
+    ccosh(Complex::new(-z.im, z.re))
+}
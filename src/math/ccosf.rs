@@ -0,0 +1,9 @@
+use super::{ccoshf, Complex};
+
+/// Complex cosine (f32)
+///
+/// Returns `ccoshf(I z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccosf(z: Complex<f32>) -> Complex<f32> {
+    ccoshf(Complex::new(-z.im, z.re))
+}
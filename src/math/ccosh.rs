@@ -0,0 +1,180 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_ccosh.c */
+/*-
+ * Copyright (c) 2005 Bruce D. Evans and Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * Hyperbolic cosine of a complex argument z = x + i y.
+ *
+ * cosh(z) = cosh(x+iy)
+ *         = cosh(x) cos(y) + i sinh(x) sin(y).
+ *
+ * Exceptional values are noted in the comments within the source code.
+ * These values and the return value were taken from n1124.pdf.
+ */
+
+use super::k_cexp::k_ldexp_cexp;
+use super::{copysign, cosh, exp, fabs, sincos, sinh, Complex};
+
+const HUGE: f64 = 8.98846567431157953865e+307; /* 0x1p1023 */
+
+/// Complex hyperbolic cosine (f64)
+///
+/// Returns `cosh(re) cos(im) + I sinh(re) sin(im)`, with the special values
+/// of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccosh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let hx = (x.to_bits() >> 32) as u32;
+    let lx = x.to_bits() as u32;
+    let hy = (y.to_bits() >> 32) as u32;
+    let ly = y.to_bits() as u32;
+    let ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    /* Handle the nearly-non-exceptional cases where x and y are finite. */
+    if ix < 0x7ff00000 && iy < 0x7ff00000 {
+        if iy | ly == 0 {
+            return Complex::new(cosh(x), x * y);
+        }
+        let (s, c) = sincos(y);
+        if ix < 0x40360000 {
+            /* small x: normal case */
+            return Complex::new(cosh(x) * c, sinh(x) * s);
+        }
+
+        /* |x| >= 22, so cosh(x) ~= exp(|x|) */
+        if ix < 0x40862e42 {
+            /* x < 710: exp(|x|) won't overflow */
+            let h = exp(fabs(x)) * 0.5;
+            return Complex::new(h * c, copysign(h, x) * s);
+        } else if ix < 0x4096bbaa {
+            /* x < 1455: scale to avoid overflow */
+            let z = k_ldexp_cexp(Complex::new(fabs(x), y), -1);
+            return Complex::new(z.re, z.im * copysign(1.0, x));
+        } else {
+            /* x >= 1455: the result always overflows */
+            let h = HUGE * x;
+            return Complex::new(h * h * c, h * s);
+        }
+    }
+
+    /*
+     * cosh(+-0 +- I Inf) = dNaN + I (+-)(+-)0.
+     * The sign of 0 in the result is unspecified.  Choice = product
+     * of the signs of the argument.  Raise the invalid floating-point
+     * exception.
+     *
+     * cosh(+-0 +- I NaN) = d(NaN) + I (+-)(+-)0.
+     * The sign of 0 in the result is unspecified.  Choice = product
+     * of the signs of the argument.
+     */
+    if ix | lx == 0 {
+        /* && iy >= 0x7ff00000 */
+        return Complex::new(y - y, x * copysign(0.0, y));
+    }
+
+    /*
+     * cosh(+-Inf +- I 0) = +Inf + I (+-)(+-)0.
+     *
+     * cosh(NaN +- I 0)   = d(NaN) + I (+-)(+-)0.
+     * The sign of 0 in the result is unspecified.  Choice = product
+     * of the signs of the argument.
+     */
+    if iy | ly == 0 {
+        /* && ix >= 0x7ff00000 */
+        return Complex::new(x * x, copysign(0.0, x) * y);
+    }
+
+    /*
+     * cosh(x +- I Inf) = dNaN + I dNaN.
+     * Raise the invalid floating-point exception for finite nonzero x.
+     *
+     * cosh(x + I NaN) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero x.  Choice = don't raise (except for signaling NaNs).
+     */
+    if ix < 0x7ff00000 {
+        /* && iy >= 0x7ff00000 */
+        return Complex::new(y - y, x * (y - y));
+    }
+
+    /*
+     * cosh(+-Inf + I NaN)  = +Inf + I d(NaN).
+     *
+     * cosh(+-Inf +- I Inf) = +Inf + I dNaN.
+     * The sign of Inf in the result is unspecified.  Choice = always +.
+     * Raise the invalid floating-point exception.
+     *
+     * cosh(+-Inf + I y)   = +Inf cos(y) +- I Inf sin(y)
+     */
+    if ix == 0x7ff00000 && lx == 0 {
+        if iy >= 0x7ff00000 {
+            return Complex::new(x * x, x * (y - y));
+        }
+        let (s, c) = sincos(y);
+        return Complex::new((x * x) * c, x * s);
+    }
+
+    /*
+     * cosh(NaN + I NaN)  = d(NaN) + I d(NaN).
+     *
+     * cosh(NaN +- I Inf) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception.
+     * Choice = raise.
+     *
+     * cosh(NaN + I y)    = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero y.  Choice = don't raise (except for signaling NaNs).
+     */
+    Complex::new((x * x) * (y - y), (x + x) * (y - y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(ccosh(Complex::new(0.0, 0.0)), Complex::new(1.0, 0.0));
+        let z = ccosh(Complex::new(-0.0, INFINITY));
+        assert!(z.re.is_nan() && z.im == 0.0);
+        let z = ccosh(Complex::new(NEG_INFINITY, 0.0));
+        assert!(z.re == INFINITY && z.im == 0.0 && z.im.is_sign_negative());
+        let z = ccosh(Complex::new(INFINITY, 1.0));
+        assert!(z.re == INFINITY && z.im == INFINITY);
+        let z = ccosh(Complex::new(NAN, 0.0));
+        assert!(z.re.is_nan() && z.im == 0.0);
+    }
+
+    #[test]
+    fn values() {
+        let z = ccosh(Complex::new(1.0, 2.0));
+        assert!((z.re / -0.64214812471552 - 1.0).abs() < 1e-15);
+        assert!((z.im / 1.0686074213827783 - 1.0).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,152 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_ccoshf.c */
+/*-
+ * Copyright (c) 2005 Bruce D. Evans and Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * Float version of ccosh(). See the s_ccosh.c for details.
+ *
+ * cosh(z) = cosh(x+iy)
+ *         = coshf(x) cos(y) + i sinhf(x) sin(y).
+ *
+ * Exceptional values are noted in the comments within the source code.
+ * These values and the return value were taken from n1124.pdf.
+ */
+
+use super::k_cexpf::k_ldexp_cexpf;
+use super::{copysignf, cosf, coshf, expf, fabsf, sinf, sinhf, Complex};
+
+const HUGE: f32 = 1.7014118346e+38; /* 0x1p127 */
+
+/// Complex hyperbolic cosine (f32)
+///
+/// Returns `cosh(re) cos(im) + I sinh(re) sin(im)`, with the special values
+/// of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ccoshf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re;
+    let y = z.im;
+    let hx = x.to_bits();
+    let hy = y.to_bits();
+    let ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    /* Handle the nearly-non-exceptional cases where x and y are finite. */
+    if ix < 0x7f800000 && iy < 0x7f800000 {
+        if iy == 0 {
+            return Complex::new(coshf(x), x * y);
+        }
+        let (s, c) = (sinf(y), cosf(y));
+        if ix < 0x41100000 {
+            /* small x: normal case */
+            return Complex::new(coshf(x) * c, sinhf(x) * s);
+        }
+
+        /* |x| >= 9, so cosh(x) ~= exp(|x|) */
+        if ix < 0x42b17218 {
+            /* x < 88.7: expf(|x|) won't overflow */
+            let h = expf(fabsf(x)) * 0.5;
+            return Complex::new(h * c, copysignf(h, x) * s);
+        } else if ix < 0x4340b1e7 {
+            /* x < 192.7: scale to avoid overflow */
+            let z = k_ldexp_cexpf(Complex::new(fabsf(x), y), -1);
+            return Complex::new(z.re, z.im * copysignf(1.0, x));
+        } else {
+            /* x >= 192.7: the result always overflows */
+            let h = HUGE * x;
+            return Complex::new(h * h * c, h * s);
+        }
+    }
+
+    /*
+     * cosh(+-0 +- I Inf) = dNaN + I (+-)(+-)0.
+     * The sign of 0 in the result is unspecified.  Choice = product
+     * of the signs of the argument.  Raise the invalid floating-point
+     * exception.
+     *
+     * cosh(+-0 +- I NaN) = d(NaN) + I (+-)(+-)0.
+     * The sign of 0 in the result is unspecified.  Choice = product
+     * of the signs of the argument.
+     */
+    if ix == 0 {
+        /* && iy >= 0x7f800000 */
+        return Complex::new(y - y, x * copysignf(0.0, y));
+    }
+
+    /*
+     * cosh(+-Inf +- I 0) = +Inf + I (+-)(+-)0.
+     *
+     * cosh(NaN +- I 0)   = d(NaN) + I (+-)(+-)0.
+     * The sign of 0 in the result is unspecified.  Choice = product
+     * of the signs of the argument.
+     */
+    if iy == 0 {
+        /* && ix >= 0x7f800000 */
+        return Complex::new(x * x, copysignf(0.0, x) * y);
+    }
+
+    /*
+     * cosh(x +- I Inf) = dNaN + I dNaN.
+     * Raise the invalid floating-point exception for finite nonzero x.
+     *
+     * cosh(x + I NaN) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero x.  Choice = don't raise (except for signaling NaNs).
+     */
+    if ix < 0x7f800000 {
+        /* && iy >= 0x7f800000 */
+        return Complex::new(y - y, x * (y - y));
+    }
+
+    /*
+     * cosh(+-Inf + I NaN)  = +Inf + I d(NaN).
+     *
+     * cosh(+-Inf +- I Inf) = +Inf + I dNaN.
+     * The sign of Inf in the result is unspecified.  Choice = always +.
+     * Raise the invalid floating-point exception.
+     *
+     * cosh(+-Inf + I y)   = +Inf cos(y) +- I Inf sin(y)
+     */
+    if ix == 0x7f800000 {
+        if iy >= 0x7f800000 {
+            return Complex::new(x * x, x * (y - y));
+        }
+        let (s, c) = (sinf(y), cosf(y));
+        return Complex::new((x * x) * c, x * s);
+    }
+
+    /*
+     * cosh(NaN + I NaN)  = d(NaN) + I d(NaN).
+     *
+     * cosh(NaN +- I Inf) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception.
+     * Choice = raise.
+     *
+     * cosh(NaN + I y)    = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero y.  Choice = don't raise (except for signaling NaNs).
+     */
+    Complex::new((x * x) * (y - y), (x + x) * (y - y))
+}
@@ -0,0 +1,59 @@
+use super::f128::{exponent, is_zero, quiet_nan, signed_zero, BIAS, SIG_BITS, SIG_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::F128;
+
+/// Ceil (F128)
+///
+/// Finds the nearest integer greater than or equal to `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceilf128(x: F128) -> F128 {
+    let mut bits = x.to_bits();
+    let e = exponent(x) - BIAS;
+
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if e >= SIG_BITS as i32 || is_zero(x) {
+        return x;
+    }
+    if e < 0 {
+        feraiseexcept(FE_INEXACT);
+        return if x.is_sign_negative() {
+            signed_zero(true)
+        } else {
+            F128::ONE
+        };
+    }
+    let m = SIG_MASK >> e;
+    if bits & m == 0 {
+        return x;
+    }
+    feraiseexcept(FE_INEXACT);
+    /* a carry out of the fraction bits goes into the exponent */
+    if !x.is_sign_negative() {
+        bits += m;
+    }
+    F128::from_bits(bits & !m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        let two = f(0x40000000000000000000000000000000);
+        /* 1.5 and -1.5 */
+        assert_eq!(ceilf128(f(0x3fff8000000000000000000000000000)), two);
+        assert_eq!(ceilf128(f(0xbfff8000000000000000000000000000)), -F128::ONE);
+        assert_eq!(ceilf128(f(1)), F128::ONE);
+        let z = ceilf128(-f(1));
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        assert_eq!(ceilf128(F128::INFINITY), F128::INFINITY);
+        assert!(ceilf128(F128::NAN).is_nan());
+    }
+}
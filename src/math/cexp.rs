@@ -0,0 +1,121 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_cexp.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::k_cexp::k_ldexp_cexp;
+use super::{cos, exp, sin, Complex};
+
+const EXP_OVFL: u32 = 0x40862e42; /* high bits of MAX_EXP * ln2 ~= 710 */
+const CEXP_OVFL: u32 = 0x4096b8e4; /* (MAX_EXP - MIN_DENORM_EXP) * ln2 */
+
+/// Complex exponential (f64)
+///
+/// Returns `e^z`, that is `e^re * (cos(im) + I sin(im))`, without overflow
+/// when `e^re` alone would overflow but the result does not.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cexp(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let ux = x.to_bits();
+    let uy = y.to_bits();
+    let hx = (ux >> 32) as u32;
+    let lx = ux as u32;
+    let hy = (uy >> 32) as u32 & 0x7fffffff;
+    let ly = uy as u32;
+
+    /* cexp(x + I 0) = exp(x) + I 0 */
+    if hy | ly == 0 {
+        return Complex::new(exp(x), y);
+    }
+    /* cexp(0 + I y) = cos(y) + I sin(y) */
+    if (hx & 0x7fffffff) | lx == 0 {
+        return Complex::new(cos(y), sin(y));
+    }
+
+    if hy >= 0x7ff00000 {
+        if lx != 0 || (hx & 0x7fffffff) != 0x7ff00000 {
+            /* cexp(finite|NaN +- I Inf|NaN) = NaN + I NaN */
+            return Complex::new(y - y, y - y);
+        } else if hx & 0x80000000 != 0 {
+            /* cexp(-Inf +- I Inf|NaN) = 0 + I 0 */
+            return Complex::new(0.0, 0.0);
+        } else {
+            /* cexp(+Inf +- I Inf|NaN) = Inf + I NaN */
+            return Complex::new(x, y - y);
+        }
+    }
+
+    if (EXP_OVFL..=CEXP_OVFL).contains(&hx) {
+        /*
+         * x is between 709.7 and 1454.3, so we must scale to avoid
+         * overflow in exp(x).
+         */
+        k_ldexp_cexp(z, 0)
+    } else {
+        /*
+         * Cases covered here:
+         *  -  x < EXP_OVFL and exp(x) won't overflow (common case)
+         *  -  x > CEXP_OVFL, so exp(x) * s overflows for all s > 0
+         *  -  x = +-Inf (generated by exp())
+         *  -  x = NaN (spurious inexact exception from y)
+         */
+        let exp_x = exp(x);
+        Complex::new(exp_x * cos(y), exp_x * sin(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(cexp(Complex::new(0.0, 0.0)), Complex::new(1.0, 0.0));
+        let z = cexp(Complex::new(-0.0, -0.0));
+        assert!(z.re == 1.0 && z.im.is_sign_negative());
+        let z = cexp(Complex::new(1.0, INFINITY));
+        assert!(z.re.is_nan() && z.im.is_nan());
+        let z = cexp(Complex::new(INFINITY, 0.0));
+        assert_eq!(z, Complex::new(INFINITY, 0.0));
+        let z = cexp(Complex::new(NEG_INFINITY, INFINITY));
+        assert_eq!(z, Complex::new(0.0, 0.0));
+        let z = cexp(Complex::new(INFINITY, NAN));
+        assert!(z.re.is_infinite() && z.im.is_nan());
+        let z = cexp(Complex::new(NAN, 0.0));
+        assert!(z.re.is_nan() && z.im == 0.0);
+    }
+
+    #[test]
+    fn values() {
+        let z = cexp(Complex::new(1.0, consts::FRAC_PI_2));
+        assert!((z.re).abs() < 1e-15 && (z.im - consts::E).abs() < 1e-15);
+        /* exp(709.9) overflows, but its products with cos(2.5) and sin(2.5) do not */
+        let z = cexp(Complex::new(709.9, 2.5));
+        assert!((z.re / -1.6194333517136333e308 - 1.0).abs() < 1e-15);
+        assert!((z.im / 1.2097528226220216e308 - 1.0).abs() < 1e-15);
+    }
+}
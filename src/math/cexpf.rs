@@ -0,0 +1,98 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_cexpf.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::k_cexpf::k_ldexp_cexpf;
+use super::{cosf, expf, sinf, Complex};
+
+const EXP_OVFL: u32 = 0x42b17218; /* MAX_EXP * ln2 ~= 88.722839355 */
+const CEXP_OVFL: u32 = 0x43400074; /* (MAX_EXP - MIN_DENORM_EXP) * ln2 */
+
+/// Complex exponential (f32)
+///
+/// Returns `e^z`, that is `e^re * (cos(im) + I sin(im))`, without overflow
+/// when `e^re` alone would overflow but the result does not.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cexpf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re;
+    let y = z.im;
+    let hx = x.to_bits();
+    let hy = y.to_bits() & 0x7fffffff;
+
+    /* cexp(x + I 0) = exp(x) + I 0 */
+    if hy == 0 {
+        return Complex::new(expf(x), y);
+    }
+    /* cexp(0 + I y) = cos(y) + I sin(y) */
+    if hx & 0x7fffffff == 0 {
+        return Complex::new(cosf(y), sinf(y));
+    }
+
+    if hy >= 0x7f800000 {
+        if (hx & 0x7fffffff) != 0x7f800000 {
+            /* cexp(finite|NaN +- I Inf|NaN) = NaN + I NaN */
+            return Complex::new(y - y, y - y);
+        } else if hx & 0x80000000 != 0 {
+            /* cexp(-Inf +- I Inf|NaN) = 0 + I 0 */
+            return Complex::new(0.0, 0.0);
+        } else {
+            /* cexp(+Inf +- I Inf|NaN) = Inf + I NaN */
+            return Complex::new(x, y - y);
+        }
+    }
+
+    if (EXP_OVFL..=CEXP_OVFL).contains(&hx) {
+        /*
+         * x is between 88.7 and 192, so we must scale to avoid
+         * overflow in expf(x).
+         */
+        k_ldexp_cexpf(z, 0)
+    } else {
+        /*
+         * Cases covered here:
+         *  -  x < EXP_OVFL and exp(x) won't overflow (common case)
+         *  -  x > CEXP_OVFL, so exp(x) * s overflows for all s > 0
+         *  -  x = +-Inf (generated by exp())
+         *  -  x = NaN (spurious inexact exception from y)
+         */
+        let exp_x = expf(x);
+        Complex::new(exp_x * cosf(y), exp_x * sinf(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        assert_eq!(cexpf(Complex::new(0.0, 0.0)), Complex::new(1.0, 0.0));
+        /* expf(88.9) overflows, but its products with cosf(2.5) and sinf(2.5) do not */
+        let z = cexpf(Complex::new(88.9, 2.5));
+        assert!((z.re / -3.2545465e38 - 1.0).abs() < 1e-6);
+        assert!((z.im / 2.4312188e38 - 1.0).abs() < 1e-6);
+    }
+}
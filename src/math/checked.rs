@@ -0,0 +1,1449 @@
+//! Checked variants of the math functions
+//!
+//! Each function here computes the same result as the function of the same
+//! name at the crate root, but reports the errors C Annex F and POSIX define
+//! for it as a [`MathError`](../enum.MathError.html) instead of returning NaN,
+//! an infinity, or a tiny result silently:
+//!
+//! - `Domain` when a NaN is produced from arguments that are not NaN;
+//! - `Pole` when an infinity is produced exactly from finite arguments, such as
+//!   `log(0)`;
+//! - `Overflow` when an infinity is produced by rounding a finite result;
+//! - `Underflow` when the result is subnormal, or is zero although the exact
+//!   result is not.
+//!
+//! NaN and infinite arguments propagate without error wherever the plain
+//! function returns a NaN or an infinity for them: `log(inf)` is `Ok(inf)`.
+
+use super::{fabs, fabsf, MathError};
+use core::{f32, f64};
+
+/* The arguments of a call, as far as classifying its result goes */
+#[derive(Clone, Copy)]
+struct Args {
+    nan: bool,
+    inf: bool,
+}
+
+fn args(xs: &[f64]) -> Args {
+    Args {
+        nan: xs.iter().any(|x| x.is_nan()),
+        inf: xs.iter().any(|x| x.is_infinite()),
+    }
+}
+
+fn argsf(xs: &[f32]) -> Args {
+    Args {
+        nan: xs.iter().any(|x| x.is_nan()),
+        inf: xs.iter().any(|x| x.is_infinite()),
+    }
+}
+
+/* Classifies a NaN or infinite result. `pole` tells whether an infinite result
+ * at finite arguments is exact. */
+fn special(nan: bool, a: Args, pole: bool) -> Option<MathError> {
+    if nan {
+        if a.nan {
+            None
+        } else {
+            Some(MathError::Domain)
+        }
+    } else if a.nan || a.inf {
+        None
+    } else if pole {
+        Some(MathError::Pole)
+    } else {
+        Some(MathError::Overflow)
+    }
+}
+
+/* Classifies the result `r` of a function with arguments `a`. `pole` tells
+ * whether an infinite result is exact, and `zero` whether a zero result at
+ * finite arguments can only come from underflow. */
+fn check(r: f64, a: Args, pole: bool, zero: bool) -> Result<f64, MathError> {
+    if r.is_nan() || r.is_infinite() {
+        return match special(r.is_nan(), a, pole) {
+            Some(e) => Err(e),
+            None => Ok(r),
+        };
+    }
+    if (r != 0.0 && fabs(r) < f64::MIN_POSITIVE) || (r == 0.0 && zero && !a.inf) {
+        return Err(MathError::Underflow);
+    }
+    Ok(r)
+}
+
+fn checkf(r: f32, a: Args, pole: bool, zero: bool) -> Result<f32, MathError> {
+    if r.is_nan() || r.is_infinite() {
+        return match special(r.is_nan(), a, pole) {
+            Some(e) => Err(e),
+            None => Ok(r),
+        };
+    }
+    if (r != 0.0 && fabsf(r) < f32::MIN_POSITIVE) || (r == 0.0 && zero && !a.inf) {
+        return Err(MathError::Underflow);
+    }
+    Ok(r)
+}
+
+/* For functions whose finite results are always exact */
+fn check_exact(r: f64, a: Args) -> Result<f64, MathError> {
+    if r.is_nan() || r.is_infinite() {
+        check(r, a, false, false)
+    } else {
+        Ok(r)
+    }
+}
+
+fn checkf_exact(r: f32, a: Args) -> Result<f32, MathError> {
+    if r.is_nan() || r.is_infinite() {
+        checkf(r, a, false, false)
+    } else {
+        Ok(r)
+    }
+}
+
+/// Arccosine (f64)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acos(x: f64) -> Result<f64, MathError> {
+    check(super::acos(x), args(&[x]), false, false)
+}
+
+/// Arccosine (f32)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosf(x: f32) -> Result<f32, MathError> {
+    checkf(super::acosf(x), argsf(&[x]), false, false)
+}
+
+/// Arcsine (f64)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asin(x: f64) -> Result<f64, MathError> {
+    check(super::asin(x), args(&[x]), false, false)
+}
+
+/// Arcsine (f32)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinf(x: f32) -> Result<f32, MathError> {
+    checkf(super::asinf(x), argsf(&[x]), false, false)
+}
+
+/// Arctangent (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan(x: f64) -> Result<f64, MathError> {
+    check(super::atan(x), args(&[x]), false, false)
+}
+
+/// Arctangent (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanf(x: f32) -> Result<f32, MathError> {
+    checkf(super::atanf(x), argsf(&[x]), false, false)
+}
+
+/// Arctangent of y/x (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2(y: f64, x: f64) -> Result<f64, MathError> {
+    check(super::atan2(y, x), args(&[y, x]), false, false)
+}
+
+/// Arctangent of y/x (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2f(y: f32, x: f32) -> Result<f32, MathError> {
+    checkf(super::atan2f(y, x), argsf(&[y, x]), false, false)
+}
+
+/// Cosine (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cos(x: f64) -> Result<f64, MathError> {
+    check(super::cos(x), args(&[x]), false, false)
+}
+
+/// Cosine (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf(x: f32) -> Result<f32, MathError> {
+    checkf(super::cosf(x), argsf(&[x]), false, false)
+}
+
+/// Sine (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sin(x: f64) -> Result<f64, MathError> {
+    check(super::sin(x), args(&[x]), false, false)
+}
+
+/// Sine (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf(x: f32) -> Result<f32, MathError> {
+    checkf(super::sinf(x), argsf(&[x]), false, false)
+}
+
+/// Tangent (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tan(x: f64) -> Result<f64, MathError> {
+    check(super::tan(x), args(&[x]), false, false)
+}
+
+/// Tangent (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanf(x: f32) -> Result<f32, MathError> {
+    checkf(super::tanf(x), argsf(&[x]), false, false)
+}
+
+/// Arccosine divided by pi (f64)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospi(x: f64) -> Result<f64, MathError> {
+    check(super::acospi(x), args(&[x]), false, false)
+}
+
+/// Arccosine divided by pi (f32)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acospif(x: f32) -> Result<f32, MathError> {
+    checkf(super::acospif(x), argsf(&[x]), false, false)
+}
+
+/// Arcsine divided by pi (f64)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpi(x: f64) -> Result<f64, MathError> {
+    check(super::asinpi(x), args(&[x]), false, false)
+}
+
+/// Arcsine divided by pi (f32)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asinpif(x: f32) -> Result<f32, MathError> {
+    checkf(super::asinpif(x), argsf(&[x]), false, false)
+}
+
+/// Arctangent divided by pi (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpi(x: f64) -> Result<f64, MathError> {
+    check(super::atanpi(x), args(&[x]), false, false)
+}
+
+/// Arctangent divided by pi (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atanpif(x: f32) -> Result<f32, MathError> {
+    checkf(super::atanpif(x), argsf(&[x]), false, false)
+}
+
+/// Arctangent of y/x divided by pi (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pi(y: f64, x: f64) -> Result<f64, MathError> {
+    check(super::atan2pi(y, x), args(&[y, x]), false, false)
+}
+
+/// Arctangent of y/x divided by pi (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2pif(y: f32, x: f32) -> Result<f32, MathError> {
+    checkf(super::atan2pif(y, x), argsf(&[y, x]), false, false)
+}
+
+/// Cosine of pi times x (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospi(x: f64) -> Result<f64, MathError> {
+    check(super::cospi(x), args(&[x]), false, false)
+}
+
+/// Cosine of pi times x (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospif(x: f32) -> Result<f32, MathError> {
+    checkf(super::cospif(x), argsf(&[x]), false, false)
+}
+
+/// Sine of pi times x (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpi(x: f64) -> Result<f64, MathError> {
+    check(super::sinpi(x), args(&[x]), false, false)
+}
+
+/// Sine of pi times x (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpif(x: f32) -> Result<f32, MathError> {
+    checkf(super::sinpif(x), argsf(&[x]), false, false)
+}
+
+/// Tangent of pi times x (f64)
+///
+/// Returns `Pole` if `x` is an odd multiple of 1/2 and `Domain` if it is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpi(x: f64) -> Result<f64, MathError> {
+    check(super::tanpi(x), args(&[x]), true, false)
+}
+
+/// Tangent of pi times x (f32)
+///
+/// Returns `Pole` if `x` is an odd multiple of 1/2 and `Domain` if it is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpif(x: f32) -> Result<f32, MathError> {
+    checkf(super::tanpif(x), argsf(&[x]), true, false)
+}
+
+/// Arccosine in degrees (f64)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosd(x: f64) -> Result<f64, MathError> {
+    check(super::acosd(x), args(&[x]), false, false)
+}
+
+/// Arccosine in degrees (f32)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn acosdf(x: f32) -> Result<f32, MathError> {
+    checkf(super::acosdf(x), argsf(&[x]), false, false)
+}
+
+/// Arcsine in degrees (f64)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asind(x: f64) -> Result<f64, MathError> {
+    check(super::asind(x), args(&[x]), false, false)
+}
+
+/// Arcsine in degrees (f32)
+///
+/// Returns `Domain` if `|x| > 1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn asindf(x: f32) -> Result<f32, MathError> {
+    checkf(super::asindf(x), argsf(&[x]), false, false)
+}
+
+/// Arctangent in degrees (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atand(x: f64) -> Result<f64, MathError> {
+    check(super::atand(x), args(&[x]), false, false)
+}
+
+/// Arctangent in degrees (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atandf(x: f32) -> Result<f32, MathError> {
+    checkf(super::atandf(x), argsf(&[x]), false, false)
+}
+
+/// Arctangent of y/x in degrees (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2d(y: f64, x: f64) -> Result<f64, MathError> {
+    check(super::atan2d(y, x), args(&[y, x]), false, false)
+}
+
+/// Arctangent of y/x in degrees (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn atan2df(y: f32, x: f32) -> Result<f32, MathError> {
+    checkf(super::atan2df(y, x), argsf(&[y, x]), false, false)
+}
+
+/// Cosine of x degrees (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosd(x: f64) -> Result<f64, MathError> {
+    check(super::cosd(x), args(&[x]), false, false)
+}
+
+/// Cosine of x degrees (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosdf(x: f32) -> Result<f32, MathError> {
+    checkf(super::cosdf(x), argsf(&[x]), false, false)
+}
+
+/// Sine of x degrees (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sind(x: f64) -> Result<f64, MathError> {
+    check(super::sind(x), args(&[x]), false, false)
+}
+
+/// Sine of x degrees (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sindf(x: f32) -> Result<f32, MathError> {
+    checkf(super::sindf(x), argsf(&[x]), false, false)
+}
+
+/// Tangent of x degrees (f64)
+///
+/// Returns `Pole` if `x` is an odd multiple of 90 and `Domain` if it is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tand(x: f64) -> Result<f64, MathError> {
+    check(super::tand(x), args(&[x]), true, false)
+}
+
+/// Tangent of x degrees (f32)
+///
+/// Returns `Pole` if `x` is an odd multiple of 90 and `Domain` if it is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tandf(x: f32) -> Result<f32, MathError> {
+    checkf(super::tandf(x), argsf(&[x]), true, false)
+}
+
+/// Inverse hyperbolic cosine (f64)
+///
+/// Returns `Domain` if `x < 1`.
+pub fn acosh(x: f64) -> Result<f64, MathError> {
+    check(super::acosh(x), args(&[x]), false, false)
+}
+
+/// Inverse hyperbolic cosine (f32)
+///
+/// Returns `Domain` if `x < 1`.
+pub fn acoshf(x: f32) -> Result<f32, MathError> {
+    checkf(super::acoshf(x), argsf(&[x]), false, false)
+}
+
+/// Inverse hyperbolic sine (f64)
+pub fn asinh(x: f64) -> Result<f64, MathError> {
+    check(super::asinh(x), args(&[x]), false, false)
+}
+
+/// Inverse hyperbolic sine (f32)
+pub fn asinhf(x: f32) -> Result<f32, MathError> {
+    checkf(super::asinhf(x), argsf(&[x]), false, false)
+}
+
+/// Inverse hyperbolic tangent (f64)
+///
+/// Returns `Pole` if `|x| == 1` and `Domain` if `|x| > 1`.
+pub fn atanh(x: f64) -> Result<f64, MathError> {
+    check(super::atanh(x), args(&[x]), x == 1.0 || x == -1.0, false)
+}
+
+/// Inverse hyperbolic tangent (f32)
+///
+/// Returns `Pole` if `|x| == 1` and `Domain` if `|x| > 1`.
+pub fn atanhf(x: f32) -> Result<f32, MathError> {
+    checkf(super::atanhf(x), argsf(&[x]), x == 1.0 || x == -1.0, false)
+}
+
+/// Hyperbolic cosine (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosh(x: f64) -> Result<f64, MathError> {
+    check(super::cosh(x), args(&[x]), false, false)
+}
+
+/// Hyperbolic cosine (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn coshf(x: f32) -> Result<f32, MathError> {
+    checkf(super::coshf(x), argsf(&[x]), false, false)
+}
+
+/// Hyperbolic sine (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinh(x: f64) -> Result<f64, MathError> {
+    check(super::sinh(x), args(&[x]), false, false)
+}
+
+/// Hyperbolic sine (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinhf(x: f32) -> Result<f32, MathError> {
+    checkf(super::sinhf(x), argsf(&[x]), false, false)
+}
+
+/// Hyperbolic tangent (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanh(x: f64) -> Result<f64, MathError> {
+    check(super::tanh(x), args(&[x]), false, false)
+}
+
+/// Hyperbolic tangent (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhf(x: f32) -> Result<f32, MathError> {
+    checkf(super::tanhf(x), argsf(&[x]), false, false)
+}
+
+/// Exponential, base e (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp(x: f64) -> Result<f64, MathError> {
+    check(super::exp(x), args(&[x]), false, true)
+}
+
+/// Exponential, base e (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf(x: f32) -> Result<f32, MathError> {
+    checkf(super::expf(x), argsf(&[x]), false, true)
+}
+
+/// Exponential, base 2 (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2(x: f64) -> Result<f64, MathError> {
+    check(super::exp2(x), args(&[x]), false, true)
+}
+
+/// Exponential, base 2 (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2f(x: f32) -> Result<f32, MathError> {
+    checkf(super::exp2f(x), argsf(&[x]), false, true)
+}
+
+/// Exponential, base 10 (f64)
+pub fn exp10(x: f64) -> Result<f64, MathError> {
+    check(super::exp10(x), args(&[x]), false, true)
+}
+
+/// Exponential, base 10 (f32)
+pub fn exp10f(x: f32) -> Result<f32, MathError> {
+    checkf(super::exp10f(x), argsf(&[x]), false, true)
+}
+
+/// Exponential, base e, of x, minus 1 (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expm1(x: f64) -> Result<f64, MathError> {
+    check(super::expm1(x), args(&[x]), false, false)
+}
+
+/// Exponential, base e, of x, minus 1 (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expm1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::expm1f(x), argsf(&[x]), false, false)
+}
+
+/// Exponential, base 2, of x, minus 1 (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1(x: f64) -> Result<f64, MathError> {
+    check(super::exp2m1(x), args(&[x]), false, false)
+}
+
+/// Exponential, base 2, of x, minus 1 (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::exp2m1f(x), argsf(&[x]), false, false)
+}
+
+/// Exponential, base 10, of x, minus 1 (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1(x: f64) -> Result<f64, MathError> {
+    check(super::exp10m1(x), args(&[x]), false, false)
+}
+
+/// Exponential, base 10, of x, minus 1 (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::exp10m1f(x), argsf(&[x]), false, false)
+}
+
+/// Natural logarithm (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log(x: f64) -> Result<f64, MathError> {
+    check(super::log(x), args(&[x]), x == 0.0, false)
+}
+
+/// Natural logarithm (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf(x: f32) -> Result<f32, MathError> {
+    checkf(super::logf(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Base 2 logarithm (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2(x: f64) -> Result<f64, MathError> {
+    check(super::log2(x), args(&[x]), x == 0.0, false)
+}
+
+/// Base 2 logarithm (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2f(x: f32) -> Result<f32, MathError> {
+    checkf(super::log2f(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Base 10 logarithm (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10(x: f64) -> Result<f64, MathError> {
+    check(super::log10(x), args(&[x]), x == 0.0, false)
+}
+
+/// Base 10 logarithm (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10f(x: f32) -> Result<f32, MathError> {
+    checkf(super::log10f(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Natural logarithm of 1 + x (f64)
+///
+/// Returns `Pole` if `x == -1` and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log1p(x: f64) -> Result<f64, MathError> {
+    check(super::log1p(x), args(&[x]), x == -1.0, false)
+}
+
+/// Natural logarithm of 1 + x (f32)
+///
+/// Returns `Pole` if `x == -1` and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log1pf(x: f32) -> Result<f32, MathError> {
+    checkf(super::log1pf(x), argsf(&[x]), x == -1.0, false)
+}
+
+/// Base 2 logarithm of 1 + x (f64)
+///
+/// Returns `Pole` if `x == -1` and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1(x: f64) -> Result<f64, MathError> {
+    check(super::log2p1(x), args(&[x]), x == -1.0, false)
+}
+
+/// Base 2 logarithm of 1 + x (f32)
+///
+/// Returns `Pole` if `x == -1` and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::log2p1f(x), argsf(&[x]), x == -1.0, false)
+}
+
+/// Base 10 logarithm of 1 + x (f64)
+///
+/// Returns `Pole` if `x == -1` and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10p1(x: f64) -> Result<f64, MathError> {
+    check(super::log10p1(x), args(&[x]), x == -1.0, false)
+}
+
+/// Base 10 logarithm of 1 + x (f32)
+///
+/// Returns `Pole` if `x == -1` and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10p1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::log10p1f(x), argsf(&[x]), x == -1.0, false)
+}
+
+/// Unbiased exponent (f64)
+///
+/// Returns `Pole` if `x` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logb(x: f64) -> Result<f64, MathError> {
+    check(super::logb(x), args(&[x]), x == 0.0, false)
+}
+
+/// Unbiased exponent (f32)
+///
+/// Returns `Pole` if `x` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf(x: f32) -> Result<f32, MathError> {
+    checkf(super::logbf(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Cube root (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cbrt(x: f64) -> Result<f64, MathError> {
+    check(super::cbrt(x), args(&[x]), false, false)
+}
+
+/// Cube root (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cbrtf(x: f32) -> Result<f32, MathError> {
+    checkf(super::cbrtf(x), argsf(&[x]), false, false)
+}
+
+/// Euclidean distance (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn hypot(x: f64, y: f64) -> Result<f64, MathError> {
+    check(super::hypot(x, y), args(&[x, y]), false, false)
+}
+
+/// Euclidean distance (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn hypotf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf(super::hypotf(x, y), argsf(&[x, y]), false, false)
+}
+
+/// Power (f64)
+///
+/// Returns `Pole` if `x` is zero and `y` negative, and `Domain` if `x` is negative and `y` finite and not an integer.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pow(x: f64, y: f64) -> Result<f64, MathError> {
+    check(
+        super::pow(x, y),
+        args(&[x, y]),
+        x == 0.0 && y < 0.0,
+        x != 0.0,
+    )
+}
+
+/// Power (f32)
+///
+/// Returns `Pole` if `x` is zero and `y` negative, and `Domain` if `x` is negative and `y` finite and not an integer.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf(
+        super::powf(x, y),
+        argsf(&[x, y]),
+        x == 0.0 && y < 0.0,
+        x != 0.0,
+    )
+}
+
+/// Power, defined through exp(y * log(x)) (f64)
+///
+/// Returns `Pole` if `x` is zero and `y` negative, and `Domain` for negative `x` and for the indeterminate forms `0^0`, `inf^0` and `1^inf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powr(x: f64, y: f64) -> Result<f64, MathError> {
+    check(
+        super::powr(x, y),
+        args(&[x, y]),
+        x == 0.0 && y < 0.0,
+        x != 0.0,
+    )
+}
+
+/// Power, defined through exp(y * log(x)) (f32)
+///
+/// Returns `Pole` if `x` is zero and `y` negative, and `Domain` for negative `x` and for the indeterminate forms `0^0`, `inf^0` and `1^inf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powrf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf(
+        super::powrf(x, y),
+        argsf(&[x, y]),
+        x == 0.0 && y < 0.0,
+        x != 0.0,
+    )
+}
+
+/// Power with an integer exponent (f64)
+///
+/// Returns `Pole` if `x` is zero and `n` negative.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pown(x: f64, n: i64) -> Result<f64, MathError> {
+    check(super::pown(x, n), args(&[x]), x == 0.0 && n < 0, x != 0.0)
+}
+
+/// Power with an integer exponent (f32)
+///
+/// Returns `Pole` if `x` is zero and `n` negative.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pownf(x: f32, n: i64) -> Result<f32, MathError> {
+    checkf(super::pownf(x, n), argsf(&[x]), x == 0.0 && n < 0, x != 0.0)
+}
+
+/// Power with an integer exponent, as the std float method (f64)
+///
+/// Returns `Pole` if `x` is zero and `n` negative.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powi(x: f64, n: i32) -> Result<f64, MathError> {
+    check(super::powi(x, n), args(&[x]), x == 0.0 && n < 0, x != 0.0)
+}
+
+/// Power with an integer exponent, as the std float method (f32)
+///
+/// Returns `Pole` if `x` is zero and `n` negative.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powif(x: f32, n: i32) -> Result<f32, MathError> {
+    checkf(super::powif(x, n), argsf(&[x]), x == 0.0 && n < 0, x != 0.0)
+}
+
+/// n-th root (f64)
+///
+/// Returns `Pole` if `x` is zero and `n` negative, and `Domain` if `n` is zero or if `x` is negative and `n` even.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootn(x: f64, n: i64) -> Result<f64, MathError> {
+    check(super::rootn(x, n), args(&[x]), x == 0.0 && n < 0, false)
+}
+
+/// n-th root (f32)
+///
+/// Returns `Pole` if `x` is zero and `n` negative, and `Domain` if `n` is zero or if `x` is negative and `n` even.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootnf(x: f32, n: i64) -> Result<f32, MathError> {
+    checkf(super::rootnf(x, n), argsf(&[x]), x == 0.0 && n < 0, false)
+}
+
+/// Compound interest (f64)
+///
+/// Returns `Pole` if `x == -1` and `n` negative, and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundn(x: f64, n: i64) -> Result<f64, MathError> {
+    check(
+        super::compoundn(x, n),
+        args(&[x]),
+        x == -1.0 && n < 0,
+        x != -1.0,
+    )
+}
+
+/// Compound interest (f32)
+///
+/// Returns `Pole` if `x == -1` and `n` negative, and `Domain` if `x < -1`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundnf(x: f32, n: i64) -> Result<f32, MathError> {
+    checkf(
+        super::compoundnf(x, n),
+        argsf(&[x]),
+        x == -1.0 && n < 0,
+        x != -1.0,
+    )
+}
+
+/// Square root (f64)
+///
+/// Returns `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrt(x: f64) -> Result<f64, MathError> {
+    check(super::sqrt(x), args(&[x]), false, false)
+}
+
+/// Square root (f32)
+///
+/// Returns `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf(x: f32) -> Result<f32, MathError> {
+    checkf(super::sqrtf(x), argsf(&[x]), false, false)
+}
+
+/// Reciprocal square root (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrt(x: f64) -> Result<f64, MathError> {
+    check(super::rsqrt(x), args(&[x]), x == 0.0, false)
+}
+
+/// Reciprocal square root (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrtf(x: f32) -> Result<f32, MathError> {
+    checkf(super::rsqrtf(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Reciprocal (f64)
+///
+/// Returns `Pole` if `x` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn recip(x: f64) -> Result<f64, MathError> {
+    check(super::recip(x), args(&[x]), x == 0.0, false)
+}
+
+/// Reciprocal (f32)
+///
+/// Returns `Pole` if `x` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn recipf(x: f32) -> Result<f32, MathError> {
+    checkf(super::recipf(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Error function (f64)
+pub fn erf(x: f64) -> Result<f64, MathError> {
+    check(super::erf(x), args(&[x]), false, false)
+}
+
+/// Error function (f32)
+pub fn erff(x: f32) -> Result<f32, MathError> {
+    checkf(super::erff(x), argsf(&[x]), false, false)
+}
+
+/// Complementary error function (f64)
+pub fn erfc(x: f64) -> Result<f64, MathError> {
+    check(super::erfc(x), args(&[x]), false, true)
+}
+
+/// Complementary error function (f32)
+pub fn erfcf(x: f32) -> Result<f32, MathError> {
+    checkf(super::erfcf(x), argsf(&[x]), false, true)
+}
+
+/// Natural logarithm of the absolute value of the gamma function (f64)
+///
+/// Returns `Pole` if `x` is zero or a negative integer.
+pub fn lgamma(x: f64) -> Result<f64, MathError> {
+    check(
+        super::lgamma(x),
+        args(&[x]),
+        x <= 0.0 && super::floor(x) == x,
+        false,
+    )
+}
+
+/// Natural logarithm of the absolute value of the gamma function (f32)
+///
+/// Returns `Pole` if `x` is zero or a negative integer.
+pub fn lgammaf(x: f32) -> Result<f32, MathError> {
+    checkf(
+        super::lgammaf(x),
+        argsf(&[x]),
+        x <= 0.0 && super::floorf(x) == x,
+        false,
+    )
+}
+
+/// Gamma function (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if it is a negative integer or negative infinity.
+pub fn tgamma(x: f64) -> Result<f64, MathError> {
+    check(super::tgamma(x), args(&[x]), x == 0.0, true)
+}
+
+/// Gamma function (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if it is a negative integer or negative infinity.
+pub fn tgammaf(x: f32) -> Result<f32, MathError> {
+    checkf(super::tgammaf(x), argsf(&[x]), x == 0.0, true)
+}
+
+/// Bessel function of the first kind of order zero (f64)
+pub fn j0(x: f64) -> Result<f64, MathError> {
+    check(super::j0(x), args(&[x]), false, false)
+}
+
+/// Bessel function of the first kind of order zero (f32)
+pub fn j0f(x: f32) -> Result<f32, MathError> {
+    checkf(super::j0f(x), argsf(&[x]), false, false)
+}
+
+/// Bessel function of the first kind of order one (f64)
+pub fn j1(x: f64) -> Result<f64, MathError> {
+    check(super::j1(x), args(&[x]), false, x != 0.0)
+}
+
+/// Bessel function of the first kind of order one (f32)
+pub fn j1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::j1f(x), argsf(&[x]), false, x != 0.0)
+}
+
+/// Bessel function of the first kind of order n (f64)
+pub fn jn(n: i32, x: f64) -> Result<f64, MathError> {
+    check(super::jn(n, x), args(&[x]), false, x != 0.0 && n != 0)
+}
+
+/// Bessel function of the first kind of order n (f32)
+pub fn jnf(n: i32, x: f32) -> Result<f32, MathError> {
+    checkf(super::jnf(n, x), argsf(&[x]), false, x != 0.0 && n != 0)
+}
+
+/// Bessel function of the second kind of order zero (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+pub fn y0(x: f64) -> Result<f64, MathError> {
+    check(super::y0(x), args(&[x]), x == 0.0, false)
+}
+
+/// Bessel function of the second kind of order zero (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+pub fn y0f(x: f32) -> Result<f32, MathError> {
+    checkf(super::y0f(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Bessel function of the second kind of order one (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+pub fn y1(x: f64) -> Result<f64, MathError> {
+    check(super::y1(x), args(&[x]), x == 0.0, false)
+}
+
+/// Bessel function of the second kind of order one (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+pub fn y1f(x: f32) -> Result<f32, MathError> {
+    checkf(super::y1f(x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Bessel function of the second kind of order n (f64)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+pub fn yn(n: i32, x: f64) -> Result<f64, MathError> {
+    check(super::yn(n, x), args(&[x]), x == 0.0, false)
+}
+
+/// Bessel function of the second kind of order n (f32)
+///
+/// Returns `Pole` if `x` is zero and `Domain` if `x < 0`.
+pub fn ynf(n: i32, x: f32) -> Result<f32, MathError> {
+    checkf(super::ynf(n, x), argsf(&[x]), x == 0.0, false)
+}
+
+/// Euclidean division (f64)
+///
+/// Returns `Pole` if `y` is zero and `x` is not, and `Domain` if both are.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn div_euclid(x: f64, y: f64) -> Result<f64, MathError> {
+    check(super::div_euclid(x, y), args(&[x, y]), y == 0.0, false)
+}
+
+/// Euclidean division (f32)
+///
+/// Returns `Pole` if `y` is zero and `x` is not, and `Domain` if both are.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn div_euclidf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf(super::div_euclidf(x, y), argsf(&[x, y]), y == 0.0, false)
+}
+
+/// Fused multiply add (f64)
+///
+/// Returns `Domain` for `0 * inf`, and for `inf - inf` when `z` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fma(x: f64, y: f64, z: f64) -> Result<f64, MathError> {
+    check(super::fma(x, y, z), args(&[x, y, z]), false, false)
+}
+
+/// Fused multiply add (f32)
+///
+/// Returns `Domain` for `0 * inf`, and for `inf - inf` when `z` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf(x: f32, y: f32, z: f32) -> Result<f32, MathError> {
+    checkf(super::fmaf(x, y, z), argsf(&[x, y, z]), false, false)
+}
+
+/// Positive difference (f64)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fdim(x: f64, y: f64) -> Result<f64, MathError> {
+    check_exact(super::fdim(x, y), args(&[x, y]))
+}
+
+/// Positive difference (f32)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fdimf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf_exact(super::fdimf(x, y), argsf(&[x, y]))
+}
+
+/// Floating-point remainder of x/y, truncated (f64)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmod(x: f64, y: f64) -> Result<f64, MathError> {
+    check_exact(super::fmod(x, y), args(&[x, y]))
+}
+
+/// Floating-point remainder of x/y, truncated (f32)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmodf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf_exact(super::fmodf(x, y), argsf(&[x, y]))
+}
+
+/// Floating-point remainder of x/y, rounded to nearest (f64)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remainder(x: f64, y: f64) -> Result<f64, MathError> {
+    check_exact(super::remainder(x, y), args(&[x, y]))
+}
+
+/// Floating-point remainder of x/y, rounded to nearest (f32)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remainderf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf_exact(super::remainderf(x, y), argsf(&[x, y]))
+}
+
+/// Euclidean remainder (f64)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rem_euclid(x: f64, y: f64) -> Result<f64, MathError> {
+    check_exact(super::rem_euclid(x, y), args(&[x, y]))
+}
+
+/// Euclidean remainder (f32)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rem_euclidf(x: f32, y: f32) -> Result<f32, MathError> {
+    checkf_exact(super::rem_euclidf(x, y), argsf(&[x, y]))
+}
+
+/// Sine and cosine (f64)
+///
+/// Returns `Domain` if `x` is infinite.
+pub fn sincos(x: f64) -> Result<(f64, f64), MathError> {
+    let (s, c) = super::sincos(x);
+    check(s, args(&[x]), false, false).map(|s| (s, c))
+}
+
+/// Sine and cosine (f32)
+///
+/// Returns `Domain` if `x` is infinite.
+pub fn sincosf(x: f32) -> Result<(f32, f32), MathError> {
+    let (s, c) = super::sincosf(x);
+    checkf(s, argsf(&[x]), false, false).map(|s| (s, c))
+}
+
+/// Remainder and part of the quotient of x/y (f64)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remquo(x: f64, y: f64) -> Result<(f64, i32), MathError> {
+    let (r, q) = super::remquo(x, y);
+    check_exact(r, args(&[x, y])).map(|r| (r, q))
+}
+
+/// Remainder and part of the quotient of x/y (f32)
+///
+/// Returns `Domain` if `x` is infinite or `y` is zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn remquof(x: f32, y: f32) -> Result<(f32, i32), MathError> {
+    let (r, q) = super::remquof(x, y);
+    checkf_exact(r, argsf(&[x, y])).map(|r| (r, q))
+}
+
+/// Multiplies x by 2 raised to the power n (f64)
+///
+/// Returns `Underflow` only if the result is tiny and inexact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbn(x: f64, n: i32) -> Result<f64, MathError> {
+    let r = super::scalbn(x, n);
+    if super::scalbn(r, n.wrapping_neg()) == x {
+        /* exact, whatever the magnitude of r */
+        return Ok(r);
+    }
+    check(r, args(&[x]), false, true)
+}
+
+/// Multiplies x by 2 raised to the power n (f32)
+///
+/// Returns `Underflow` only if the result is tiny and inexact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbnf(x: f32, n: i32) -> Result<f32, MathError> {
+    let r = super::scalbnf(x, n);
+    if super::scalbnf(r, n.wrapping_neg()) == x {
+        return Ok(r);
+    }
+    checkf(r, argsf(&[x]), false, true)
+}
+
+/// Multiplies x by 2 raised to the power n (f64)
+///
+/// Returns `Underflow` only if the result is tiny and inexact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbln(x: f64, n: i64) -> Result<f64, MathError> {
+    let r = super::scalbln(x, n);
+    if super::scalbln(r, n.wrapping_neg()) == x {
+        return Ok(r);
+    }
+    check(r, args(&[x]), false, true)
+}
+
+/// Multiplies x by 2 raised to the power n (f32)
+///
+/// Returns `Underflow` only if the result is tiny and inexact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalblnf(x: f32, n: i64) -> Result<f32, MathError> {
+    let r = super::scalblnf(x, n);
+    if super::scalblnf(r, n.wrapping_neg()) == x {
+        return Ok(r);
+    }
+    checkf(r, argsf(&[x]), false, true)
+}
+
+/// Multiplies x by 2 raised to the power n (f64)
+///
+/// Returns `Underflow` only if the result is tiny and inexact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexp(x: f64, n: i32) -> Result<f64, MathError> {
+    scalbn(x, n)
+}
+
+/// Multiplies x by 2 raised to the power n (f32)
+///
+/// Returns `Underflow` only if the result is tiny and inexact.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexpf(x: f32, n: i32) -> Result<f32, MathError> {
+    scalbnf(x, n)
+}
+
+/// Next representable value after x in the direction of y (f64)
+///
+/// Returns `Overflow` if `x` is finite and the result is not, and `Underflow`
+/// if the result is subnormal or zero and differs from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafter(x: f64, y: f64) -> Result<f64, MathError> {
+    let r = super::nextafter(x, y);
+    if x.is_nan() || y.is_nan() || r == x {
+        return Ok(r);
+    }
+    check(r, args(&[x]), false, true)
+}
+
+/// Next representable value after x in the direction of y (f32)
+///
+/// Returns `Overflow` if `x` is finite and the result is not, and `Underflow`
+/// if the result is subnormal or zero and differs from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafterf(x: f32, y: f32) -> Result<f32, MathError> {
+    let r = super::nextafterf(x, y);
+    if x.is_nan() || y.is_nan() || r == x {
+        return Ok(r);
+    }
+    checkf(r, argsf(&[x]), false, true)
+}
+
+/// Next representable value after x in the direction of y (f64)
+///
+/// Returns `Overflow` if `x` is finite and the result is not, and `Underflow`
+/// if the result is subnormal or zero and differs from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttoward(x: f64, y: f64) -> Result<f64, MathError> {
+    nextafter(x, y)
+}
+
+/// Next representable value after x in the direction of y (f32)
+///
+/// Returns `Overflow` if `x` is finite and the result is not, and `Underflow`
+/// if the result is subnormal or zero and differs from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttowardf(x: f32, y: f64) -> Result<f32, MathError> {
+    let r = super::nexttowardf(x, y);
+    if x.is_nan() || y.is_nan() || r == x {
+        return Ok(r);
+    }
+    checkf(r, argsf(&[x]), false, true)
+}
+
+/// Unbiased exponent as an integer (f64)
+///
+/// Returns `Domain` if `x` is zero, infinite or NaN.
+pub fn ilogb(x: f64) -> Result<i32, MathError> {
+    if x == 0.0 || x.is_nan() || x.is_infinite() {
+        return Err(MathError::Domain);
+    }
+    Ok(super::ilogb(x))
+}
+
+/// Unbiased exponent as an integer (f32)
+///
+/// Returns `Domain` if `x` is zero, infinite or NaN.
+pub fn ilogbf(x: f32) -> Result<i32, MathError> {
+    if x == 0.0 || x.is_nan() || x.is_infinite() {
+        return Err(MathError::Domain);
+    }
+    Ok(super::ilogbf(x))
+}
+
+/// Round to nearest integer (f64)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrint(x: f64) -> Result<i64, MathError> {
+    super::trunc_to_i64(super::rint(x)).ok_or(MathError::Domain)
+}
+
+/// Round to nearest integer (f32)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrintf(x: f32) -> Result<i64, MathError> {
+    super::truncf_to_i64(super::rintf(x)).ok_or(MathError::Domain)
+}
+
+/// Round to nearest integer (f64)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llrint(x: f64) -> Result<i64, MathError> {
+    lrint(x)
+}
+
+/// Round to nearest integer (f32)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llrintf(x: f32) -> Result<i64, MathError> {
+    lrintf(x)
+}
+
+/// Round to nearest integer, ties away from zero (f64)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lround(x: f64) -> Result<i64, MathError> {
+    super::round_to_i64(x).ok_or(MathError::Domain)
+}
+
+/// Round to nearest integer, ties away from zero (f32)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lroundf(x: f32) -> Result<i64, MathError> {
+    super::roundf_to_i64(x).ok_or(MathError::Domain)
+}
+
+/// Round to nearest integer, ties away from zero (f64)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llround(x: f64) -> Result<i64, MathError> {
+    lround(x)
+}
+
+/// Round to nearest integer, ties away from zero (f32)
+///
+/// Returns `Domain` if `x` is NaN or the rounded value does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llroundf(x: f32) -> Result<i64, MathError> {
+    lroundf(x)
+}
+
+/// Addition narrowed to f32
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fadd(x: f64, y: f64) -> Result<f32, MathError> {
+    checkf(super::fadd(x, y), args(&[x, y]), false, false)
+}
+
+/// Subtraction narrowed to f32
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fsub(x: f64, y: f64) -> Result<f32, MathError> {
+    checkf(super::fsub(x, y), args(&[x, y]), false, false)
+}
+
+/// Multiplication narrowed to f32
+///
+/// Returns `Domain` for `0 * inf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmul(x: f64, y: f64) -> Result<f32, MathError> {
+    checkf(
+        super::fmul(x, y),
+        args(&[x, y]),
+        false,
+        x != 0.0 && y != 0.0,
+    )
+}
+
+/// Division narrowed to f32
+///
+/// Returns `Pole` if `y` is zero and `x` is not, and `Domain` for `0 / 0` and
+/// `inf / inf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fdiv(x: f64, y: f64) -> Result<f32, MathError> {
+    checkf(super::fdiv(x, y), args(&[x, y]), y == 0.0, x != 0.0)
+}
+
+/// Square root narrowed to f32
+///
+/// Returns `Domain` if `x < 0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fsqrt(x: f64) -> Result<f32, MathError> {
+    checkf(super::fsqrt(x), args(&[x]), false, false)
+}
+
+/// Fused multiply-add narrowed to f32
+///
+/// Returns `Domain` for `0 * inf`, and for `inf - inf` when `z` is infinite.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ffma(x: f64, y: f64, z: f64) -> Result<f32, MathError> {
+    checkf(super::ffma(x, y, z), args(&[x, y, z]), false, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn domain() {
+        assert_eq!(log(-1.0), Err(MathError::Domain));
+        assert_eq!(sqrt(-1.0), Err(MathError::Domain));
+        assert_eq!(acosf(2.0), Err(MathError::Domain));
+        assert_eq!(sin(INFINITY), Err(MathError::Domain));
+        assert_eq!(pow(-8.0, 1.0 / 3.0), Err(MathError::Domain));
+        assert_eq!(fmod(1.0, 0.0), Err(MathError::Domain));
+        assert_eq!(tgamma(-2.0), Err(MathError::Domain));
+        assert_eq!(ilogb(0.0), Err(MathError::Domain));
+        assert_eq!(lrint(1e300), Err(MathError::Domain));
+        assert_eq!(lround(NAN), Err(MathError::Domain));
+    }
+
+    #[test]
+    fn pole() {
+        assert_eq!(log(0.0), Err(MathError::Pole));
+        assert_eq!(log2f(-0.0), Err(MathError::Pole));
+        assert_eq!(log1p(-1.0), Err(MathError::Pole));
+        assert_eq!(atanh(-1.0), Err(MathError::Pole));
+        assert_eq!(pow(0.0, -1.0), Err(MathError::Pole));
+        assert_eq!(lgamma(-3.0), Err(MathError::Pole));
+        assert_eq!(tgamma(0.0), Err(MathError::Pole));
+        assert_eq!(y0(0.0), Err(MathError::Pole));
+        assert_eq!(tanpi(0.5), Err(MathError::Pole));
+        assert_eq!(tand(90.0), Err(MathError::Pole));
+        assert_eq!(fdiv(1.0, 0.0), Err(MathError::Pole));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(exp(1000.0), Err(MathError::Overflow));
+        assert_eq!(cosh(-1000.0), Err(MathError::Overflow));
+        assert_eq!(powf(10.0, 40.0), Err(MathError::Overflow));
+        assert_eq!(hypot(MAX, MAX), Err(MathError::Overflow));
+        assert_eq!(scalbn(1.0, 1024), Err(MathError::Overflow));
+        assert_eq!(nextafter(MAX, INFINITY), Err(MathError::Overflow));
+        assert_eq!(fadd(1e300, 0.0), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn underflow() {
+        assert_eq!(exp(-1000.0), Err(MathError::Underflow));
+        assert_eq!(expf(-90.0), Err(MathError::Underflow));
+        assert_eq!(pow(2.0, -1070.0), Err(MathError::Underflow));
+        assert_eq!(erfc(30.0), Err(MathError::Underflow));
+        assert_eq!(sin(1e-310), Err(MathError::Underflow));
+        assert_eq!(scalbn(3.0, -1075), Err(MathError::Underflow));
+        assert_eq!(nextafter(MIN_POSITIVE, 0.0), Err(MathError::Underflow));
+        assert_eq!(fmul(1e-30, 1e-30), Err(MathError::Underflow));
+    }
+
+    #[test]
+    fn no_error() {
+        assert_eq!(log(1.0), Ok(0.0));
+        assert_eq!(log(INFINITY), Ok(INFINITY));
+        assert_eq!(exp(NEG_INFINITY), Ok(0.0));
+        assert_eq!(pow(0.5, INFINITY), Ok(0.0));
+        assert!(log(NAN).unwrap().is_nan());
+        assert_eq!(sind(180.0), Ok(0.0));
+        assert_eq!(fmod(5e-324, 1.0), Ok(5e-324));
+        assert_eq!(fdim(2e-308, 1e-308), Ok(1e-308));
+        assert_eq!(scalbn(1.0, -1074), Ok(5e-324));
+        assert_eq!(nextafter(0.0, -0.0), Ok(-0.0));
+        assert_eq!(ilogb(8.0), Ok(3));
+        assert_eq!(lround(-2.5), Ok(-3));
+        assert_eq!(sincos(0.0), Ok((0.0, 1.0)));
+        assert_eq!(remquo(7.0, 2.0), Ok((-1.0, 4)));
+    }
+}
@@ -0,0 +1,51 @@
+/// Restrict x to an interval (f64)
+///
+/// Returns `max` if `x` is greater than `max`, `min` if `x` is less than `min`
+/// and `x` otherwise, NaN included, like `f64::clamp`.
+///
+/// # Panics
+///
+/// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+pub fn clamp(x: f64, min: f64, max: f64) -> f64 {
+    assert!(
+        min <= max,
+        "min > max, or either was NaN. min = {:?}, max = {:?}",
+        min,
+        max
+    );
+    let mut x = x;
+    if x < min {
+        x = min;
+    }
+    if x > max {
+        x = max;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(clamp(-3.0, -2.0, 1.0), -2.0);
+        assert_eq!(clamp(0.0, -2.0, 1.0), 0.0);
+        assert_eq!(clamp(2.0, -2.0, 1.0), 1.0);
+        assert!(clamp(NAN, -2.0, 1.0).is_nan());
+        assert_eq!(clamp(-0.0, 0.0, 0.0).to_bits(), (-0.0f64).to_bits());
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverted_bounds() {
+        clamp(0.0, 1.0, -1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_bound() {
+        clamp(0.0, NAN, 1.0);
+    }
+}
@@ -0,0 +1,24 @@
+/// Restrict x to an interval (f32)
+///
+/// Returns `max` if `x` is greater than `max`, `min` if `x` is less than `min`
+/// and `x` otherwise, NaN included, like `f32::clamp`.
+///
+/// # Panics
+///
+/// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+pub fn clampf(x: f32, min: f32, max: f32) -> f32 {
+    assert!(
+        min <= max,
+        "min > max, or either was NaN. min = {:?}, max = {:?}",
+        min,
+        max
+    );
+    let mut x = x;
+    if x < min {
+        x = min;
+    }
+    if x > max {
+        x = max;
+    }
+    x
+}
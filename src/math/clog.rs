@@ -0,0 +1,72 @@
+use super::{cabs, carg, fma, log, log1p, Complex};
+
+/* a + b as hi + lo exactly */
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let hi = a + b;
+    let t = hi - a;
+    (hi, (a - (hi - t)) + (b - t))
+}
+
+/// Complex natural logarithm (f64)
+///
+/// Returns the natural logarithm of `z`, with the branch cut along the
+/// negative real axis: the imaginary part of the result is in `[-pi, pi]`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clog(z: Complex<f64>) -> Complex<f64> {
+    let (x, y) = (z.re, z.im);
+    let r2 = x * x + y * y;
+    if (0.5..=2.0).contains(&r2) {
+        /* log|z| = log1p(x^2 + y^2 - 1)/2, which would lose the low bits of
+         * x^2 + y^2 near |z| == 1 unless the sum is done exactly */
+        let xh = x * x;
+        let xl = fma(x, x, -xh);
+        let yh = y * y;
+        let yl = fma(y, y, -yh);
+        let (h, l) = two_sum(xh, -1.0);
+        let (h, l2) = two_sum(h, yh);
+        let s = h + (l + l2 + xl + yl);
+        return Complex::new(0.5 * log1p(s), carg(z));
+    }
+    Complex::new(log(cabs(z)), carg(z))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(
+            clog(Complex::new(-0.0, 0.0)),
+            Complex::new(NEG_INFINITY, PI)
+        );
+        assert_eq!(
+            clog(Complex::new(0.0, -0.0)),
+            Complex::new(NEG_INFINITY, -0.0)
+        );
+        assert_eq!(
+            clog(Complex::new(NEG_INFINITY, 1.0)),
+            Complex::new(INFINITY, PI)
+        );
+        let z = clog(Complex::new(NAN, INFINITY));
+        assert!(z.re == INFINITY && z.im.is_nan());
+    }
+
+    #[test]
+    fn branch_cut() {
+        assert_eq!(clog(Complex::new(-1.0, 0.0)), Complex::new(0.0, PI));
+        assert_eq!(clog(Complex::new(-1.0, -0.0)), Complex::new(0.0, -PI));
+        assert_eq!(
+            clog(Complex::new(0.0, 1.0)),
+            Complex::new(0.0, consts::FRAC_PI_2)
+        );
+    }
+
+    #[test]
+    fn near_unit_circle() {
+        /* 0.6 and 0.8 are not exact, so |z| is just above 1 */
+        assert_eq!(clog(Complex::new(0.6, 0.8)).re, 2.2204460492503132e-17);
+        assert_eq!(clog(Complex::new(1.0, 1e-9)).re, 5e-19);
+    }
+}
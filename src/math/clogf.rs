@@ -0,0 +1,18 @@
+use super::{cabsf, cargf, log1p, logf, Complex};
+
+/// Complex natural logarithm (f32)
+///
+/// Returns the natural logarithm of `z`, with the branch cut along the
+/// negative real axis: the imaginary part of the result is in `[-pi, pi]`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn clogf(z: Complex<f32>) -> Complex<f32> {
+    let (x, y) = (z.re as f64, z.im as f64);
+    let r2 = x * x + y * y;
+    if (0.5..=2.0).contains(&r2) {
+        /* log|z| = log1p(x^2 + y^2 - 1)/2, where x^2 - 1 and y^2 are exact
+         * in double precision */
+        let s = (x * x - 1.0) + y * y;
+        return Complex::new((0.5 * log1p(s)) as f32, cargf(z));
+    }
+    Complex::new(logf(cabsf(z)), cargf(z))
+}
@@ -0,0 +1,178 @@
+use super::{copysign, fabs, fmax, logb, scalbn};
+use core::f64;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A complex number
+///
+/// The layout is that of C's `double complex` and `float complex`, with the
+/// real part first. Multiplication and division follow C Annex G: a product
+/// or quotient that is infinite in the limit is an infinity, also where the
+/// textbook formulas would give NaN + I NaN.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Complex<T> {
+    /// The real part
+    pub re: T,
+    /// The imaginary part
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Returns `re + I im` (`CMPLX`)
+    pub const fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+/* 1 with the sign of x if x is infinite, 0 with the sign of x otherwise */
+fn box_inf(x: f64) -> f64 {
+    copysign(if x.is_infinite() { 1.0 } else { 0.0 }, x)
+}
+
+/* 0 with the sign of x if x is NaN, x otherwise */
+fn nan_to_zero(x: f64) -> f64 {
+    if x.is_nan() {
+        copysign(0.0, x)
+    } else {
+        x
+    }
+}
+
+impl Mul for Complex<f64> {
+    type Output = Complex<f64>;
+
+    fn mul(self, rhs: Complex<f64>) -> Complex<f64> {
+        let (mut a, mut b) = (self.re, self.im);
+        let (mut c, mut d) = (rhs.re, rhs.im);
+        let (ac, bd, ad, bc) = (a * c, b * d, a * d, b * c);
+        let mut x = ac - bd;
+        let mut y = ad + bc;
+
+        if x.is_nan() && y.is_nan() {
+            /* recover infinities that computed as NaN + I NaN */
+            let mut recalc = false;
+            if a.is_infinite() || b.is_infinite() {
+                a = box_inf(a);
+                b = box_inf(b);
+                c = nan_to_zero(c);
+                d = nan_to_zero(d);
+                recalc = true;
+            }
+            if c.is_infinite() || d.is_infinite() {
+                c = box_inf(c);
+                d = box_inf(d);
+                a = nan_to_zero(a);
+                b = nan_to_zero(b);
+                recalc = true;
+            }
+            if !recalc
+                && (ac.is_infinite() || bd.is_infinite() || ad.is_infinite() || bc.is_infinite())
+            {
+                /* recover infinities from overflow */
+                a = nan_to_zero(a);
+                b = nan_to_zero(b);
+                c = nan_to_zero(c);
+                d = nan_to_zero(d);
+                recalc = true;
+            }
+            if recalc {
+                x = f64::INFINITY * (a * c - b * d);
+                y = f64::INFINITY * (a * d + b * c);
+            }
+        }
+        Complex::new(x, y)
+    }
+}
+
+impl Div for Complex<f64> {
+    type Output = Complex<f64>;
+
+    fn div(self, rhs: Complex<f64>) -> Complex<f64> {
+        let (mut a, mut b) = (self.re, self.im);
+        let (mut c, mut d) = (rhs.re, rhs.im);
+        let mut ilogbw = 0;
+        let logbw = logb(fmax(fabs(c), fabs(d)));
+        if logbw.is_finite() {
+            ilogbw = logbw as i32;
+            c = scalbn(c, -ilogbw);
+            d = scalbn(d, -ilogbw);
+        }
+        let denom = c * c + d * d;
+        let mut x = scalbn((a * c + b * d) / denom, -ilogbw);
+        let mut y = scalbn((b * c - a * d) / denom, -ilogbw);
+
+        if x.is_nan() && y.is_nan() {
+            if denom == 0.0 && (!a.is_nan() || !b.is_nan()) {
+                x = copysign(f64::INFINITY, c) * a;
+                y = copysign(f64::INFINITY, c) * b;
+            } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+                a = box_inf(a);
+                b = box_inf(b);
+                x = f64::INFINITY * (a * c + b * d);
+                y = f64::INFINITY * (b * c - a * d);
+            } else if logbw == f64::INFINITY && a.is_finite() && b.is_finite() {
+                c = box_inf(c);
+                d = box_inf(d);
+                x = 0.0 * (a * c + b * d);
+                y = 0.0 * (b * c - a * d);
+            }
+        }
+        Complex::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn arithmetic() {
+        let z = Complex::new(1.0, 2.0);
+        let w = Complex::new(3.0, -1.0);
+        assert_eq!(z + w, Complex::new(4.0, 1.0));
+        assert_eq!(z - w, Complex::new(-2.0, 3.0));
+        assert_eq!(-z, Complex::new(-1.0, -2.0));
+        assert_eq!(z * w, Complex::new(5.0, 5.0));
+        assert_eq!(Complex::new(5.0, 5.0) / w, z);
+        let big = Complex::new(1e300, 1e300);
+        assert_eq!(big / big, Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn annex_g_infinities() {
+        let inf_nan = Complex::new(INFINITY, NAN);
+        let p = inf_nan * Complex::new(1.0, 1.0);
+        assert!(p.re.is_infinite() || p.im.is_infinite());
+        let q = Complex::new(1.0f64, 1.0) / Complex::new(0.0, 0.0);
+        assert!(q.re.is_infinite() && q.im.is_infinite());
+        let r = Complex::new(1.0, 1.0) / Complex::new(INFINITY, NAN);
+        assert_eq!(r.re, 0.0);
+        let s = Complex::new(INFINITY, 1.0) / Complex::new(2.0, 0.0);
+        assert_eq!(s.re, INFINITY);
+    }
+}
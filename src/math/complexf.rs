@@ -0,0 +1,120 @@
+use super::{copysignf, fabsf, fmaxf, logbf, scalbnf, Complex};
+use core::f32;
+use core::ops::{Div, Mul};
+
+/* 1 with the sign of x if x is infinite, 0 with the sign of x otherwise */
+fn box_inf(x: f32) -> f32 {
+    copysignf(if x.is_infinite() { 1.0 } else { 0.0 }, x)
+}
+
+/* 0 with the sign of x if x is NaN, x otherwise */
+fn nan_to_zero(x: f32) -> f32 {
+    if x.is_nan() {
+        copysignf(0.0, x)
+    } else {
+        x
+    }
+}
+
+impl Mul for Complex<f32> {
+    type Output = Complex<f32>;
+
+    fn mul(self, rhs: Complex<f32>) -> Complex<f32> {
+        let (mut a, mut b) = (self.re, self.im);
+        let (mut c, mut d) = (rhs.re, rhs.im);
+        let (ac, bd, ad, bc) = (a * c, b * d, a * d, b * c);
+        let mut x = ac - bd;
+        let mut y = ad + bc;
+
+        if x.is_nan() && y.is_nan() {
+            /* recover infinities that computed as NaN + I NaN */
+            let mut recalc = false;
+            if a.is_infinite() || b.is_infinite() {
+                a = box_inf(a);
+                b = box_inf(b);
+                c = nan_to_zero(c);
+                d = nan_to_zero(d);
+                recalc = true;
+            }
+            if c.is_infinite() || d.is_infinite() {
+                c = box_inf(c);
+                d = box_inf(d);
+                a = nan_to_zero(a);
+                b = nan_to_zero(b);
+                recalc = true;
+            }
+            if !recalc
+                && (ac.is_infinite() || bd.is_infinite() || ad.is_infinite() || bc.is_infinite())
+            {
+                /* recover infinities from overflow */
+                a = nan_to_zero(a);
+                b = nan_to_zero(b);
+                c = nan_to_zero(c);
+                d = nan_to_zero(d);
+                recalc = true;
+            }
+            if recalc {
+                x = f32::INFINITY * (a * c - b * d);
+                y = f32::INFINITY * (a * d + b * c);
+            }
+        }
+        Complex::new(x, y)
+    }
+}
+
+impl Div for Complex<f32> {
+    type Output = Complex<f32>;
+
+    fn div(self, rhs: Complex<f32>) -> Complex<f32> {
+        let (mut a, mut b) = (self.re, self.im);
+        let (mut c, mut d) = (rhs.re, rhs.im);
+        let mut ilogbw = 0;
+        let logbw = logbf(fmaxf(fabsf(c), fabsf(d)));
+        if logbw.is_finite() {
+            ilogbw = logbw as i32;
+            c = scalbnf(c, -ilogbw);
+            d = scalbnf(d, -ilogbw);
+        }
+        let denom = c * c + d * d;
+        let mut x = scalbnf((a * c + b * d) / denom, -ilogbw);
+        let mut y = scalbnf((b * c - a * d) / denom, -ilogbw);
+
+        if x.is_nan() && y.is_nan() {
+            if denom == 0.0 && (!a.is_nan() || !b.is_nan()) {
+                x = copysignf(f32::INFINITY, c) * a;
+                y = copysignf(f32::INFINITY, c) * b;
+            } else if (a.is_infinite() || b.is_infinite()) && c.is_finite() && d.is_finite() {
+                a = box_inf(a);
+                b = box_inf(b);
+                x = f32::INFINITY * (a * c + b * d);
+                y = f32::INFINITY * (b * c - a * d);
+            } else if logbw == f32::INFINITY && a.is_finite() && b.is_finite() {
+                c = box_inf(c);
+                d = box_inf(d);
+                x = 0.0 * (a * c + b * d);
+                y = 0.0 * (b * c - a * d);
+            }
+        }
+        Complex::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::*;
+
+    #[test]
+    fn arithmetic() {
+        let z = Complex::new(1.0f32, 2.0);
+        let w = Complex::new(3.0f32, -1.0);
+        assert_eq!(z * w, Complex::new(5.0, 5.0));
+        assert_eq!(Complex::new(5.0, 5.0) / w, z);
+        let big = Complex::new(1e30f32, 1e30);
+        assert_eq!(big / big, Complex::new(1.0, 0.0));
+        let q = Complex::new(1.0f32, 1.0) / Complex::new(0.0, 0.0);
+        assert!(q.re.is_infinite() && q.im.is_infinite());
+        let p = Complex::new(INFINITY, NAN) * Complex::new(1.0, 1.0);
+        assert!(p.re.is_infinite() || p.im.is_infinite());
+    }
+}
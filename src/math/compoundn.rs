@@ -0,0 +1,68 @@
+use super::fenv::invalid;
+use super::{expm1, fabs, log1p, pow};
+
+/// Compound interest (f64)
+///
+/// Returns `(1 + x)` raised to the power `n`. The rounding error of `1 + x` is
+/// carried separately instead of being magnified by `n`. `x < -1` is a domain
+/// error giving NaN and `compoundn(-1, n)` is `+inf` for negative `n`;
+/// `compoundn(x, 0)` is 1 for any other `x`, NaN included.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundn(x: f64, n: i64) -> f64 {
+    if x < -1.0 {
+        /* compoundn(x<-1, n) = NaN */
+        return invalid(x);
+    }
+    if n == 0 {
+        return 1.0;
+    }
+    if x.is_nan() {
+        return x;
+    }
+
+    /* u + c = 1 + x exactly */
+    let u = 1.0 + x;
+    let c = if fabs(x) <= 1.0 {
+        x - (u - 1.0)
+    } else {
+        1.0 - (u - x)
+    };
+    let r = pow(u, n as f64);
+    if c == 0.0 || !u.is_finite() {
+        return r;
+    }
+    /* (1 + x)^n = u^n * (1 + c/u)^n */
+    r + r * expm1(n as f64 * log1p(c / u))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_eq;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert!(compoundn(-2.0, 2).is_nan());
+        assert!(compoundn(NEG_INFINITY, 0).is_nan());
+        assert!(compoundn(NAN, 2).is_nan());
+        assert_eq!(compoundn(NAN, 0), 1.0);
+        assert_eq!(compoundn(INFINITY, 0), 1.0);
+        assert_eq!(compoundn(-1.0, 0), 1.0);
+        assert_eq!(compoundn(-1.0, 3), 0.0);
+        assert_eq!(compoundn(-1.0, -3), INFINITY);
+        assert_eq!(compoundn(INFINITY, 2), INFINITY);
+        assert_eq!(compoundn(INFINITY, -2), 0.0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(compoundn(1.0, 10), 1024.0);
+        assert_eq!(compoundn(-0.5, -2), 4.0);
+        assert_eq!(compoundn(0.05, 30), 4.3219423751506625);
+        assert_eq!(compoundn(0.07, -12), 0.4440119592407353);
+        /* 1 + 1e-10 is inexact; pow(1.0 + 1e-10, 1e9) is off by millions of ulps */
+        _eq(compoundn(1e-10, 1000000000), 1.1051709180701217).unwrap();
+        assert_eq!(compoundn(1e-20, 1000), 1.0);
+    }
+}
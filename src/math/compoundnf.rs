@@ -0,0 +1,11 @@
+use super::compoundn;
+use super::fenv::narrow;
+
+/// Compound interest (f32)
+///
+/// Returns `(1 + x)` raised to the power `n`, with the special cases of
+/// `compoundn`. The computation is carried out in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn compoundnf(x: f32, n: i64) -> f32 {
+    narrow(compoundn(x as f64, n))
+}
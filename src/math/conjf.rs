@@ -0,0 +1,9 @@
+use super::Complex;
+
+/// Complex conjugate (f32)
+///
+/// Returns `z` with the sign of its imaginary part flipped.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn conjf(z: Complex<f32>) -> Complex<f32> {
+    Complex::new(z.re, -z.im)
+}
@@ -0,0 +1,225 @@
+//! Conversions between `f64`, `f32`, binary16 and bfloat16 with an explicit
+//! rounding direction.
+//!
+//! A narrowing conversion rounds a number once in the given direction,
+//! whatever the rounding mode set with `fesetround`, and raises overflow,
+//! underflow and inexact as needed. Rounding to odd keeps enough information
+//! for a later rounding to nearest, to a format at least two bits narrower,
+//! to be correct. A NaN keeps its sign, its quiet bit and the top of its
+//! payload, so that a signaling NaN stays signaling, and raises nothing.
+
+use super::round_ops::{round_bits, split};
+use super::{Round, BF16, F16};
+
+/* Rounds x to the format with p bits of precision and exponent bias `bias`,
+ * returning its bits. `frac` is the fraction of x in its own format,
+ * left-aligned, for a NaN to keep its payload. */
+#[inline]
+fn convert(x: f64, frac: u64, round: Round, p: i32, bias: i32) -> u64 {
+    let max = (2 * bias + 1) as u64; /* the all-ones exponent field */
+    let sign = x.is_sign_negative();
+    let sign_bit = (sign as u64) << (p - 1 + (max + 1).trailing_zeros() as i32);
+    if x.is_nan() {
+        let mut f = frac >> (65 - p);
+        if f == 0 {
+            /* a signaling NaN with none of its payload left */
+            f = 1;
+        }
+        return sign_bit | max << (p - 1) | f;
+    }
+    if x.is_infinite() {
+        return sign_bit | max << (p - 1);
+    }
+    if x == 0.0 {
+        return sign_bit;
+    }
+    let (m, e) = split(x);
+    round_bits(m, e, sign, round, p, bias)
+}
+
+/// Converts `x` to `f32`, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64_to_f32(x: f64, round: Round) -> f32 {
+    f32::from_bits(convert(x, x.to_bits() << 12, round, 24, 127) as u32)
+}
+
+/// Converts `x` to binary16, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64_to_f16(x: f64, round: Round) -> F16 {
+    F16::from_bits(convert(x, x.to_bits() << 12, round, 11, 15) as u16)
+}
+
+/// Converts `x` to bfloat16, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f64_to_bf16(x: f64, round: Round) -> BF16 {
+    BF16::from_bits(convert(x, x.to_bits() << 12, round, 8, 127) as u16)
+}
+
+/// Converts `x` to `f64` exactly. Unlike `x as f64`, a signaling NaN stays
+/// signaling.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f32_to_f64(x: f32) -> f64 {
+    if x.is_nan() {
+        let frac = (x.to_bits() as u64) << 41;
+        let nan = if x.is_sign_negative() {
+            -f64::NAN
+        } else {
+            f64::NAN
+        };
+        return f64::from_bits(convert(nan, frac, Round::Nearest, 53, 1023));
+    }
+    x as f64
+}
+
+/// Converts `x` to binary16, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f32_to_f16(x: f32, round: Round) -> F16 {
+    let frac = (x.to_bits() as u64) << 41;
+    F16::from_bits(convert(f32_to_f64(x), frac, round, 11, 15) as u16)
+}
+
+/// Converts `x` to bfloat16, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f32_to_bf16(x: f32, round: Round) -> BF16 {
+    let frac = (x.to_bits() as u64) << 41;
+    BF16::from_bits(convert(f32_to_f64(x), frac, round, 8, 127) as u16)
+}
+
+/// Converts `x` to bfloat16, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn f16_to_bf16(x: F16, round: Round) -> BF16 {
+    let frac = (x.to_bits() as u64) << 54;
+    BF16::from_bits(convert(f64::from(x), frac, round, 8, 127) as u16)
+}
+
+/// Converts `x` to binary16, rounding in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn bf16_to_f16(x: BF16, round: Round) -> F16 {
+    let frac = (x.to_bits() as u64) << 57;
+    F16::from_bits(convert(f64::from(x), frac, round, 11, 15) as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions() {
+        let third = 1.0f64 / 3.0;
+        assert_eq!(f64_to_f32(third, Round::Nearest).to_bits(), 0x3eaaaaab);
+        assert_eq!(f64_to_f32(third, Round::Down).to_bits(), 0x3eaaaaaa);
+        assert_eq!(f64_to_f32(-third, Round::Up).to_bits(), 0xbeaaaaaa);
+        assert_eq!(f64_to_f32(third, Round::Odd).to_bits(), 0x3eaaaaab);
+        assert_eq!(f64_to_f32(0.5, Round::Odd), 0.5);
+
+        assert_eq!(f64_to_f16(third, Round::Nearest).to_bits(), 0x3555);
+        assert_eq!(f64_to_f16(third, Round::Up).to_bits(), 0x3556);
+        assert_eq!(f64_to_f16(third, Round::Odd).to_bits(), 0x3555);
+        assert_eq!(f64_to_bf16(third, Round::Nearest).to_bits(), 0x3eab);
+        assert_eq!(f64_to_bf16(third, Round::TowardZero).to_bits(), 0x3eaa);
+        assert_eq!(f64_to_bf16(third, Round::Odd).to_bits(), 0x3eab);
+
+        /* 1 + 2^-10 has no bfloat16 neighbor between 1 and 1 + 2^-7 */
+        let x = F16::from_bits(0x3c01);
+        assert_eq!(f16_to_bf16(x, Round::Nearest).to_bits(), 0x3f80);
+        assert_eq!(f16_to_bf16(x, Round::Up).to_bits(), 0x3f81);
+        assert_eq!(f16_to_bf16(x, Round::Odd).to_bits(), 0x3f81);
+        assert_eq!(
+            bf16_to_f16(BF16::from_bits(0x3eab), Round::Nearest).to_bits(),
+            0x3558
+        );
+        assert_eq!(f32_to_f16(1.0 + f32::EPSILON, Round::Up).to_bits(), 0x3c01);
+        assert_eq!(
+            f32_to_bf16(-1.0 - f32::EPSILON, Round::Down).to_bits(),
+            0xbf81
+        );
+    }
+
+    #[test]
+    fn range() {
+        assert_eq!(f64_to_f16(1e10, Round::Nearest), F16::INFINITY);
+        assert_eq!(f64_to_f16(1e10, Round::TowardZero), F16::MAX);
+        assert_eq!(f64_to_f16(1e10, Round::Odd), F16::MAX);
+        assert_eq!(f64_to_f32(-1e300, Round::Up), -f32::MAX);
+        assert_eq!(bf16_to_f16(BF16::MAX, Round::Nearest), F16::INFINITY);
+        assert_eq!(bf16_to_f16(BF16::MAX, Round::Down), F16::MAX);
+
+        /* 2^-25 is half of the smallest binary16 subnormal */
+        let tiny = f64::from_bits(0x3e60000000000000);
+        assert_eq!(f64_to_f16(tiny, Round::Nearest).to_bits(), 0);
+        assert_eq!(f64_to_f16(tiny, Round::NearestAway).to_bits(), 1);
+        assert_eq!(f64_to_f16(-tiny, Round::Odd).to_bits(), 0x8001);
+        assert_eq!(f64_to_f32(1e-300, Round::Odd).to_bits(), 1);
+        assert_eq!(f64_to_bf16(-0.0, Round::Odd).to_bits(), 0x8000);
+        assert_eq!(f32_to_f16(f32::NEG_INFINITY, Round::Odd), F16::NEG_INFINITY);
+    }
+
+    #[test]
+    fn nan_payloads() {
+        /* signaling NaNs stay signaling, with the top of their payload */
+        let snan = f64::from_bits(0x7ff4000000000001);
+        assert_eq!(f64_to_f32(snan, Round::Nearest).to_bits(), 0x7fa00000);
+        assert_eq!(f64_to_f16(snan, Round::Nearest).to_bits(), 0x7d00);
+        assert_eq!(f64_to_bf16(-snan, Round::Nearest).to_bits(), 0xffa0);
+        /* or with the lowest bit set if none of it is left */
+        let snan = f64::from_bits(0x7ff0000000000001);
+        assert_eq!(f64_to_f32(snan, Round::Nearest).to_bits(), 0x7f800001);
+        assert_eq!(
+            f16_to_bf16(F16::from_bits(0x7c01), Round::Up).to_bits(),
+            0x7f81
+        );
+        /* quiet NaNs stay quiet */
+        assert_eq!(
+            f64_to_f16(f64::NAN, Round::Down).to_bits(),
+            F16::NAN.to_bits()
+        );
+        assert_eq!(
+            bf16_to_f16(BF16::from_bits(0xffff), Round::Odd).to_bits(),
+            0xfff8
+        );
+        let pairs = [
+            (0x7f800001, 0x7ff0000020000000),
+            (0x7fa00000, 0x7ff4000000000000),
+            (0xffc00123, 0xfff8002460000000),
+        ];
+        for &(bits, wide) in pairs.iter() {
+            let y = f32_to_f64(f32::from_bits(bits));
+            assert_eq!(y.to_bits(), wide);
+            assert_eq!(f64_to_f32(y, Round::Odd).to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn odd_avoids_double_rounding() {
+        /* 1 + 2^-11 + 2^-40 rounds to nearest to 1 + 2^-11 in f32, a tie in
+         * binary16 which then rounds down, but it is above the tie */
+        let x = f64::from_bits(0x3ff0020000100000);
+        let twice = f32_to_f16(f64_to_f32(x, Round::Nearest), Round::Nearest);
+        assert_eq!(twice.to_bits(), 0x3c00);
+        assert_eq!(f64_to_f16(x, Round::Nearest).to_bits(), 0x3c01);
+        assert_eq!(
+            f32_to_f16(f64_to_f32(x, Round::Odd), Round::Nearest).to_bits(),
+            0x3c01
+        );
+
+        let mut s: u64 = 0x9e3779b97f4a7c15;
+        for _ in 0..100000 {
+            s ^= s << 13;
+            s ^= s >> 7;
+            s ^= s << 17;
+            /* exponents around the range of binary16, with the bits around
+             * the last bits of binary16 and bfloat16, and a few far below */
+            let bits = s & 0x800f_ff00_0000_00ff | (0x3c6 + (s >> 52 & 0x7f)) << 52;
+            let x = f64::from_bits(bits);
+            let odd = f64_to_f32(x, Round::Odd);
+            assert_eq!(
+                f32_to_f16(odd, Round::Nearest).to_bits(),
+                f64_to_f16(x, Round::Nearest).to_bits()
+            );
+            assert_eq!(
+                f32_to_bf16(odd, Round::Nearest).to_bits(),
+                f64_to_bf16(x, Round::Nearest).to_bits()
+            );
+        }
+    }
+}
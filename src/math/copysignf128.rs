@@ -0,0 +1,11 @@
+use super::f128::SIGN_MASK;
+use super::F128;
+
+/// Sign of Y, magnitude of X (F128)
+///
+/// Constructs a number with the magnitude (absolute value) of its
+/// first argument, `x`, and the sign of its second argument, `y`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn copysignf128(x: F128, y: F128) -> F128 {
+    F128::from_bits(x.to_bits() & !SIGN_MASK | y.to_bits() & SIGN_MASK)
+}
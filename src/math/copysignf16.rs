@@ -0,0 +1,24 @@
+use super::f16::SIGN_MASK;
+use super::F16;
+
+/// Sign of Y, magnitude of X (F16)
+///
+/// Constructs a number with the magnitude (absolute value) of its
+/// first argument, `x`, and the sign of its second argument, `y`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn copysignf16(x: F16, y: F16) -> F16 {
+    F16::from_bits(x.to_bits() & !SIGN_MASK | y.to_bits() & SIGN_MASK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(copysignf16(F16::ONE, -F16::ZERO), -F16::ONE);
+        assert_eq!(copysignf16(-F16::MAX, F16::ONE), F16::MAX);
+        assert!(copysignf16(F16::ZERO, -F16::NAN).is_sign_negative());
+        assert_eq!(copysignf16(F16::NAN, -F16::ONE).to_bits(), 0xfe00);
+    }
+}
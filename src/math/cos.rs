@@ -9,6 +9,7 @@
 // is preserved.
 // ====================================================
 
+use super::fenv::{feraiseexcept, FE_INVALID};
 use super::{k_cos, k_sin, rem_pio2};
 
 // cos(x)
@@ -59,6 +60,9 @@ pub fn cos(x: f64) -> f64 {
 
     /* cos(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         return x - x;
     }
 
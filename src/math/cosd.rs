@@ -0,0 +1,73 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::{deg_to_rad, k_cos, k_sin, rem_90};
+
+/// Cosine of x degrees (f64)
+///
+/// Computes `cos(x * pi/180)`, reducing `x` modulo 360 exactly first. The
+/// result is exact for multiples of 30 degrees; `cosd(180 * n + 90)` is `+0.0`
+/// for every integer `n`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosd(x: f64) -> f64 {
+    let ix = x.to_bits() & 0x7fffffffffffffff;
+
+    if ix >= 0x7ff0000000000000 {
+        /* cosd(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix < 0x3e10000000000000 {
+        /* |x| < 2^-30: cosd(x) rounds to 1 */
+        return 1.0;
+    }
+
+    let (n, f) = rem_90(f64::from_bits(ix));
+    if f == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+    if n & 1 == 1 && (f == 30.0 || f == -30.0) {
+        /* cos(90 +- 30) = -+1/2 */
+        let s = f / 60.0;
+        return if n == 1 { -s } else { s };
+    }
+    let (hi, lo) = deg_to_rad(f);
+    match n {
+        0 => k_cos(hi, lo),
+        1 => -k_sin(hi, lo, 1),
+        2 => -k_cos(hi, lo),
+        _ => k_sin(hi, lo, 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cosd;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(cosd(0.0), 1.0);
+        assert_eq!(cosd(-180.0), -1.0);
+        assert_eq!(cosd(360.0), 1.0);
+        assert_eq!(cosd(90.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(cosd(-270.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(cosd(60.0), 0.5);
+        assert_eq!(cosd(-60.0), 0.5);
+        assert_eq!(cosd(120.0), -0.5);
+        assert_eq!(cosd(240.0), -0.5);
+        assert_eq!(cosd(300.0), 0.5);
+        assert_eq!(cosd(1e300), cosd(1e300 % 360.0));
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(cosd(NAN).is_nan());
+        assert!(cosd(INFINITY).is_nan());
+        assert!(cosd(NEG_INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,43 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::{k_cosf, k_sinf, rem_90};
+
+/* pi/180 */
+const D2R: f64 = 0.017453292519943295; /* 0x3F91DF46, 0xA2529D39 */
+
+/// Cosine of x degrees (f32)
+///
+/// Computes `cos(x * pi/180)`, reducing `x` modulo 360 exactly first. The
+/// result is exact for multiples of 30 degrees; `cosdf(180 * n + 90)` is
+/// `+0.0` for every integer `n`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosdf(x: f32) -> f32 {
+    let ix = x.to_bits() & 0x7fffffff;
+
+    if ix >= 0x7f800000 {
+        /* cosdf(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+
+    let (n, f) = rem_90(f32::from_bits(ix) as f64);
+    if f == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+    if n & 1 == 1 && (f == 30.0 || f == -30.0) {
+        /* cos(90 +- 30) = -+1/2 */
+        let s = (f / 60.0) as f32;
+        return if n == 1 { -s } else { s };
+    }
+    match n {
+        0 => k_cosf(D2R * f),
+        1 => -k_sinf(D2R * f),
+        2 => -k_cosf(D2R * f),
+        _ => k_sinf(D2R * f),
+    }
+}
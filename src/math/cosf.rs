@@ -14,6 +14,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_INVALID};
 use super::{k_cosf, k_sinf, rem_pio2f};
 
 use core::f64::consts::FRAC_PI_2;
@@ -28,8 +29,6 @@ const C4_PIO2: f64 = 4. * FRAC_PI_2; /* 0x401921FB, 0x54442D18 */
 pub fn cosf(x: f32) -> f32 {
     let x64 = x as f64;
 
-    let x1p120 = f32::from_bits(0x7b800000); // 0x1p120f === 2 ^ 120
-
     let mut ix = x.to_bits();
     let sign = (ix >> 31) != 0;
     ix &= 0x7fffffff;
@@ -39,7 +38,9 @@ pub fn cosf(x: f32) -> f32 {
         if ix < 0x39800000 {
             /* |x| < 2**-12 */
             /* raise inexact if x != 0 */
-            force_eval!(x + x1p120);
+            if x != 0.0 {
+                feraiseexcept(FE_INEXACT);
+            }
             return 1.;
         }
         return k_cosf(x64);
@@ -69,6 +70,9 @@ pub fn cosf(x: f32) -> f32 {
 
     /* cos(Inf or NaN) is NaN */
     if ix >= 0x7f800000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         return x - x;
     }
 
@@ -0,0 +1,188 @@
+/* Reduction by pi/2, then k_cosf128 or k_sinf128; within 1 ulp */
+
+use super::f128::{exponent, invalid, quiet_nan, BIAS, SIGN_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::k_cosf128::k_cosf128;
+use super::k_sinf128::k_sinf128;
+use super::rem_pio2f128::rem_pio2f128;
+use super::sinf128::PIO4;
+use super::F128;
+
+/// The cosine of `x` in radians (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf128(x: F128) -> F128 {
+    /* |x| ~< pi/4 */
+    if x.to_bits() & !SIGN_MASK <= PIO4 {
+        if exponent(x) < BIAS - 57 {
+            /* |x| < 2**-57, raise inexact if x != 0 */
+            if x != F128::ZERO {
+                feraiseexcept(FE_INEXACT);
+            }
+            return F128::ONE;
+        }
+        return k_cosf128(x, F128::ZERO);
+    }
+
+    /* cos(Inf or NaN) is NaN */
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x.is_infinite() {
+        return invalid();
+    }
+
+    /* argument reduction needed */
+    let (n, y0, y1) = rem_pio2f128(x);
+    match n & 3 {
+        0 => k_cosf128(y0, y1),
+        1 => -k_sinf128(y0, y1),
+        2 => -k_cosf128(y0, y1),
+        _ => k_sinf128(y0, y1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1, 0.5, 1e6, 2^16383 and pi */
+        assert!(ulps(cosf128(F128::ONE), 0x3ffe14a280fb5068b923848cdb2ed0e3) <= 1);
+        assert!(
+            ulps(
+                cosf128(f(0x3ffe0000000000000000000000000000)),
+                0x3ffec1528065b7d4f9db7bbb3b45f5f6
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                cosf128(F128::from(1000000)),
+                0x3ffedf9df9906d32c6aec89a83199fe2
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                cosf128(f(0x7ffe0000000000000000000000000000)),
+                0x3ffed7985f9055c73d7f1b4bad0f7e6c
+            ) <= 1
+        );
+        assert_eq!(cosf128(f(0x4000921fb54442d18469898cc51701b8)), -F128::ONE);
+        assert_eq!(cosf128(-F128::ZERO), F128::ONE);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cosf128(F128::NEG_INFINITY).is_nan());
+        assert!(cosf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3ff52bc2a6505a0cc97b77614f3bf9b8,
+            0x3ffeffffea0ffbe017cc9f785ca7ed18,
+        ),
+        (
+            0x3ff71d35f753e01712f508177069a217,
+            0x3ffefffec23eeccce712e8ff250c2836,
+        ),
+        (
+            0x3ffd496c4d51cff7cfddb3cb1b1671a8,
+            0x3ffee5bbd23ca6a043aff6d42aefb579,
+        ),
+        (
+            0x4002199a998debfda81d1f492c6faf6a,
+            0xbffe9f50f093c35bfc70c950011a15b4,
+        ),
+        (
+            0x4003eb443b20cb8c3e9cccd3b023e91b,
+            0x3ffe83b0bfe6474502fc81078bc58463,
+        ),
+        (
+            0x4005f048762e9231d831cf30798a6355,
+            0xbff96ad18336945d2b7e0741b2144860,
+        ),
+        (
+            0x40068d60881e451b6c864d23389e5f51,
+            0xbffe7035f3ffbbab70b144dbef79affb,
+        ),
+        (
+            0x40190c8502d9ed654ce54790ccf5a7ff,
+            0x3ffef24e017e168b7c8d51281cbabafe,
+        ),
+        (
+            0x40267c32c7a7f6865c12628404d3d8eb,
+            0xbffdcf940dc4ce6c9d0945c8c802bcb4,
+        ),
+        (
+            0x4086e78afec1f7e6ea264058feaf4d89,
+            0x3ffe4c85828658e9869b2735cda5b280,
+        ),
+        (
+            0xbff9c3d7a3f1e54ded22c90b27e8f5c7,
+            0x3ffeffce28b6f343f2a9fef3590d6a5c,
+        ),
+        (
+            0xbffaa7c81602aba2cf46c9a25f1a0675,
+            0x3ffeff50a84db7ec8c6b31387f988289,
+        ),
+        (
+            0xbffae353761f213c0537c8b213e0d07e,
+            0x3ffeff1befedaa201f36b0cc2c5f9191,
+        ),
+        (
+            0xbffe72ee8ea40a342817cb86e5bd4f20,
+            0x3ffe7f68d27fa1aa09d45a471a4d25eb,
+        ),
+        (
+            0xbffea9429b299ef5d028b49e46b6d088,
+            0x3ffe595097e0c75c8026a201879a2c4b,
+        ),
+        (
+            0xbfff0e245c3cd850a9769fd9eb78b5e0,
+            0x3ffdf8d96ddfb176f79f6989ad0ac957,
+        ),
+        (
+            0xc00010935280031b6a35ecbb9ffca5dd,
+            0xbffe0f672a958454f34760e6a5756858,
+        ),
+        (
+            0xc000357767ab163759ba4aff5ad12ddb,
+            0xbffe7f9c109b05d92bbd0827c862f4c0,
+        ),
+        (
+            0xc005245af7b4bbbf5922885c8212dd08,
+            0xbffe58b3e017d186b84ffbd286ba4993,
+        ),
+        (
+            0xc005fe1ed753104347c5dcded177c6a3,
+            0xbffd2a5225f6e5ade7e73a989975b692,
+        ),
+        (
+            0xc00731a50a384644d9390c29896527c8,
+            0xbffe3a2834cba18d52dda9976c53e8cf,
+        ),
+        (
+            0xc035e8462c278593ebfb4fc0c59cce58,
+            0xbffed9675bd039a060db3cd7f1dfa97e,
+        ),
+        (
+            0xc079687091f8de6b4ef76af8bf0fd517,
+            0xbffef1cb7a8930031ef0b1f2890c47e5,
+        ),
+        (
+            0xc08741c82c0354b30604caf0ebac1eda,
+            0x3ffa819d0064560da7335c07538146bc,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(cosf128, &REFERENCE, 1);
+    }
+}
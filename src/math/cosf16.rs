@@ -0,0 +1,47 @@
+use super::f16::{from_f64_round, rem_pio2f16};
+use super::{cos, fabs, k_cos, k_sin, Round, F16};
+use core::f64::consts::FRAC_PI_4;
+
+/// Cosine (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cosf16(x: F16) -> F16 {
+    let y = f64::from(x);
+    if !y.is_finite() || fabs(y) <= FRAC_PI_4 {
+        return from_f64_round(cos(y), Round::current());
+    }
+    let (n, y0, y1) = rem_pio2f16(y);
+    let r = match n & 3 {
+        0 => k_cos(y0, y1),
+        1 => -k_sin(y0, y1, 1),
+        2 => -k_cos(y0, y1),
+        _ => k_sin(y0, y1, 1),
+    };
+    from_f64_round(r, Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(cosf16(F16::ONE).to_bits(), 0x3853);
+        assert_eq!(cosf16(F16::MAX).to_bits(), 0xb30f);
+        assert_eq!(cosf16(F16::from_f32(1.5703125)).to_bits(), 0x0fed);
+        assert_eq!(cosf16(F16::from_bits(0x0001)).to_bits(), 0x3c00);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(cosf16(F16::NEG_INFINITY).is_nan());
+        assert_eq!(cosf16(-F16::ZERO), F16::ONE);
+        assert!(cosf16(F16::NAN).is_nan());
+        assert!(cosf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(cosf16, cos, |x| x == F16::ZERO);
+    }
+}
@@ -1,5 +1,6 @@
 use super::exp;
 use super::expm1;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW};
 use super::k_expo2;
 
 /// Hyperbolic cosine (f64)
@@ -18,8 +19,9 @@ pub fn cosh(mut x: f64) -> f64 {
     /* |x| < log(2) */
     if w < 0x3fe62e42 {
         if w < 0x3ff00000 - (26 << 20) {
-            let x1p120 = f64::from_bits(0x4770000000000000);
-            force_eval!(x + x1p120);
+            if x != 0.0 {
+                feraiseexcept(FE_INEXACT);
+            }
             return 1.;
         }
         let t = expm1(x); // exponential minus 1
@@ -34,5 +36,9 @@ pub fn cosh(mut x: f64) -> f64 {
     }
 
     /* |x| > log(DBL_MAX) or nan */
-    k_expo2(x)
+    let t = k_expo2(x);
+    if t.is_infinite() && x.is_finite() {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    }
+    t
 }
@@ -1,5 +1,6 @@
 use super::expf;
 use super::expm1f;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW};
 use super::k_expo2f;
 
 /// Hyperbolic cosine (f64)
@@ -9,8 +10,6 @@ use super::k_expo2f;
 /// Angles are specified in radians.
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn coshf(mut x: f32) -> f32 {
-    let x1p120 = f32::from_bits(0x7b800000); // 0x1p120f === 2 ^ 120
-
     /* |x| */
     let mut ix = x.to_bits();
     ix &= 0x7fffffff;
@@ -20,7 +19,9 @@ pub fn coshf(mut x: f32) -> f32 {
     /* |x| < log(2) */
     if w < 0x3f317217 {
         if w < (0x3f800000 - (12 << 23)) {
-            force_eval!(x + x1p120);
+            if x != 0.0 {
+                feraiseexcept(FE_INEXACT);
+            }
             return 1.;
         }
         let t = expm1f(x);
@@ -34,5 +35,9 @@ pub fn coshf(mut x: f32) -> f32 {
     }
 
     /* |x| > log(FLT_MAX) or nan */
-    k_expo2f(x)
+    let t = k_expo2f(x);
+    if t.is_infinite() && x.is_finite() {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    }
+    t
 }
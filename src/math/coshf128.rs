@@ -0,0 +1,181 @@
+/* The cases of the f64 cosh, with expm1f128 and expf128; within 1 ulp */
+
+use super::expf128::EXP_MAX_ARG;
+use super::f128::{exponent, is_zero, quiet_nan, BIAS, SIGN_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::{expf128, expm1f128, F128};
+
+/// Hyperbolic cosine (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn coshf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    let half = F128::from_bits(0x3ffe0000000000000000000000000000);
+    /* |x| */
+    let x = F128::from_bits(x.to_bits() & !SIGN_MASK);
+
+    /* |x| < log(2)/2 */
+    if x < F128::from_bits(0x3ffd62e42fefa39ef35793c7673007e6) {
+        if exponent(x) < BIAS - 57 {
+            if !is_zero(x) {
+                feraiseexcept(FE_INEXACT);
+            }
+            return F128::ONE;
+        }
+        let t = expm1f128(x);
+        return F128::ONE + t * t / (F128::from(2) * (F128::ONE + t));
+    }
+
+    /* |x| < 40, where the 1/exp(x) still matters */
+    if x < F128::from(40) {
+        let t = expf128(x);
+        return half * t + half / t;
+    }
+
+    /* |x| < log(MAX) */
+    if x <= F128::from_bits(EXP_MAX_ARG) {
+        return half * expf128(x);
+    }
+
+    /* exp(x/2)^2/2 does not overflow for slightly larger x, inf stays inf */
+    let w = expf128(half * x);
+    (half * w) * w
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(coshf128(F128::ZERO), F128::ONE);
+        /* 1, -0.25, 20 */
+        assert!(ulps(coshf128(F128::ONE), 0x3fff8b07551d9f5504c2bd28100196a5) <= 1);
+        assert!(
+            ulps(
+                coshf128(f(0xbffd0000000000000000000000000000)),
+                0x3fff080ab05ca6145edcde90399c8714
+            ) <= 1
+        );
+        assert!(ulps(coshf128(F128::from(20)), 0x401aceb088b68e8040af2dac2f312d03) <= 1);
+        assert!(coshf128(F128::from(-11357)).is_finite());
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(coshf128(F128::INFINITY), F128::INFINITY);
+        assert_eq!(coshf128(F128::NEG_INFINITY), F128::INFINITY);
+        assert_eq!(coshf128(F128::from(11400)), F128::INFINITY);
+        assert!(coshf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fee5f47a9766cddb36a657598ac1744,
+            0x3fff000000003c40b2d84829cda21d70,
+        ),
+        (
+            0x3ff17fbe72f803e527ca7441e525aaa2,
+            0x3fff00000011f9db4dbd96a463683143,
+        ),
+        (
+            0x3ff24d47c0565e4ca7b3e1365268e1f0,
+            0x3fff000000363c77d1887a7ab24c50b7,
+        ),
+        (
+            0x3ff467d2db82d5c0fb264eaa84eea06b,
+            0x3fff000003f3822545c1a17523389889,
+        ),
+        (
+            0x3ff55e5739dc2a51bd610dc029652645,
+            0x3fff00000efb9524dd0fe3d5925f3ff0,
+        ),
+        (
+            0x4000830f97aa4c0e790d92369dbbdb16,
+            0x400249ec8a15466878191ffa39ed1655,
+        ),
+        (
+            0x4001b4ac5152f5524e4809358b974438,
+            0x4007cb608996bd4c7ea19bdd2f85c7ed,
+        ),
+        (
+            0x4002171185148da1a5afc2efab7decfa,
+            0x400a7f19fba85b7b2a72b3841e2c2f32,
+        ),
+        (
+            0x40039dab0452f4cb1c6382271fba03e2,
+            0x40233b212c773b0e20ea3101c0151f81,
+        ),
+        (
+            0xbfeb3d8f4b92dd395ebd8f7fc79396c3,
+            0x3fff0000000000c4f618af9e3a8fed65,
+        ),
+        (
+            0xbfeb502313ec64f76afca8a2e1b061fe,
+            0x3fff0000000000dcae0c8d7ebcb77cbf,
+        ),
+        (
+            0xbfeb5f7e5ad1d90d2b5eb96b1acd99ff,
+            0x3fff0000000000f14dddb47c23e7e0c4,
+        ),
+        (
+            0xbfeed5145d72d0eefe04f2c4eeffcecf,
+            0x3fff000000006b707400b536fc26a80d,
+        ),
+        (
+            0xbff48104e6d5cc69b32d722958845db1,
+            0x3fff000004861f803764ce37acbbfb55,
+        ),
+        (
+            0xbffad1195ae6293cdb6f8c4d40222a6e,
+            0x3fff0069a6e76e841a38e20d92721105,
+        ),
+        (
+            0xbfffe03c7e9f30d8fd187c5a36c738b9,
+            0x4000ab8603a0a7eba9abccfd0b93d6a9,
+        ),
+        (
+            0xbffff4cb8ee47bd26e5d0b2720d707a5,
+            0x4000cdb25a1595b75535ec7189600b97,
+        ),
+        (
+            0xc000a4e070e4acd00ecf2ec1bfa65189,
+            0x4002ad447b4f351a4d962964f48706ed,
+        ),
+        (
+            0xc003fdbb9019b2b7096fb0a04f27cc12,
+            0x402bf29ef1c7aedf3f0b867f7724bb09,
+        ),
+        (
+            0xc0074ade61ccd78727bc3a7eab1a4fe8,
+            0x41db449e758f952dd3163ba8b5f52d17,
+        ),
+        (
+            0xc00b203ac6e5ce25cb73cfa9ea2d2cf9,
+            0x59fb2e090f0c83fd56ce95fc33e29839,
+        ),
+        (
+            0xc00b5198a75dcc9630a10a1cddec928f,
+            0x5e6eb66ee61b5755a42e1d98a7becd17,
+        ),
+        (
+            0xc00bead13dafb3fd83ff567165f3b3da,
+            0x6c3f82f685adcc88fd8283ec49f7f855,
+        ),
+        (
+            0xc00bfb3553b99cb5f59a143e217762cc,
+            0x6db9eec2e1023f73400f28e214e87a00,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(coshf128, &REFERENCE, 1);
+    }
+}
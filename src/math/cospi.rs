@@ -0,0 +1,72 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::{k_cos, k_sin, mul_pi, rem_half};
+
+/// Cosine of pi times x (f64)
+///
+/// Computes `cos(pi * x)` without first rounding `pi * x`. The result is exact
+/// for integer and half-integer `x`; `cospi(n + 0.5)` is `+0.0` for every
+/// integer `n`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospi(x: f64) -> f64 {
+    let ix = x.to_bits() & 0x7fffffffffffffff;
+
+    if ix >= 0x7ff0000000000000 {
+        /* cospi(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix >= 0x4340000000000000 {
+        /* |x| >= 2^53: x is an even integer */
+        return 1.0;
+    }
+    if ix >= 0x4330000000000000 {
+        /* 2^52 <= |x| < 2^53: x is an integer, odd if its last bit is set */
+        return if ix & 1 != 0 { -1.0 } else { 1.0 };
+    }
+    if ix < 0x3c30000000000000 {
+        /* |x| < 2^-60: cospi(x) rounds to 1 */
+        return 1.0;
+    }
+
+    let (n, f) = rem_half(f64::from_bits(ix));
+    if f == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+    let (hi, lo) = mul_pi(f);
+    match n {
+        0 => k_cos(hi, lo),
+        1 => -k_sin(hi, lo, 1),
+        2 => -k_cos(hi, lo),
+        _ => k_sin(hi, lo, 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cospi;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(cospi(0.0), 1.0);
+        assert_eq!(cospi(-1.0), -1.0);
+        assert_eq!(cospi(4503599627370497.0), -1.0);
+        assert_eq!(cospi(1e300), 1.0);
+        assert_eq!(cospi(0.5).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(cospi(-1.5).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(cospi(1.0 / 3.0), 0.5);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(cospi(NAN).is_nan());
+        assert!(cospi(INFINITY).is_nan());
+        assert!(cospi(NEG_INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,44 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::{k_cosf, k_sinf, rem_half};
+use core::f64::consts::PI;
+
+/// Cosine of pi times x (f32)
+///
+/// Computes `cos(pi * x)` without first rounding `pi * x`. The result is exact
+/// for integer and half-integer `x`; `cospif(n + 0.5)` is `+0.0` for every
+/// integer `n`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cospif(x: f32) -> f32 {
+    let ix = x.to_bits() & 0x7fffffff;
+
+    if ix >= 0x7f800000 {
+        /* cospif(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix >= 0x4b800000 {
+        /* |x| >= 2^24: x is an even integer */
+        return 1.0;
+    }
+    if ix >= 0x4b000000 {
+        /* 2^23 <= |x| < 2^24: x is an integer, odd if its last bit is set */
+        return if ix & 1 != 0 { -1.0 } else { 1.0 };
+    }
+
+    let (n, f) = rem_half(f32::from_bits(ix) as f64);
+    if f == 0.0 {
+        return match n {
+            0 => 1.0,
+            2 => -1.0,
+            _ => 0.0,
+        };
+    }
+    match n {
+        0 => k_cosf(PI * f),
+        1 => -k_sinf(PI * f),
+        2 => -k_cosf(PI * f),
+        _ => k_sinf(PI * f),
+    }
+}
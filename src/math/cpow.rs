@@ -0,0 +1,23 @@
+use super::{cexp, clog, Complex};
+
+/// Complex power (f64)
+///
+/// Returns `x` raised to the power `y`, computed as `cexp(y * clog(x))`, with
+/// the branch cut of `clog` along the negative real axis.
+pub fn cpow(x: Complex<f64>, y: Complex<f64>) -> Complex<f64> {
+    cexp(y * clog(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        /* i^i = e^(-pi/2) */
+        let z = cpow(Complex::new(0.0, 1.0), Complex::new(0.0, 1.0));
+        assert!((z.re - 0.20787957635076193).abs() < 1e-16 && z.im.abs() < 1e-16);
+        let z = cpow(Complex::new(-8.0, 0.0), Complex::new(1.0 / 3.0, 0.0));
+        assert!((z.re - 1.0).abs() < 1e-15 && (z.im - 3f64.sqrt()).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,9 @@
+use super::{cexpf, clogf, Complex};
+
+/// Complex power (f32)
+///
+/// Returns `x` raised to the power `y`, computed as `cexpf(y * clogf(x))`, with
+/// the branch cut of `clogf` along the negative real axis.
+pub fn cpowf(x: Complex<f32>, y: Complex<f32>) -> Complex<f32> {
+    cexpf(y * clogf(x))
+}
@@ -0,0 +1,14 @@
+use super::{copysign, Complex};
+use core::f64;
+
+/// Projection onto the Riemann sphere (f64)
+///
+/// Returns `z`, except that every complex infinity, even one with a NaN part,
+/// is projected to `inf + I 0` with the sign of the imaginary part of `z`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cproj(z: Complex<f64>) -> Complex<f64> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        return Complex::new(f64::INFINITY, copysign(0.0, z.im));
+    }
+    z
+}
@@ -0,0 +1,14 @@
+use super::{copysignf, Complex};
+use core::f32;
+
+/// Projection onto the Riemann sphere (f32)
+///
+/// Returns `z`, except that every complex infinity, even one with a NaN part,
+/// is projected to `inf + I 0` with the sign of the imaginary part of `z`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn cprojf(z: Complex<f32>) -> Complex<f32> {
+    if z.re.is_infinite() || z.im.is_infinite() {
+        return Complex::new(f32::INFINITY, copysignf(0.0, z.im));
+    }
+    z
+}
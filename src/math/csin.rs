@@ -0,0 +1,10 @@
+use super::{csinh, Complex};
+
+/// Complex sine (f64)
+///
+/// Returns `-I csinh(I z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csin(z: Complex<f64>) -> Complex<f64> {
+    let w = csinh(Complex::new(-z.im, z.re));
+    Complex::new(w.im, -w.re)
+}
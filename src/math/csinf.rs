@@ -0,0 +1,10 @@
+use super::{csinhf, Complex};
+
+/// Complex sine (f32)
+///
+/// Returns `-I csinhf(I z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinf(z: Complex<f32>) -> Complex<f32> {
+    let w = csinhf(Complex::new(-z.im, z.re));
+    Complex::new(w.im, -w.re)
+}
@@ -0,0 +1,186 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csinh.c */
+/*-
+ * Copyright (c) 2005 Bruce D. Evans and Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * Hyperbolic sine of a complex argument z = x + i y.
+ *
+ * sinh(z) = sinh(x+iy)
+ *         = sinh(x) cos(y) + i cosh(x) sin(y).
+ *
+ * Exceptional values are noted in the comments within the source code.
+ * These values and the return value were taken from n1124.pdf.
+ */
+
+use super::k_cexp::k_ldexp_cexp;
+use super::{copysign, cosh, exp, fabs, sincos, sinh, Complex};
+use core::f64;
+
+const HUGE: f64 = 8.98846567431157953865e+307; /* 0x1p1023 */
+
+/// Complex hyperbolic sine (f64)
+///
+/// Returns `sinh(re) cos(im) + I cosh(re) sin(im)`, with the special values
+/// of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let hx = (x.to_bits() >> 32) as u32;
+    let lx = x.to_bits() as u32;
+    let hy = (y.to_bits() >> 32) as u32;
+    let ly = y.to_bits() as u32;
+    let ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    /* Handle the nearly-non-exceptional cases where x and y are finite. */
+    if ix < 0x7ff00000 && iy < 0x7ff00000 {
+        if iy | ly == 0 {
+            return Complex::new(sinh(x), y);
+        }
+        let (s, c) = sincos(y);
+        if ix < 0x40360000 {
+            /* small x: normal case */
+            return Complex::new(sinh(x) * c, cosh(x) * s);
+        }
+
+        /* |x| >= 22, so cosh(x) ~= exp(|x|) */
+        if ix < 0x40862e42 {
+            /* x < 710: exp(|x|) won't overflow */
+            let h = exp(fabs(x)) * 0.5;
+            return Complex::new(copysign(h, x) * c, h * s);
+        } else if ix < 0x4096bbaa {
+            /* x < 1455: scale to avoid overflow */
+            let z = k_ldexp_cexp(Complex::new(fabs(x), y), -1);
+            return Complex::new(z.re * copysign(1.0, x), z.im);
+        } else {
+            /* x >= 1455: the result always overflows */
+            let h = HUGE * x;
+            return Complex::new(h * c, h * h * s);
+        }
+    }
+
+    /*
+     * sinh(+-0 +- I Inf) = sign(d(+-0, dNaN))0 + I dNaN.
+     * The sign of 0 in the result is unspecified.  Choice = normally
+     * the same as dNaN.  Raise the invalid floating-point exception.
+     *
+     * sinh(+-0 +- I NaN) = sign(d(+-0, NaN))0 + I d(NaN).
+     * The sign of 0 in the result is unspecified.  Choice = normally
+     * the same as d(NaN).
+     */
+    if ix | lx == 0 && iy >= 0x7ff00000 {
+        return Complex::new(copysign(0.0, x * (y - y)), y - y);
+    }
+
+    /*
+     * sinh(+-Inf +- I 0) = +-Inf + I +-0.
+     *
+     * sinh(NaN +- I 0)   = d(NaN) + I +-0.
+     */
+    if iy | ly == 0 && ix >= 0x7ff00000 {
+        if (hx & 0xfffff) | lx == 0 {
+            return Complex::new(x, y);
+        }
+        return Complex::new(x, copysign(0.0, y));
+    }
+
+    /*
+     * sinh(x +- I Inf) = dNaN + I dNaN.
+     * Raise the invalid floating-point exception for finite nonzero x.
+     *
+     * sinh(x + I NaN) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero x.  Choice = don't raise (except for signaling NaNs).
+     */
+    if ix < 0x7ff00000 && iy >= 0x7ff00000 {
+        return Complex::new(y - y, x * (y - y));
+    }
+
+    /*
+     * sinh(+-Inf + I NaN)  = +-Inf + I d(NaN).
+     * The sign of Inf in the result is unspecified.  Choice = normally
+     * the same as d(NaN).
+     *
+     * sinh(+-Inf +- I Inf) = +Inf + I dNaN.
+     * The sign of Inf in the result is unspecified.  Choice = always +.
+     * Raise the invalid floating-point exception.
+     *
+     * sinh(+-Inf + I y)   = +-Inf cos(y) + I Inf sin(y)
+     */
+    if ix == 0x7ff00000 && lx == 0 {
+        if iy >= 0x7ff00000 {
+            return Complex::new(x * x, x * (y - y));
+        }
+        let (s, c) = sincos(y);
+        return Complex::new(x * c, f64::INFINITY * s);
+    }
+
+    /*
+     * sinh(NaN1 + I NaN2) = d(NaN1, NaN2) + I d(NaN1, NaN2).
+     *
+     * sinh(NaN +- I Inf) = d(NaN, dNaN) + I d(NaN, dNaN).
+     * Optionally raises the invalid floating-point exception.
+     * Choice = raise.
+     *
+     * sinh(NaN + I y)    = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero y.  Choice = don't raise (except for signaling NaNs).
+     */
+    Complex::new((x * x) * (y - y), (x + x) * (y - y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(csinh(Complex::new(-0.0, 0.0)), Complex::new(-0.0, 0.0));
+        let z = csinh(Complex::new(0.0, INFINITY));
+        assert!(z.re == 0.0 && z.im.is_nan());
+        let z = csinh(Complex::new(INFINITY, -0.0));
+        assert!(z.re == INFINITY && z.im == 0.0 && z.im.is_sign_negative());
+        let z = csinh(Complex::new(NEG_INFINITY, consts::PI));
+        assert!(z.re == INFINITY && z.im == INFINITY);
+        let z = csinh(Complex::new(INFINITY, INFINITY));
+        assert!(z.re.is_infinite() && z.im.is_nan());
+        let z = csinh(Complex::new(NAN, 0.0));
+        assert!(z.re.is_nan() && z.im == 0.0);
+    }
+
+    #[test]
+    fn values() {
+        let z = csinh(Complex::new(1.0, 2.0));
+        assert!((z.re / -0.4890562590412937 - 1.0).abs() < 1e-15);
+        assert!((z.im / 1.4031192506220407 - 1.0).abs() < 1e-15);
+        /* cosh(711) overflows, but sinh(711) cos(1) does not */
+        let z = csinh(Complex::new(711.0, 1.0));
+        assert!((z.re / 1.640527287432755e308 - 1.0).abs() < 1e-15);
+        assert_eq!(z.im, INFINITY);
+    }
+}
@@ -0,0 +1,168 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csinhf.c */
+/*-
+ * Copyright (c) 2005 Bruce D. Evans and Steven G. Kargl
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * Float version of csinh(). See the s_csinh.c for details.
+ *
+ * sinh(z) = sinh(x+iy)
+ *         = sinhf(x) cos(y) + i coshf(x) sin(y).
+ *
+ * Exceptional values are noted in the comments within the source code.
+ * These values and the return value were taken from n1124.pdf.
+ */
+
+use super::k_cexpf::k_ldexp_cexpf;
+use super::{copysignf, cosf, coshf, expf, fabsf, sinf, sinhf, Complex};
+use core::f32;
+
+const HUGE: f32 = 1.7014118346e+38; /* 0x1p127 */
+
+/// Complex hyperbolic sine (f32)
+///
+/// Returns `sinh(re) cos(im) + I cosh(re) sin(im)`, with the special values
+/// of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csinhf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re;
+    let y = z.im;
+    let hx = x.to_bits();
+    let hy = y.to_bits();
+    let ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    /* Handle the nearly-non-exceptional cases where x and y are finite. */
+    if ix < 0x7f800000 && iy < 0x7f800000 {
+        if iy == 0 {
+            return Complex::new(sinhf(x), y);
+        }
+        let (s, c) = (sinf(y), cosf(y));
+        if ix < 0x41100000 {
+            /* small x: normal case */
+            return Complex::new(sinhf(x) * c, coshf(x) * s);
+        }
+
+        /* |x| >= 9, so cosh(x) ~= exp(|x|) */
+        if ix < 0x42b17218 {
+            /* x < 88.7: expf(|x|) won't overflow */
+            let h = expf(fabsf(x)) * 0.5;
+            return Complex::new(copysignf(h, x) * c, h * s);
+        } else if ix < 0x4340b1e7 {
+            /* x < 192.7: scale to avoid overflow */
+            let z = k_ldexp_cexpf(Complex::new(fabsf(x), y), -1);
+            return Complex::new(z.re * copysignf(1.0, x), z.im);
+        } else {
+            /* x >= 192.7: the result always overflows */
+            let h = HUGE * x;
+            return Complex::new(h * c, h * h * s);
+        }
+    }
+
+    /*
+     * sinh(+-0 +- I Inf) = sign(d(+-0, dNaN))0 + I dNaN.
+     * The sign of 0 in the result is unspecified.  Choice = normally
+     * the same as dNaN.  Raise the invalid floating-point exception.
+     *
+     * sinh(+-0 +- I NaN) = sign(d(+-0, NaN))0 + I d(NaN).
+     * The sign of 0 in the result is unspecified.  Choice = normally
+     * the same as d(NaN).
+     */
+    if ix == 0 && iy >= 0x7f800000 {
+        return Complex::new(copysignf(0.0, x * (y - y)), y - y);
+    }
+
+    /*
+     * sinh(+-Inf +- I 0) = +-Inf + I +-0.
+     *
+     * sinh(NaN +- I 0)   = d(NaN) + I +-0.
+     */
+    if iy == 0 && ix >= 0x7f800000 {
+        if hx & 0x7fffff == 0 {
+            return Complex::new(x, y);
+        }
+        return Complex::new(x, copysignf(0.0, y));
+    }
+
+    /*
+     * sinh(x +- I Inf) = dNaN + I dNaN.
+     * Raise the invalid floating-point exception for finite nonzero x.
+     *
+     * sinh(x + I NaN) = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero x.  Choice = don't raise (except for signaling NaNs).
+     */
+    if ix < 0x7f800000 && iy >= 0x7f800000 {
+        return Complex::new(y - y, x * (y - y));
+    }
+
+    /*
+     * sinh(+-Inf + I NaN)  = +-Inf + I d(NaN).
+     * The sign of Inf in the result is unspecified.  Choice = normally
+     * the same as d(NaN).
+     *
+     * sinh(+-Inf +- I Inf) = +Inf + I dNaN.
+     * The sign of Inf in the result is unspecified.  Choice = always +.
+     * Raise the invalid floating-point exception.
+     *
+     * sinh(+-Inf + I y)   = +-Inf cos(y) + I Inf sin(y)
+     */
+    if ix == 0x7f800000 {
+        if iy >= 0x7f800000 {
+            return Complex::new(x * x, x * (y - y));
+        }
+        let (s, c) = (sinf(y), cosf(y));
+        return Complex::new(x * c, f32::INFINITY * s);
+    }
+
+    /*
+     * sinh(NaN1 + I NaN2) = d(NaN1, NaN2) + I d(NaN1, NaN2).
+     *
+     * sinh(NaN +- I Inf) = d(NaN, dNaN) + I d(NaN, dNaN).
+     * Optionally raises the invalid floating-point exception.
+     * Choice = raise.
+     *
+     * sinh(NaN + I y)    = d(NaN) + I d(NaN).
+     * Optionally raises the invalid floating-point exception for finite
+     * nonzero y.  Choice = don't raise (except for signaling NaNs).
+     */
+    Complex::new((x * x) * (y - y), (x + x) * (y - y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values() {
+        let z = csinhf(Complex::new(1.0, 2.0));
+        assert!((z.re / -0.48905626 - 1.0).abs() < 1e-6);
+        assert!((z.im / 1.4031193 - 1.0).abs() < 1e-6);
+        /* coshf(90) overflows, but sinhf(90) cosf(1) does not */
+        let z = csinhf(Complex::new(90.0, 1.0));
+        assert!((z.re / 3.2969336e38 - 1.0).abs() < 1e-6);
+        assert_eq!(z.im, f32::INFINITY);
+    }
+}
@@ -0,0 +1,131 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csqrt.c */
+/*-
+ * Copyright (c) 2007 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::{copysign, fabs, hypot, sqrt, Complex};
+use core::f64;
+
+/* We risk spurious overflow for components >= DBL_MAX / (1 + sqrt(2)). */
+const THRESH: f64 = 7.446288774449766337959726e+307; /* 0x7fda8279, 0x99fcef32 */
+
+/// Complex square root (f64)
+///
+/// Returns the square root of `z` with a nonnegative real part, with the
+/// branch cut along the negative real axis.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csqrt(z: Complex<f64>) -> Complex<f64> {
+    let mut a = z.re;
+    let mut b = z.im;
+
+    /* Handle special cases. */
+    if a == 0.0 && b == 0.0 {
+        return Complex::new(0.0, b);
+    }
+    if b.is_infinite() {
+        return Complex::new(f64::INFINITY, b);
+    }
+    if a.is_nan() {
+        let t = (b - b) / (b - b); /* raise invalid if b is not a NaN */
+        return Complex::new(a, t); /* return NaN + NaN i */
+    }
+    if a.is_infinite() {
+        /*
+         * csqrt(inf + NaN i)  = inf +  NaN i
+         * csqrt(inf + y i)    = inf +  0 i
+         * csqrt(-inf + NaN i) = NaN +- inf i
+         * csqrt(-inf + y i)   = 0   +  inf i
+         */
+        if a.is_sign_negative() {
+            return Complex::new(fabs(b - b), copysign(a, b));
+        } else {
+            return Complex::new(a, copysign(b - b, b));
+        }
+    }
+    /*
+     * The remaining special case (b is NaN) is handled just fine by
+     * the normal code path below.
+     */
+
+    /* Scale to avoid overflow. */
+    let scale = fabs(a) >= THRESH || fabs(b) >= THRESH;
+    if scale {
+        a *= 0.25;
+        b *= 0.25;
+    }
+
+    /* Algorithm 312, CACM vol 10, Oct 1967. */
+    let (re, im) = if a >= 0.0 {
+        let t = sqrt((a + hypot(a, b)) * 0.5);
+        (t, b / (2.0 * t))
+    } else {
+        let t = sqrt((-a + hypot(a, b)) * 0.5);
+        (fabs(b) / (2.0 * t), copysign(t, b))
+    };
+
+    /* Rescale. */
+    if scale {
+        Complex::new(re * 2.0, im * 2.0)
+    } else {
+        Complex::new(re, im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        let z = csqrt(Complex::new(-0.0, -0.0));
+        assert!(z.re == 0.0 && !z.re.is_sign_negative() && z.im.is_sign_negative());
+        assert_eq!(
+            csqrt(Complex::new(NAN, INFINITY)),
+            Complex::new(INFINITY, INFINITY)
+        );
+        assert_eq!(
+            csqrt(Complex::new(NEG_INFINITY, 1.0)),
+            Complex::new(0.0, INFINITY)
+        );
+        assert_eq!(csqrt(Complex::new(INFINITY, -1.0)).re, INFINITY);
+        assert!(csqrt(Complex::new(INFINITY, -1.0)).im.is_sign_negative());
+        let z = csqrt(Complex::new(NEG_INFINITY, NAN));
+        assert!(z.re.is_nan() && z.im.is_infinite());
+        let z = csqrt(Complex::new(1.0, NAN));
+        assert!(z.re.is_nan() && z.im.is_nan());
+    }
+
+    #[test]
+    fn branch_cut() {
+        assert_eq!(csqrt(Complex::new(-4.0, 0.0)), Complex::new(0.0, 2.0));
+        assert_eq!(csqrt(Complex::new(-4.0, -0.0)), Complex::new(0.0, -2.0));
+        assert_eq!(csqrt(Complex::new(3.0, 4.0)), Complex::new(2.0, 1.0));
+        /* scaled to avoid overflow */
+        let z = csqrt(Complex::new(MAX, MAX));
+        assert!((z.re / 1.4730945569055652e154 - 1.0).abs() < 1e-15);
+        assert_eq!(THRESH.to_bits(), 0x7fda827999fcef32);
+    }
+}
@@ -0,0 +1,95 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_csqrtf.c */
+/*-
+ * Copyright (c) 2007 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::{copysignf, fabs, fabsf, hypot, sqrt, Complex};
+use core::f32;
+
+/// Complex square root (f32)
+///
+/// Returns the square root of `z` with a nonnegative real part, with the
+/// branch cut along the negative real axis.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn csqrtf(z: Complex<f32>) -> Complex<f32> {
+    let a = z.re;
+    let b = z.im;
+
+    /* Handle special cases. */
+    if a == 0.0 && b == 0.0 {
+        return Complex::new(0.0, b);
+    }
+    if b.is_infinite() {
+        return Complex::new(f32::INFINITY, b);
+    }
+    if a.is_nan() {
+        let t = (b - b) / (b - b); /* raise invalid if b is not a NaN */
+        return Complex::new(a, t); /* return NaN + NaN i */
+    }
+    if a.is_infinite() {
+        /*
+         * csqrtf(inf + NaN i)  = inf +  NaN i
+         * csqrtf(inf + y i)    = inf +  0 i
+         * csqrtf(-inf + NaN i) = NaN +- inf i
+         * csqrtf(-inf + y i)   = 0   +  inf i
+         */
+        if a.is_sign_negative() {
+            return Complex::new(fabsf(b - b), copysignf(a, b));
+        } else {
+            return Complex::new(a, copysignf(b - b, b));
+        }
+    }
+
+    /*
+     * We compute t in double precision to avoid overflow and to
+     * provide correct rounding in nearly all cases.
+     * This is Algorithm 312, CACM vol 10, Oct 1967.
+     */
+    let (a, b) = (a as f64, b as f64);
+    if a >= 0.0 {
+        let t = sqrt((a + hypot(a, b)) * 0.5);
+        Complex::new(t as f32, (b / (2.0 * t)) as f32)
+    } else {
+        let t = sqrt((-a + hypot(a, b)) * 0.5);
+        Complex::new((fabs(b) / (2.0 * t)) as f32, copysignf(t as f32, b as f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::*;
+
+    #[test]
+    fn values() {
+        assert_eq!(csqrtf(Complex::new(3.0, 4.0)), Complex::new(2.0, 1.0));
+        assert_eq!(csqrtf(Complex::new(-4.0, -0.0)), Complex::new(0.0, -2.0));
+        assert_eq!(csqrtf(Complex::new(MAX, MAX)).re, 2.0267144e19);
+        assert_eq!(
+            csqrtf(Complex::new(NAN, INFINITY)),
+            Complex::new(INFINITY, INFINITY)
+        );
+    }
+}
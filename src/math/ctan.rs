@@ -0,0 +1,24 @@
+use super::{ctanh, Complex};
+
+/// Complex tangent (f64)
+///
+/// Returns `-I ctanh(I z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctan(z: Complex<f64>) -> Complex<f64> {
+    let w = ctanh(Complex::new(-z.im, z.re));
+    Complex::new(w.im, -w.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn values() {
+        let z = ctan(Complex::new(1.0, 2.0));
+        assert!((z.re / 0.03381282607989669 - 1.0).abs() < 1e-14);
+        assert!((z.im / 1.0147936161466335 - 1.0).abs() < 1e-15);
+        assert_eq!(ctan(Complex::new(1.0, INFINITY)), Complex::new(0.0, 1.0));
+    }
+}
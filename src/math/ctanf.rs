@@ -0,0 +1,10 @@
+use super::{ctanhf, Complex};
+
+/// Complex tangent (f32)
+///
+/// Returns `-I ctanhf(I z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanf(z: Complex<f32>) -> Complex<f32> {
+    let w = ctanhf(Complex::new(-z.im, z.re));
+    Complex::new(w.im, -w.re)
+}
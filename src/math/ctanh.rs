@@ -0,0 +1,168 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_ctanh.c */
+/*-
+ * Copyright (c) 2011 David Schultz
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * Hyperbolic tangent of a complex argument z = x + i y.
+ *
+ * The algorithm is from:
+ *
+ *   W. Kahan.  Branch Cuts for Complex Elementary Functions or Much
+ *   Ado About Nothing's Sign Bit.  In The State of the Art in
+ *   Numerical Analysis, pp. 165 ff.  Iserles and Powell, eds., 1987.
+ *
+ * Method:
+ *
+ *   Let t    = tan(x)
+ *       beta = 1/cos^2(y)
+ *       s    = sinh(x)
+ *       rho  = cosh(x)
+ *
+ *   We have:
+ *
+ *   tanh(z) = sinh(z) / cosh(z)
+ *
+ *             sinh(x) cos(y) + i cosh(x) sin(y)
+ *           = ---------------------------------
+ *             cosh(x) cos(y) + i sinh(x) sin(y)
+ *
+ *             cosh(x) sinh(x) / cos^2(y) + i tan(y)
+ *           = -------------------------------------
+ *                    1 + sinh^2(x) / cos^2(y)
+ *
+ *             beta rho s + i t
+ *           = ----------------
+ *               1 + beta s^2
+ *
+ * Modifications:
+ *
+ *   I omitted the original algorithm's handling of overflow in tan(x) after
+ *   verifying with nearpi.c that this can't happen in IEEE single or double
+ *   precision.  I also handle large x differently.
+ */
+
+use super::{copysign, exp, fabs, sincos, sinh, sqrt, tan, Complex};
+
+/// Complex hyperbolic tangent (f64)
+///
+/// Returns `csinh(z) / ccosh(z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanh(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let hx = (x.to_bits() >> 32) as u32;
+    let lx = x.to_bits() as u32;
+    let ix = hx & 0x7fffffff;
+
+    /*
+     * ctanh(NaN +- 0i) = NaN +- 0i
+     *
+     * ctanh(NaN + i y) = NaN + NaN i, for y != 0
+     *
+     * The imaginary part has the sign of x*sin(2*y), but there's no
+     * special effort to get this right.
+     *
+     * ctanh(+-Inf +- Inf i) = +-1 +- 0
+     *
+     * ctanh(+-Inf + i y) = +-1 + 0 sin(2y), for y finite
+     *
+     * The imaginary part of the sign is unspecified.  This special
+     * case is only needed to avoid a spurious invalid exception when
+     * y is infinite.
+     */
+    if ix >= 0x7ff00000 {
+        if (ix & 0xfffff) | lx != 0 {
+            /* x is NaN */
+            return Complex::new(x + y, if y == 0.0 { y } else { x + y });
+        }
+        let im = if y.is_infinite() {
+            y
+        } else {
+            let (s, c) = sincos(y);
+            s * c
+        };
+        return Complex::new(copysign(1.0, x), copysign(0.0, im));
+    }
+
+    /*
+     * ctanh(+-0 + i NAN) = +-0 + i NaN
+     * ctanh(+-0 +- i Inf) = +-0 + i NaN
+     * ctanh(x + i NAN) = NaN + i NaN
+     * ctanh(x +- i Inf) = NaN + i NaN
+     */
+    if !y.is_finite() {
+        return Complex::new(if x != 0.0 { y - y } else { x }, y - y);
+    }
+
+    /*
+     * ctanh(+-huge +- i y) ~= +-1 +- i 2sin(2y)/exp(2x), using the
+     * approximation sinh^2(huge) ~= exp(2*huge) / 4.
+     * We use a modified formula to avoid spurious overflow.
+     */
+    if ix >= 0x40360000 {
+        /* |x| >= 22 */
+        let exp_mx = exp(-fabs(x));
+        let (s, c) = sincos(y);
+        return Complex::new(copysign(1.0, x), 4.0 * s * c * exp_mx * exp_mx);
+    }
+
+    /* Kahan's algorithm */
+    let t = tan(y);
+    let beta = 1.0 + t * t; /* = 1 / cos^2(y) */
+    let s = sinh(x);
+    let rho = sqrt(1.0 + s * s); /* = cosh(x) */
+    let denom = 1.0 + beta * s * s;
+    Complex::new((beta * rho * s) / denom, t / denom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn special_values() {
+        assert_eq!(ctanh(Complex::new(-0.0, 0.0)), Complex::new(-0.0, 0.0));
+        assert_eq!(ctanh(Complex::new(INFINITY, 1.0)), Complex::new(1.0, 0.0));
+        let z = ctanh(Complex::new(NEG_INFINITY, INFINITY));
+        assert!(z.re == -1.0 && z.im == 0.0);
+        let z = ctanh(Complex::new(0.0, INFINITY));
+        assert!(z.re == 0.0 && z.im.is_nan());
+        let z = ctanh(Complex::new(1.0, NAN));
+        assert!(z.re.is_nan() && z.im.is_nan());
+        let z = ctanh(Complex::new(NAN, -0.0));
+        assert!(z.re.is_nan() && z.im == 0.0 && z.im.is_sign_negative());
+    }
+
+    #[test]
+    fn values() {
+        let z = ctanh(Complex::new(1.0, 2.0));
+        assert!((z.re / 1.16673625724092 - 1.0).abs() < 1e-15);
+        assert!((z.im / -0.24345820118572525 - 1.0).abs() < 1e-15);
+        let z = ctanh(Complex::new(-1000.0, 1.0));
+        assert!(z.re == -1.0 && z.im == 0.0);
+    }
+}
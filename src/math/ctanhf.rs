@@ -0,0 +1,103 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/s_ctanhf.c */
+/*-
+ * Copyright (c) 2011 David Schultz
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * Hyperbolic tangent of a complex argument z.  See s_ctanh.c for details.
+ */
+
+use super::{copysignf, cosf, expf, fabsf, sinf, sinhf, sqrtf, tanf, Complex};
+
+/// Complex hyperbolic tangent (f32)
+///
+/// Returns `csinhf(z) / ccoshf(z)`, with the special values of C Annex G.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ctanhf(z: Complex<f32>) -> Complex<f32> {
+    let x = z.re;
+    let y = z.im;
+    let hx = x.to_bits();
+    let ix = hx & 0x7fffffff;
+
+    /*
+     * ctanh(NaN +- 0i) = NaN +- 0i
+     *
+     * ctanh(NaN + i y) = NaN + NaN i, for y != 0
+     *
+     * The imaginary part has the sign of x*sin(2*y), but there's no
+     * special effort to get this right.
+     *
+     * ctanh(+-Inf +- Inf i) = +-1 +- 0
+     *
+     * ctanh(+-Inf + i y) = +-1 + 0 sin(2y), for y finite
+     *
+     * The imaginary part of the sign is unspecified.  This special
+     * case is only needed to avoid a spurious invalid exception when
+     * y is infinite.
+     */
+    if ix >= 0x7f800000 {
+        if ix & 0x7fffff != 0 {
+            /* x is NaN */
+            return Complex::new(x + y, if y == 0.0 { y } else { x + y });
+        }
+        let im = if y.is_infinite() {
+            y
+        } else {
+            let (s, c) = (sinf(y), cosf(y));
+            s * c
+        };
+        return Complex::new(copysignf(1.0, x), copysignf(0.0, im));
+    }
+
+    /*
+     * ctanh(+-0 + i NAN) = +-0 + i NaN
+     * ctanh(+-0 +- i Inf) = +-0 + i NaN
+     * ctanh(x + i NAN) = NaN + i NaN
+     * ctanh(x +- i Inf) = NaN + i NaN
+     */
+    if !y.is_finite() {
+        return Complex::new(if x != 0.0 { y - y } else { x }, y - y);
+    }
+
+    /*
+     * ctanh(+-huge +- i y) ~= +-1 +- i 2sin(2y)/exp(2x), using the
+     * approximation sinh^2(huge) ~= exp(2*huge) / 4.
+     * We use a modified formula to avoid spurious overflow.
+     */
+    if ix >= 0x41300000 {
+        /* |x| >= 11 */
+        let exp_mx = expf(-fabsf(x));
+        let (s, c) = (sinf(y), cosf(y));
+        return Complex::new(copysignf(1.0, x), 4.0 * s * c * exp_mx * exp_mx);
+    }
+
+    /* Kahan's algorithm */
+    let t = tanf(y);
+    let beta = 1.0 + t * t; /* = 1 / cos^2(y) */
+    let s = sinhf(x);
+    let rho = sqrtf(1.0 + s * s); /* = cosh(x) */
+    let denom = 1.0 + beta * s * s;
+    Complex::new((beta * rho * s) / denom, t / denom)
+}
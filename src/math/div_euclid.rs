@@ -0,0 +1,34 @@
+use super::{fmod, trunc};
+
+/// Euclidean division (f64)
+///
+/// Returns the quotient `q` such that `x = y * q + rem_euclid(x, y)`, rounded
+/// to an integer, like `f64::div_euclid`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn div_euclid(x: f64, y: f64) -> f64 {
+    let q = trunc(x / y);
+    if fmod(x, y) < 0.0 {
+        return if y > 0.0 { q - 1.0 } else { q + 1.0 };
+    }
+    q
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rem_euclid;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(div_euclid(7.0, 4.0), 1.0);
+        assert_eq!(div_euclid(-7.0, 4.0), -2.0);
+        assert_eq!(div_euclid(7.0, -4.0), -1.0);
+        assert_eq!(div_euclid(-7.0, -4.0), 2.0);
+        assert_eq!(rem_euclid(7.0, 4.0), 3.0);
+        assert_eq!(rem_euclid(-7.0, 4.0), 1.0);
+        assert_eq!(rem_euclid(7.0, -4.0), 3.0);
+        assert_eq!(rem_euclid(-7.0, -4.0), 1.0);
+        /* rounding can make the remainder equal to |y| */
+        assert_eq!(rem_euclid(-f64::EPSILON, 3.0), 3.0);
+    }
+}
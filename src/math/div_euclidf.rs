@@ -0,0 +1,14 @@
+use super::{fmodf, truncf};
+
+/// Euclidean division (f32)
+///
+/// Returns the quotient `q` such that `x = y * q + rem_euclidf(x, y)`, rounded
+/// to an integer, like `f32::div_euclid`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn div_euclidf(x: f32, y: f32) -> f32 {
+    let q = truncf(x / y);
+    if fmodf(x, y) < 0.0 {
+        return if y > 0.0 { q - 1.0 } else { q + 1.0 };
+    }
+    q
+}
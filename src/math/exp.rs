@@ -65,6 +65,7 @@
  *          if x < -745.133219101941108420 then exp(x) underflows
  */
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
 use super::scalbn;
 
 const HALF: [f64; 2] = [0.5, -0.5];
@@ -84,7 +85,6 @@ const P5: f64 = 4.13813679705723846039e-08; /* 0x3E663769, 0x72BEA4D0 */
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn exp(mut x: f64) -> f64 {
     let x1p1023 = f64::from_bits(0x7fe0000000000000); // 0x1p1023 === 2 ^ 1023
-    let x1p_149 = f64::from_bits(0x36a0000000000000); // 0x1p-149 === 2 ^ -149
 
     let hi: f64;
     let lo: f64;
@@ -107,12 +107,17 @@ pub fn exp(mut x: f64) -> f64 {
         }
         if x > 709.782712893383973096 {
             /* overflow if x!=inf */
+            if x.is_finite() {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            }
             x *= x1p1023;
             return x;
         }
         if x < -708.39641853226410622 {
             /* underflow if x!=-inf */
-            force_eval!((-x1p_149 / x) as f32);
+            if x.is_finite() {
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+            }
             if x < -745.13321910194110842 {
                 return 0.;
             }
@@ -138,7 +143,9 @@ pub fn exp(mut x: f64) -> f64 {
         lo = 0.;
     } else {
         /* inexact if x!=0 */
-        force_eval!(x1p1023 + x);
+        if x != 0.0 {
+            feraiseexcept(FE_INEXACT);
+        }
         return 1. + x;
     }
 
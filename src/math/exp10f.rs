@@ -1,3 +1,4 @@
+use super::fenv::narrow;
 use super::{exp2, exp2f, modff};
 
 const LN10_F32: f32 = 3.32192809488736234787031942948939;
@@ -17,5 +18,5 @@ pub fn exp10f(x: f32) -> f32 {
         y = exp2f(LN10_F32 * y);
         return y * P10[((n as isize) + 7) as usize];
     }
-    return exp2(LN10_F64 * (x as f64)) as f32;
+    return narrow(exp2(LN10_F64 * (x as f64)));
 }
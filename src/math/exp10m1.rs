@@ -0,0 +1,68 @@
+use super::expm1;
+use super::fenv::{feraiseexcept, underflow_if_tiny, FE_INEXACT, FE_OVERFLOW};
+
+const LN10_HI: f64 = 2.30258506536483764648e+00; /* 0x40026bb1, 0xb8000000 */
+const LN10_LO: f64 = 2.76292080375336166641e-08; /* 0x3e5daaa8, 0xac16ea57 */
+const SPLIT: f64 = 134217729.0; /* 0x41a00000, 0x02000000 */
+
+/// Computes `x * ln(10)` as an unevaluated sum `hi + lo`.
+fn mul_ln10(x: f64) -> (f64, f64) {
+    let c = x * SPLIT;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let hi = xh * LN10_HI;
+    let lo = xl * LN10_HI + x * LN10_LO;
+    let s = hi + lo;
+    (s, lo - (s - hi))
+}
+
+/// Exponential, base 10, of x, minus 1 (f64)
+///
+/// Calculates `10^x - 1` without the cancellation of `exp10(x) - 1` for small `x`
+/// and without rounding `x * ln(10)` first. The result is exact when `x` is a
+/// non-negative integer and `10^x` is representable.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp10m1(x: f64) -> f64 {
+    let x1p60 = f64::from_bits(0x43b0000000000000); // 0x1p60 === 2 ^ 60
+    let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+    let x1p1023 = f64::from_bits(0x7fe0000000000000); // 0x1p1023 === 2 ^ 1023
+
+    let ix = x.to_bits() & 0x7fffffffffffffff;
+
+    if x.is_nan() {
+        return x;
+    }
+    if x > 309.0 {
+        /* overflow, or +inf */
+        if x.is_finite() {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        }
+        return x * x1p1023;
+    }
+    if x <= -20.0 {
+        /* 10^x - 1 rounds to -1, including x = -inf */
+        return -1.0;
+    }
+    if ix < 0x0170000000000000 {
+        /* |x| < 2^-1000: exp10m1(x) ~= x * ln(10), scaled to avoid subnormal intermediates */
+        if ix == 0 {
+            return x;
+        }
+        let (hi, lo) = mul_ln10(x * x1p60);
+        return underflow_if_tiny((hi + lo) * x1p_60);
+    }
+    let n = x as i32;
+    if n as f64 == x && (1..=22).contains(&n) {
+        /* 10^n is exact up to 10^22, so a single rounding remains */
+        let mut p = 10.0;
+        for _ in 1..n {
+            p *= 10.0;
+        }
+        return p - 1.0;
+    }
+
+    /* expm1(hi + lo) ~= expm1(hi) + lo * exp(hi) */
+    let (hi, lo) = mul_ln10(x);
+    let e = expm1(hi);
+    e + lo * (1.0 + e)
+}
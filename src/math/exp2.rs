@@ -24,6 +24,7 @@
 // OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
 // SUCH DAMAGE.
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
 use super::scalbn;
 
 const TBLSIZE: usize = 256;
@@ -337,7 +338,6 @@ pub fn exp2(mut x: f64) -> f64 {
     // union {uint32_t u; int32_t i;} k;
     let x1p1023 = f64::from_bits(0x7fe0000000000000);
     let x1p52 = f64::from_bits(0x4330000000000000);
-    let _0x1p_149 = f64::from_bits(0xb6a0000000000000);
 
     /* Filter out exceptional cases. */
     let ui = f64::to_bits(x);
@@ -347,6 +347,9 @@ pub fn exp2(mut x: f64) -> f64 {
         if ix >= 0x40900000 && ui >> 63 == 0 {
             /* x >= 1024 or nan */
             /* overflow */
+            if x.is_finite() {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            }
             x *= x1p1023;
             return x;
         }
@@ -358,7 +361,7 @@ pub fn exp2(mut x: f64) -> f64 {
             /* x <= -1022 */
             /* underflow */
             if x <= -1075.0 || x - x1p52 + x1p52 != x {
-                force_eval!((_0x1p_149 / x) as f32);
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             }
             if x <= -1075.0 {
                 return 0.0;
@@ -24,6 +24,8 @@
 // OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
 // SUCH DAMAGE.
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+
 const TBLSIZE: usize = 16;
 
 static EXP2FT: [u64; TBLSIZE] = [
@@ -97,13 +99,16 @@ pub fn exp2f(mut x: f32) -> f32 {
         }
         if ui >= 0x43000000 && ui < 0x80000000 {
             /* x >= 128 */
+            if x.is_finite() {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            }
             x *= x1p127;
             return x;
         }
         if ui >= 0x80000000 {
             /* x < -126 */
-            if ui >= 0xc3160000 || (ui & 0x0000ffff != 0) {
-                force_eval!(f32::from_bits(0x80000001) / x);
+            if (ui >= 0xc3160000 || (ui & 0x0000ffff != 0)) && x.is_finite() {
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             }
             if ui >= 0xc3160000 {
                 /* x <= -150 */
@@ -0,0 +1,169 @@
+/* exp2(x) = exp(x*ln2), with x*ln2 as a pair hi, lo; within 1 ulp */
+
+use super::f128::{fast_two_sum, quiet_nan, two_prod};
+use super::k_expf128::k_expf128;
+use super::{scalbnf128, F128};
+
+/* ln2 as a pair hi, lo */
+const LN2_HI: F128 = F128::from_bits(0x3ffe62e42fefa39ef35793c7673007e6);
+const LN2_LO: F128 = F128::from_bits(0xbf8a2a17e1979b31ace93a4ebe5d148f);
+
+/// Exponential, base 2 (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2f128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x >= F128::from(16384) {
+        return scalbnf128(F128::MAX, 1);
+    }
+    if x < F128::from(-16495) {
+        return scalbnf128(F128::MIN_POSITIVE, -200);
+    }
+    /* x*ln2 to about 2^-220 */
+    let (p, e) = two_prod(x, LN2_HI);
+    let (hi, lo) = fast_two_sum(p, e + x * LN2_LO);
+    let (k, a, b) = k_expf128(hi, lo);
+    scalbnf128(a + b, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(exp2f128(F128::from(3)), F128::from(8));
+        assert_eq!(exp2f128(F128::from(-16494)), f(1));
+        /* 2^0.5, 2^16383.5 and 2^-0.25 */
+        assert_eq!(
+            exp2f128(f(0x3ffe0000000000000000000000000000)).to_bits(),
+            0x3fff6a09e667f3bcc908b2fb1366ea95
+        );
+        assert_eq!(
+            exp2f128(f(0x400cfffc000000000000000000000000)).to_bits(),
+            0x7ffe6a09e667f3bcc908b2fb1366ea95
+        );
+        assert_eq!(
+            exp2f128(f(0xbffd0000000000000000000000000000)).to_bits(),
+            0x3ffeae89f995ad3ad5e8734d1773205a
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(exp2f128(F128::INFINITY), F128::INFINITY);
+        assert_eq!(exp2f128(F128::NEG_INFINITY), F128::ZERO);
+        assert_eq!(exp2f128(F128::from(16384)), F128::INFINITY);
+        assert!(exp2f128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3feb3507013c551fc411399bde628a75,
+            0x3fff00000d6339781de0840e0be6fdbc,
+        ),
+        (
+            0x3fed61f0b3005f3836215c52ba9a9186,
+            0x3fff00003d5551de6fe6e9273d5034bf,
+        ),
+        (
+            0x3ff25ee354931c9170cfe2c7a8bf3ee8,
+            0x3fff000799d95f15cf83e32313b7e33d,
+        ),
+        (
+            0x3ff27ae5d655b27ab017001ab1e6c79e,
+            0x3fff0008352fe66e70271c286fff5aff,
+        ),
+        (
+            0x3ff82a6e533866e0b5a64b7e1101ae2d,
+            0x3fff019f0590de919e9b69f2bb36eb6d,
+        ),
+        (
+            0x4000c043b1d73f07696dee22c5e9b0b9,
+            0x40026a8eb62db34eb86f7b39010644a4,
+        ),
+        (
+            0x4001bb4309e183acd469800590b12bab,
+            0x4005e66393bb77e7b09988b5635e7f1f,
+        ),
+        (
+            0x40044b347a4c74fb791897806a9eb2d2,
+            0x402851f0acb3f7113f350b032a773f2d,
+        ),
+        (
+            0x400678543201dace5f605d7d74bcaafb,
+            0x40bb1ee86dfc388a968ca9051260fb5b,
+        ),
+        (
+            0x40067d47512e66fe4d5903d8d3ac45b3,
+            0x40bd8ebc7fefbf2bde36ccae15062910,
+        ),
+        (
+            0x400a8c65361eeac53b5a83d22a30c650,
+            0x4c621e97a77e736a9ee526471066ae6f,
+        ),
+        (
+            0x400cb810675dfc1bff1d7d4c6d292123,
+            0x7701091d55715c09ee146f678d076a06,
+        ),
+        (
+            0xbfeee405ccaa3a856faea8f1758d6fb0,
+            0x3ffefffeb080b383e0721cb0c94536bb,
+        ),
+        (
+            0xbff13ff07f8c8fea056ab93bb5472c75,
+            0x3ffefff911ed07c4002651a8de700400,
+        ),
+        (
+            0xbff5d699c132fc8a54429e5f1678fbf3,
+            0x3ffeff5d00f1e81ff1bc88ecbc10ef62,
+        ),
+        (
+            0xbff83fa360e918915047b9e0a2bccee0,
+            0x3ffefc8cc39847f88c052fe769c04488,
+        ),
+        (
+            0xbff96a746e15eaba9993afb1e13a2fef,
+            0x3ffef835748729e7c9fc11077e1ee5df,
+        ),
+        (
+            0xc00264585635857ac842c0ec3ab7f0f0,
+            0x3ff3d20286ebe07b9d1150e30b46cee4,
+        ),
+        (
+            0xc0053086e5b59da7a5bb7ab2c8157f5c,
+            0x3fb2d351b3ee502c760aaaec0c47e4dc,
+        ),
+        (
+            0xc0068cef7f7cdd9851bf75ca61404223,
+            0x3f387237c2116e277622e8e975b7fbe1,
+        ),
+        (
+            0xc008c72ef4735660126682978e1a28cc,
+            0x3c708d0bf5bd7ffe5b912a4cbfda2494,
+        ),
+        (
+            0xc008ed4e76d4a165734d91a829f3f167,
+            0x3c244ec3770d0a58ef160bea943c1420,
+        ),
+        (
+            0xc0098f6aa2588e17b4772f6d456b5acb,
+            0x39c142a741e77652e0759cfc663bfa45,
+        ),
+        (
+            0xc00cf93784b243356de42f281aab4af3,
+            0x00d80ae8d53e739a4299753021214feb,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(exp2f128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,39 @@
+use super::f16::from_f64_round;
+use super::{exp2, Round, F16};
+
+/// Exponential, base 2 (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2f16(x: F16) -> F16 {
+    from_f64_round(exp2(f64::from(x)), Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::super::floor;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(exp2f16(F16::from_f32(0.5)).to_bits(), 0x3da8);
+        assert_eq!(exp2f16(F16::from_f32(-24.0)).to_bits(), 0x0001);
+        assert_eq!(exp2f16(F16::from_f32(15.5)).to_bits(), 0x79a8);
+        assert_eq!(exp2f16(F16::from_f32(-3.25)).to_bits(), 0x2eba);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(exp2f16(F16::from_f32(16.0)), F16::INFINITY);
+        assert_eq!(exp2f16(F16::from_f32(-25.0)), F16::ZERO);
+        assert_eq!(exp2f16(F16::NEG_INFINITY), F16::ZERO);
+        assert!(exp2f16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(exp2f16, exp2, |x| {
+            let y = f64::from(x);
+            y == floor(y)
+        });
+    }
+}
@@ -0,0 +1,97 @@
+use super::fenv::{feraiseexcept, underflow_if_tiny, FE_INEXACT, FE_OVERFLOW};
+use super::{expm1, scalbn};
+
+const LN2_HI: f64 = 6.93147182464599609375e-01; /* 0x3fe62e43, 0x00000000 */
+const LN2_LO: f64 = -1.90465429995776804525e-09; /* 0xbe205c61, 0x0ca86c39 */
+const SPLIT: f64 = 134217729.0; /* 0x41a00000, 0x02000000 */
+
+/// Computes `x * ln(2)` as an unevaluated sum `hi + lo`.
+fn mul_ln2(x: f64) -> (f64, f64) {
+    let c = x * SPLIT;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let hi = xh * LN2_HI;
+    let lo = xl * LN2_HI + x * LN2_LO;
+    let s = hi + lo;
+    (s, lo - (s - hi))
+}
+
+/// Exponential, base 2, of x, minus 1 (f64)
+///
+/// Calculates `2^x - 1` without the cancellation of `exp2(x) - 1` for small `x`
+/// and without rounding `x * ln(2)` first. The result is exact when `x` is an
+/// integer and `2^x - 1` is representable.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1(x: f64) -> f64 {
+    let x1p60 = f64::from_bits(0x43b0000000000000); // 0x1p60 === 2 ^ 60
+    let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+    let x1p1023 = f64::from_bits(0x7fe0000000000000); // 0x1p1023 === 2 ^ 1023
+
+    let ix = x.to_bits() & 0x7fffffffffffffff;
+
+    if x.is_nan() {
+        return x;
+    }
+    if x >= 1024.0 {
+        /* overflow, or +inf */
+        if x.is_finite() {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        }
+        return x * x1p1023;
+    }
+    if x <= -64.0 {
+        /* 2^x - 1 rounds to -1, including x = -inf */
+        return -1.0;
+    }
+    if ix < 0x0170000000000000 {
+        /* |x| < 2^-1000: exp2m1(x) ~= x * ln(2), scaled to avoid subnormal intermediates */
+        if ix == 0 {
+            return x;
+        }
+        let (hi, lo) = mul_ln2(x * x1p60);
+        return underflow_if_tiny((hi + lo) * x1p_60);
+    }
+    let n = x as i32;
+    if n as f64 == x {
+        /* integers are exact: 2^n is, so a single rounding remains */
+        return scalbn(1.0, n) - 1.0;
+    }
+
+    /* expm1(hi + lo) ~= expm1(hi) + lo * exp(hi) */
+    let (hi, lo) = mul_ln2(x);
+    let e = expm1(hi);
+    e + lo * (1.0 + e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert!(exp2m1(NAN).is_nan());
+        assert_eq!(exp2m1(INFINITY), INFINITY);
+        assert_eq!(exp2m1(NEG_INFINITY), -1.0);
+        assert_eq!(exp2m1(1024.0), INFINITY);
+        assert_eq!(exp2m1(-100.0), -1.0);
+        assert_eq!(exp2m1(0.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(exp2m1(-0.0).to_bits(), (-0.0f64).to_bits());
+    }
+
+    #[test]
+    fn exact_integers() {
+        assert_eq!(exp2m1(1.0), 1.0);
+        assert_eq!(exp2m1(10.0), 1023.0);
+        assert_eq!(exp2m1(-1.0), -0.5);
+        assert_eq!(exp2m1(-2.0), -0.75);
+        assert_eq!(exp2m1(1023.0), f64::from_bits(0x7fe0000000000000));
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(exp2m1(0.5), 0.41421356237309503);
+        assert_eq!(exp2m1(1e-20), 6.931471805599452e-21);
+        assert_eq!(exp2m1(MIN_POSITIVE), 1.5423036715619055e-308);
+    }
+}
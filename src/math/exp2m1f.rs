@@ -0,0 +1,11 @@
+use super::exp2m1;
+use super::fenv::narrow;
+
+/// Exponential, base 2, of x, minus 1 (f32)
+///
+/// Calculates `2^x - 1`. The computation is carried out in double precision,
+/// which also makes the exact cases of `exp2m1` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn exp2m1f(x: f32) -> f32 {
+    narrow(exp2m1(x as f64))
+}
@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
 use super::scalbnf;
 
 const HALF: [f32; 2] = [0.5, -0.5];
@@ -33,7 +34,6 @@ const P2: f32 = -2.7667332906e-3; /* -0xb55215.0p-32 */
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn expf(mut x: f32) -> f32 {
     let x1p127 = f32::from_bits(0x7f000000); // 0x1p127f === 2 ^ 127
-    let x1p_126 = f32::from_bits(0x800000); // 0x1p-126f === 2 ^ -126  /*original 0x1p-149f    ??????????? */
     let mut hx = x.to_bits();
     let sign = (hx >> 31) as i32; /* sign bit of x */
     let signb: bool = sign != 0;
@@ -49,12 +49,17 @@ pub fn expf(mut x: f32) -> f32 {
         if (hx >= 0x42b17218) && (!signb) {
             /* x >= 88.722839f */
             /* overflow */
+            if x.is_finite() {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            }
             x *= x1p127;
             return x;
         }
         if signb {
             /* underflow */
-            force_eval!(-x1p_126 / x);
+            if x.is_finite() {
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+            }
             if hx >= 0x42cff1b5 {
                 /* x <= -103.972084f */
                 return 0.;
@@ -85,7 +90,9 @@ pub fn expf(mut x: f32) -> f32 {
         lo = 0.;
     } else {
         /* raise inexact */
-        force_eval!(x1p127 + x);
+        if x != 0.0 {
+            feraiseexcept(FE_INEXACT);
+        }
         return 1. + x;
     }
 
@@ -0,0 +1,165 @@
+/* exp(x) = 2^k * 2^(j/32) * exp(r), from k_expf128; within 1 ulp */
+
+use super::f128::quiet_nan;
+use super::k_expf128::k_expf128;
+use super::{scalbnf128, F128};
+
+/* Beyond these exp(x) overflows or underflows to zero, in round to nearest */
+pub(crate) const EXP_MAX_ARG: u128 = 0x400c62e42fefa39ef35793c7673007e6; /* ~11356.52 */
+pub(crate) const EXP_MIN_ARG: u128 = 0xc00c654bb3b2c73ebb059fabb506ff34; /* ~-11433.46 */
+
+/// Exponential, base *e* (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x > F128::from_bits(EXP_MAX_ARG) {
+        /* overflow, or MAX when rounding toward zero */
+        return scalbnf128(F128::MAX, 1);
+    }
+    if x < F128::from_bits(EXP_MIN_ARG) {
+        return scalbnf128(F128::MIN_POSITIVE, -200);
+    }
+    let (k, a, b) = k_expf128(x, F128::ZERO);
+    scalbnf128(a + b, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(expf128(F128::ZERO), F128::ONE);
+        /* e, exp(-1000) and exp(11356) */
+        assert_eq!(
+            expf128(F128::ONE).to_bits(),
+            0x40005bf0a8b1457695355fb8ac404e7a
+        );
+        assert_eq!(
+            expf128(F128::from(-1000)).to_bits(),
+            0x3a5c3c4219e4189540f324f46e7945bf
+        );
+        assert_eq!(
+            expf128(F128::from(11356)).to_bits(),
+            0x7ffe2f5c036bda9471f881247e0e3df7
+        );
+        /* a subnormal result */
+        assert_eq!(expf128(F128::from(-11400)).to_bits(), 0x9b0b43706715);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(expf128(F128::INFINITY), F128::INFINITY);
+        assert_eq!(expf128(F128::NEG_INFINITY), F128::ZERO);
+        assert_eq!(expf128(F128::from(11357)), F128::INFINITY);
+        assert_eq!(expf128(F128::from(-11434)), F128::ZERO);
+        assert!(expf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fefc79ce9fcbc7bd3e5f1084f093bc3,
+            0x3fff0001c79e7f6d40f963b6344c7308,
+        ),
+        (
+            0x3ff3b763d498000deef7480a13aa934d,
+            0x3fff001b77b66ac124e2cf86a9c954ea,
+        ),
+        (
+            0x3ff686f766c8db25dfd8fc5466f123d3,
+            0x3fff00c3c6693ef53a46a00cedf53168,
+        ),
+        (
+            0x3ff71fa12839f0ce334268cbbb5270af,
+            0x3fff012042fa2dd6250006d89f08cfcb,
+        ),
+        (
+            0x3ff8a70950cd637658fb98bf153ae430,
+            0x3fff03538eca6a1f08208af9dc30b474,
+        ),
+        (
+            0x3ffaa1b9402cafd5b1cd7054ebe9a3ca,
+            0x3fff0d6475258c2d24e674a42926f465,
+        ),
+        (
+            0x3ffbd4a50b28fb5662d659feea2abf20,
+            0x3fff1f081d5828bde8ba4d78d62136ac,
+        ),
+        (
+            0x3ffc67c3db9a6c0f58ce0ae68119bb17,
+            0x3fff312990d0da35a73d47bd2002a494,
+        ),
+        (
+            0x3ffdf7dacad6d6723b200bac5e84a75e,
+            0x3fffa2ba86628831e035dc9306073d05,
+        ),
+        (
+            0x3ffedce62e613b49a9c5232c4be40b7f,
+            0x400044e2c76d9519e7b3aaf536efbf65,
+        ),
+        (
+            0x40001d7ab2f2291043691bcdacbb8942,
+            0x400229afe4b3d3a7f9c42a2e91d4235b,
+        ),
+        (
+            0x4000918c959b556efa370ca87040f844,
+            0x40037097a9bae84bb9504cae83412e6f,
+        ),
+        (
+            0x4006c97595f33c1403c47c16db4d5f6c,
+            0x4148fb755844c5aa9a9d16fa1266083d,
+        ),
+        (
+            0x4008af886f2ebbc050eb29654e4816b7,
+            0x44dc1a42b50d4007d7664e08e529e969,
+        ),
+        (
+            0x4008f74a7230909bfdc52db91617b77a,
+            0x45ab241877afd91acf109ce2133eb760,
+        ),
+        (
+            0xbff0cf9c0c22567e61950e44ecc44d52,
+            0x3ffefff8c19cedbe1b8c24360b615b2a,
+        ),
+        (
+            0xbff6e4b163b0e4b50d2a48e6e1a0083c,
+            0x3ffefe1c33bfca1029afe90bee5051a1,
+        ),
+        (
+            0xbff891234f5e88b45351b5d03e3e98a5,
+            0x3ffef9c53ac6a426dc3f4e9b3e857f0c,
+        ),
+        (
+            0xbffe244cd25b90b813f0bd63bc3e0999,
+            0x3ffe2149f8bf23ae8129515dd38b641f,
+        ),
+        (
+            0xc000a4661058e2a8b379908a24cff96d,
+            0x3ffa32e87e4bf994af86a72aa04e6395,
+        ),
+        (
+            0xc001be58015ca860d5a80bff61180930,
+            0x3ff4ea9f5cc6f334ba5033041327f3d6,
+        ),
+        (
+            0xc0027acf95daa447ac7aa4c5e94ee0cf,
+            0x3fede4ebdcac624258fcac981e7b7f2d,
+        ),
+        (
+            0xc00b403baeccf6533c970d44c2989b7f,
+            0x231f038d85478b71b0302e2481cad90b,
+        ),
+        (
+            0xc00c24570350efdd70179c9956121942,
+            0x0b46b34ee4fa4aa7db271ed43bca0998,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(expf128, &REFERENCE, 1);
+    }
+}
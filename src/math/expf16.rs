@@ -0,0 +1,35 @@
+use super::f16::from_f64_round;
+use super::{exp, Round, F16};
+
+/// Exponential, base *e* (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expf16(x: F16) -> F16 {
+    from_f64_round(exp(f64::from(x)), Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(expf16(F16::ONE).to_bits(), 0x4170);
+        assert_eq!(expf16(F16::from_f32(-1.0)).to_bits(), 0x35e3);
+        assert_eq!(expf16(F16::from_f32(11.0)).to_bits(), 0x7b4f);
+        assert_eq!(expf16(F16::from_f32(-17.0)).to_bits(), 0x0001);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(expf16(F16::from_f32(12.0)), F16::INFINITY);
+        assert_eq!(expf16(F16::NEG_INFINITY), F16::ZERO);
+        assert_eq!(expf16(F16::INFINITY), F16::INFINITY);
+        assert!(expf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(expf16, exp, |x| x == F16::ZERO);
+    }
+}
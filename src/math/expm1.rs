@@ -10,6 +10,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
 use core::f64;
 
 const O_THRESHOLD: f64 = 7.09782712893383973096e+02; /* 0x40862E42, 0xFEFA39EF */
@@ -53,6 +54,9 @@ pub fn expm1(mut x: f64) -> f64 {
             return -1.0;
         }
         if x > O_THRESHOLD {
+            if x.is_finite() {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            }
             x *= f64::from_bits(0x7fe0000000000000);
             return x;
         }
@@ -82,8 +86,8 @@ pub fn expm1(mut x: f64) -> f64 {
         c = (hi - x) - lo;
     } else if hx < 0x3c900000 {
         /* |x| < 2**-54, return x */
-        if hx < 0x00100000 {
-            force_eval!(x);
+        if hx < 0x00100000 && x != 0.0 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
         }
         return x;
     } else {
@@ -13,6 +13,8 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+
 const O_THRESHOLD: f32 = 8.8721679688e+01; /* 0x42b17180 */
 const LN2_HI: f32 = 6.9313812256e-01; /* 0x3f317180 */
 const LN2_LO: f32 = 9.0580006145e-06; /* 0x3717f7d1 */
@@ -51,6 +53,9 @@ pub fn expm1f(mut x: f32) -> f32 {
             return -1.;
         }
         if x > O_THRESHOLD {
+            if x.is_finite() {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            }
             x *= x1p127;
             return x;
         }
@@ -84,8 +89,8 @@ pub fn expm1f(mut x: f32) -> f32 {
         c = (hi - x) - lo;
     } else if hx < 0x33000000 {
         /* when |x|<2**-25, return x */
-        if hx < 0x00800000 {
-            force_eval!(x * x);
+        if hx < 0x00800000 && hx != 0 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
         }
         return x;
     } else {
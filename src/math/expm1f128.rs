@@ -0,0 +1,184 @@
+/* expm1(x) = 2^k * (a + b) - 1, with the subtraction exact; within 1 ulp */
+
+use super::expf128::EXP_MAX_ARG;
+use super::f128::{quiet_nan, two_sum};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::k_expf128::k_expf128;
+use super::{expf128, scalbnf128, F128};
+
+/// Exponential, base *e*, of x-1 (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn expm1f128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    /* x itself for |x| < 2^-114, also keeping the sign of zero */
+    if x.to_bits() << 1 < 0x3f8d0000000000000000000000000000 << 1 {
+        return x;
+    }
+    /* beyond these the -1 is below half an ulp of the result */
+    if x > F128::from(80) {
+        if x > F128::from_bits(EXP_MAX_ARG) {
+            return scalbnf128(F128::MAX, 1);
+        }
+        return expf128(x);
+    }
+    if x < F128::from(-80) {
+        feraiseexcept(FE_INEXACT);
+        return -F128::ONE;
+    }
+
+    /* 2^k * (a + b) - 1, where 2^k * a - 1 is computed exactly */
+    let (k, a, b) = k_expf128(x, F128::ZERO);
+    let (hi, lo) = two_sum(scalbnf128(a, k), -F128::ONE);
+    hi + (lo + scalbnf128(b, k))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 2^-100, 1, -1, 100, 0.01 */
+        assert_eq!(
+            expm1f128(f(0x3f9b0000000000000000000000000000)).to_bits(),
+            0x3f9b0000000000000000000000000800
+        );
+        assert_eq!(
+            expm1f128(F128::ONE).to_bits(),
+            0x3fffb7e151628aed2a6abf7158809cf5
+        );
+        assert_eq!(
+            expm1f128(-F128::ONE).to_bits(),
+            0xbffe43a54e4e988641ca8a4270fadf56
+        );
+        assert_eq!(
+            expm1f128(F128::from(100)).to_bits(),
+            0x408f3494a9b171bf4acc225093322428
+        );
+        assert_eq!(
+            expm1f128(f(0x3ff847ae147ae147ae147ae147ae147b)).to_bits(),
+            0x3ff84952e9791133e7f2e171fbbd191c
+        );
+        assert_eq!(expm1f128(F128::from(-100)), -F128::ONE);
+        let z = expm1f128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(expm1f128(F128::INFINITY), F128::INFINITY);
+        assert_eq!(expm1f128(F128::NEG_INFINITY), -F128::ONE);
+        assert!(expm1f128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fc6f207a7f002effc0b8b3126b6b92a,
+            0x3fc6f207a7f002effcfdc3a38dd0e762,
+        ),
+        (
+            0x3fd3aac7ca2c391c7734b80e0549a66e,
+            0x3fd3aac7ca2c3932b320fe46f9fa1cd5,
+        ),
+        (
+            0x3fd592cdbba885c5059caf7fd15a058e,
+            0x3fd592cdbba886143ef585eb0e520606,
+        ),
+        (
+            0x3fda9a184ffd3dd354c32bef8147d368,
+            0x3fda9a184ffd48171c4d452565d76395,
+        ),
+        (
+            0x3fe8de1b078943ff4b177ac566d94eb8,
+            0x3fe8de1b0b062cf6a204170aa2f9af7e,
+        ),
+        (
+            0x3fead663ad53eab264d96dc5006ba1e7,
+            0x3fead663bad533918fc110433986a374,
+        ),
+        (
+            0x3feeb7e36ac5c25a4a48bf599b979f5e,
+            0x3feeb7e427bd69068fe6cee97c709d69,
+        ),
+        (
+            0x3ff8dff0140af982c9ba6192304eccf1,
+            0x3ff8e37841086d5567207f2fe3c82d0d,
+        ),
+        (
+            0x3ff8f6b376219e4f1884b780f605aeb3,
+            0x3ff8fa93abb440b8c5082797647d4df5,
+        ),
+        (
+            0x3ff9fd59b558bec49b1e71ef3ab73eb9,
+            0x3ffa02acde0b9a37f5454ca321e232fb,
+        ),
+        (
+            0x3ffa0dea50454278cd43c499900256fc,
+            0x3ffa126944750dde41fbf2fc0c0233a4,
+        ),
+        (
+            0x3ffa350c268c6fee192464f089454cb1,
+            0x3ffa3af371c70021438362d4cdedcf9f,
+        ),
+        (
+            0x400b784dbf51d476e3a58b919e68aa5e,
+            0x61ed3358b8baa2dc5a0d6e7ad311343f,
+        ),
+        (
+            0x400bc3ef5f15ec88f2a330de33e3e551,
+            0x68bf0cf1ba663781b38491f49d315eb5,
+        ),
+        (
+            0xbfd4c9013f8aed01118d4cf0b3d8989b,
+            0xbfd4c9013f8aecce1435fef7a70e1d28,
+        ),
+        (
+            0xbfd509496aac0d5db77189f796dfa207,
+            0xbfd509496aac0d3b5a4f0d30d204d46b,
+        ),
+        (
+            0xbfe65b52ea1733835c1530e316379d24,
+            0xbfe65b52e9a1650b194c0d5251e0890e,
+        ),
+        (
+            0xbfec768259450c37b69e6fb57aec6bef,
+            0xbfec76823706fc5ac488b6c44c299839,
+        ),
+        (
+            0xbff5c252d93c41e00eb9490339644163,
+            0xbff5c1efe2d2bcb156420c1cfd0e27f3,
+        ),
+        (
+            0xbff8956bfb423983a816c7c2c16abd30,
+            0xbff892ec90346f04b4c546a4435ac24f,
+        ),
+        (
+            0xbff9fe50e1fbbcd4bd908330b417fead,
+            0xbff9f6734b05ddfef05e2ddca82aef8d,
+        ),
+        (
+            0xc003d5dc8ec0f4dfd9f0f47bdc97a2f6,
+            0xbffeffffffffff9cba5652d46389d2d3,
+        ),
+        (
+            0xc008e66dee827ac93d38a83b0260ec7c,
+            0xbfff0000000000000000000000000000,
+        ),
+        (
+            0xc00dc11b3adb9fa9d5e9d8750c10e2d6,
+            0xbfff0000000000000000000000000000,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(expm1f128, &REFERENCE, 1);
+    }
+}
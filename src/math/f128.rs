@@ -0,0 +1,641 @@
+use super::fenv::{feraiseexcept, FE_DIVBYZERO, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_UNDERFLOW};
+use super::{fmaf128, Round};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A binary128 floating-point number
+///
+/// IEEE 754 quadruple precision, with a 113 bit significand and a 15 bit
+/// exponent, stored as its bits. The arithmetic operators and functions on
+/// it, such as `sqrtf128` and `fmaf128`, are done in integer arithmetic and
+/// give the same results on every target. Like the software `f64` functions, they round in the
+/// direction set with `fesetround` and raise the exceptions of the
+/// operation. Comparisons follow IEEE 754: a NaN is unordered, also with
+/// itself, and `-0 == +0`.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct F128(u128);
+
+pub(crate) const SIG_BITS: u32 = 112;
+pub(crate) const BIAS: i32 = 0x3fff;
+pub(crate) const EXP_MAX: i32 = 0x7fff; /* the exponent field of inf and NaN */
+pub(crate) const SIGN_MASK: u128 = 1 << 127;
+pub(crate) const SIG_MASK: u128 = (1 << SIG_BITS) - 1;
+pub(crate) const QUIET_BIT: u128 = 1 << (SIG_BITS - 1);
+const INF_BITS: u128 = (EXP_MAX as u128) << SIG_BITS;
+
+impl F128 {
+    /// Positive zero
+    pub const ZERO: F128 = F128(0);
+    /// One
+    pub const ONE: F128 = F128((BIAS as u128) << SIG_BITS);
+    /// Positive infinity
+    pub const INFINITY: F128 = F128(INF_BITS);
+    /// Negative infinity
+    pub const NEG_INFINITY: F128 = F128(SIGN_MASK | INF_BITS);
+    /// A quiet NaN
+    pub const NAN: F128 = F128(INF_BITS | QUIET_BIT);
+    /// The largest finite value
+    pub const MAX: F128 = F128(INF_BITS - 1);
+    /// The smallest positive normal value, `2^-16382`
+    pub const MIN_POSITIVE: F128 = F128(1 << SIG_BITS);
+    /// The difference between 1 and the next larger value, `2^-112`
+    pub const EPSILON: F128 = F128(((BIAS - SIG_BITS as i32) as u128) << SIG_BITS);
+
+    /// Returns the number with the bit representation `bits`.
+    pub const fn from_bits(bits: u128) -> F128 {
+        F128(bits)
+    }
+
+    /// Returns the bit representation of the number.
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Returns `true` if the number is a NaN.
+    pub fn is_nan(self) -> bool {
+        self.0 & !SIGN_MASK > INF_BITS
+    }
+
+    /// Returns `true` if the number is positive or negative infinity.
+    pub fn is_infinite(self) -> bool {
+        self.0 & !SIGN_MASK == INF_BITS
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.0 & !SIGN_MASK < INF_BITS
+    }
+
+    /// Returns `true` if the sign bit is set, also for `-0` and NaNs.
+    pub fn is_sign_negative(self) -> bool {
+        self.0 & SIGN_MASK != 0
+    }
+}
+
+impl fmt::Debug for F128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "F128({:#034x})", self.0)
+    }
+}
+
+impl PartialEq for F128 {
+    fn eq(&self, other: &F128) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for F128 {
+    fn partial_cmp(&self, other: &F128) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        /* sign-magnitude to a monotonic two's complement key, with -0 at 0 */
+        let key = |x: u128| {
+            let mag = (x & !SIGN_MASK) as i128;
+            if x & SIGN_MASK != 0 {
+                -mag
+            } else {
+                mag
+            }
+        };
+        Some(key(self.0).cmp(&key(other.0)))
+    }
+}
+
+impl Neg for F128 {
+    type Output = F128;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn neg(self) -> F128 {
+        F128(self.0 ^ SIGN_MASK)
+    }
+}
+
+impl Add for F128 {
+    type Output = F128;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn add(self, rhs: F128) -> F128 {
+        /* x*1 is exact, so this rounds the sum once */
+        fmaf128(self, F128::ONE, rhs)
+    }
+}
+
+impl Sub for F128 {
+    type Output = F128;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn sub(self, rhs: F128) -> F128 {
+        fmaf128(self, F128::ONE, -rhs)
+    }
+}
+
+impl Mul for F128 {
+    type Output = F128;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn mul(self, rhs: F128) -> F128 {
+        let sign = self.is_sign_negative() ^ rhs.is_sign_negative();
+        if self.is_nan() || rhs.is_nan() {
+            return quiet_nan(self, rhs);
+        }
+        if self.is_infinite() || rhs.is_infinite() {
+            if is_zero(self) || is_zero(rhs) {
+                return invalid();
+            }
+            return F128((sign as u128) << 127 | INF_BITS);
+        }
+        if is_zero(self) || is_zero(rhs) {
+            return signed_zero(sign);
+        }
+
+        /* the product has its top bit at bit 224 or 225, keep the top 127 */
+        let (mx, ex) = split(self);
+        let (my, ey) = split(rhs);
+        let p = mul_wide(mx, my);
+        let shift = 256 - p.0.leading_zeros() - 127;
+        round_pack(
+            shr_sticky(p, shift).1,
+            ex + ey + shift as i32,
+            sign,
+            Round::current(),
+        )
+    }
+}
+
+impl Div for F128 {
+    type Output = F128;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn div(self, rhs: F128) -> F128 {
+        let sign = self.is_sign_negative() ^ rhs.is_sign_negative();
+        if self.is_nan() || rhs.is_nan() {
+            return quiet_nan(self, rhs);
+        }
+        if self.is_infinite() {
+            if rhs.is_infinite() {
+                return invalid();
+            }
+            return F128((sign as u128) << 127 | INF_BITS);
+        }
+        if rhs.is_infinite() {
+            return signed_zero(sign);
+        }
+        if is_zero(rhs) {
+            if is_zero(self) {
+                return invalid();
+            }
+            feraiseexcept(FE_DIVBYZERO);
+            return F128((sign as u128) << 127 | INF_BITS);
+        }
+        if is_zero(self) {
+            return signed_zero(sign);
+        }
+
+        /* long division, 116 quotient bits and a sticky bit for the rest */
+        let (mx, ex) = split(self);
+        let (my, ey) = split(rhs);
+        let mut rem = mx;
+        let mut q: u128 = 0;
+        for _ in 0..116 {
+            q <<= 1;
+            if rem >= my {
+                rem -= my;
+                q |= 1;
+            }
+            rem <<= 1;
+        }
+        round_pack(
+            q << 1 | (rem != 0) as u128,
+            ex - ey - 116,
+            sign,
+            Round::current(),
+        )
+    }
+}
+
+impl From<f64> for F128 {
+    /// Converts `x` exactly, NaN payloads included.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn from(x: f64) -> F128 {
+        let bits = x.to_bits();
+        let sign = ((bits >> 63) as u128) << 127;
+        let e = (bits >> 52 & 0x7ff) as i32;
+        let m = (bits & ((1 << 52) - 1)) as u128;
+        if e == 0x7ff {
+            return F128(sign | INF_BITS | m << (SIG_BITS - 52));
+        }
+        if e == 0 {
+            if m == 0 {
+                return F128(sign);
+            }
+            /* normalize the subnormal */
+            let shift = m.leading_zeros() as i32 - (127 - 52);
+            let be = BIAS - 1022 - shift;
+            return F128(
+                sign | (be as u128) << SIG_BITS | (m << (SIG_BITS - 52 + shift as u32) & SIG_MASK),
+            );
+        }
+        F128(sign | ((e - 1023 + BIAS) as u128) << SIG_BITS | m << (SIG_BITS - 52))
+    }
+}
+
+impl From<i32> for F128 {
+    /// Converts `x` exactly.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn from(x: i32) -> F128 {
+        if x == 0 {
+            return F128::ZERO;
+        }
+        let m = (x as i64).unsigned_abs() as u128;
+        let shift = m.leading_zeros() - 15;
+        let e = BIAS + 112 - shift as i32;
+        F128(((x < 0) as u128) << 127 | (e as u128) << SIG_BITS | (m << shift & SIG_MASK))
+    }
+}
+
+impl F128 {
+    /// Rounds to `f64` in the current rounding direction, raising overflow,
+    /// underflow and inexact as needed. NaNs are quieted, and keep the top of
+    /// their payload.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn to_f64(self) -> f64 {
+        let sign = (self.is_sign_negative() as u64) << 63;
+        if self.is_nan() {
+            if self.0 & QUIET_BIT == 0 {
+                feraiseexcept(FE_INVALID);
+            }
+            let payload = ((self.0 & SIG_MASK) >> (SIG_BITS - 52)) as u64;
+            return f64::from_bits(sign | 0x7ff8 << 48 | payload);
+        }
+        if self.is_infinite() {
+            return f64::from_bits(sign | 0x7ff << 52);
+        }
+        if is_zero(self) {
+            return f64::from_bits(sign);
+        }
+
+        let (m, e) = split(self);
+        /* biased f64 exponent of the result, if normal */
+        let be = e + SIG_BITS as i32 + 1023;
+        let away = match Round::current() {
+            Round::Nearest | Round::NearestAway => true,
+            Round::TowardZero | Round::Odd => false,
+            Round::Up => sign == 0,
+            Round::Down => sign != 0,
+        };
+        if be >= 0x7ff {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            return f64::from_bits(sign | if away { 0x7ff << 52 } else { (0x7ff << 52) - 1 });
+        }
+        let shift = SIG_BITS as i32 - 52 + if be < 1 { 1 - be } else { 0 };
+        let (mut kept, above, tie, inexact) = if shift < 114 {
+            let rem = m & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            ((m >> shift) as u64, rem > half, rem == half, rem != 0)
+        } else {
+            (0, false, false, true)
+        };
+        let up = match Round::current() {
+            Round::Nearest => above || (tie && kept & 1 == 1),
+            Round::NearestAway => above || tie,
+            Round::Odd => inexact && kept & 1 == 0,
+            _ => inexact && away,
+        };
+        kept += up as u64;
+        let bits = if be < 1 { 0 } else { ((be - 1) as u64) << 52 } + kept;
+        if inexact {
+            if bits >= 0x7ff << 52 {
+                feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+            } else if be < 1 {
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+            } else {
+                feraiseexcept(FE_INEXACT);
+            }
+        }
+        f64::from_bits(sign | bits)
+    }
+}
+
+/* 256 bit unsigned integers as (hi, lo) */
+pub(crate) type U256 = (u128, u128);
+
+/* The full product of a and b, both below 2^127 */
+#[inline]
+pub(crate) fn mul_wide(a: u128, b: u128) -> U256 {
+    const M64: u128 = (1 << 64) - 1;
+    let (a1, a0) = (a >> 64, a & M64);
+    let (b1, b0) = (b >> 64, b & M64);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    /* a1 and b1 are below 2^63, so none of these overflow */
+    let mid = (p00 >> 64) + (p01 & M64) + (p10 & M64);
+    let lo = (p00 & M64) | mid << 64;
+    let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/* x >> s with the bits shifted out or-ed into the last bit */
+#[inline]
+pub(crate) fn shr_sticky(x: U256, s: u32) -> U256 {
+    let (hi, lo) = x;
+    if s == 0 {
+        x
+    } else if s < 128 {
+        let sticky = (lo << (128 - s) != 0) as u128;
+        (hi >> s, lo >> s | hi << (128 - s) | sticky)
+    } else if s < 256 {
+        let sticky = (lo != 0 || (s > 128 && hi << (256 - s) != 0)) as u128;
+        (0, hi >> (s - 128) | sticky)
+    } else {
+        (0, (hi | lo != 0) as u128)
+    }
+}
+
+/* The biased exponent field of x */
+#[inline]
+pub(crate) fn exponent(x: F128) -> i32 {
+    (x.0 >> SIG_BITS) as i32 & EXP_MAX
+}
+
+/* Returns true for a zero of either sign */
+#[inline]
+pub(crate) fn is_zero(x: F128) -> bool {
+    x.0 << 1 == 0
+}
+
+/* Splits a finite nonzero x into (m, e) with |x| = m * 2^e and m in
+ * [2^112, 2^113) */
+#[inline]
+pub(crate) fn split(x: F128) -> (u128, i32) {
+    let ix = x.0 & !SIGN_MASK;
+    let e = exponent(x);
+    if e == 0 {
+        let shift = ix.leading_zeros() as i32 - 15;
+        (ix << shift, 1 - BIAS - SIG_BITS as i32 - shift)
+    } else {
+        (ix & SIG_MASK | 1 << SIG_BITS, e - BIAS - SIG_BITS as i32)
+    }
+}
+
+/* The integer part of x, for finite |x| < 2^31 */
+#[inline]
+pub(crate) fn to_int(x: F128) -> i32 {
+    if exponent(x) < BIAS {
+        return 0;
+    }
+    let (m, e) = split(x);
+    let n = (m >> -e) as i32;
+    if x.is_sign_negative() {
+        -n
+    } else {
+        n
+    }
+}
+
+/* Returns the NaN result of an operation on x and y, at least one of which
+ * is a NaN: the first NaN, quieted, raising invalid if either is signaling */
+#[inline]
+pub(crate) fn quiet_nan(x: F128, y: F128) -> F128 {
+    let signaling = |z: F128| z.is_nan() && z.0 & QUIET_BIT == 0;
+    if signaling(x) || signaling(y) {
+        feraiseexcept(FE_INVALID);
+    }
+    F128(if x.is_nan() { x.0 } else { y.0 } | QUIET_BIT)
+}
+
+/* Returns the NaN result of an invalid operation on non-NaN operands */
+#[inline]
+pub(crate) fn invalid() -> F128 {
+    feraiseexcept(FE_INVALID);
+    F128::NAN
+}
+
+/* Returns x as the rounded result of a function that is x plus a tiny
+ * amount, raising inexact if x is nonzero and underflow if it is subnormal */
+#[inline]
+pub(crate) fn inexact_tiny(x: F128) -> F128 {
+    if exponent(x) == 0 {
+        if !is_zero(x) {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        }
+    } else {
+        feraiseexcept(FE_INEXACT);
+    }
+    x
+}
+
+/* Returns the zero, negative if sign */
+#[inline]
+pub(crate) fn signed_zero(sign: bool) -> F128 {
+    F128((sign as u128) << 127)
+}
+
+/// Rounds the finite nonzero `(-1)^sign * m * 2^e`, where `m` has a sticky
+/// last bit, to binary128 in the direction `round`, raising overflow,
+/// underflow and inexact as needed.
+#[inline]
+pub(crate) fn round_pack(mut m: u128, mut e: i32, sign: bool, round: Round) -> F128 {
+    let sign_bit = (sign as u128) << 127;
+
+    /* normalize m to have its top bit at bit 126, keeping the sticky bit */
+    let lz = m.leading_zeros() as i32 - 1;
+    if lz < 0 {
+        m = m >> 1 | (m & 1);
+        e += 1;
+    } else {
+        m <<= lz;
+        e -= lz;
+    }
+
+    /* biased exponent of the result, if normal */
+    let be = e + 126 + BIAS;
+    let away = match round {
+        Round::Nearest | Round::NearestAway => true,
+        Round::TowardZero | Round::Odd => false,
+        Round::Up => !sign,
+        Round::Down => sign,
+    };
+    if be >= EXP_MAX {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        return F128(sign_bit | if away { INF_BITS } else { INF_BITS - 1 });
+    }
+
+    /* number of bits of m below the last bit kept */
+    let shift = 126 - SIG_BITS as i32 + if be < 1 { 1 - be } else { 0 };
+    let (mut kept, above, tie, inexact) = if shift < 128 {
+        let rem = m & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        (m >> shift, rem > half, rem == half, rem != 0)
+    } else {
+        /* below half of the smallest subnormal */
+        (0, false, false, true)
+    };
+    let up = match round {
+        Round::Nearest => above || (tie && kept & 1 == 1),
+        Round::NearestAway => above || tie,
+        Round::Odd => inexact && kept & 1 == 0,
+        _ => inexact && away,
+    };
+    kept += up as u128;
+
+    /* a carry out of the significand goes into the exponent */
+    let bits = if be < 1 {
+        0
+    } else {
+        ((be - 1) as u128) << SIG_BITS
+    } + kept;
+    if inexact {
+        if bits >= INF_BITS {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        } else if be < 1 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        } else {
+            feraiseexcept(FE_INEXACT);
+        }
+    }
+    F128(sign_bit | bits)
+}
+
+/* Double-F128 arithmetic for the transcendental functions: a value is an
+ * unevaluated sum hi + lo with |lo| <= ulp(hi)/2. The error terms are exact in
+ * round to nearest. */
+
+/* (s, e) with s = a + b rounded and s + e = a + b, for |a| >= |b| */
+#[inline]
+pub(crate) fn fast_two_sum(a: F128, b: F128) -> (F128, F128) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/* (s, e) with s = a + b rounded and s + e = a + b */
+#[inline]
+pub(crate) fn two_sum(a: F128, b: F128) -> (F128, F128) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/* (p, e) with p = a * b rounded and p + e = a * b */
+#[inline]
+pub(crate) fn two_prod(a: F128, b: F128) -> (F128, F128) {
+    let p = a * b;
+    (p, fmaf128(a, b, -p))
+}
+
+#[inline]
+pub(crate) fn dd_add(a: (F128, F128), b: (F128, F128)) -> (F128, F128) {
+    let (s, e) = two_sum(a.0, b.0);
+    fast_two_sum(s, e + (a.1 + b.1))
+}
+
+#[inline]
+pub(crate) fn dd_mul(a: (F128, F128), b: (F128, F128)) -> (F128, F128) {
+    let (p, e) = two_prod(a.0, b.0);
+    fast_two_sum(p, e + (a.0 * b.1 + a.1 * b.0))
+}
+
+#[inline]
+pub(crate) fn dd_div(a: (F128, F128), b: (F128, F128)) -> (F128, F128) {
+    let q = a.0 / b.0;
+    /* the remainder a - q*b, then one correction step */
+    let (p, e) = two_prod(q, b.0);
+    let r = ((a.0 - p) - e + a.1) - q * b.1;
+    fast_two_sum(q, r / b.0)
+}
+
+/* The distance in ulps between x and the finite F128 with the given bits,
+ * for the tests of functions that are not correctly rounded */
+#[cfg(test)]
+pub(crate) fn ulps(x: F128, bits: u128) -> u128 {
+    (x.to_bits() as i128 - bits as i128).unsigned_abs()
+}
+
+/* The transcendental functions of F128 were written for this crate rather
+ * than ported from the ld128 code of FreeBSD, and each file gives the bound
+ * of its error, in ulps from the correctly rounded result. These check it on
+ * results computed with a multiple-precision library (mpmath), at random
+ * arguments and at the worst of several thousand more: (x, result) and
+ * (x, y, result) as bits. */
+#[cfg(test)]
+pub(crate) fn check_reference<F: Fn(F128) -> F128>(f: F, cases: &[(u128, u128)], max: u128) {
+    for &(x, r) in cases.iter() {
+        let z = f(F128::from_bits(x));
+        assert!(
+            ulps(z, r) <= max,
+            "{:#x}: {:#x}, not {:#x}",
+            x,
+            z.to_bits(),
+            r
+        );
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn check_reference2<F: Fn(F128, F128) -> F128>(
+    f: F,
+    cases: &[(u128, u128, u128)],
+    max: u128,
+) {
+    for &(x, y, r) in cases.iter() {
+        let z = f(F128::from_bits(x), F128::from_bits(y));
+        assert!(
+            ulps(z, r) <= max,
+            "{:#x}, {:#x}: {:#x}, not {:#x}",
+            x,
+            y,
+            z.to_bits(),
+            r
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify() {
+        assert!(F128::NAN.is_nan() && !F128::NAN.is_finite());
+        assert!(F128::NEG_INFINITY.is_infinite() && F128::NEG_INFINITY.is_sign_negative());
+        assert!(F128::MAX.is_finite() && !F128::MAX.is_infinite());
+        assert_eq!(F128::ONE.to_bits(), 0x3fff0000000000000000000000000000);
+        assert_eq!(F128::EPSILON.to_bits(), 0x3f8f0000000000000000000000000000);
+    }
+
+    #[test]
+    fn compare() {
+        let two = F128::from_bits(0x40000000000000000000000000000000);
+        assert!(F128::ONE < two && -two < -F128::ONE);
+        assert!(F128::NEG_INFINITY < -F128::MAX && F128::MAX < F128::INFINITY);
+        assert_eq!(F128::ZERO, -F128::ZERO);
+        assert!(F128::NAN != F128::NAN);
+        assert_eq!(F128::NAN.partial_cmp(&F128::ONE), None);
+        assert!(F128::from_bits(1) > -F128::ZERO);
+    }
+
+    #[test]
+    fn rounding() {
+        /* 1 + 2^-113 is a tie, 1 + 2^-113 + 2^-200 is not */
+        let m = (1 << 113) | 1;
+        assert_eq!(round_pack(m, -113, false, Round::Nearest), F128::ONE);
+        assert_eq!(
+            round_pack(m << 1 | 1, -114, false, Round::Nearest).to_bits(),
+            F128::ONE.to_bits() + 1
+        );
+        assert_eq!(
+            round_pack(m, -113, true, Round::Down).to_bits(),
+            (-F128::ONE).to_bits() + 1
+        );
+        assert_eq!(round_pack(1, 16384, false, Round::Nearest), F128::INFINITY);
+        assert_eq!(round_pack(1, 16384, false, Round::TowardZero), F128::MAX);
+        /* the smallest subnormal and half of it */
+        assert_eq!(round_pack(1, -16494, false, Round::Nearest).to_bits(), 1);
+        assert_eq!(round_pack(1, -16495, false, Round::Nearest).to_bits(), 0);
+        assert_eq!(round_pack(3, -16496, false, Round::Nearest).to_bits(), 1);
+    }
+}
@@ -0,0 +1,343 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::round_ops::round_bits;
+use super::{round, Round};
+use core::cmp::Ordering;
+use core::f64::consts::FRAC_2_PI;
+use core::fmt;
+use core::ops::Neg;
+
+/// A binary16 floating-point number
+///
+/// IEEE 754 half precision, with an 11 bit significand and a 5 bit
+/// exponent, stored as its bits. It converts exactly to `f32` and `f64`,
+/// and the functions on it, such as `sqrtf16` and `expf16`, compute in
+/// `f64` and round once, so that their results are correctly rounded in
+/// the direction set with `fesetround`. Comparisons follow IEEE 754: a NaN
+/// is unordered, also with itself, and `-0 == +0`.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct F16(u16);
+
+pub(crate) const SIG_BITS: u32 = 10;
+pub(crate) const BIAS: i32 = 15;
+pub(crate) const EXP_MAX: i32 = 0x1f; /* the exponent field of inf and NaN */
+pub(crate) const SIGN_MASK: u16 = 1 << 15;
+pub(crate) const SIG_MASK: u16 = (1 << SIG_BITS) - 1;
+pub(crate) const QUIET_BIT: u16 = 1 << (SIG_BITS - 1);
+const INF_BITS: u16 = (EXP_MAX as u16) << SIG_BITS;
+
+impl F16 {
+    /// Positive zero
+    pub const ZERO: F16 = F16(0);
+    /// One
+    pub const ONE: F16 = F16((BIAS as u16) << SIG_BITS);
+    /// Positive infinity
+    pub const INFINITY: F16 = F16(INF_BITS);
+    /// Negative infinity
+    pub const NEG_INFINITY: F16 = F16(SIGN_MASK | INF_BITS);
+    /// A quiet NaN
+    pub const NAN: F16 = F16(INF_BITS | QUIET_BIT);
+    /// The largest finite value, `65504`
+    pub const MAX: F16 = F16(INF_BITS - 1);
+    /// The smallest positive normal value, `2^-14`
+    pub const MIN_POSITIVE: F16 = F16(1 << SIG_BITS);
+    /// The difference between 1 and the next larger value, `2^-10`
+    pub const EPSILON: F16 = F16(((BIAS - SIG_BITS as i32) as u16) << SIG_BITS);
+
+    /// Returns the number with the bit representation `bits`.
+    pub const fn from_bits(bits: u16) -> F16 {
+        F16(bits)
+    }
+
+    /// Returns the bit representation of the number.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if the number is a NaN.
+    pub fn is_nan(self) -> bool {
+        self.0 & !SIGN_MASK > INF_BITS
+    }
+
+    /// Returns `true` if the number is positive or negative infinity.
+    pub fn is_infinite(self) -> bool {
+        self.0 & !SIGN_MASK == INF_BITS
+    }
+
+    /// Returns `true` if the number is neither infinite nor NaN.
+    pub fn is_finite(self) -> bool {
+        self.0 & !SIGN_MASK < INF_BITS
+    }
+
+    /// Returns `true` if the sign bit is set, also for `-0` and NaNs.
+    pub fn is_sign_negative(self) -> bool {
+        self.0 & SIGN_MASK != 0
+    }
+
+    /// Rounds `x` to binary16 in the current rounding direction, raising
+    /// overflow, underflow and inexact as needed. NaNs are quieted, and keep
+    /// the top of their payload.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f32(x: f32) -> F16 {
+        if x.is_nan() {
+            /* as f64 could quiet it */
+            let bits = x.to_bits();
+            if bits & 1 << 22 == 0 {
+                feraiseexcept(FE_INVALID);
+            }
+            let sign = (bits >> 31) as u16;
+            return F16(sign << 15
+                | INF_BITS
+                | QUIET_BIT
+                | (bits >> (23 - SIG_BITS)) as u16 & SIG_MASK);
+        }
+        from_f64_round(x as f64, Round::current())
+    }
+
+    /// Rounds `x` to binary16 in the current rounding direction, raising
+    /// overflow, underflow and inexact as needed. NaNs are quieted, and keep
+    /// the top of their payload.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    pub fn from_f64(x: f64) -> F16 {
+        from_f64_round(x, Round::current())
+    }
+}
+
+impl fmt::Debug for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "F16({:#06x})", self.0)
+    }
+}
+
+impl PartialEq for F16 {
+    fn eq(&self, other: &F16) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for F16 {
+    fn partial_cmp(&self, other: &F16) -> Option<Ordering> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+        /* sign-magnitude to a monotonic two's complement key, with -0 at 0 */
+        let key = |x: u16| {
+            let mag = (x & !SIGN_MASK) as i32;
+            if x & SIGN_MASK != 0 {
+                -mag
+            } else {
+                mag
+            }
+        };
+        Some(key(self.0).cmp(&key(other.0)))
+    }
+}
+
+impl Neg for F16 {
+    type Output = F16;
+
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn neg(self) -> F16 {
+        F16(self.0 ^ SIGN_MASK)
+    }
+}
+
+impl From<F16> for f64 {
+    /// Converts `x` exactly, NaN payloads included.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn from(x: F16) -> f64 {
+        let sign = ((x.0 >> 15) as u64) << 63;
+        let e = (x.0 >> SIG_BITS) as i32 & EXP_MAX;
+        let m = (x.0 & SIG_MASK) as u64;
+        if e == EXP_MAX {
+            return f64::from_bits(sign | 0x7ff << 52 | m << (52 - SIG_BITS));
+        }
+        if e == 0 {
+            /* zero or subnormal, exact as m * 2^-24 */
+            let z = m as f64 * f64::from_bits(0x3e70000000000000);
+            return f64::from_bits(sign | z.to_bits());
+        }
+        f64::from_bits(sign | ((e - BIAS + 1023) as u64) << 52 | m << (52 - SIG_BITS))
+    }
+}
+
+impl From<F16> for f32 {
+    /// Converts `x` exactly, NaN payloads included.
+    #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+    fn from(x: F16) -> f32 {
+        let sign = ((x.0 >> 15) as u32) << 31;
+        let e = (x.0 >> SIG_BITS) as i32 & EXP_MAX;
+        let m = (x.0 & SIG_MASK) as u32;
+        if e == EXP_MAX {
+            return f32::from_bits(sign | 0xff << 23 | m << (23 - SIG_BITS));
+        }
+        if e == 0 {
+            /* zero or subnormal, exact as m * 2^-24 */
+            let z = m as f32 * f32::from_bits(0x33800000);
+            return f32::from_bits(sign | z.to_bits());
+        }
+        f32::from_bits(sign | ((e - BIAS + 127) as u32) << 23 | m << (23 - SIG_BITS))
+    }
+}
+
+/* Rounds x to binary16 in the direction `round`, see F16::from_f64 */
+#[inline]
+pub(crate) fn from_f64_round(x: f64, round: Round) -> F16 {
+    let bits = x.to_bits();
+    let sign = bits >> 63 != 0;
+    let e = (bits >> 52 & 0x7ff) as i32;
+    let m = bits & ((1 << 52) - 1);
+    if e == 0x7ff {
+        if m == 0 {
+            return F16((sign as u16) << 15 | INF_BITS);
+        }
+        if m & 1 << 51 == 0 {
+            feraiseexcept(FE_INVALID);
+        }
+        return F16((sign as u16) << 15 | INF_BITS | QUIET_BIT | (m >> (52 - SIG_BITS)) as u16);
+    }
+    if e == 0 {
+        /* zero, or a subnormal far below the smallest binary16 value */
+        if m == 0 {
+            return F16((sign as u16) << 15);
+        }
+        return F16(round_bits(m, -1074, sign, round, 11, BIAS) as u16);
+    }
+    F16(round_bits(m | 1 << 52, e - 1075, sign, round, 11, BIAS) as u16)
+}
+
+/* pi/2 in parts, from rem_pio2: the first 33 bits, and the rest */
+const PIO2_1: f64 = 1.5707963267341256; /* 0x3FF921FB, 0x54400000 */
+const PIO2_1T: f64 = 6.077100506506192e-11; /* 0x3DD0B461, 0x1A626331 */
+
+/* Reduces the finite binary16 value `x`, |x| > pi/4, to n * pi/2 + y0 + y1
+ * with |y0 + y1| <= pi/4, as rem_pio2 does. The quotient is rounded to the
+ * nearest integer with round: the TO_INT of rem_pio2 rounds it in the
+ * direction of the rounding mode, leaving |y0 + y1| up to pi/2, where k_sin
+ * and k_cos lose their accuracy.
+ *
+ * One step is enough: n * PIO2_1 and x - n * PIO2_1 are exact for |x| below
+ * 2^16, and the remainder is at least 1.8e-4, at x = 0x7aa1. */
+pub(crate) fn rem_pio2f16(x: f64) -> (i32, f64, f64) {
+    let f_n = round(x * FRAC_2_PI);
+    let r = x - f_n * PIO2_1;
+    let w = f_n * PIO2_1T;
+    let y0 = r - w;
+    let y1 = (r - y0) - w;
+    (f_n as i32, y0, y1)
+}
+
+/* The elementary functions of binary16 round the result of the f64 function
+ * once, in the direction of the rounding mode: in every mode it is far enough
+ * from the rounding boundaries of binary16 for that rounding to be correct,
+ * which this checks on every input.
+ *
+ * Checks that `f` is the correctly rounded binary16 version of `g` on every
+ * input, given that g has a relative error below 2^-51 (two ulps): the
+ * rounding of every value within that error of g(x) must be the same, in
+ * every rounding direction. Where g(x) is a binary16 value, or a midpoint of
+ * two, it must be exact, which `exact` tells; it also tells the inputs whose
+ * rounding is checked otherwise. */
+#[cfg(test)]
+pub(crate) fn check_all(f: fn(F16) -> F16, g: fn(f64) -> f64, exact: fn(F16) -> bool) {
+    use super::fenv::{fegetenv, fesetenv, fesetround, FE_DOWNWARD, FE_TOWARDZERO, FE_UPWARD};
+
+    let rounds = [Round::Nearest, Round::TowardZero, Round::Up, Round::Down];
+    for bits in 0..=u16::MAX {
+        let x = F16(bits);
+        let z = f(x);
+        let r = g(f64::from(x));
+        if r.is_nan() {
+            assert!(z.is_nan(), "{:?}", x);
+            continue;
+        }
+        assert_eq!(z.0, from_f64_round(r, Round::Nearest).0, "{:?}", x);
+        if r == 0.0 || r.is_infinite() {
+            /* exact, or an overflow or underflow of f64 too */
+            continue;
+        }
+        if exact(x) {
+            continue;
+        }
+        let representable = from_f64_round(r, Round::Up).0 == from_f64_round(r, Round::Down).0;
+        assert!(!representable, "{:?}", x);
+        let d = r.abs() * f64::from_bits(0x3cc0000000000000); /* 2^-51 */
+        for &round in rounds.iter() {
+            assert_eq!(
+                from_f64_round(r - d, round).0,
+                from_f64_round(r + d, round).0,
+                "{:?}",
+                x
+            );
+        }
+        /* and f rounds in the direction of the rounding mode */
+        let modes = [
+            (FE_TOWARDZERO, Round::TowardZero),
+            (FE_UPWARD, Round::Up),
+            (FE_DOWNWARD, Round::Down),
+        ];
+        for &(mode, round) in modes.iter() {
+            let saved = fegetenv();
+            if fesetround(mode) != 0 {
+                continue;
+            }
+            let z = f(core::hint::black_box(x));
+            fesetenv(&saved);
+            assert_eq!(z.0, from_f64_round(r, round).0, "{:?} {:?}", x, round);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        for bits in 0..=u16::MAX {
+            let x = F16(bits);
+            let y = f64::from(x);
+            if x.is_nan() {
+                assert!(y.is_nan() && f32::from(x).is_nan());
+                /* quieted, with the payload kept */
+                assert_eq!(F16::from_f64(y).0, bits | QUIET_BIT);
+                assert_eq!(F16::from_f32(f32::from(x)).0, bits | QUIET_BIT);
+            } else {
+                assert_eq!(f32::from(x) as f64, y);
+                assert_eq!(F16::from_f64(y).0, bits);
+            }
+        }
+        assert_eq!(f64::from(F16::MAX), 65504.0);
+        assert_eq!(f64::from(F16(1)), 5.960464477539063e-8);
+    }
+
+    #[test]
+    fn rounding() {
+        /* 1 + 2^-11 is a tie, 1 + 2^-11 + 2^-40 is not */
+        assert_eq!(F16::from_f64(1.0 + 0.00048828125), F16::ONE);
+        assert_eq!(
+            F16::from_f64(1.0 + 0.00048828125 + 9.094947017729282e-13).0,
+            0x3c01
+        );
+        assert_eq!(from_f64_round(-1.0000001, Round::Down).0, 0xbc01);
+        assert_eq!(from_f64_round(-1.0000001, Round::TowardZero).0, 0xbc00);
+        assert_eq!(F16::from_f64(65520.0), F16::INFINITY);
+        assert_eq!(F16::from_f64(65519.99), F16::MAX);
+        assert_eq!(from_f64_round(1e10, Round::TowardZero), F16::MAX);
+        /* the smallest subnormal, half of it and below */
+        assert_eq!(F16::from_f64(5.960464477539063e-8).0, 1);
+        assert_eq!(F16::from_f64(2.9802322387695312e-8).0, 0);
+        assert_eq!(F16::from_f64(f64::from_bits(0x3e60000000000001)).0, 1);
+        assert_eq!(from_f64_round(1e-300, Round::Up).0, 1);
+        assert_eq!(F16::from_f64(-1e-300).0, 0x8000);
+    }
+
+    #[test]
+    fn comparisons() {
+        assert!(F16::ONE < F16::from_f32(2.0) && -F16::ONE > F16::from_f32(-2.0));
+        assert!(F16::NEG_INFINITY < -F16::MAX && F16::MAX < F16::INFINITY);
+        assert_eq!(F16::ZERO, -F16::ZERO);
+        assert!(F16::NAN != F16::NAN);
+        assert!(F16(1) > -F16::ZERO);
+    }
+}
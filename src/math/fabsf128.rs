@@ -0,0 +1,25 @@
+use super::f128::SIGN_MASK;
+use super::F128;
+
+/// Absolute value (magnitude) (F128)
+///
+/// Calculates the absolute value (magnitude) of the argument `x`,
+/// by direct manipulation of the bit representation of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fabsf128(x: F128) -> F128 {
+    F128::from_bits(x.to_bits() & !SIGN_MASK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fabsf128(-F128::ONE), F128::ONE);
+        assert_eq!(fabsf128(F128::MAX), F128::MAX);
+        assert!(!fabsf128(-F128::ZERO).is_sign_negative());
+        assert_eq!(fabsf128(F128::NEG_INFINITY), F128::INFINITY);
+        assert!(fabsf128(-F128::NAN).is_nan());
+    }
+}
@@ -0,0 +1,25 @@
+use super::f16::SIGN_MASK;
+use super::F16;
+
+/// Absolute value (magnitude) (F16)
+///
+/// Calculates the absolute value (magnitude) of the argument `x`,
+/// by direct manipulation of the bit representation of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fabsf16(x: F16) -> F16 {
+    F16::from_bits(x.to_bits() & !SIGN_MASK)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fabsf16(-F16::ONE), F16::ONE);
+        assert_eq!(fabsf16(F16::MAX), F16::MAX);
+        assert!(!fabsf16(-F16::ZERO).is_sign_negative());
+        assert_eq!(fabsf16(F16::NEG_INFINITY), F16::INFINITY);
+        assert_eq!(fabsf16(-F16::NAN).to_bits(), F16::NAN.to_bits());
+    }
+}
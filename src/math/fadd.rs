@@ -0,0 +1,52 @@
+use super::fenv::{feraiseexcept, narrow, FE_INEXACT, FE_INVALID, FE_OVERFLOW};
+use super::round_odd;
+
+/// Addition narrowed to f32
+///
+/// Returns `x + y` rounded once to `f32`, which `(x + y) as f32` does not do:
+/// the sum is first rounded to `f64`, and rounding that to `f32` can differ
+/// from rounding the exact sum.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fadd(x: f64, y: f64) -> f32 {
+    let s = x + y;
+    if !s.is_finite() {
+        if s.is_nan() && !x.is_nan() && !y.is_nan() {
+            /* inf - inf */
+            feraiseexcept(FE_INVALID);
+        } else if x.is_finite() && y.is_finite() {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        }
+        return s as f32;
+    }
+    /* 2sum: err = (x + y) - s exactly */
+    let bp = s - x;
+    let err = (x - (s - bp)) + (y - bp);
+    narrow(round_odd(s, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn double_rounding() {
+        /* 1 + 2^-24 + 2^-80 rounds to 1 + 2^-24 as a f64, a tie for f32 */
+        let x = 1.0 + f64::from_bits(0x3e70000000000000);
+        let y = f64::from_bits(0x3af0000000000000);
+        assert_eq!((x + y) as f32, 1.0);
+        assert_eq!(fadd(x, y), 1.0 + f32::EPSILON);
+        assert_eq!(fadd(-x, -y), -1.0 - f32::EPSILON);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(fadd(NAN, 1.0).is_nan());
+        assert!(fadd(INFINITY, NEG_INFINITY).is_nan());
+        assert_eq!(fadd(MAX, MAX), f32::INFINITY);
+        assert_eq!(fadd(1e39, 0.0), f32::INFINITY);
+        assert_eq!(fadd(-0.0, -0.0).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(fadd(-0.0, 0.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(fadd(1.0, -1.0).to_bits(), 0.0f32.to_bits());
+    }
+}
@@ -1,3 +1,4 @@
+use super::fenv::overflow;
 use core::f64;
 
 /// Positive difference (f64)
@@ -15,7 +16,11 @@ pub fn fdim(x: f64, y: f64) -> f64 {
     } else if y.is_nan() {
         y
     } else if x > y {
-        x - y
+        let d = x - y;
+        if d.is_infinite() && x.is_finite() && y.is_finite() {
+            return overflow(d);
+        }
+        d
     } else {
         0.0
     }
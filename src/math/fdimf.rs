@@ -1,3 +1,4 @@
+use super::fenv::overflow;
 use core::f32;
 
 /// Positive difference (f32)
@@ -15,7 +16,11 @@ pub fn fdimf(x: f32, y: f32) -> f32 {
     } else if y.is_nan() {
         y
     } else if x > y {
-        x - y
+        let d = x - y;
+        if d.is_infinite() && x.is_finite() && y.is_finite() {
+            return overflow(d);
+        }
+        d
     } else {
         0.0
     }
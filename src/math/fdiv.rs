@@ -0,0 +1,26 @@
+use super::fenv::{feraiseexcept, narrow, FE_DIVBYZERO, FE_INVALID};
+use super::{fma, frexp, round_odd, scalbn};
+
+/// Division narrowed to f32
+///
+/// Returns `x / y` rounded once to `f32`, which `(x / y) as f32` does not do.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fdiv(x: f64, y: f64) -> f32 {
+    if x == 0.0 || y == 0.0 || !x.is_finite() || !y.is_finite() {
+        let q = x / y;
+        if q.is_nan() && !x.is_nan() && !y.is_nan() {
+            /* 0 / 0 or inf / inf */
+            feraiseexcept(FE_INVALID);
+        } else if y == 0.0 && x.is_finite() {
+            feraiseexcept(FE_DIVBYZERO);
+        }
+        return q as f32;
+    }
+    /* scale to [1/2, 1) so that the fma residual cannot underflow */
+    let (mx, ex) = frexp(x);
+    let (my, ey) = frexp(y);
+    let q = mx / my;
+    /* the sign of the rounding error is that of (mx - q*my) / my */
+    let err = fma(-q, my, mx) * my;
+    narrow(scalbn(round_odd(q, err), ex - ey))
+}
@@ -1,33 +1,550 @@
-// src: musl/src/fenv/fenv.c
-/* Dummy functions for archs lacking fenv implementation */
+// src: musl/src/fenv/fenv.c, musl/src/fenv/x86_64/fenv.s, musl/src/fenv/aarch64/fenv.s
+/* Floating-point environment: rounding mode and exception flags.
+ *
+ * On x86_64 and aarch64 the environment is the hardware one, kept in MXCSR
+ * and in FPCR and FPSR respectively. It belongs to the current thread: the
+ * rounding mode applies to all its floating-point arithmetic and the flags
+ * are raised both by the hardware and by the functions of this crate. On
+ * x86_64 only the SSE unit is used, as Rust does for f32 and f64, the x87 one
+ * is left alone.
+ *
+ * Elsewhere the flags live in a single process-wide status word that the
+ * functions of this crate update explicitly when they detect an exception,
+ * since the hardware flags raised by the arithmetic itself cannot be observed
+ * from here. Flags are raised and cleared with atomic read-modify-write
+ * operations, so that concurrent updates from several threads are not lost;
+ * on targets without them, separate atomic loads and stores are used and
+ * concurrent updates can lose flags. The rounding mode is always to nearest
+ * there, and fesetround fails for any other mode.
+ *
+ * The compiler assumes the default environment: it may evaluate constant
+ * expressions or move arithmetic across these calls as if rounding to
+ * nearest. */
 
-pub(crate) const FE_UNDERFLOW: i32 = 0;
-pub(crate) const FE_INEXACT: i32 = 0;
+use super::fabs;
+use core::f32;
 
-pub(crate) const FE_TONEAREST: i32 = 0;
-pub(crate) const FE_TOWARDZERO: i32 = 0;
+#[cfg(target_arch = "aarch64")]
+mod consts {
+    pub const FE_INVALID: i32 = 1;
+    pub const FE_DIVBYZERO: i32 = 2;
+    pub const FE_OVERFLOW: i32 = 4;
+    pub const FE_UNDERFLOW: i32 = 8;
+    pub const FE_INEXACT: i32 = 16;
+    pub const FE_ALL_EXCEPT: i32 = 31;
 
+    pub const FE_TONEAREST: i32 = 0;
+    pub const FE_UPWARD: i32 = 0x400000;
+    pub const FE_DOWNWARD: i32 = 0x800000;
+    pub const FE_TOWARDZERO: i32 = 0xc00000;
+}
+
+/* the x86 values, also used by the software environment */
+#[cfg(not(target_arch = "aarch64"))]
+mod consts {
+    pub const FE_INVALID: i32 = 1;
+    pub const FE_DIVBYZERO: i32 = 4;
+    pub const FE_OVERFLOW: i32 = 8;
+    pub const FE_UNDERFLOW: i32 = 16;
+    pub const FE_INEXACT: i32 = 32;
+    pub const FE_ALL_EXCEPT: i32 = 61;
+
+    pub const FE_TONEAREST: i32 = 0;
+    pub const FE_DOWNWARD: i32 = 0x400;
+    pub const FE_UPWARD: i32 = 0x800;
+    pub const FE_TOWARDZERO: i32 = 0xc00;
+}
+
+pub use self::consts::*;
+
+#[cfg(target_arch = "x86_64")]
+mod env {
+    use core::arch::asm;
+
+    /* MXCSR holds both the control bits and the flags, in bits 0-5. The
+     * rounding mode is in bits 13-14, 3 bits above the x87 values. */
+    const FLAGS: u32 = 0x3f;
+    const ROUND_SHIFT: u32 = 3;
+    pub const ROUND_MASK: i32 = 0xc00;
+    pub const DEFAULT_CONTROL: u32 = 0x1f80;
+
+    #[inline]
+    fn get() -> u32 {
+        let mut csr: u32 = 0;
+        unsafe {
+            asm!("stmxcsr [{}]", in(reg) &mut csr, options(nostack, preserves_flags));
+        }
+        csr
+    }
+
+    #[inline]
+    fn set(csr: u32) {
+        unsafe {
+            asm!("ldmxcsr [{}]", in(reg) &csr, options(nostack, readonly));
+        }
+    }
+
+    #[inline]
+    pub fn status() -> u32 {
+        get() & FLAGS
+    }
+
+    #[inline]
+    pub fn set_status(status: u32) {
+        set(get() & !FLAGS | status & FLAGS);
+    }
+
+    /* the register belongs to the thread, so these need no atomicity */
+    #[inline]
+    pub fn raise_status(bits: u32) -> u32 {
+        let status = status();
+        if status | bits != status {
+            set_status(status | bits);
+        }
+        status
+    }
+
+    #[inline]
+    pub fn clear_status(bits: u32) -> u32 {
+        let status = status();
+        if status & !bits != status {
+            set_status(status & !bits);
+        }
+        status
+    }
+
+    #[inline]
+    pub fn control() -> u32 {
+        get() & !FLAGS
+    }
+
+    #[inline]
+    pub fn set_control(control: u32) {
+        set(control & !FLAGS | get() & FLAGS);
+    }
+
+    #[inline]
+    pub fn round() -> i32 {
+        (control() >> ROUND_SHIFT) as i32 & ROUND_MASK
+    }
+
+    #[inline]
+    pub fn set_round(r: i32) {
+        set_control(control() & !((ROUND_MASK as u32) << ROUND_SHIFT) | (r as u32) << ROUND_SHIFT);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod env {
+    use core::arch::asm;
+
+    /* the rounding mode is the RMode field of FPCR, the flags are in FPSR */
+    pub const ROUND_MASK: i32 = 0xc00000;
+    pub const DEFAULT_CONTROL: u32 = 0;
+
+    #[inline]
+    pub fn status() -> u32 {
+        let fpsr: u64;
+        unsafe {
+            asm!("mrs {}, fpsr", out(reg) fpsr, options(nostack, preserves_flags));
+        }
+        fpsr as u32
+    }
+
+    #[inline]
+    pub fn set_status(status: u32) {
+        unsafe {
+            asm!("msr fpsr, {}", in(reg) status as u64, options(nostack));
+        }
+    }
+
+    /* the register belongs to the thread, so these need no atomicity */
+    #[inline]
+    pub fn raise_status(bits: u32) -> u32 {
+        let status = status();
+        if status | bits != status {
+            set_status(status | bits);
+        }
+        status
+    }
+
+    #[inline]
+    pub fn clear_status(bits: u32) -> u32 {
+        let status = status();
+        if status & !bits != status {
+            set_status(status & !bits);
+        }
+        status
+    }
+
+    #[inline]
+    pub fn control() -> u32 {
+        let fpcr: u64;
+        unsafe {
+            asm!("mrs {}, fpcr", out(reg) fpcr, options(nostack, preserves_flags));
+        }
+        fpcr as u32
+    }
+
+    #[inline]
+    pub fn set_control(control: u32) {
+        unsafe {
+            asm!("msr fpcr, {}", in(reg) control as u64, options(nostack));
+        }
+    }
+
+    #[inline]
+    pub fn round() -> i32 {
+        control() as i32 & ROUND_MASK
+    }
+
+    #[inline]
+    pub fn set_round(r: i32) {
+        set_control(control() & !(ROUND_MASK as u32) | r as u32);
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod env {
+    use super::FE_TONEAREST;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    /* only rounding to nearest, so no control bits */
+    pub const ROUND_MASK: i32 = 0;
+    pub const DEFAULT_CONTROL: u32 = 0;
+
+    static STATUS: AtomicU32 = AtomicU32::new(0);
+
+    #[inline]
+    pub fn status() -> u32 {
+        STATUS.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn set_status(status: u32) {
+        STATUS.store(status, Ordering::Relaxed);
+    }
+
+    #[cfg(target_has_atomic = "32")]
+    #[inline]
+    pub fn raise_status(bits: u32) -> u32 {
+        STATUS.fetch_or(bits, Ordering::Relaxed)
+    }
+
+    #[cfg(target_has_atomic = "32")]
+    #[inline]
+    pub fn clear_status(bits: u32) -> u32 {
+        STATUS.fetch_and(!bits, Ordering::Relaxed)
+    }
+
+    /* without atomic read-modify-write, a concurrent update can be lost */
+    #[cfg(not(target_has_atomic = "32"))]
+    #[inline]
+    pub fn raise_status(bits: u32) -> u32 {
+        let status = status();
+        if status | bits != status {
+            set_status(status | bits);
+        }
+        status
+    }
+
+    #[cfg(not(target_has_atomic = "32"))]
+    #[inline]
+    pub fn clear_status(bits: u32) -> u32 {
+        let status = status();
+        if status & !bits != status {
+            set_status(status & !bits);
+        }
+        status
+    }
+
+    #[inline]
+    pub fn control() -> u32 {
+        0
+    }
+
+    #[inline]
+    pub fn set_control(_control: u32) {}
+
+    #[inline]
+    pub fn round() -> i32 {
+        FE_TONEAREST
+    }
+
+    #[inline]
+    pub fn set_round(_r: i32) {}
+}
+
+/// Floating-point environment (`fenv_t`)
+///
+/// The rounding mode, the other control bits and the exception flags, as
+/// saved by `fegetenv` and restored by `fesetenv`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FeEnv {
+    control: u32,
+    status: u32,
+}
+
+/// The default environment (`FE_DFL_ENV`): rounding to nearest, no exception
+/// flags set, and all exceptions masked.
+pub const FE_DFL_ENV: FeEnv = FeEnv {
+    control: env::DEFAULT_CONTROL,
+    status: 0,
+};
+
+/// Clear floating-point exception flags
+///
+/// Clears the flags of the exceptions in `excepts`, a bitwise or of
+/// `FE_INVALID`, `FE_DIVBYZERO`, `FE_OVERFLOW`, `FE_UNDERFLOW` and
+/// `FE_INEXACT`. Returns 0.
 #[inline]
-pub(crate) fn feclearexcept(_mask: i32) -> i32 {
+pub fn feclearexcept(excepts: i32) -> i32 {
+    env::clear_status((excepts & FE_ALL_EXCEPT) as u32);
     0
 }
 
+/// Raise floating-point exceptions
+///
+/// Sets the flags of the exceptions in `excepts`. Returns 0.
 #[inline]
-pub(crate) fn feraiseexcept(_mask: i32) -> i32 {
+pub fn feraiseexcept(excepts: i32) -> i32 {
+    env::raise_status((excepts & FE_ALL_EXCEPT) as u32);
     0
 }
 
+/* Clears the flags of `excepts` and returns the ones of them that were set,
+ * in a single update of the status */
+#[inline]
+pub(crate) fn fetch_clear_except(excepts: i32) -> i32 {
+    env::clear_status((excepts & FE_ALL_EXCEPT) as u32) as i32 & excepts & FE_ALL_EXCEPT
+}
+
+/* Sets the flags of `excepts` and returns all the flags that were set
+ * before, in a single update of the status */
 #[inline]
-pub(crate) fn fetestexcept(_mask: i32) -> i32 {
+pub(crate) fn fetch_raise_except(excepts: i32) -> i32 {
+    env::raise_status((excepts & FE_ALL_EXCEPT) as u32) as i32 & FE_ALL_EXCEPT
+}
+
+/// Test floating-point exception flags
+///
+/// Returns the subset of `excepts` whose flags are currently set.
+#[inline]
+pub fn fetestexcept(excepts: i32) -> i32 {
+    env::status() as i32 & excepts & FE_ALL_EXCEPT
+}
+
+/// Get the rounding mode
+///
+/// Returns the current rounding mode: `FE_TONEAREST`, `FE_DOWNWARD`,
+/// `FE_UPWARD` or `FE_TOWARDZERO`.
+#[inline]
+pub fn fegetround() -> i32 {
+    env::round()
+}
+
+/// Set the rounding mode
+///
+/// Sets the rounding mode to `r`, one of `FE_TONEAREST`, `FE_DOWNWARD`,
+/// `FE_UPWARD` and `FE_TOWARDZERO`. Returns 0 on success and a nonzero value
+/// if `r` is not a rounding mode supported on this target, in which case
+/// the mode is unchanged.
+///
+/// The compiler still assumes the default environment: it may fold constant
+/// expressions or move arithmetic across this call as if rounding to
+/// nearest, so values computed under another mode should come from
+/// `core::hint::black_box` or from outside the function.
+#[inline]
+pub fn fesetround(r: i32) -> i32 {
+    if r & !env::ROUND_MASK != 0 {
+        return -1;
+    }
+    env::set_round(r);
     0
 }
 
+/// Get the floating-point environment
+///
+/// Returns the current rounding mode, control bits and exception flags.
 #[inline]
-pub(crate) fn fegetround() -> i32 {
-    FE_TONEAREST
+pub fn fegetenv() -> FeEnv {
+    FeEnv {
+        control: env::control(),
+        status: env::status(),
+    }
 }
 
+/// Set the floating-point environment
+///
+/// Restores an environment returned by `fegetenv`, or `FE_DFL_ENV`. Returns 0.
 #[inline]
-pub(crate) fn fesetround(_r: i32) -> i32 {
+pub fn fesetenv(env: &FeEnv) -> i32 {
+    env::set_control(env.control);
+    env::set_status(env.status);
     0
 }
+
+/* Returns the NaN result of an invalid operation on `x`, raising invalid
+ * unless `x` is itself a NaN */
+#[inline]
+pub(crate) fn invalid(x: f64) -> f64 {
+    if !x.is_nan() {
+        feraiseexcept(FE_INVALID);
+    }
+    (x - x) / 0.0
+}
+
+#[inline]
+pub(crate) fn invalidf(x: f32) -> f32 {
+    if !x.is_nan() {
+        feraiseexcept(FE_INVALID);
+    }
+    (x - x) / 0.0
+}
+
+/* Returns the overflowed result `r`, raising overflow and inexact */
+#[inline]
+pub(crate) fn overflow<T>(r: T) -> T {
+    feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    r
+}
+
+/* Returns the underflowed result `r`, raising underflow and inexact */
+#[inline]
+pub(crate) fn underflow<T>(r: T) -> T {
+    feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+    r
+}
+
+/* Raises underflow and inexact if the inexact result `r` is tiny, that is
+ * subnormal or zero */
+#[inline]
+pub(crate) fn underflow_if_tiny(r: f64) -> f64 {
+    if (r.to_bits() >> 52 & 0x7ff) == 0 {
+        feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+    }
+    r
+}
+
+#[inline]
+pub(crate) fn underflow_if_tinyf(r: f32) -> f32 {
+    if (r.to_bits() >> 23 & 0xff) == 0 {
+        feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+    }
+    r
+}
+
+/* Rounds `x` to f32, raising the exceptions of that rounding: inexact, and
+ * overflow or underflow when the result is out of range */
+#[inline]
+pub(crate) fn narrow(x: f64) -> f32 {
+    let r = x as f32;
+    if r as f64 != x && !x.is_nan() {
+        if r.is_infinite() {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        } else if fabs(x) < f32::MIN_POSITIVE as f64 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        } else {
+            feraiseexcept(FE_INEXACT);
+        }
+    }
+    r
+}
+
+/* Raises the exceptions of returning a nonzero `x` as the rounded value of a
+ * function that is `x` to first order: inexact, and underflow if `x` is
+ * subnormal */
+#[inline]
+pub(crate) fn inexact_tiny(x: f64) {
+    if x != 0.0 {
+        underflow_if_tiny(x);
+        feraiseexcept(FE_INEXACT);
+    }
+}
+
+#[inline]
+pub(crate) fn inexact_tinyf(x: f32) {
+    if x != 0.0 {
+        underflow_if_tinyf(x);
+        feraiseexcept(FE_INEXACT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::hint::black_box;
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn rounding_modes() {
+        let saved = fegetenv();
+        let tiny = f32::from_bits(0x30800000); /* 2^-30 */
+
+        assert_eq!(fesetround(FE_UPWARD), 0);
+        assert_eq!(fegetround(), FE_UPWARD);
+        assert_eq!(crate::rint(black_box(2.5)), 3.0);
+        assert_eq!(crate::rint(black_box(-2.5)), -2.0);
+        assert_eq!(crate::sqrt(black_box(2.0)).to_bits(), 0x3ff6a09e667f3bcd);
+        assert_eq!(crate::fmaf(black_box(1.0), 1.0, tiny), 1.0 + f32::EPSILON);
+
+        assert_eq!(fesetround(FE_DOWNWARD), 0);
+        assert_eq!(fegetround(), FE_DOWNWARD);
+        assert_eq!(crate::rint(black_box(2.5)), 2.0);
+        assert_eq!(crate::rint(black_box(-2.5)), -3.0);
+        assert_eq!(crate::sqrt(black_box(2.0)).to_bits(), 0x3ff6a09e667f3bcc);
+        assert_eq!(crate::fmaf(black_box(1.0), 1.0, tiny), 1.0);
+        assert_eq!(
+            crate::fmaf(black_box(-1.0), 1.0, -tiny),
+            -1.0 - f32::EPSILON
+        );
+
+        assert_eq!(fesetround(FE_TOWARDZERO), 0);
+        assert_eq!(fegetround(), FE_TOWARDZERO);
+        assert_eq!(crate::rint(black_box(-2.5)), -2.0);
+        assert_eq!(crate::sqrt(black_box(2.0)).to_bits(), 0x3ff6a09e667f3bcc);
+        assert_eq!(crate::fmaf(black_box(-1.0), 1.0, -tiny), -1.0);
+
+        assert_ne!(fesetround(FE_TOWARDZERO + 1), 0);
+        assert_eq!(fegetround(), FE_TOWARDZERO);
+
+        fesetenv(&saved);
+        assert_eq!(fegetround(), FE_TONEAREST);
+        assert_eq!(crate::sqrt(black_box(2.0)).to_bits(), 0x3ff6a09e667f3bcd);
+    }
+
+    #[test]
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn rounding_modes() {
+        assert_eq!(fesetround(FE_TONEAREST), 0);
+        assert_ne!(fesetround(FE_UPWARD), 0);
+        assert_eq!(fegetround(), FE_TONEAREST);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn exception_flags() {
+        let saved = fegetenv();
+
+        fesetenv(&FE_DFL_ENV);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), 0);
+        assert_eq!(black_box(1.0f64) / 0.0, f64::INFINITY);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), FE_DIVBYZERO);
+
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        assert_eq!(
+            fetestexcept(FE_ALL_EXCEPT),
+            FE_DIVBYZERO | FE_OVERFLOW | FE_INEXACT
+        );
+        let env = fegetenv();
+        feclearexcept(FE_DIVBYZERO | FE_OVERFLOW);
+        assert_eq!(fetestexcept(FE_ALL_EXCEPT), FE_INEXACT);
+        fesetenv(&env);
+        assert_eq!(
+            fetestexcept(FE_ALL_EXCEPT),
+            FE_DIVBYZERO | FE_OVERFLOW | FE_INEXACT
+        );
+
+        fesetenv(&saved);
+    }
+}
@@ -0,0 +1,73 @@
+use super::fenv::{feraiseexcept, narrow, FE_INVALID};
+use super::fma::{mul_add, normalize, ZEROINFNAN};
+use super::{fmul, scalbn};
+
+/// Fused multiply-add narrowed to f32
+///
+/// Returns `x * y + z` rounded once to `f32`, which neither `fma(x, y, z) as f32`
+/// nor any other combination of `f64` operations does.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ffma(x: f64, y: f64, z: f64) -> f32 {
+    let nx = normalize(x);
+    let ny = normalize(y);
+    let nz = normalize(z);
+
+    if nx.e >= ZEROINFNAN || ny.e >= ZEROINFNAN {
+        /* x*y is exact */
+        let r = x * y + z;
+        if r.is_nan() && !x.is_nan() && !y.is_nan() && !z.is_nan() {
+            /* 0 * inf or inf - inf */
+            feraiseexcept(FE_INVALID);
+        }
+        return narrow(r);
+    }
+    if nz.e >= ZEROINFNAN {
+        if nz.e > ZEROINFNAN {
+            /* z==0 */
+            return fmul(x, y);
+        }
+        return narrow(z);
+    }
+
+    let (m, e, sign) = match mul_add(&nx, &ny, &nz) {
+        Some(r) => r,
+        None => {
+            /* exact +-0 */
+            return narrow(x * y + z);
+        }
+    };
+    /* round to odd at 53 bits, so that the conversion to f32 rounds once */
+    let i = m >> 10 | (m & 0x3ff != 0) as u64;
+    let r = if sign != 0 { -(i as f64) } else { i as f64 };
+    narrow(scalbn(r, e + 10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fma;
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn double_rounding() {
+        /* 1 + 2^-24 + 2^-80 rounds to 1 + 2^-24 as a f64, a tie for f32 */
+        let x = 1.0 + f64::from_bits(0x3e70000000000000);
+        let z = f64::from_bits(0x3af0000000000000);
+        assert_eq!(fma(x, 1.0, z) as f32, 1.0);
+        assert_eq!(ffma(x, 1.0, z), 1.0 + f32::EPSILON);
+        assert_eq!(ffma(-x, 1.0, -z), -1.0 - f32::EPSILON);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(ffma(NAN, 1.0, 1.0).is_nan());
+        assert!(ffma(INFINITY, 0.0, 1.0).is_nan());
+        assert!(ffma(INFINITY, 1.0, NEG_INFINITY).is_nan());
+        assert_eq!(ffma(1.0, 1.0, INFINITY), f32::INFINITY);
+        assert_eq!(ffma(1e300, 1e300, -INFINITY), f32::NEG_INFINITY);
+        assert_eq!(ffma(1.0, -1.0, 1.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(ffma(-0.0, 1.0, -0.0).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(ffma(1e-300, 1e-300, -0.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(ffma(1e300, 1e300, -1e300), f32::INFINITY);
+    }
+}
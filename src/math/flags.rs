@@ -0,0 +1,185 @@
+use super::fenv::{
+    feclearexcept, feraiseexcept, fetch_clear_except, fetch_raise_except, fetestexcept,
+    FE_ALL_EXCEPT, FE_DIVBYZERO, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_UNDERFLOW,
+};
+use core::fmt;
+use core::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// Floating-point exception flags
+///
+/// A set of the five IEEE 754 exceptions, as recorded by the functions of this
+/// crate when they detect one. Whether `INEXACT` is raised for a result that
+/// had to be rounded is unspecified, as in C, except for the functions that
+/// document it, such as `rint` and `fma`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Flags(u8);
+
+impl Flags {
+    /// An operation had no defined result, such as `sqrt(-1)` (`FE_INVALID`)
+    pub const INVALID: Flags = Flags(FE_INVALID as u8);
+    /// An exact infinite result was produced from finite arguments, such as
+    /// `log(0)` (`FE_DIVBYZERO`)
+    pub const DIVBYZERO: Flags = Flags(FE_DIVBYZERO as u8);
+    /// A finite result was too large to be represented (`FE_OVERFLOW`)
+    pub const OVERFLOW: Flags = Flags(FE_OVERFLOW as u8);
+    /// A nonzero result was too small to be represented as a normal number
+    /// and was rounded (`FE_UNDERFLOW`)
+    pub const UNDERFLOW: Flags = Flags(FE_UNDERFLOW as u8);
+    /// A result had to be rounded (`FE_INEXACT`)
+    pub const INEXACT: Flags = Flags(FE_INEXACT as u8);
+
+    /// The empty set
+    pub const fn empty() -> Flags {
+        Flags(0)
+    }
+
+    /// The set of all five flags (`FE_ALL_EXCEPT`)
+    pub const fn all() -> Flags {
+        Flags(FE_ALL_EXCEPT as u8)
+    }
+
+    /// Returns `true` if no flag is set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if every flag of `other` is also set in `self`.
+    pub fn contains(self, other: Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any flag of `other` is also set in `self`.
+    pub fn intersects(self, other: Flags) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Flags;
+
+    fn bitor(self, rhs: Flags) -> Flags {
+        Flags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Flags {
+    fn bitor_assign(&mut self, rhs: Flags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Flags {
+    type Output = Flags;
+
+    fn bitand(self, rhs: Flags) -> Flags {
+        Flags(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = [
+            (Flags::INVALID, "INVALID"),
+            (Flags::DIVBYZERO, "DIVBYZERO"),
+            (Flags::OVERFLOW, "OVERFLOW"),
+            (Flags::UNDERFLOW, "UNDERFLOW"),
+            (Flags::INEXACT, "INEXACT"),
+        ];
+        let mut first = true;
+        f.write_str("Flags(")?;
+        for &(flag, name) in names.iter() {
+            if self.contains(flag) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+/// Returns the exception flags that are currently set (`fetestexcept`)
+pub fn test_flags() -> Flags {
+    Flags(fetestexcept(FE_ALL_EXCEPT) as u8)
+}
+
+/// Clears the given exception flags (`feclearexcept`)
+pub fn clear_flags(flags: Flags) {
+    feclearexcept(flags.0 as i32);
+}
+
+/// Sets the given exception flags (`feraiseexcept`)
+pub fn raise_flags(flags: Flags) {
+    feraiseexcept(flags.0 as i32);
+}
+
+/// Runs `f` and returns its result together with the exception flags it
+/// raised
+///
+/// The flags that were set before the call are hidden from `f` and restored
+/// afterwards, with the ones `f` raised added to them, as `feholdexcept`
+/// followed by `feupdateenv` do in C.
+///
+/// On x86_64 and aarch64 the flags are those of the current thread, and also
+/// include the ones raised by the hardware for the arithmetic done in `f`.
+/// Elsewhere they are kept in a single status shared by the whole program,
+/// so the result also includes flags raised meanwhile by other threads, and
+/// calls running concurrently in several threads see each other's flags.
+/// The saved flags are taken and restored with single atomic updates, so no
+/// flag is lost.
+pub fn with_flags<T, F: FnOnce() -> T>(f: F) -> (T, Flags) {
+    let saved = fetch_clear_except(FE_ALL_EXCEPT);
+    let r = f();
+    let raised = fetch_raise_except(saved);
+    (r, Flags(raised as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let f = Flags::OVERFLOW | Flags::INEXACT;
+        assert!(f.contains(Flags::OVERFLOW));
+        assert!(!f.contains(Flags::OVERFLOW | Flags::INVALID));
+        assert!(f.intersects(Flags::OVERFLOW | Flags::INVALID));
+        assert_eq!(f & Flags::INEXACT, Flags::INEXACT);
+        assert!(Flags::empty().is_empty());
+        assert!(Flags::all().contains(f | Flags::INVALID | Flags::DIVBYZERO));
+    }
+
+    /* a single test, since elsewhere than on x86_64 and aarch64 the flags are
+     * shared by the threads that run the tests */
+    #[test]
+    fn with_flags_records_and_restores() {
+        let (r, f) = with_flags(|| crate::log(0.0));
+        assert_eq!(r, f64::NEG_INFINITY);
+        assert!(f.contains(Flags::DIVBYZERO));
+
+        let (r, f) = with_flags(|| crate::sqrt(-1.0));
+        assert!(r.is_nan());
+        assert!(f.contains(Flags::INVALID));
+
+        let (_, f) = with_flags(|| crate::exp(1000.0));
+        assert!(f.contains(Flags::OVERFLOW | Flags::INEXACT));
+
+        let (_, f) = with_flags(|| crate::exp(-1000.0));
+        assert!(f.contains(Flags::UNDERFLOW | Flags::INEXACT));
+
+        let (_, f) = with_flags(|| crate::rint(2.5));
+        assert!(f.contains(Flags::INEXACT));
+
+        let (_, f) = with_flags(|| crate::fmaf(1e-20, 1e-20, 1e-45));
+        assert!(f.contains(Flags::UNDERFLOW | Flags::INEXACT));
+
+        let ((_, inner), outer) = with_flags(|| {
+            raise_flags(Flags::INVALID);
+            with_flags(|| crate::log(0.0))
+        });
+        assert!(inner.contains(Flags::DIVBYZERO));
+        assert!(outer.contains(Flags::INVALID | Flags::DIVBYZERO));
+    }
+}
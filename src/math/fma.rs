@@ -1,16 +1,17 @@
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_INVALID, FE_UNDERFLOW};
 use core::{f32, f64};
 
 use super::scalbn;
 
-const ZEROINFNAN: i32 = 0x7ff - 0x3ff - 52 - 1;
+pub(crate) const ZEROINFNAN: i32 = 0x7ff - 0x3ff - 52 - 1;
 
-struct Num {
-    m: u64,
-    e: i32,
-    sign: i32,
+pub(crate) struct Num {
+    pub(crate) m: u64,
+    pub(crate) e: i32,
+    pub(crate) sign: i32,
 }
 
-fn normalize(x: f64) -> Num {
+pub(crate) fn normalize(x: f64) -> Num {
     let x1p63: f64 = f64::from_bits(0x43e0000000000000); // 0x1p63 === 2 ^ 63
 
     let mut ix: u64 = x.to_bits();
@@ -46,32 +47,12 @@ fn mul(x: u64, y: u64) -> (u64, u64) {
     (hi, lo)
 }
 
-/// Floating multiply add (f64)
-///
-/// Computes `(x*y)+z`, rounded as one ternary operation:
-/// Computes the value (as if) to infinite precision and rounds once to the result format,
-/// according to the rounding mode characterized by the value of FLT_ROUNDS.
-#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
-pub fn fma(x: f64, y: f64, z: f64) -> f64 {
-    let x1p63: f64 = f64::from_bits(0x43e0000000000000); // 0x1p63 === 2 ^ 63
-    let x0_ffffff8p_63 = f64::from_bits(0x3bfffffff0000000); // 0x0.ffffff8p-63
-
-    /* normalize so top 10bits and last bit are 0 */
-    let nx = normalize(x);
-    let ny = normalize(y);
-    let nz = normalize(z);
-
-    if nx.e >= ZEROINFNAN || ny.e >= ZEROINFNAN {
-        return x * y + z;
-    }
-    if nz.e >= ZEROINFNAN {
-        if nz.e > ZEROINFNAN {
-            /* z==0 */
-            return x * y + z;
-        }
-        return z;
-    }
-
+/// Computes `x*y+z` exactly for finite nonzero `x`, `y` and `z` given in
+/// normalized form, returning `(m, e, sign)` such that the result is
+/// `m * 2^e` with the sign bit `sign`, where `m` has its top bit at bit 62 and
+/// a sticky last bit. Returns `None` if the result is exactly zero.
+#[inline]
+pub(crate) fn mul_add(nx: &Num, ny: &Num, nz: &Num) -> Option<(u64, i32, i32)> {
     /* mul: r = x*y */
     let zhi: u64;
     let zlo: u64;
@@ -147,10 +128,54 @@ pub fn fma(x: f64, y: f64, z: f64) -> f64 {
             rhi = rlo << d;
         }
     } else {
-        /* exact +-0 */
-        return x * y + z;
+        return None;
     }
     e -= d;
+    Some((rhi, e, sign))
+}
+
+/// Floating multiply add (f64)
+///
+/// Computes `(x*y)+z`, rounded as one ternary operation:
+/// Computes the value (as if) to infinite precision and rounds once to the result format,
+/// according to the rounding mode characterized by the value of FLT_ROUNDS.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fma(x: f64, y: f64, z: f64) -> f64 {
+    let x1p63: f64 = f64::from_bits(0x43e0000000000000); // 0x1p63 === 2 ^ 63
+    let x0_ffffff8p_63 = f64::from_bits(0x3bfffffff0000000); // 0x0.ffffff8p-63
+
+    /* normalize so top 10bits and last bit are 0 */
+    let nx = normalize(x);
+    let ny = normalize(y);
+    let mut nz = normalize(z);
+
+    if nx.e >= ZEROINFNAN || ny.e >= ZEROINFNAN {
+        let r = x * y + z;
+        if r.is_nan() && !x.is_nan() && !y.is_nan() && !z.is_nan() {
+            /* 0 * inf or inf - inf */
+            feraiseexcept(FE_INVALID);
+        }
+        return r;
+    }
+    if nz.e >= ZEROINFNAN {
+        if nz.e == ZEROINFNAN {
+            return z;
+        }
+        /* z==0: round x*y alone, so that its exceptions are raised */
+        nz = Num {
+            m: 0,
+            e: nx.e + ny.e,
+            sign: nx.sign ^ ny.sign,
+        };
+    }
+
+    let (rhi, e, sign) = match mul_add(&nx, &ny, &nz) {
+        Some(r) => r,
+        None => {
+            /* exact +-0 */
+            return x * y + z;
+        }
+    };
 
     /* convert to double */
     let mut i: i64 = rhi as i64; /* i is in [1<<62,(1<<63)-1] */
@@ -189,16 +214,20 @@ pub fn fma(x: f64, y: f64, z: f64) -> f64 {
                     let tiny: f64 = f64::MIN_POSITIVE / f32::MIN_POSITIVE as f64 * r;
                     r += (tiny * tiny) * (r - r);
                 }
+                feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             }
         } else {
             /* only round once when scaled */
-            d = 10;
+            let d = 10;
             i = ((rhi >> d | ((rhi << (64 - d)) != 0) as u64) << d) as i64;
             if sign != 0 {
                 i = -i;
             }
             r = i as f64;
         }
+    } else if rhi & 0x3ff != 0 {
+        /* the conversion to double drops nonzero bits */
+        feraiseexcept(FE_INEXACT);
     }
     scalbn(r, e)
 }
@@ -25,12 +25,8 @@
  * SUCH DAMAGE.
  */
 
-use core::f32;
-use core::ptr::read_volatile;
-
 use super::fenv::{
-    feclearexcept, fegetround, feraiseexcept, fesetround, fetestexcept, FE_INEXACT, FE_TONEAREST,
-    FE_TOWARDZERO, FE_UNDERFLOW,
+    fegetround, feraiseexcept, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_TONEAREST, FE_UNDERFLOW,
 };
 
 /*
@@ -47,60 +43,82 @@ use super::fenv::{
 /// Computes the value (as if) to infinite precision and rounds once to the result format,
 /// according to the rounding mode characterized by the value of FLT_ROUNDS.
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
-pub fn fmaf(x: f32, y: f32, mut z: f32) -> f32 {
-    let xy: f64;
-    let mut result: f64;
-    let mut ui: u64;
-    let e: i32;
+pub fn fmaf(x: f32, y: f32, z: f32) -> f32 {
+    let xy: f64 = x as f64 * y as f64; /* exact */
+    let mut result: f64 = xy + z as f64;
+    let mut ui: u64 = result.to_bits();
+    let e: i32 = (ui >> 52) as i32 & 0x7ff;
+
+    if e == 0x7ff {
+        if result.is_nan() && !x.is_nan() && !y.is_nan() && !z.is_nan() {
+            /* 0 * inf or inf - inf */
+            feraiseexcept(FE_INVALID);
+        }
+        return result as f32;
+    }
+
+    /* the rounding error of xy + z, exactly (TwoSum) */
+    let bv: f64 = result - xy;
+    let av: f64 = result - bv;
+    let err: f64 = (xy - av) + (z as f64 - bv);
 
-    xy = x as f64 * y as f64;
-    result = xy + z as f64;
-    ui = result.to_bits();
-    e = (ui >> 52) as i32 & 0x7ff;
-    /* Common case: The double precision result is fine. */
-    if (
-        /* not a halfway case */
-        ui & 0x1fffffff) != 0x10000000 ||
-        /* NaN */
-        e == 0x7ff ||
-        /* exact */
-        (result - xy == z as f64 && result - z as f64 == xy) ||
-        /* not round-to-nearest */
-        fegetround() != FE_TONEAREST
-    {
+    if err != 0.0 && (ui & 0x1fffffff) == 0x10000000 && fegetround() == FE_TONEAREST {
         /*
-            underflow may not be raised correctly, example:
-            fmaf(0x1p-120f, 0x1p-120f, 0x1p-149f)
-        */
-        if e < 0x3ff - 126 && e >= 0x3ff - 149 && fetestexcept(FE_INEXACT) != 0 {
-            feclearexcept(FE_INEXACT);
-            // prevent `xy + vz` from being CSE'd with `xy + z` above
-            let vz: f32 = unsafe { read_volatile(&z) };
-            result = xy + vz as f64;
-            if fetestexcept(FE_INEXACT) != 0 {
-                feraiseexcept(FE_UNDERFLOW);
-            } else {
-                feraiseexcept(FE_INEXACT);
-            }
+         * The result is inexact and exactly halfway between two float
+         * values: adjust the low-order bit in the direction of the error.
+         * Directed roundings need no adjustment, rounding twice in the
+         * same direction is the same as rounding once.
+         */
+        if (err > 0.0) == (result > 0.0) {
+            ui += 1;
+        } else {
+            ui -= 1;
+        }
+        result = f64::from_bits(ui);
+    }
+
+    let r: f32 = result as f32;
+    if err != 0.0 || r as f64 != result {
+        if r.is_infinite() {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        } else if e < 0x3ff - 126 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        } else {
+            feraiseexcept(FE_INEXACT);
         }
-        z = result as f32;
-        return z;
     }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halfway_cases() {
+        /* x*x = 1 + 2^-11 + 2^-24 is halfway between two floats */
+        let x = 1.0 + f32::from_bits(0x39800000);
+        let z = f32::from_bits(0x21800000); /* 2^-60 */
+        assert_eq!(fmaf(x, x, -z), 1.0 + f32::from_bits(0x3a000000));
+        assert_eq!(
+            fmaf(x, x, z),
+            1.0 + f32::from_bits(0x3a000000) + f32::EPSILON
+        );
+        assert_eq!(fmaf(-x, x, z), -1.0 - f32::from_bits(0x3a000000));
+    }
+    /* the exceptions are raised in software, without reading back the
+     * hardware flags or changing the rounding mode */
+    #[test]
+    fn exceptions() {
+        use super::super::{with_flags, Flags};
 
-    /*
-     * If result is inexact, and exactly halfway between two float values,
-     * we need to adjust the low-order bit in the direction of the error.
-     */
-    fesetround(FE_TOWARDZERO);
-    // prevent `vxy + z` from being CSE'd with `xy + z` above
-    let vxy: f64 = unsafe { read_volatile(&xy) };
-    let mut adjusted_result: f64 = vxy + z as f64;
-    fesetround(FE_TONEAREST);
-    if result == adjusted_result {
-        ui = adjusted_result.to_bits();
-        ui += 1;
-        adjusted_result = f64::from_bits(ui);
+        let (r, f) = with_flags(|| fmaf(2.0, 3.0, 1.0));
+        assert_eq!((r, f), (7.0, Flags::empty()));
+        let (_, f) = with_flags(|| fmaf(1e-20, 1e-20, 1e-45));
+        assert!(f.contains(Flags::UNDERFLOW | Flags::INEXACT));
+        let (_, f) = with_flags(|| fmaf(f32::MAX, 2.0, -1.0));
+        assert!(f.contains(Flags::OVERFLOW | Flags::INEXACT));
+        let (r, f) = with_flags(|| fmaf(0.0, f32::INFINITY, 1.0));
+        assert!(r.is_nan() && f.contains(Flags::INVALID));
     }
-    z = adjusted_result as f32;
-    z
 }
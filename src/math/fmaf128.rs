@@ -0,0 +1,186 @@
+use super::f128::{
+    invalid, is_zero, mul_wide, quiet_nan, round_pack, shr_sticky, signed_zero, split, U256,
+};
+use super::{Round, F128};
+
+/* x << s for s in [0, 128) */
+#[inline]
+fn shl(x: U256, s: u32) -> U256 {
+    if s == 0 {
+        return x;
+    }
+    (x.0 << s | x.1 >> (128 - s), x.1 << s)
+}
+
+#[inline]
+fn add(x: U256, y: U256) -> U256 {
+    let (lo, carry) = x.1.overflowing_add(y.1);
+    (x.0 + y.0 + carry as u128, lo)
+}
+
+/* x - y for x >= y */
+#[inline]
+fn sub(x: U256, y: U256) -> U256 {
+    let (lo, borrow) = x.1.overflowing_sub(y.1);
+    (x.0 - y.0 - borrow as u128, lo)
+}
+
+/// Floating multiply add (F128)
+///
+/// Computes `(x*y)+z`, rounded as one ternary operation: the exact value is
+/// computed in 256 bit integer arithmetic and rounded once.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf128(x: F128, y: F128, z: F128) -> F128 {
+    let round = Round::current();
+    let sign_p = x.is_sign_negative() ^ y.is_sign_negative();
+    let sign_z = z.is_sign_negative();
+
+    if x.is_nan() || y.is_nan() || z.is_nan() {
+        let xy = if x.is_nan() || y.is_nan() {
+            quiet_nan(x, y)
+        } else {
+            z
+        };
+        return quiet_nan(xy, z);
+    }
+    if x.is_infinite() || y.is_infinite() {
+        /* 0 * inf, or inf - inf */
+        if is_zero(x) || is_zero(y) || (z.is_infinite() && sign_z != sign_p) {
+            return invalid();
+        }
+        return if sign_p {
+            F128::NEG_INFINITY
+        } else {
+            F128::INFINITY
+        };
+    }
+    if z.is_infinite() {
+        return z;
+    }
+    if is_zero(x) || is_zero(y) {
+        if is_zero(z) {
+            /* the sum of zeros is -0 only if both are, or when rounding down */
+            return signed_zero(if sign_p == sign_z {
+                sign_p
+            } else {
+                round == Round::Down
+            });
+        }
+        return z;
+    }
+
+    /* the product, with its top bit at bit 224 or 225 */
+    let (mx, ex) = split(x);
+    let (my, ey) = split(y);
+    let mut p = mul_wide(mx, my);
+    let mut e = ex + ey;
+    let mut sign = sign_p;
+
+    if !is_zero(z) {
+        /* z with its top bit at bit 225 */
+        let (mz, ez) = split(z);
+        let mut q: U256 = (mz >> 15, mz << 113);
+        let ez = ez - 113;
+
+        /* align to the smaller exponent by shifting the other operand left,
+         * by at most 29 bits so that it stays below 2^255, and shift what
+         * remains to the right with a sticky bit; that only happens when the
+         * operands are so far apart that the sum cannot cancel */
+        let d = ez - e;
+        if d > 0 {
+            let s = if d < 29 { d } else { 29 };
+            q = shl(q, s as u32);
+            p = shr_sticky(p, (d - s) as u32);
+            e = ez - s;
+        } else if d < 0 {
+            let s = if -d < 29 { -d } else { 29 };
+            p = shl(p, s as u32);
+            q = shr_sticky(q, (-d - s) as u32);
+            e -= s;
+        }
+
+        if sign_p == sign_z {
+            p = add(p, q);
+        } else if p >= q {
+            p = sub(p, q);
+        } else {
+            p = sub(q, p);
+            sign = sign_z;
+        }
+        if p == (0, 0) {
+            /* an exact zero is +0, or -0 when rounding down */
+            return signed_zero(round == Round::Down);
+        }
+    }
+
+    /* keep the top 127 bits and a sticky bit */
+    let bits = if p.0 != 0 {
+        256 - p.0.leading_zeros()
+    } else {
+        128 - p.1.leading_zeros()
+    };
+    let m = if bits > 127 {
+        e += (bits - 127) as i32;
+        shr_sticky(p, bits - 127).1
+    } else {
+        p.1
+    };
+    round_pack(m, e, sign, round)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn exact() {
+        let two = f(0x40000000000000000000000000000000);
+        let three = f(0x40008000000000000000000000000000);
+        let seven = f(0x4001c000000000000000000000000000);
+        assert_eq!(fmaf128(two, three, F128::ONE), seven);
+        assert_eq!(fmaf128(two, three, -seven), -F128::ONE);
+        /* (1 + 2^-112)^2 - (1 + 2^-111) = 2^-224 */
+        let x = f(F128::ONE.to_bits() + 1);
+        let y = f(F128::ONE.to_bits() + 2);
+        assert_eq!(
+            fmaf128(x, x, -y).to_bits(),
+            0x3f1f0000000000000000000000000000
+        );
+    }
+
+    #[test]
+    fn rounded() {
+        /* (1 + 2^-112)^2 = 1 + 2^-111 + 2^-224 */
+        let x = f(F128::ONE.to_bits() + 1);
+        assert_eq!(fmaf128(x, x, F128::ZERO).to_bits(), F128::ONE.to_bits() + 2);
+        /* 1 + 2^-113 is a tie, which the product 2^-225 breaks */
+        let half = f(0x3ffe0000000000000000000000000000);
+        assert_eq!(fmaf128(F128::EPSILON, half, F128::ONE), F128::ONE);
+        assert_eq!(
+            fmaf128(F128::EPSILON, f(half.to_bits() + 1), F128::ONE).to_bits(),
+            F128::ONE.to_bits() + 1
+        );
+        assert_eq!(fmaf128(F128::MAX, F128::MAX, -F128::MAX), F128::INFINITY);
+        /* halves of the smallest subnormals */
+        assert_eq!(fmaf128(f(1), half, F128::ZERO).to_bits(), 0);
+        assert_eq!(fmaf128(f(3), half, F128::ZERO).to_bits(), 2);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(fmaf128(F128::INFINITY, F128::ZERO, F128::ONE).is_nan());
+        assert!(fmaf128(F128::INFINITY, F128::ONE, F128::NEG_INFINITY).is_nan());
+        assert_eq!(
+            fmaf128(F128::ONE, F128::ONE, F128::INFINITY),
+            F128::INFINITY
+        );
+        let z = fmaf128(-F128::ZERO, F128::ONE, -F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        let z = fmaf128(F128::ONE, F128::ONE, -F128::ONE);
+        assert!(z == F128::ZERO && !z.is_sign_negative());
+    }
+}
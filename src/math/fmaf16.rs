@@ -0,0 +1,119 @@
+use super::round_ops::round_bits;
+use super::{Round, F16};
+
+/* Splits the finite nonzero x into (m, e) with |x| = m * 2^e and m odd */
+#[inline]
+fn split_odd(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let e = (bits >> 52 & 0x7ff) as i32;
+    let m = bits & ((1 << 52) - 1) | 1 << 52;
+    /* the product of binary16 values is never subnormal in f64 */
+    let tz = m.trailing_zeros();
+    (m >> tz, e - 1075 + tz as i32)
+}
+
+/// Floating multiply add (F16)
+///
+/// Computes `(x*y)+z`, rounded as one ternary operation:
+/// Computes the value (as if) to infinite precision and rounds once to the result format,
+/// according to the rounding mode characterized by the value of FLT_ROUNDS.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf16(x: F16, y: F16, z: F16) -> F16 {
+    /* the product of two binary16 values is exact in f64 */
+    let p = f64::from(x) * f64::from(y);
+    let c = f64::from(z);
+    if !p.is_finite() || !c.is_finite() || p == 0.0 || c == 0.0 {
+        /* special cases, and sums with a zero, which f64 does exactly */
+        return F16::from_f64(p + c);
+    }
+
+    /* the exact sum, which spans at most 80 bits */
+    let (mp, ep) = split_odd(p);
+    let (mc, ec) = split_odd(c);
+    let e = ep.min(ec);
+    let tp = ((mp as i128) << (ep - e)) * if p < 0.0 { -1 } else { 1 };
+    let tc = ((mc as i128) << (ec - e)) * if c < 0.0 { -1 } else { 1 };
+    let s = tp + tc;
+    if s == 0 {
+        /* an exact zero sum is -0 only when rounding down */
+        return if Round::current() == Round::Down {
+            -F16::ZERO
+        } else {
+            F16::ZERO
+        };
+    }
+
+    /* keep 63 bits of the magnitude and a sticky bit for the rest */
+    let mut m = s.unsigned_abs();
+    let mut e = e;
+    let lz = m.leading_zeros();
+    if lz < 65 {
+        let shift = 65 - lz;
+        m = m >> shift | (m & ((1 << shift) - 1) != 0) as u128;
+        e += shift as i32;
+    }
+    F16::from_bits(round_bits(m as u64, e, s < 0, Round::current(), 11, 15) as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{copysignf16, fabsf128, fmaf128, F128};
+    use super::*;
+
+    fn f(bits: u16) -> F16 {
+        F16::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(
+            fmaf16(F16::from_f32(2.0), F16::from_f32(3.0), F16::ONE),
+            F16::from_f32(7.0)
+        );
+        /* a cancellation, where x*x rounded first would lose bits */
+        assert_eq!(fmaf16(f(0x3555), f(0x3555), f(0xb000)).to_bits(), 0xa324);
+        /* a tie of the product, which the tiny term decides */
+        assert_eq!(fmaf16(f(0x3e00), f(0x3c01), f(0x0001)).to_bits(), 0x3e02);
+        assert_eq!(fmaf16(f(0x3e00), f(0x3c01), f(0x8001)).to_bits(), 0x3e01);
+        /* the smallest subnormal below the largest value */
+        assert_eq!(fmaf16(F16::MAX, F16::ONE, f(0x8001)), F16::MAX);
+        let z = fmaf16(F16::ONE, F16::ONE, -F16::ONE);
+        assert!(z == F16::ZERO && !z.is_sign_negative());
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(fmaf16(F16::INFINITY, F16::ZERO, F16::ONE).is_nan());
+        assert!(fmaf16(F16::INFINITY, F16::ONE, F16::NEG_INFINITY).is_nan());
+        assert_eq!(fmaf16(F16::MAX, F16::MAX, F16::ONE), F16::INFINITY);
+        assert_eq!(fmaf16(F16::ONE, F16::ONE, F16::INFINITY), F16::INFINITY);
+        assert!(fmaf16(F16::NAN, F16::ONE, F16::ONE).is_nan());
+    }
+
+    #[test]
+    fn random() {
+        /* the result is nearer to the exact binary128 fma than its neighbors,
+         * or as near and even */
+        let mut s: u64 = 0x9e3779b97f4a7c15;
+        for _ in 0..200000 {
+            s ^= s << 13;
+            s ^= s >> 7;
+            s ^= s << 17;
+            let (x, y, z) = (f(s as u16), f((s >> 16) as u16), f((s >> 32) as u16));
+            let to_f128 = |v: F16| F128::from(f64::from(v));
+            let exact = fmaf128(to_f128(x), to_f128(y), to_f128(z));
+            let r = fmaf16(x, y, z);
+            if !r.is_finite() || r == F16::ZERO {
+                continue;
+            }
+            let d = fabsf128(exact - to_f128(r));
+            let mag = r.to_bits() & 0x7fff;
+            for &n in [mag.wrapping_sub(1), mag + 1].iter() {
+                if n < 0x7c00 {
+                    let dn = fabsf128(exact - to_f128(copysignf16(f(n), r)));
+                    assert!(d < dn || (d == dn && mag & 1 == 0), "{:?}", (x, y, z));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use super::{fmaxf, F16};
+
+/// Maximum (F16)
+///
+/// Returns the larger of `x` and `y`. A NaN is ignored when the other
+/// argument is a number, as in `fmaxf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaxf16(x: F16, y: F16) -> F16 {
+    F16::from_f32(fmaxf(f32::from(x), f32::from(y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmaxf16(F16::ONE, -F16::ONE), F16::ONE);
+        assert_eq!(fmaxf16(-F16::ONE, F16::ONE), F16::ONE);
+        assert_eq!(fmaxf16(F16::NAN, F16::ONE), F16::ONE);
+        assert_eq!(fmaxf16(F16::ONE, F16::NAN), F16::ONE);
+        assert!(fmaxf16(F16::NAN, F16::NAN).is_nan());
+    }
+}
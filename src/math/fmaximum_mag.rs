@@ -0,0 +1,37 @@
+use super::{fabs, fmaximum};
+
+/// Maximum magnitude, propagating NaN (f64)
+///
+/// Returns whichever of `x` and `y` has the larger absolute value, or
+/// `fmaximum(x, y)` if they have the same absolute value. If either argument
+/// is NaN, the result is NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_mag(x: f64, y: f64) -> f64 {
+    // IEEE754-2019 says: maximumMagnitude(x, y) is x if |x| > |y|, y if |y| > |x|, and
+    // maximum(x, y) otherwise.
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        fmaximum(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_mag;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmaximum_mag(-3.0, 2.0), -3.0);
+        assert_eq!(fmaximum_mag(1.0, -2.0), -2.0);
+        assert_eq!(fmaximum_mag(2.0, -2.0), 2.0);
+        assert_eq!(fmaximum_mag(-0.0, 0.0).to_bits(), 0.0_f64.to_bits());
+        assert!(fmaximum_mag(NAN, 1.0).is_nan());
+        assert!(fmaximum_mag(INFINITY, NAN).is_nan());
+    }
+}
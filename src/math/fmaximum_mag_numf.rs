@@ -0,0 +1,40 @@
+use super::{fabsf, fmaximum_numf};
+
+/// Maximum magnitude number (f32)
+///
+/// Returns whichever of `x` and `y` has the larger absolute value, or
+/// `fmaximum_numf(x, y)` if they have the same absolute value. A NaN argument
+/// is ignored if the other is a number.
+/// A signaling NaN argument raises the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_mag_numf(x: f32, y: f32) -> f32 {
+    // IEEE754-2019 says: maximumMagnitudeNumber(x, y) is x if |x| > |y|, y if |y| > |x|, and
+    // maximumNumber(x, y) otherwise.
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        fmaximum_numf(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_mag_numf;
+
+    #[test]
+    fn signaling_nan() {
+        use super::super::{with_flags, Flags};
+
+        let snan = f32::from_bits(0x7f800001);
+        let (r, f) = with_flags(|| fmaximum_mag_numf(snan, 1.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (r, f) = with_flags(|| fmaximum_mag_numf(1.0, snan));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (_, f) = with_flags(|| fmaximum_mag_numf(f32::NAN, 1.0));
+        assert!(!f.contains(Flags::INVALID));
+    }
+}
@@ -0,0 +1,21 @@
+use super::{fabsf, fmaximumf};
+
+/// Maximum magnitude, propagating NaN (f32)
+///
+/// Returns whichever of `x` and `y` has the larger absolute value, or
+/// `fmaximumf(x, y)` if they have the same absolute value. If either argument
+/// is NaN, the result is NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_magf(x: f32, y: f32) -> f32 {
+    // IEEE754-2019 says: maximumMagnitude(x, y) is x if |x| > |y|, y if |y| > |x|, and
+    // maximum(x, y) otherwise.
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+    if ax > ay {
+        x
+    } else if ay > ax {
+        y
+    } else {
+        fmaximumf(x, y)
+    }
+}
@@ -0,0 +1,52 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::issignaling;
+
+/// Maximum number (f64)
+///
+/// Returns the larger of `x` and `y`, ignoring a NaN argument if the other is
+/// a number. `-0.0` is treated as less than `+0.0`.
+/// A signaling NaN argument raises the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_num(x: f64, y: f64) -> f64 {
+    // IEEE754-2019 says: maximumNumber(x, y) is x if x > y, y if y > x, and the number if one
+    // operand is a number and the other a NaN, even a signaling one, which raises invalid. -0 is
+    // considered less than +0.
+    if issignaling(x) || issignaling(y) {
+        feraiseexcept(FE_INVALID);
+    }
+    if x.is_nan() && y.is_nan() {
+        x + y
+    } else if y.is_nan() || x > y || (x == y && y.is_sign_negative()) {
+        x
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_num;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmaximum_num(1.0, 2.0), 2.0);
+        assert_eq!(fmaximum_num(NAN, 1.0), 1.0);
+        assert_eq!(fmaximum_num(1.0, NAN), 1.0);
+        assert_eq!(fmaximum_num(-0.0, 0.0).to_bits(), 0.0_f64.to_bits());
+        assert!(fmaximum_num(NAN, NAN).is_nan());
+    }
+
+    #[test]
+    fn signaling_nan() {
+        use super::super::{with_flags, Flags};
+
+        let snan = f64::from_bits(0x7ff0000000000001);
+        let (r, f) = with_flags(|| fmaximum_num(snan, 1.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (r, f) = with_flags(|| fmaximum_num(1.0, snan));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (_, f) = with_flags(|| fmaximum_num(f64::NAN, 1.0));
+        assert!(!f.contains(Flags::INVALID));
+    }
+}
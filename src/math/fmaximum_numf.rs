@@ -0,0 +1,42 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::issignalingf;
+
+/// Maximum number (f32)
+///
+/// Returns the larger of `x` and `y`, ignoring a NaN argument if the other is
+/// a number. `-0.0` is treated as less than `+0.0`.
+/// A signaling NaN argument raises the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaximum_numf(x: f32, y: f32) -> f32 {
+    // IEEE754-2019 says: maximumNumber(x, y) is x if x > y, y if y > x, and the number if one
+    // operand is a number and the other a NaN, even a signaling one, which raises invalid. -0 is
+    // considered less than +0.
+    if issignalingf(x) || issignalingf(y) {
+        feraiseexcept(FE_INVALID);
+    }
+    if x.is_nan() && y.is_nan() {
+        x + y
+    } else if y.is_nan() || x > y || (x == y && y.is_sign_negative()) {
+        x
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fmaximum_numf;
+
+    #[test]
+    fn signaling_nan() {
+        use super::super::{with_flags, Flags};
+
+        let snan = f32::from_bits(0x7f800001);
+        let (r, f) = with_flags(|| fmaximum_numf(snan, 1.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (r, f) = with_flags(|| fmaximum_numf(1.0, snan));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (_, f) = with_flags(|| fmaximum_numf(f32::NAN, 1.0));
+        assert!(!f.contains(Flags::INVALID));
+    }
+}
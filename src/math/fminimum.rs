@@ -0,0 +1,32 @@
+/// Minimum, propagating NaN (f64)
+///
+/// Returns the smaller of `x` and `y`. If either argument is NaN, the result is
+/// NaN, and `-0.0` is treated as less than `+0.0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum(x: f64, y: f64) -> f64 {
+    // IEEE754-2019 says: minimum(x, y) is x if x < y, y if y < x, and a quiet NaN if either
+    // operand is a NaN. Unlike minNum, -0 is considered less than +0.
+    if x.is_nan() || y.is_nan() {
+        x + y
+    } else if x < y || (x == y && x.is_sign_negative()) {
+        x
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fminimum(1.0, 2.0), 1.0);
+        assert_eq!(fminimum(NEG_INFINITY, 2.0), NEG_INFINITY);
+        assert_eq!(fminimum(-0.0, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum(0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert!(fminimum(NAN, 1.0).is_nan());
+        assert!(fminimum(1.0, NAN).is_nan());
+    }
+}
@@ -0,0 +1,21 @@
+use super::{fabs, fminimum};
+
+/// Minimum magnitude, propagating NaN (f64)
+///
+/// Returns whichever of `x` and `y` has the smaller absolute value, or
+/// `fminimum(x, y)` if they have the same absolute value. If either argument
+/// is NaN, the result is NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_mag(x: f64, y: f64) -> f64 {
+    // IEEE754-2019 says: minimumMagnitude(x, y) is x if |x| < |y|, y if |y| < |x|, and
+    // minimum(x, y) otherwise.
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax < ay {
+        x
+    } else if ay < ax {
+        y
+    } else {
+        fminimum(x, y)
+    }
+}
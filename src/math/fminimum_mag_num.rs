@@ -0,0 +1,51 @@
+use super::{fabs, fminimum_num};
+
+/// Minimum magnitude number (f64)
+///
+/// Returns whichever of `x` and `y` has the smaller absolute value, or
+/// `fminimum_num(x, y)` if they have the same absolute value. A NaN argument
+/// is ignored if the other is a number.
+/// A signaling NaN argument raises the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_mag_num(x: f64, y: f64) -> f64 {
+    // IEEE754-2019 says: minimumMagnitudeNumber(x, y) is x if |x| < |y|, y if |y| < |x|, and
+    // minimumNumber(x, y) otherwise.
+    let ax = fabs(x);
+    let ay = fabs(y);
+    if ax < ay {
+        x
+    } else if ay < ax {
+        y
+    } else {
+        fminimum_num(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum_mag_num;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fminimum_mag_num(-1.0, 2.0), -1.0);
+        assert_eq!(fminimum_mag_num(3.0, -2.0), -2.0);
+        assert_eq!(fminimum_mag_num(2.0, -2.0), -2.0);
+        assert_eq!(fminimum_mag_num(0.0, -0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(fminimum_mag_num(NAN, -3.0), -3.0);
+        assert!(fminimum_mag_num(NAN, NAN).is_nan());
+    }
+
+    #[test]
+    fn signaling_nan() {
+        use super::super::{with_flags, Flags};
+
+        let snan = f64::from_bits(0x7ff0000000000001);
+        let (r, f) = with_flags(|| fminimum_mag_num(snan, 1.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (r, f) = with_flags(|| fminimum_mag_num(1.0, snan));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (_, f) = with_flags(|| fminimum_mag_num(f64::NAN, 1.0));
+        assert!(!f.contains(Flags::INVALID));
+    }
+}
@@ -0,0 +1,21 @@
+use super::{fabsf, fminimumf};
+
+/// Minimum magnitude, propagating NaN (f32)
+///
+/// Returns whichever of `x` and `y` has the smaller absolute value, or
+/// `fminimumf(x, y)` if they have the same absolute value. If either argument
+/// is NaN, the result is NaN.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_magf(x: f32, y: f32) -> f32 {
+    // IEEE754-2019 says: minimumMagnitude(x, y) is x if |x| < |y|, y if |y| < |x|, and
+    // minimum(x, y) otherwise.
+    let ax = fabsf(x);
+    let ay = fabsf(y);
+    if ax < ay {
+        x
+    } else if ay < ax {
+        y
+    } else {
+        fminimumf(x, y)
+    }
+}
@@ -0,0 +1,42 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::issignalingf;
+
+/// Minimum number (f32)
+///
+/// Returns the smaller of `x` and `y`, ignoring a NaN argument if the other is
+/// a number. `-0.0` is treated as less than `+0.0`.
+/// A signaling NaN argument raises the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimum_numf(x: f32, y: f32) -> f32 {
+    // IEEE754-2019 says: minimumNumber(x, y) is x if x < y, y if y < x, and the number if one
+    // operand is a number and the other a NaN, even a signaling one, which raises invalid. -0 is
+    // considered less than +0.
+    if issignalingf(x) || issignalingf(y) {
+        feraiseexcept(FE_INVALID);
+    }
+    if x.is_nan() && y.is_nan() {
+        x + y
+    } else if y.is_nan() || x < y || (x == y && x.is_sign_negative()) {
+        x
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fminimum_numf;
+
+    #[test]
+    fn signaling_nan() {
+        use super::super::{with_flags, Flags};
+
+        let snan = f32::from_bits(0x7f800001);
+        let (r, f) = with_flags(|| fminimum_numf(snan, 1.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (r, f) = with_flags(|| fminimum_numf(1.0, snan));
+        assert_eq!((r, f.contains(Flags::INVALID)), (1.0, true));
+        let (_, f) = with_flags(|| fminimum_numf(f32::NAN, 1.0));
+        assert!(!f.contains(Flags::INVALID));
+    }
+}
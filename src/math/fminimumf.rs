@@ -0,0 +1,16 @@
+/// Minimum, propagating NaN (f32)
+///
+/// Returns the smaller of `x` and `y`. If either argument is NaN, the result is
+/// NaN, and `-0.0` is treated as less than `+0.0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fminimumf(x: f32, y: f32) -> f32 {
+    // IEEE754-2019 says: minimum(x, y) is x if x < y, y if y < x, and a quiet NaN if either
+    // operand is a NaN. Unlike minNum, -0 is considered less than +0.
+    if x.is_nan() || y.is_nan() {
+        x + y
+    } else if x < y || (x == y && x.is_sign_negative()) {
+        x
+    } else {
+        y
+    }
+}
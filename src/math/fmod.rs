@@ -1,3 +1,4 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
 use core::u64;
 
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
@@ -10,6 +11,9 @@ pub fn fmod(x: f64, y: f64) -> f64 {
     let mut i;
 
     if uyi << 1 == 0 || y.is_nan() || ex == 0x7ff {
+        if !x.is_nan() && !y.is_nan() {
+            feraiseexcept(FE_INVALID);
+        }
         return (x * y) / (x * y);
     }
     if uxi << 1 <= uyi << 1 {
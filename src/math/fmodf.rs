@@ -1,3 +1,4 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
 use core::f32;
 use core::u32;
 
@@ -11,6 +12,9 @@ pub fn fmodf(x: f32, y: f32) -> f32 {
     let mut i;
 
     if uyi << 1 == 0 || y.is_nan() || ex == 0xff {
+        if !x.is_nan() && !y.is_nan() {
+            feraiseexcept(FE_INVALID);
+        }
         return (x * y) / (x * y);
     }
 
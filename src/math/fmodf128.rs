@@ -0,0 +1,117 @@
+use super::f128::{exponent, invalid, is_zero, quiet_nan, EXP_MAX, SIGN_MASK, SIG_BITS, SIG_MASK};
+use super::F128;
+
+/// Floating-point remainder (F128)
+///
+/// Returns `x - n*y`, where `n` is `x/y` rounded toward zero. The result is
+/// exact and has the sign of `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmodf128(x: F128, y: F128) -> F128 {
+    let mut uxi = x.to_bits();
+    let mut uyi = y.to_bits();
+    let mut ex = exponent(x);
+    let mut ey = exponent(y);
+    let sx = uxi & SIGN_MASK;
+    let mut i;
+
+    if x.is_nan() || y.is_nan() {
+        return quiet_nan(x, y);
+    }
+    if is_zero(y) || ex == EXP_MAX {
+        return invalid();
+    }
+    if uxi << 1 <= uyi << 1 {
+        if uxi << 1 == uyi << 1 {
+            return F128::from_bits(sx);
+        }
+        return x;
+    }
+
+    /* normalize x and y */
+    if ex == 0 {
+        i = uxi << 16;
+        while i >> 127 == 0 {
+            ex -= 1;
+            i <<= 1;
+        }
+        uxi <<= -ex + 1;
+    } else {
+        uxi &= SIG_MASK;
+        uxi |= 1 << SIG_BITS;
+    }
+    if ey == 0 {
+        i = uyi << 16;
+        while i >> 127 == 0 {
+            ey -= 1;
+            i <<= 1;
+        }
+        uyi <<= -ey + 1;
+    } else {
+        uyi &= SIG_MASK;
+        uyi |= 1 << SIG_BITS;
+    }
+
+    /* x mod y */
+    while ex > ey {
+        i = uxi.wrapping_sub(uyi);
+        if i >> 127 == 0 {
+            if i == 0 {
+                return F128::from_bits(sx);
+            }
+            uxi = i;
+        }
+        uxi <<= 1;
+        ex -= 1;
+    }
+    i = uxi.wrapping_sub(uyi);
+    if i >> 127 == 0 {
+        if i == 0 {
+            return F128::from_bits(sx);
+        }
+        uxi = i;
+    }
+    while uxi >> SIG_BITS == 0 {
+        uxi <<= 1;
+        ex -= 1;
+    }
+
+    /* scale result */
+    if ex > 0 {
+        uxi -= 1 << SIG_BITS;
+        uxi |= (ex as u128) << SIG_BITS;
+    } else {
+        uxi >>= -ex + 1;
+    }
+    F128::from_bits(uxi | sx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        let two = f(0x40000000000000000000000000000000);
+        let seven = f(0x4001c000000000000000000000000000);
+        assert_eq!(fmodf128(seven, two), F128::ONE);
+        assert_eq!(fmodf128(-seven, two), -F128::ONE);
+        let z = fmodf128(-two, F128::ONE);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        /* every finite value is a multiple of the smallest subnormal */
+        assert_eq!(fmodf128(F128::MAX, f(1)), F128::ZERO);
+        assert_eq!(fmodf128(f(3), f(2)), f(1));
+        assert_eq!(fmodf128(F128::MIN_POSITIVE, f(3)).to_bits(), 1);
+        assert_eq!(fmodf128(f(5), F128::INFINITY), f(5));
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(fmodf128(F128::ONE, F128::ZERO).is_nan());
+        assert!(fmodf128(F128::INFINITY, F128::ONE).is_nan());
+        assert!(fmodf128(F128::NAN, F128::ONE).is_nan());
+    }
+}
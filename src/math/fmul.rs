@@ -0,0 +1,47 @@
+use super::fenv::{feraiseexcept, narrow, FE_INVALID};
+use super::{fma, frexp, round_odd, scalbn};
+
+/// Multiplication narrowed to f32
+///
+/// Returns `x * y` rounded once to `f32`, which `(x * y) as f32` does not do.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmul(x: f64, y: f64) -> f32 {
+    if x == 0.0 || y == 0.0 || !x.is_finite() || !y.is_finite() {
+        let p = x * y;
+        if p.is_nan() && !x.is_nan() && !y.is_nan() {
+            /* 0 * inf */
+            feraiseexcept(FE_INVALID);
+        }
+        return p as f32;
+    }
+    /* scale to [1/2, 1) so that the fma residual cannot underflow */
+    let (mx, ex) = frexp(x);
+    let (my, ey) = frexp(y);
+    let p = mx * my;
+    let err = fma(mx, my, -p);
+    narrow(scalbn(round_odd(p, err), ex + ey))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn double_rounding() {
+        /* (1 + 2^-24 + 2^-52) * (1 - 2^-53) rounds to 1 + 2^-24 as a f64 */
+        let x = f64::from_bits(0x3ff0000010000001);
+        let y = 1.0 - f64::EPSILON / 2.0;
+        assert_eq!((x * y) as f32, 1.0);
+        assert_eq!(fmul(x, y), 1.0 + f32::EPSILON);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fmul(3.0, 0.5), 1.5);
+        assert_eq!(fmul(1e-300, 1e-300).to_bits(), 0.0f32.to_bits());
+        assert_eq!(fmul(-1e-300, 1e300), -1.0);
+        assert_eq!(fmul(1e300, 1e300), f32::INFINITY);
+        assert_eq!(fmul(f64::MAX, 0.5), f32::INFINITY);
+        assert_eq!(fmul(5e-324, f64::from_bits(0x79c0000000000000)), 1e-45);
+    }
+}
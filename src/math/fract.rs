@@ -0,0 +1,25 @@
+use super::trunc;
+
+/// Fractional part (f64)
+///
+/// Returns `x - trunc(x)`, the fractional part of `x` with the sign of `x`,
+/// like `f64::fract`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fract(x: f64) -> f64 {
+    x - trunc(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(fract(3.75), 0.75);
+        assert_eq!(fract(-3.75), -0.75);
+        assert_eq!(fract(1e300), 0.0);
+        assert!(fract(INFINITY).is_nan());
+        assert!(fract(NAN).is_nan());
+    }
+}
@@ -0,0 +1,10 @@
+use super::truncf;
+
+/// Fractional part (f32)
+///
+/// Returns `x - truncf(x)`, the fractional part of `x` with the sign of `x`,
+/// like `f32::fract`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fractf(x: f32) -> f32 {
+    x - truncf(x)
+}
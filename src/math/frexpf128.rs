@@ -0,0 +1,37 @@
+use super::f128::{exponent, is_zero, split, BIAS, EXP_MAX, SIGN_MASK, SIG_BITS, SIG_MASK};
+use super::F128;
+
+/// Breaks `x` into a normalized fraction and a power of two (F128)
+///
+/// Returns `(y, e)` with `x = y * 2^e` and `|y|` in `[0.5, 1)`. Zeros,
+/// infinities and NaNs are returned as they are, with an exponent of 0.
+pub fn frexpf128(x: F128) -> (F128, i32) {
+    if is_zero(x) || exponent(x) == EXP_MAX {
+        return (x, 0);
+    }
+    /* subnormals are normalized by split */
+    let (m, e) = split(x);
+    let y = x.to_bits() & SIGN_MASK | ((BIAS - 1) as u128) << SIG_BITS | m & SIG_MASK;
+    (F128::from_bits(y), e + SIG_BITS as i32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        let half = F128::from_bits(0x3ffe0000000000000000000000000000);
+        assert_eq!(frexpf128(F128::ONE), (half, 1));
+        assert_eq!(
+            frexpf128(-F128::MAX),
+            (-F128::from_bits(0x3ffeffffffffffffffffffffffffffff), 16384)
+        );
+        assert_eq!(frexpf128(F128::MIN_POSITIVE), (half, -16381));
+        assert_eq!(
+            frexpf128(F128::from_bits(3)),
+            (F128::from_bits(0x3ffe8000000000000000000000000000), -16492)
+        );
+        assert_eq!(frexpf128(F128::INFINITY), (F128::INFINITY, 0));
+    }
+}
@@ -0,0 +1,31 @@
+use super::{frexpf, F16};
+
+/// Breaks `x` into a normalized fraction and a power of two (F16)
+///
+/// Returns `(y, e)` with `x = y * 2^e` and `|y|` in `[0.5, 1)`. Zeros,
+/// infinities and NaNs are returned as they are, with an exponent of 0.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn frexpf16(x: F16) -> (F16, i32) {
+    if !x.is_finite() {
+        return (x, 0);
+    }
+    /* y is exact in binary16, as a fraction of x */
+    let (y, e) = frexpf(f32::from(x));
+    (F16::from_f32(y), e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        let half = F16::from_f32(0.5);
+        assert_eq!(frexpf16(F16::ONE), (half, 1));
+        assert_eq!(frexpf16(-F16::MAX), (F16::from_bits(0xbbff), 16));
+        assert_eq!(frexpf16(F16::MIN_POSITIVE), (half, -13));
+        assert_eq!(frexpf16(F16::from_bits(3)), (F16::from_f32(0.75), -22));
+        assert_eq!(frexpf16(F16::INFINITY), (F16::INFINITY, 0));
+        assert_eq!(frexpf16(F16::NAN).0.to_bits(), F16::NAN.to_bits());
+    }
+}
@@ -0,0 +1,137 @@
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_INVALID};
+use super::{ceil, copysign, floor, round, roundeven, scalbn, trunc, Round};
+use core::f64;
+
+/// Rounds `x` to an integral value in the direction `round`.
+#[inline]
+fn round_to_integral(x: f64, round_dir: Round) -> f64 {
+    match round_dir {
+        Round::Nearest => roundeven(x),
+        Round::NearestAway => round(x),
+        Round::TowardZero => trunc(x),
+        Round::Up => ceil(x),
+        Round::Down => floor(x),
+        Round::Odd => {
+            /* truncated, then away from zero to the odd neighbor if inexact */
+            let t = trunc(x);
+            if t != x && trunc(t * 0.5) * 2.0 == t {
+                t + copysign(1.0, x)
+            } else {
+                t
+            }
+        }
+    }
+}
+
+/// Shared implementation: rounds `x` and checks that the result lies in
+/// `[lo, hi)`, where the bounds are powers of two derived from `width`.
+#[inline]
+fn fromfp_inner(x: f64, round_dir: Round, width: u32, signed: bool, exact: bool) -> f64 {
+    if width == 0 || !x.is_finite() {
+        feraiseexcept(FE_INVALID);
+        return f64::NAN;
+    }
+
+    /* wider than any finite f64 needs: clamp to keep the exponent in range */
+    let bits = if width > 2048 { 2048 } else { width as i32 };
+    let r = round_to_integral(x, round_dir);
+    let (lo, hi) = if signed {
+        let p = scalbn(1.0, bits - 1);
+        (-p, p)
+    } else {
+        (0.0, scalbn(1.0, bits))
+    };
+    /* r is integral, so r < hi is the same as r <= hi - 1 */
+    if !(r >= lo && r < hi) {
+        feraiseexcept(FE_INVALID);
+        return f64::NAN;
+    }
+    if exact && r != x {
+        feraiseexcept(FE_INEXACT);
+    }
+    r
+}
+
+/// Round to signed integer of given width (f64)
+///
+/// Rounds `x` to an integral value in the direction `round`, and returns it
+/// if it is representable as a signed integer of `width` bits. Otherwise, or
+/// if `width` is zero or `x` is NaN or infinite, returns NaN and raises the
+/// invalid exception. The inexact exception is never raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfp(x: f64, round: Round, width: u32) -> f64 {
+    fromfp_inner(x, round, width, true, false)
+}
+
+/// Round to unsigned integer of given width (f64)
+///
+/// Like `fromfp`, but checks the rounded value against the range of an
+/// unsigned integer of `width` bits.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfp(x: f64, round: Round, width: u32) -> f64 {
+    fromfp_inner(x, round, width, false, false)
+}
+
+/// Round to signed integer of given width, raising inexact (f64)
+///
+/// Like `fromfp`, but raises the inexact exception if the result differs in
+/// value from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpx(x: f64, round: Round, width: u32) -> f64 {
+    fromfp_inner(x, round, width, true, true)
+}
+
+/// Round to unsigned integer of given width, raising inexact (f64)
+///
+/// Like `ufromfp`, but raises the inexact exception if the result differs in
+/// value from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpx(x: f64, round: Round, width: u32) -> f64 {
+    fromfp_inner(x, round, width, false, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn directions() {
+        assert_eq!(fromfp(2.5, Round::Nearest, 8), 2.0);
+        assert_eq!(fromfp(2.5, Round::NearestAway, 8), 3.0);
+        assert_eq!(fromfp(-2.5, Round::TowardZero, 8), -2.0);
+        assert_eq!(fromfp(-2.5, Round::Up, 8), -2.0);
+        assert_eq!(fromfp(-2.5, Round::Down, 8), -3.0);
+        assert_eq!(fromfpx(-2.5, Round::Down, 8), -3.0);
+        assert_eq!(fromfp(2.5, Round::Odd, 8), 3.0);
+        assert_eq!(fromfp(-4.25, Round::Odd, 8), -5.0);
+        assert_eq!(fromfp(-3.75, Round::Odd, 8), -3.0);
+        assert_eq!(fromfp(4.0, Round::Odd, 8), 4.0);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(fromfp(127.4, Round::Nearest, 8), 127.0);
+        assert!(fromfp(127.5, Round::Nearest, 8).is_nan());
+        assert_eq!(fromfp(-128.5, Round::Up, 8), -128.0);
+        assert!(fromfp(-128.5, Round::Down, 8).is_nan());
+        assert_eq!(ufromfp(255.0, Round::Nearest, 8), 255.0);
+        assert!(ufromfp(256.0, Round::Nearest, 8).is_nan());
+        assert_eq!(ufromfp(-0.5, Round::TowardZero, 8), 0.0);
+        assert!(ufromfpx(-0.5, Round::Down, 8).is_nan());
+        assert_eq!(
+            fromfp(-9223372036854775808.0, Round::Nearest, 64),
+            -9223372036854775808.0
+        );
+        assert!(fromfp(9223372036854775808.0, Round::Nearest, 64).is_nan());
+        assert_eq!(fromfp(MAX, Round::Nearest, u32::max_value()), MAX);
+        assert!(fromfp(0.0, Round::Nearest, 0).is_nan());
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(fromfp(NAN, Round::Nearest, 32).is_nan());
+        assert!(fromfp(INFINITY, Round::Nearest, 32).is_nan());
+        assert!(ufromfp(NEG_INFINITY, Round::Nearest, 32).is_nan());
+    }
+}
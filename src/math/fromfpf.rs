@@ -0,0 +1,91 @@
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_INVALID};
+use super::{ceilf, copysignf, floorf, roundevenf, roundf, scalbnf, truncf, Round};
+use core::f32;
+
+/// Rounds `x` to an integral value in the direction `round`.
+#[inline]
+fn round_to_integral(x: f32, round_dir: Round) -> f32 {
+    match round_dir {
+        Round::Nearest => roundevenf(x),
+        Round::NearestAway => roundf(x),
+        Round::TowardZero => truncf(x),
+        Round::Up => ceilf(x),
+        Round::Down => floorf(x),
+        Round::Odd => {
+            /* truncated, then away from zero to the odd neighbor if inexact */
+            let t = truncf(x);
+            if t != x && truncf(t * 0.5) * 2.0 == t {
+                t + copysignf(1.0, x)
+            } else {
+                t
+            }
+        }
+    }
+}
+
+/// Shared implementation: rounds `x` and checks that the result lies in
+/// `[lo, hi)`, where the bounds are powers of two derived from `width`.
+#[inline]
+fn fromfpf_inner(x: f32, round_dir: Round, width: u32, signed: bool, exact: bool) -> f32 {
+    if width == 0 || !x.is_finite() {
+        feraiseexcept(FE_INVALID);
+        return f32::NAN;
+    }
+
+    /* wider than any finite f32 needs: clamp to keep the exponent in range */
+    let bits = if width > 256 { 256 } else { width as i32 };
+    let r = round_to_integral(x, round_dir);
+    let (lo, hi) = if signed {
+        let p = scalbnf(1.0, bits - 1);
+        (-p, p)
+    } else {
+        (0.0, scalbnf(1.0, bits))
+    };
+    /* r is integral, so r < hi is the same as r <= hi - 1 */
+    if !(r >= lo && r < hi) {
+        feraiseexcept(FE_INVALID);
+        return f32::NAN;
+    }
+    if exact && r != x {
+        feraiseexcept(FE_INEXACT);
+    }
+    r
+}
+
+/// Round to signed integer of given width (f32)
+///
+/// Rounds `x` to an integral value in the direction `round`, and returns it
+/// if it is representable as a signed integer of `width` bits. Otherwise, or
+/// if `width` is zero or `x` is NaN or infinite, returns NaN and raises the
+/// invalid exception. The inexact exception is never raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpf(x: f32, round: Round, width: u32) -> f32 {
+    fromfpf_inner(x, round, width, true, false)
+}
+
+/// Round to unsigned integer of given width (f32)
+///
+/// Like `fromfpf`, but checks the rounded value against the range of an
+/// unsigned integer of `width` bits.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpf(x: f32, round: Round, width: u32) -> f32 {
+    fromfpf_inner(x, round, width, false, false)
+}
+
+/// Round to signed integer of given width, raising inexact (f32)
+///
+/// Like `fromfpf`, but raises the inexact exception if the result differs in
+/// value from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fromfpxf(x: f32, round: Round, width: u32) -> f32 {
+    fromfpf_inner(x, round, width, true, true)
+}
+
+/// Round to unsigned integer of given width, raising inexact (f32)
+///
+/// Like `ufromfpf`, but raises the inexact exception if the result differs in
+/// value from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ufromfpxf(x: f32, round: Round, width: u32) -> f32 {
+    fromfpf_inner(x, round, width, false, true)
+}
@@ -0,0 +1,22 @@
+use super::fenv::narrow;
+use super::{fma, frexp, round_odd, scalbn, sqrt};
+
+/// Square root narrowed to f32
+///
+/// Returns `sqrt(x)` rounded once to `f32`, which `sqrt(x) as f32` does not do.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fsqrt(x: f64) -> f32 {
+    if x <= 0.0 || !x.is_finite() {
+        /* +-0, negative, inf and nan */
+        return sqrt(x) as f32;
+    }
+    /* scale to [1/2, 2) by an even power of 2 */
+    let (mut m, mut e) = frexp(x);
+    if e & 1 == 1 {
+        m *= 2.0;
+        e -= 1;
+    }
+    let r = sqrt(m);
+    let err = fma(-r, r, m);
+    narrow(scalbn(round_odd(r, err), e >> 1))
+}
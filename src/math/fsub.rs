@@ -0,0 +1,9 @@
+use super::fadd;
+
+/// Subtraction narrowed to f32
+///
+/// Returns `x - y` rounded once to `f32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fsub(x: f64, y: f64) -> f32 {
+    fadd(x, -y)
+}
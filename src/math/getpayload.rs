@@ -0,0 +1,58 @@
+/// NaN payload (f64)
+///
+/// Returns the payload of the NaN `x` as an integer-valued `f64`, the quiet bit
+/// not being part of the payload. If `x` is not a NaN the result is -1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayload(x: f64) -> f64 {
+    if !x.is_nan() {
+        return -1.0;
+    }
+    (x.to_bits() & 0x0007ffffffffffff) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{setpayload, setpayloadsig};
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn getpayload_tests() {
+        assert_eq!(getpayload(NAN), 0.0);
+        assert_eq!(getpayload(f64::from_bits(0xfff8000000000042)), 66.0);
+        assert_eq!(getpayload(f64::from_bits(0x7ff0000000000042)), 66.0);
+        assert_eq!(
+            getpayload(f64::from_bits(0x7fffffffffffffff)),
+            2251799813685247.0
+        );
+        assert_eq!(getpayload(INFINITY), -1.0);
+        assert_eq!(getpayload(1.0), -1.0);
+    }
+
+    #[test]
+    fn setpayload_tests() {
+        assert_eq!(setpayload(0.0).map(f64::to_bits), Some(0x7ff8000000000000));
+        assert_eq!(setpayload(66.0).map(f64::to_bits), Some(0x7ff8000000000042));
+        assert_eq!(
+            setpayload(2251799813685247.0).map(f64::to_bits),
+            Some(0x7fffffffffffffff)
+        );
+        assert_eq!(setpayload(2251799813685248.0), None);
+        assert_eq!(setpayload(0.5), None);
+        assert_eq!(setpayload(-1.0), None);
+        assert_eq!(setpayload(-0.0), None);
+        assert_eq!(setpayload(INFINITY), None);
+        assert_eq!(setpayload(NAN), None);
+    }
+
+    #[test]
+    fn setpayloadsig_tests() {
+        assert_eq!(
+            setpayloadsig(66.0).map(f64::to_bits),
+            Some(0x7ff0000000000042)
+        );
+        assert_eq!(setpayloadsig(0.0), None);
+        assert_eq!(setpayloadsig(2251799813685248.0), None);
+        assert_eq!(setpayloadsig(1.5), None);
+    }
+}
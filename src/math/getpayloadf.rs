@@ -0,0 +1,11 @@
+/// NaN payload (f32)
+///
+/// Returns the payload of the NaN `x` as an integer-valued `f32`, the quiet bit
+/// not being part of the payload. If `x` is not a NaN the result is -1.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn getpayloadf(x: f32) -> f32 {
+    if !x.is_nan() {
+        return -1.0;
+    }
+    (x.to_bits() & 0x003fffff) as f32
+}
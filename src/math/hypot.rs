@@ -1,3 +1,4 @@
+use super::fenv::overflow;
 use core::f64;
 
 use super::sqrt;
@@ -70,5 +71,9 @@ pub fn hypot(mut x: f64, mut y: f64) -> f64 {
     }
     let (hx, lx) = sq(x);
     let (hy, ly) = sq(y);
-    z * sqrt(ly + lx + hy + hx)
+    z *= sqrt(ly + lx + hy + hx);
+    if z.is_infinite() {
+        return overflow(z);
+    }
+    z
 }
@@ -1,3 +1,4 @@
+use super::fenv::overflow;
 use core::f32;
 
 use super::sqrtf;
@@ -39,5 +40,9 @@ pub fn hypotf(mut x: f32, mut y: f32) -> f32 {
         x *= x1p90;
         y *= x1p90;
     }
-    z * sqrtf((x as f64 * x as f64 + y as f64 * y as f64) as f32)
+    z *= sqrtf((x as f64 * x as f64 + y as f64 * y as f64) as f32);
+    if z.is_infinite() {
+        return overflow(z);
+    }
+    z
 }
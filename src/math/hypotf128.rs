@@ -0,0 +1,169 @@
+/* The square root of x*x + y*y as a pair hi, lo, corrected; within 1 ulp */
+
+use super::f128::{dd_add, is_zero, quiet_nan, two_prod, SIGN_MASK};
+use super::{fmaf128, ilogbf128, scalbnf128, sqrtf128, F128};
+
+/// Euclidean distance (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn hypotf128(x: F128, y: F128) -> F128 {
+    let mut ax = F128::from_bits(x.to_bits() & !SIGN_MASK);
+    let mut ay = F128::from_bits(y.to_bits() & !SIGN_MASK);
+
+    /* special cases: inf wins over nan */
+    if ax.is_infinite() || ay.is_infinite() {
+        return F128::INFINITY;
+    }
+    if ax.is_nan() || ay.is_nan() {
+        return quiet_nan(x, y);
+    }
+    /* arrange |x| >= |y| */
+    if ax < ay {
+        core::mem::swap(&mut ax, &mut ay);
+    }
+    if is_zero(ay) {
+        return ax;
+    }
+    let e = ilogbf128(ax);
+    if e - ilogbf128(ay) > 115 {
+        /* y*y is below half an ulp of x*x */
+        return ax + ay;
+    }
+
+    /* scale |x| into [1,2), which keeps y exact and normal */
+    let ax = scalbnf128(ax, -e);
+    let ay = scalbnf128(ay, -e);
+
+    /* x*x + y*y as a pair hi, lo and a corrected square root of it */
+    let (hi, lo) = dd_add(two_prod(ax, ax), two_prod(ay, ay));
+    let h = sqrtf128(hi);
+    let r = fmaf128(-h, h, hi) + lo;
+    let z = h + r / (F128::from(2) * h);
+    scalbnf128(z, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference2;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(hypotf128(F128::from(3), F128::from(-4)), F128::from(5));
+        /* hypot(1, 1) = sqrt(2) */
+        assert_eq!(
+            hypotf128(F128::ONE, F128::ONE).to_bits(),
+            0x3fff6a09e667f3bcc908b2fb1366ea95
+        );
+        /* without overflow, underflow or loss of the small argument */
+        assert_eq!(hypotf128(F128::MAX, F128::ONE), F128::MAX);
+        assert_eq!(hypotf128(f(3), f(4)), f(5));
+        assert_eq!(
+            hypotf128(
+                f(0x7ff00000000000000000000000000000),
+                f(0x7ff00000000000000000000000000000)
+            )
+            .to_bits(),
+            0x7ff06a09e667f3bcc908b2fb1366ea95
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(hypotf128(F128::NAN, F128::NEG_INFINITY), F128::INFINITY);
+        assert!(hypotf128(F128::NAN, F128::ONE).is_nan());
+        assert_eq!(hypotf128(F128::ZERO, -F128::ZERO), F128::ZERO);
+        assert_eq!(hypotf128(F128::MAX, F128::MAX), F128::INFINITY);
+    }
+
+    const REFERENCE: [(u128, u128, u128); 16] = [
+        (
+            0x3ff8b4f5887af2a2b8ebc8f4fc0cefc7,
+            0xc005af7acd221e170f2790c94c2b3feb,
+            0x4005af7acd596e598e2d8e2262fbccce,
+        ),
+        (
+            0x3ffd30115e8b281c2c1ea408c967b94c,
+            0xc00839d0e2d0b7f44aedfe4d36f06b9b,
+            0x400839d0e51df6b033d5689a3214237d,
+        ),
+        (
+            0x40008b172578effef2c47a8b0bb421a1,
+            0x3ff78d2237829f21e99551192410725f,
+            0x40008b17575eec9a0b4c1d33f1eb12a1,
+        ),
+        (
+            0x40035470337a22c70532892247488742,
+            0xbffb308783b49ac1b17fe2ea35a563b4,
+            0x40035470bbae42484bba4459cd01432c,
+        ),
+        (
+            0x40049cc868e93fffd09b33e70108f240,
+            0xbff9bb5f61b07643eb71a08a7178e66f,
+            0x40049cc86ca1b555c8136f066126d8e3,
+        ),
+        (
+            0x4005fb42a41f304291eeadb87dd75ea2,
+            0x3ff7351dcedcfd6e1361fc7863fbdf14,
+            0x4005fb42a4251339ba47bc517f83d519,
+        ),
+        (
+            0x4008add82b44df4845f40c78d82084f6,
+            0x40027306c18d65cf82575dbcda66ecc4,
+            0x4008ade22d34ea4fd073881535daf3f0,
+        ),
+        (
+            0xbff6696b40d732423a4f28d7dc9e4ca6,
+            0x40029821fb0bab4ceb06c2f3d14aef1a,
+            0x40029821fbabb1f1e37fb11889c817a9,
+        ),
+        (
+            0xbff6a75ed7f07f972cf13a3eace29aa8,
+            0x3ffd658625b2d73614b45927619abf18,
+            0x3ffd658a105d8b280e7b4493b53fa3ac,
+        ),
+        (
+            0xbff8e9e3c3766cb1ca3fb5550cad8c28,
+            0x3ff5a978213bf48ac690662e22d3e1e3,
+            0x3ff8ecc4a38f4305802684b43d3fda02,
+        ),
+        (
+            0xbffcb96d4ffed9813f8f2d7d79036472,
+            0x3ff6fa59e2b154c6f2a382f05867c7ef,
+            0x3ffcb97f763724631fefa6cf7ef418a1,
+        ),
+        (
+            0xc00037d8eddbd08e26f069fc59bb84af,
+            0xc005bfefca018196a1f215acebed2715,
+            0x4005c00aec820c7b029d20165e536579,
+        ),
+        (
+            0xc00298cbbffa326fc4862a63ebd2bdcb,
+            0xbff98da2c5807064d20bf48f3d13e984,
+            0x400298cbf05328ccb7e4c0d62d0856f5,
+        ),
+        (
+            0xc003fd2996ae2787016e29975eea7a08,
+            0x4006febe9f1673a540039c86c5c41602,
+            0x40070158f0c9b8647fe45995a4c14b8a,
+        ),
+        (
+            0xc007fe5495c66ca1ec1addf05092e838,
+            0xc005b28da9d1846f1613d9654f20dca7,
+            0x400804e201915d579644709df85fedff,
+        ),
+        (
+            0xc0090d2cfd45bfff36adf9ff14af2b40,
+            0xbff6c9dfb1e731dc0dde397a800d08bf,
+            0x40090d2cfd45c614eb9733704ade5ded,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference2(hypotf128, &REFERENCE, 1);
+    }
+}
@@ -1,3 +1,5 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+
 const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
 const FP_ILOGB0: i32 = FP_ILOGBNAN;
 
@@ -8,7 +10,7 @@ pub fn ilogb(x: f64) -> i32 {
     if e == 0 {
         i <<= 12;
         if i == 0 {
-            force_eval!(0.0 / 0.0);
+            feraiseexcept(FE_INVALID);
             return FP_ILOGB0;
         }
         /* subnormal x */
@@ -19,7 +21,7 @@ pub fn ilogb(x: f64) -> i32 {
         }
         e
     } else if e == 0x7ff {
-        force_eval!(0.0 / 0.0);
+        feraiseexcept(FE_INVALID);
         if (i << 12) != 0 {
             FP_ILOGBNAN
         } else {
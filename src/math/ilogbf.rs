@@ -1,3 +1,5 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+
 const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
 const FP_ILOGB0: i32 = FP_ILOGBNAN;
 
@@ -8,7 +10,7 @@ pub fn ilogbf(x: f32) -> i32 {
     if e == 0 {
         i <<= 9;
         if i == 0 {
-            force_eval!(0.0 / 0.0);
+            feraiseexcept(FE_INVALID);
             return FP_ILOGB0;
         }
         /* subnormal x */
@@ -19,7 +21,7 @@ pub fn ilogbf(x: f32) -> i32 {
         }
         e
     } else if e == 0xff {
-        force_eval!(0.0 / 0.0);
+        feraiseexcept(FE_INVALID);
         if (i << 9) != 0 {
             FP_ILOGBNAN
         } else {
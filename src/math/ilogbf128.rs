@@ -0,0 +1,39 @@
+use super::f128::{exponent, is_zero, split, EXP_MAX, SIG_BITS, SIG_MASK};
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::F128;
+
+const FP_ILOGBNAN: i32 = -1 - 0x7fffffff;
+const FP_ILOGB0: i32 = FP_ILOGBNAN;
+
+/// Returns the unbiased exponent of `x` as an integer (F128)
+pub fn ilogbf128(x: F128) -> i32 {
+    if is_zero(x) {
+        feraiseexcept(FE_INVALID);
+        FP_ILOGB0
+    } else if exponent(x) == EXP_MAX {
+        feraiseexcept(FE_INVALID);
+        if x.to_bits() & SIG_MASK != 0 {
+            FP_ILOGBNAN
+        } else {
+            i32::MAX
+        }
+    } else {
+        split(x).1 + SIG_BITS as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(ilogbf128(F128::ONE), 0);
+        assert_eq!(ilogbf128(-F128::MAX), 16383);
+        assert_eq!(ilogbf128(F128::MIN_POSITIVE), -16382);
+        assert_eq!(ilogbf128(F128::from_bits(1)), -16494);
+        assert_eq!(ilogbf128(F128::ZERO), FP_ILOGB0);
+        assert_eq!(ilogbf128(F128::NAN), FP_ILOGBNAN);
+        assert_eq!(ilogbf128(F128::INFINITY), i32::MAX);
+    }
+}
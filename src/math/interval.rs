@@ -0,0 +1,521 @@
+use super::{
+    add_round, atan, ceil, cos, div_round, exp, floor, fmax, fmin, log, mul_round, nextdown,
+    nextup, pow, sin, sqrt_round, sub_round, Round,
+};
+use core::f64;
+use core::f64::consts::TAU;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A closed interval of real numbers, with floating-point bounds
+///
+/// An `Interval` stands for every real number between its bounds, which may be
+/// infinite. The arithmetic operators round the bounds of their result
+/// outward, and the elementary functions widen the bounds they get from the
+/// functions of this crate by the error of those, so that every result
+/// contains the exact value of the operation at every point of its operands.
+///
+/// The empty interval, the result of a function entirely outside of its
+/// domain, has NaN bounds.
+///
+/// Intervals of `f64` and of `f32` have the same methods, so the constructors
+/// need the type spelled out, as in `Interval::<f64>::new(1.0, 2.0)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Interval<T> {
+    pub(crate) lo: T,
+    pub(crate) hi: T,
+}
+
+/* The exact value of 2*pi lies between TAU, which is below it, and the next
+ * double */
+const TWO_PI: Interval<f64> = Interval {
+    lo: TAU,
+    hi: TAU + 4.0 * f64::EPSILON,
+};
+
+/* The functions used for the bounds below are accurate to within one ulp, so
+ * the exact value is within two steps of their result, even when it lies in
+ * the binade below. */
+fn down(r: f64) -> f64 {
+    nextdown(nextdown(r))
+}
+
+fn up(r: f64) -> f64 {
+    nextup(nextup(r))
+}
+
+/* Tells whether [lo, hi] may contain a point 2*pi*(n + phase) for an integer n.
+ * The answer errs on the side of yes. */
+pub(crate) fn may_reach(lo: f64, hi: f64, phase: f64) -> bool {
+    let turns = Interval::<f64>::new(lo, hi) / TWO_PI;
+    let a = sub_round(turns.lo, phase, Round::Down);
+    let b = sub_round(turns.hi, phase, Round::Up);
+    ceil(a) <= floor(b)
+}
+
+/* A bound of the product, where 0 * inf is 0 since 0 stands for itself */
+fn mul_bound(x: f64, y: f64, round: Round) -> f64 {
+    if x == 0.0 || y == 0.0 {
+        0.0
+    } else {
+        mul_round(x, y, round)
+    }
+}
+
+/* The interval spanned by op over the corners of x and y. A NaN at a corner
+ * is an inf/inf whose neighbours bound it, so it is left out. */
+fn corners(x: Interval<f64>, y: Interval<f64>, op: fn(f64, f64, Round) -> f64) -> Interval<f64> {
+    let lo = fmin(
+        fmin(op(x.lo, y.lo, Round::Down), op(x.lo, y.hi, Round::Down)),
+        fmin(op(x.hi, y.lo, Round::Down), op(x.hi, y.hi, Round::Down)),
+    );
+    let hi = fmax(
+        fmax(op(x.lo, y.lo, Round::Up), op(x.lo, y.hi, Round::Up)),
+        fmax(op(x.hi, y.lo, Round::Up), op(x.hi, y.hi, Round::Up)),
+    );
+    Interval::<f64>::new(lo, hi)
+}
+
+impl Interval<f64> {
+    /// The empty interval
+    pub const EMPTY: Interval<f64> = Interval {
+        lo: f64::NAN,
+        hi: f64::NAN,
+    };
+
+    /// The interval of all real numbers
+    pub const ENTIRE: Interval<f64> = Interval {
+        lo: f64::NEG_INFINITY,
+        hi: f64::INFINITY,
+    };
+
+    /// Returns the interval `[lo, hi]`, or the empty interval if `lo > hi`, if
+    /// either bound is NaN, or if the interval contains no finite number.
+    pub fn new(lo: f64, hi: f64) -> Interval<f64> {
+        if lo <= hi && lo != f64::INFINITY && hi != f64::NEG_INFINITY {
+            Interval { lo, hi }
+        } else {
+            Interval::<f64>::EMPTY
+        }
+    }
+
+    /// Returns the interval `[x, x]`.
+    pub fn point(x: f64) -> Interval<f64> {
+        Interval::<f64>::new(x, x)
+    }
+
+    /// The lower bound, NaN for the empty interval
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// The upper bound, NaN for the empty interval
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Returns `true` for the empty interval.
+    pub fn is_empty(self) -> bool {
+        self.lo.is_nan()
+    }
+
+    /// Returns `true` if `x` lies in the interval.
+    pub fn contains(self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// The smallest interval containing both `self` and `other`
+    pub fn hull(self, other: Interval<f64>) -> Interval<f64> {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            self
+        } else {
+            Interval::<f64>::new(fmin(self.lo, other.lo), fmax(self.hi, other.hi))
+        }
+    }
+
+    /// Encloses `e^x` over the interval.
+    pub fn exp(self) -> Interval<f64> {
+        if self.is_empty() {
+            return self;
+        }
+        Interval::<f64>::new(fmax(down(exp(self.lo)), 0.0), up(exp(self.hi)))
+    }
+
+    /// Encloses the natural logarithm over the nonnegative part of the
+    /// interval.
+    pub fn log(self) -> Interval<f64> {
+        if self.is_empty() || self.hi < 0.0 {
+            return Interval::<f64>::EMPTY;
+        }
+        Interval::<f64>::new(down(log(fmax(self.lo, 0.0))), up(log(self.hi)))
+    }
+
+    /// Encloses the square root over the nonnegative part of the interval.
+    pub fn sqrt(self) -> Interval<f64> {
+        if self.is_empty() || self.hi < 0.0 {
+            return Interval::<f64>::EMPTY;
+        }
+        Interval::<f64>::new(
+            sqrt_round(fmax(self.lo, 0.0), Round::Down),
+            sqrt_round(self.hi, Round::Up),
+        )
+    }
+
+    /// Encloses the sine over the interval.
+    pub fn sin(self) -> Interval<f64> {
+        self.periodic(sin, 0.25, 0.75)
+    }
+
+    /// Encloses the cosine over the interval.
+    pub fn cos(self) -> Interval<f64> {
+        self.periodic(cos, 0.0, 0.5)
+    }
+
+    /* f is sin or cos, which reach 1 at 2*pi*(n + max) and -1 at
+     * 2*pi*(n + min), and are monotonic in between. */
+    fn periodic(self, f: fn(f64) -> f64, max: f64, min: f64) -> Interval<f64> {
+        if self.is_empty() {
+            return self;
+        }
+        if self.lo.is_infinite() || self.hi.is_infinite() {
+            return Interval::<f64>::new(-1.0, 1.0);
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut lo = fmax(down(fmin(a, b)), -1.0);
+        let mut hi = fmin(up(fmax(a, b)), 1.0);
+        if may_reach(self.lo, self.hi, max) {
+            hi = 1.0;
+        }
+        if may_reach(self.lo, self.hi, min) {
+            lo = -1.0;
+        }
+        Interval::<f64>::new(lo, hi)
+    }
+
+    /// Encloses the arc tangent over the interval.
+    pub fn atan(self) -> Interval<f64> {
+        if self.is_empty() {
+            return self;
+        }
+        Interval::<f64>::new(down(atan(self.lo)), up(atan(self.hi)))
+    }
+
+    /// Encloses `x^y` for `x` in the nonnegative part of `self` and `y` in
+    /// `y`, taking `0^0` as 1.
+    pub fn pow(self, y: Interval<f64>) -> Interval<f64> {
+        if self.is_empty() || self.hi < 0.0 || y.is_empty() {
+            return Interval::<f64>::EMPTY;
+        }
+        /* y*log(x) is monotonic in each argument, so the extrema are at the
+         * corners */
+        let x = Interval::<f64>::new(fmax(self.lo, 0.0), self.hi);
+        let r = corners(x, y, |x, y, round| match round {
+            Round::Down => down(pow(x, y)),
+            _ => up(pow(x, y)),
+        });
+        Interval::<f64>::new(fmax(r.lo, 0.0), r.hi)
+    }
+}
+
+impl Neg for Interval<f64> {
+    type Output = Interval<f64>;
+
+    fn neg(self) -> Interval<f64> {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval<f64> {
+    type Output = Interval<f64>;
+
+    fn add(self, rhs: Interval<f64>) -> Interval<f64> {
+        Interval::<f64>::new(
+            add_round(self.lo, rhs.lo, Round::Down),
+            add_round(self.hi, rhs.hi, Round::Up),
+        )
+    }
+}
+
+impl Sub for Interval<f64> {
+    type Output = Interval<f64>;
+
+    fn sub(self, rhs: Interval<f64>) -> Interval<f64> {
+        Interval::<f64>::new(
+            sub_round(self.lo, rhs.hi, Round::Down),
+            sub_round(self.hi, rhs.lo, Round::Up),
+        )
+    }
+}
+
+impl Mul for Interval<f64> {
+    type Output = Interval<f64>;
+
+    fn mul(self, rhs: Interval<f64>) -> Interval<f64> {
+        if self.is_empty() || rhs.is_empty() {
+            return Interval::<f64>::EMPTY;
+        }
+        corners(self, rhs, mul_bound)
+    }
+}
+
+impl Div for Interval<f64> {
+    type Output = Interval<f64>;
+
+    /// Divides by the interval. Dividing by an interval containing zero gives
+    /// the entire line, or the empty interval for `[0, 0]`.
+    fn div(self, rhs: Interval<f64>) -> Interval<f64> {
+        if self.is_empty() || rhs.is_empty() || (rhs.lo == 0.0 && rhs.hi == 0.0) {
+            return Interval::<f64>::EMPTY;
+        }
+        if rhs.contains(0.0) {
+            return Interval::<f64>::ENTIRE;
+        }
+        corners(self, rhs, div_round)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::*;
+
+    fn check(r: Interval<f64>, x: f64) {
+        assert!(r.contains(x), "{:?} does not contain {:e}", r, x);
+    }
+
+    /* Points spread over [lo, hi], including both ends */
+    fn points(lo: f64, hi: f64) -> impl Iterator<Item = f64> {
+        (0..=64).map(move |i| lo + (hi - lo) * (i as f64 / 64.0))
+    }
+
+    #[test]
+    fn arithmetic() {
+        let third = Interval::<f64>::point(1.0) / Interval::<f64>::point(3.0);
+        assert_eq!(third.hi(), nextup(third.lo()));
+        check(third * Interval::<f64>::point(3.0), 1.0);
+
+        let x = Interval::<f64>::new(-2.0, 3.0);
+        let y = Interval::<f64>::new(0.5, 4.0);
+        let p = x * y;
+        assert_eq!((p.lo(), p.hi()), (-8.0, 12.0));
+        let q = x / y;
+        assert_eq!((q.lo(), q.hi()), (-4.0, 6.0));
+        let s = x - y;
+        assert_eq!((s.lo(), s.hi()), (-6.0, 2.5));
+        assert!((y / x).lo() == f64::NEG_INFINITY);
+        assert!((x / Interval::<f64>::point(0.0)).is_empty());
+
+        let z = Interval::<f64>::point(0.0) * Interval::<f64>::ENTIRE;
+        assert_eq!((z.lo(), z.hi()), (0.0, 0.0));
+        let big = Interval::<f64>::new(1.0, f64::INFINITY);
+        let r = big / big;
+        assert_eq!((r.lo(), r.hi()), (0.0, f64::INFINITY));
+
+        let sum = Interval::<f64>::point(0.1) + Interval::<f64>::point(0.2);
+        assert_eq!(sum.hi(), nextup(sum.lo()));
+        check(sum, 0.1 + 0.2);
+    }
+
+    #[test]
+    fn functions() {
+        check(Interval::<f64>::point(1.0).exp(), E);
+        check(Interval::<f64>::point(2.0).log(), LN_2);
+        check(Interval::<f64>::new(-1.0, 2.0).log(), f64::NEG_INFINITY);
+        assert!(Interval::<f64>::new(-2.0, -1.0).log().is_empty());
+        check(Interval::<f64>::point(2.0).sqrt(), SQRT_2);
+        check(Interval::<f64>::point(1.0).atan(), FRAC_PI_4);
+        check(
+            Interval::<f64>::point(2.0).pow(Interval::<f64>::point(0.5)),
+            SQRT_2,
+        );
+
+        let s = Interval::<f64>::new(1.0, 2.0).sin();
+        assert_eq!(s.hi(), 1.0);
+        assert!(s.lo() < sin(1.0) && s.lo() > 0.8);
+        let c = Interval::<f64>::new(3.0, 3.5).cos();
+        assert_eq!(c.lo(), -1.0);
+        let c = Interval::<f64>::new(0.5, 1.0).cos();
+        assert!(c.lo() > 0.5 && c.hi() < 0.9);
+        let s = Interval::<f64>::new(-1e300, 1e300).sin();
+        assert_eq!((s.lo(), s.hi()), (-1.0, 1.0));
+        check(Interval::<f64>::new(3.0, 3.2).sin(), 0.0);
+    }
+
+    /* Bounds of the exact values at sample points, the exact value rounded
+     * down and up, computed with a multiple-precision library rather than
+     * with the functions of this crate: (t, below, above) */
+    const EXP: [(f64, f64, f64); 15] = [
+        (-3.0, 0.04978706836786394, 0.049787068367863944),
+        (-1.25, 0.2865047968601901, 0.28650479686019015),
+        (0.5, 1.648721270700128, 1.6487212707001282),
+        (0.1, 1.1051709180756475, 1.1051709180756477),
+        (0.15, 1.161834242728283, 1.1618342427282833),
+        (0.2, 1.2214027581601696, 1.2214027581601699),
+        (1.5, 4.4816890703380645, 4.481689070338065),
+        (3.125, 22.759895093526726, 22.75989509352673),
+        (4.75, 115.58428452718765, 115.58428452718766),
+        (100.0, 2.688117141816135e+43, 2.6881171418161356e+43),
+        (100.5, 4.431955909845895e+43, 4.4319559098458955e+43),
+        (101.0, 7.307059979368067e+43, 7.307059979368068e+43),
+        (-100000.0, 0.0, 5e-324),
+        (-99999.5, 0.0, 5e-324),
+        (-99999.0, 0.0, 5e-324),
+    ];
+    const SIN: [(f64, f64, f64); 15] = [
+        (-3.0, -0.14112000805986724, -0.1411200080598672),
+        (-1.25, -0.9489846193555863, -0.9489846193555862),
+        (0.5, 0.47942553860420295, 0.479425538604203),
+        (0.1, 0.09983341664682815, 0.09983341664682817),
+        (0.15, 0.1494381324735992, 0.14943813247359922),
+        (0.2, 0.19866933079506122, 0.19866933079506124),
+        (1.5, 0.9974949866040543, 0.9974949866040544),
+        (3.125, 0.016591892229347902, 0.016591892229347906),
+        (4.75, -0.999292788975378, -0.9992927889753779),
+        (100.0, -0.5063656411097589, -0.5063656411097588),
+        (100.5, -0.030959966783271346, -0.030959966783271342),
+        (101.0, 0.45202578717835057, 0.4520257871783506),
+        (-100000.0, -0.035748797972016515, -0.03574879797201651),
+        (-99999.5, -0.5104916150747799, -0.5104916150747798),
+        (-99999.0, -0.8602482807897421, -0.860248280789742),
+    ];
+    const COS: [(f64, f64, f64); 15] = [
+        (-3.0, -0.9899924966004455, -0.9899924966004454),
+        (-1.25, 0.3153223623952686, 0.3153223623952687),
+        (0.5, 0.8775825618903726, 0.8775825618903728),
+        (0.1, 0.9950041652780257, 0.9950041652780258),
+        (0.15, 0.9887710779360422, 0.9887710779360424),
+        (0.2, 0.9800665778412416, 0.9800665778412417),
+        (1.5, 0.0707372016677029, 0.07073720166770292),
+        (3.125, -0.9998623450816866, -0.9998623450816865),
+        (4.75, 0.03760215288797655, 0.03760215288797656),
+        (100.0, 0.8623188722876839, 0.862318872287684),
+        (100.5, 0.9995206253283514, 0.9995206253283515),
+        (101.0, 0.8920048697881601, 0.8920048697881602),
+        (-100000.0, -0.9993608074382125, -0.9993608074382124),
+        (-99999.5, -0.8598827309222711, -0.859882730922271),
+        (-99999.0, -0.5098753724179009, -0.5098753724179008),
+    ];
+    const ATAN: [(f64, f64, f64); 15] = [
+        (-3.0, -1.2490457723982544, -1.2490457723982542),
+        (-1.25, -0.896055384571344, -0.8960553845713439),
+        (0.5, 0.4636476090008061, 0.46364760900080615),
+        (0.1, 0.09966865249116202, 0.09966865249116204),
+        (0.15, 0.14888994760949723, 0.14888994760949725),
+        (0.2, 0.19739555984988075, 0.19739555984988078),
+        (1.5, 0.982793723247329, 0.9827937232473292),
+        (3.125, 1.2610933822524404, 1.2610933822524406),
+        (4.75, 1.3633001003596938, 1.363300100359694),
+        (100.0, 1.5607966601082313, 1.5607966601082315),
+        (100.5, 1.5608464064020913, 1.5608464064020915),
+        (101.0, 1.560895660206908, 1.5608956602069082),
+        (-100000.0, -1.5707863267948972, -1.570786326794897),
+        (-99999.5, -1.5707863267448967, -1.5707863267448965),
+        (-99999.0, -1.570786326694896, -1.5707863266948958),
+    ];
+    #[allow(clippy::approx_constant)]
+    const LOG: [(f64, f64, f64); 10] = [
+        (0.5, -0.6931471805599454, -0.6931471805599453),
+        (0.1, -2.302585092994046, -2.3025850929940455),
+        (0.15, -1.8971199848858815, -1.8971199848858813),
+        (0.2, -1.6094379124341005, -1.6094379124341003),
+        (1.5, 0.40546510810816433, 0.4054651081081644),
+        (3.125, 1.1394342831883648, 1.139434283188365),
+        (4.75, 1.5581446180465497, 1.55814461804655),
+        (100.0, 4.605170185988091, 4.605170185988092),
+        (100.5, 4.61015772749913, 4.610157727499131),
+        (101.0, 4.615120516841259, 4.61512051684126),
+    ];
+    /* (t, u, below, above) for t^u */
+    #[allow(clippy::approx_constant)]
+    const POW: [(f64, f64, f64, f64); 20] = [
+        (0.5, 0.5, 0.7071067811865475, 0.7071067811865476),
+        (0.5, 1.25, 0.42044820762685725, 0.4204482076268573),
+        (0.1, 0.5, 0.31622776601683794, 0.316227766016838),
+        (0.1, 1.25, 0.05623413251903491, 0.05623413251903492),
+        (0.15, 0.5, 0.38729833462074165, 0.3872983346207417),
+        (0.15, 1.25, 0.09334994659327174, 0.09334994659327175),
+        (0.2, 0.5, 0.4472135954999579, 0.447213595499958),
+        (0.2, 1.25, 0.1337480609952844, 0.13374806099528444),
+        (1.5, 0.5, 1.224744871391589, 1.2247448713915892),
+        (1.5, 1.25, 1.6600228795504823, 1.6600228795504826),
+        (3.125, 0.5, 1.7677669529663687, 1.7677669529663689),
+        (3.125, 1.25, 4.154918669488272, 4.154918669488273),
+        (4.75, 0.5, 2.1794494717703365, 2.179449471770337),
+        (4.75, 1.25, 7.012405343875824, 7.012405343875825),
+        (100.0, 0.5, 10.0, 10.0),
+        (100.0, 1.25, 316.2277660168379, 316.22776601683796),
+        (100.5, 0.5, 10.02496882788171, 10.024968827881711),
+        (100.5, 1.25, 318.2054232784417, 318.20542327844174),
+        (101.0, 0.5, 10.04987562112089, 10.049875621120892),
+        (101.0, 1.25, 320.1855418519927, 320.18554185199275),
+    ];
+
+    fn check_exact(r: Interval<f64>, below: f64, above: f64) {
+        assert!(
+            r.lo() <= below && above <= r.hi(),
+            "{:?} does not contain [{:e}, {:e}]",
+            r,
+            below,
+            above
+        );
+    }
+
+    /* Checks f at each sample point, and on each case around it */
+    fn check_function(
+        f: fn(Interval<f64>) -> Interval<f64>,
+        values: &[(f64, f64, f64)],
+        cases: &[(f64, f64)],
+    ) {
+        for &(t, below, above) in values.iter() {
+            check_exact(f(Interval::<f64>::point(t)), below, above);
+            for &(lo, hi) in cases.iter().filter(|c| c.0 <= t && t <= c.1) {
+                check_exact(f(Interval::<f64>::new(lo, hi)), below, above);
+            }
+        }
+    }
+
+    #[test]
+    fn two_pi() {
+        assert_eq!(TWO_PI.lo().to_bits(), 0x401921fb54442d18);
+        assert_eq!(TWO_PI.hi().to_bits(), 0x401921fb54442d19);
+    }
+
+    #[test]
+    fn contains_exact_values() {
+        let cases = [
+            (-3.0, 0.5),
+            (0.1, 0.2),
+            (1.5, 4.75),
+            (100.0, 101.0),
+            (-1e5, -99999.0),
+        ];
+        check_function(Interval::<f64>::exp, &EXP, &cases);
+        check_function(Interval::<f64>::sin, &SIN, &cases);
+        check_function(Interval::<f64>::cos, &COS, &cases);
+        check_function(Interval::<f64>::atan, &ATAN, &cases);
+        check_function(Interval::<f64>::log, &LOG, &cases);
+        let y = Interval::<f64>::new(0.5, 1.5);
+        for &(t, u, below, above) in POW.iter() {
+            let p = Interval::<f64>::point(t).pow(Interval::<f64>::point(u));
+            check_exact(p, below, above);
+            for &(lo, hi) in cases.iter().filter(|c| c.0 <= t && t <= c.1) {
+                check_exact(Interval::<f64>::new(lo, hi).pow(y), below, above);
+            }
+        }
+
+        /* a product or quotient rounded to nearest lies within the outward bounds */
+        for &(lo, hi) in cases.iter() {
+            let x = Interval::<f64>::new(lo, hi);
+            for t in points(lo, hi) {
+                check(x * x, t * t);
+                for u in points(0.5, 1.5) {
+                    check(x / y, t / u);
+                }
+            }
+        }
+    }
+}
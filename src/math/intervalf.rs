@@ -0,0 +1,482 @@
+use super::interval::may_reach;
+use super::{
+    addf_round, atanf, cosf, divf_round, expf, fmaxf, fminf, logf, mulf_round, nextdownf, nextupf,
+    powf, sinf, sqrtf_round, subf_round, Interval, Round,
+};
+use core::f32;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/* The functions used for the bounds below are accurate to within one ulp, so
+ * the exact value is within two steps of their result, even when it lies in
+ * the binade below. */
+fn down(r: f32) -> f32 {
+    nextdownf(nextdownf(r))
+}
+
+fn up(r: f32) -> f32 {
+    nextupf(nextupf(r))
+}
+
+/* A bound of the product, where 0 * inf is 0 since 0 stands for itself */
+fn mul_bound(x: f32, y: f32, round: Round) -> f32 {
+    if x == 0.0 || y == 0.0 {
+        0.0
+    } else {
+        mulf_round(x, y, round)
+    }
+}
+
+/* The interval spanned by op over the corners of x and y. A NaN at a corner
+ * is an inf/inf whose neighbours bound it, so it is left out. */
+fn corners(x: Interval<f32>, y: Interval<f32>, op: fn(f32, f32, Round) -> f32) -> Interval<f32> {
+    let lo = fminf(
+        fminf(op(x.lo, y.lo, Round::Down), op(x.lo, y.hi, Round::Down)),
+        fminf(op(x.hi, y.lo, Round::Down), op(x.hi, y.hi, Round::Down)),
+    );
+    let hi = fmaxf(
+        fmaxf(op(x.lo, y.lo, Round::Up), op(x.lo, y.hi, Round::Up)),
+        fmaxf(op(x.hi, y.lo, Round::Up), op(x.hi, y.hi, Round::Up)),
+    );
+    Interval::<f32>::new(lo, hi)
+}
+
+impl Interval<f32> {
+    /// The empty interval
+    pub const EMPTY: Interval<f32> = Interval {
+        lo: f32::NAN,
+        hi: f32::NAN,
+    };
+
+    /// The interval of all real numbers
+    pub const ENTIRE: Interval<f32> = Interval {
+        lo: f32::NEG_INFINITY,
+        hi: f32::INFINITY,
+    };
+
+    /// Returns the interval `[lo, hi]`, or the empty interval if `lo > hi`, if
+    /// either bound is NaN, or if the interval contains no finite number.
+    pub fn new(lo: f32, hi: f32) -> Interval<f32> {
+        if lo <= hi && lo != f32::INFINITY && hi != f32::NEG_INFINITY {
+            Interval { lo, hi }
+        } else {
+            Interval::<f32>::EMPTY
+        }
+    }
+
+    /// Returns the interval `[x, x]`.
+    pub fn point(x: f32) -> Interval<f32> {
+        Interval::<f32>::new(x, x)
+    }
+
+    /// The lower bound, NaN for the empty interval
+    pub fn lo(self) -> f32 {
+        self.lo
+    }
+
+    /// The upper bound, NaN for the empty interval
+    pub fn hi(self) -> f32 {
+        self.hi
+    }
+
+    /// Returns `true` for the empty interval.
+    pub fn is_empty(self) -> bool {
+        self.lo.is_nan()
+    }
+
+    /// Returns `true` if `x` lies in the interval.
+    pub fn contains(self, x: f32) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// The smallest interval containing both `self` and `other`
+    pub fn hull(self, other: Interval<f32>) -> Interval<f32> {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            self
+        } else {
+            Interval::<f32>::new(fminf(self.lo, other.lo), fmaxf(self.hi, other.hi))
+        }
+    }
+
+    /// Encloses `e^x` over the interval.
+    pub fn exp(self) -> Interval<f32> {
+        if self.is_empty() {
+            return self;
+        }
+        Interval::<f32>::new(fmaxf(down(expf(self.lo)), 0.0), up(expf(self.hi)))
+    }
+
+    /// Encloses the natural logarithm over the nonnegative part of the
+    /// interval.
+    pub fn log(self) -> Interval<f32> {
+        if self.is_empty() || self.hi < 0.0 {
+            return Interval::<f32>::EMPTY;
+        }
+        Interval::<f32>::new(down(logf(fmaxf(self.lo, 0.0))), up(logf(self.hi)))
+    }
+
+    /// Encloses the square root over the nonnegative part of the interval.
+    pub fn sqrt(self) -> Interval<f32> {
+        if self.is_empty() || self.hi < 0.0 {
+            return Interval::<f32>::EMPTY;
+        }
+        Interval::<f32>::new(
+            sqrtf_round(fmaxf(self.lo, 0.0), Round::Down),
+            sqrtf_round(self.hi, Round::Up),
+        )
+    }
+
+    /// Encloses the sine over the interval.
+    pub fn sin(self) -> Interval<f32> {
+        self.periodic(sinf, 0.25, 0.75)
+    }
+
+    /// Encloses the cosine over the interval.
+    pub fn cos(self) -> Interval<f32> {
+        self.periodic(cosf, 0.0, 0.5)
+    }
+
+    /* f is sinf or cosf, which reach 1 at 2*pi*(n + max) and -1 at
+     * 2*pi*(n + min), and are monotonic in between. */
+    fn periodic(self, f: fn(f32) -> f32, max: f64, min: f64) -> Interval<f32> {
+        if self.is_empty() {
+            return self;
+        }
+        if self.lo.is_infinite() || self.hi.is_infinite() {
+            return Interval::<f32>::new(-1.0, 1.0);
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut lo = fmaxf(down(fminf(a, b)), -1.0);
+        let mut hi = fminf(up(fmaxf(a, b)), 1.0);
+        if may_reach(self.lo as f64, self.hi as f64, max) {
+            hi = 1.0;
+        }
+        if may_reach(self.lo as f64, self.hi as f64, min) {
+            lo = -1.0;
+        }
+        Interval::<f32>::new(lo, hi)
+    }
+
+    /// Encloses the arc tangent over the interval.
+    pub fn atan(self) -> Interval<f32> {
+        if self.is_empty() {
+            return self;
+        }
+        Interval::<f32>::new(down(atanf(self.lo)), up(atanf(self.hi)))
+    }
+
+    /// Encloses `x^y` for `x` in the nonnegative part of `self` and `y` in
+    /// `y`, taking `0^0` as 1.
+    pub fn pow(self, y: Interval<f32>) -> Interval<f32> {
+        if self.is_empty() || self.hi < 0.0 || y.is_empty() {
+            return Interval::<f32>::EMPTY;
+        }
+        /* y*log(x) is monotonic in each argument, so the extrema are at the
+         * corners */
+        let x = Interval::<f32>::new(fmaxf(self.lo, 0.0), self.hi);
+        let r = corners(x, y, |x, y, round| match round {
+            Round::Down => down(powf(x, y)),
+            _ => up(powf(x, y)),
+        });
+        Interval::<f32>::new(fmaxf(r.lo, 0.0), r.hi)
+    }
+}
+
+impl Neg for Interval<f32> {
+    type Output = Interval<f32>;
+
+    fn neg(self) -> Interval<f32> {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Add for Interval<f32> {
+    type Output = Interval<f32>;
+
+    fn add(self, rhs: Interval<f32>) -> Interval<f32> {
+        Interval::<f32>::new(
+            addf_round(self.lo, rhs.lo, Round::Down),
+            addf_round(self.hi, rhs.hi, Round::Up),
+        )
+    }
+}
+
+impl Sub for Interval<f32> {
+    type Output = Interval<f32>;
+
+    fn sub(self, rhs: Interval<f32>) -> Interval<f32> {
+        Interval::<f32>::new(
+            subf_round(self.lo, rhs.hi, Round::Down),
+            subf_round(self.hi, rhs.lo, Round::Up),
+        )
+    }
+}
+
+impl Mul for Interval<f32> {
+    type Output = Interval<f32>;
+
+    fn mul(self, rhs: Interval<f32>) -> Interval<f32> {
+        if self.is_empty() || rhs.is_empty() {
+            return Interval::<f32>::EMPTY;
+        }
+        corners(self, rhs, mul_bound)
+    }
+}
+
+impl Div for Interval<f32> {
+    type Output = Interval<f32>;
+
+    /// Divides by the interval. Dividing by an interval containing zero gives
+    /// the entire line, or the empty interval for `[0, 0]`.
+    fn div(self, rhs: Interval<f32>) -> Interval<f32> {
+        if self.is_empty() || rhs.is_empty() || (rhs.lo == 0.0 && rhs.hi == 0.0) {
+            return Interval::<f32>::EMPTY;
+        }
+        if rhs.contains(0.0) {
+            return Interval::<f32>::ENTIRE;
+        }
+        corners(self, rhs, divf_round)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* The products and quotients of f32 values in f64 are within an ulp of
+     * f64 of the exact values, far inside the bounds of f32. */
+    fn check(r: Interval<f32>, x: f64) {
+        assert!(
+            r.lo as f64 <= x && x <= r.hi as f64,
+            "{:?} does not contain {:e}",
+            r,
+            x
+        );
+    }
+
+    fn points(lo: f32, hi: f32) -> impl Iterator<Item = f32> {
+        (0..=64).map(move |i| lo + (hi - lo) * (i as f32 / 64.0))
+    }
+
+    /* Bounds of the exact values at sample points, the exact value rounded
+     * down and up to f64, computed with a multiple-precision library rather
+     * than with the functions of this crate: (t, below, above) */
+    const EXP: [(f32, f64, f64); 18] = [
+        (-3.0, 0.04978706836786394, 0.049787068367863944),
+        (-1.25, 0.2865047968601901, 0.28650479686019015),
+        (0.5, 1.648721270700128, 1.6487212707001282),
+        (0.1, 1.1051709197224806, 1.1051709197224808),
+        (0.15, 1.1618342496533547, 1.161834249653355),
+        (0.2, 1.2214027618002337, 1.2214027618002339),
+        (1.5, 4.4816890703380645, 4.481689070338065),
+        (3.125, 22.759895093526726, 22.75989509352673),
+        (4.75, 115.58428452718765, 115.58428452718766),
+        (1.5703125, 4.808150506059801, 4.8081505060598015),
+        (1.5708008, 4.810498809068596, 4.810498809068597),
+        (1.571289, 4.812847685253517, 4.812847685253518),
+        (100.0, 2.688117141816135e+43, 2.6881171418161356e+43),
+        (100.5, 4.431955909845895e+43, 4.4319559098458955e+43),
+        (101.0, 7.307059979368067e+43, 7.307059979368068e+43),
+        (-1e5, 0.0, 5e-324),
+        (-99999.5, 0.0, 5e-324),
+        (-99999.0, 0.0, 5e-324),
+    ];
+    const SIN: [(f32, f64, f64); 18] = [
+        (-3.0, -0.14112000805986724, -0.1411200080598672),
+        (-1.25, -0.9489846193555863, -0.9489846193555862),
+        (0.5, 0.47942553860420295, 0.479425538604203),
+        (0.1, 0.09983341812949989, 0.0998334181294999),
+        (0.15, 0.1494381383671341, 0.14943813836713413),
+        (0.2, 0.19866933371588721, 0.19866933371588724),
+        (1.5, 0.9974949866040543, 0.9974949866040544),
+        (3.125, 0.016591892229347902, 0.016591892229347906),
+        (4.75, -0.999292788975378, -0.9992927889753779),
+        (1.5703125, 0.9999998829558185, 0.9999998829558187),
+        (1.5708008, 0.9999999999900788, 0.9999999999900789),
+        (1.571289, 0.9999998786644965, 0.9999998786644966),
+        (100.0, -0.5063656411097589, -0.5063656411097588),
+        (100.5, -0.030959966783271346, -0.030959966783271342),
+        (101.0, 0.45202578717835057, 0.4520257871783506),
+        (-1e5, -0.035748797972016515, -0.03574879797201651),
+        (-99999.5, -0.5104916150747799, -0.5104916150747798),
+        (-99999.0, -0.8602482807897421, -0.860248280789742),
+    ];
+    const COS: [(f32, f64, f64); 18] = [
+        (-3.0, -0.9899924966004455, -0.9899924966004454),
+        (-1.25, 0.3153223623952686, 0.3153223623952687),
+        (0.5, 0.8775825618903726, 0.8775825618903728),
+        (0.1, 0.9950041651292624, 0.9950041651292625),
+        (0.15, 0.9887710770453215, 0.9887710770453216),
+        (0.2, 0.9800665772491608, 0.9800665772491609),
+        (1.5, 0.0707372016677029, 0.07073720166770292),
+        (3.125, -0.9998623450816866, -0.9998623450816865),
+        (4.75, 0.03760215288797655, 0.03760215288797656),
+        (1.5703125, 0.0004838267760202487, 0.00048382677602024874),
+        (1.5708008, -4.454455103366038e-06, -4.454455103366037e-06),
+        (1.571289, -0.0004926164758898731, -0.000492616475889873),
+        (100.0, 0.8623188722876839, 0.862318872287684),
+        (100.5, 0.9995206253283514, 0.9995206253283515),
+        (101.0, 0.8920048697881601, 0.8920048697881602),
+        (-1e5, -0.9993608074382125, -0.9993608074382124),
+        (-99999.5, -0.8598827309222711, -0.859882730922271),
+        (-99999.0, -0.5098753724179009, -0.5098753724179008),
+    ];
+    const ATAN: [(f32, f64, f64); 18] = [
+        (-3.0, -1.2490457723982544, -1.2490457723982542),
+        (-1.25, -0.896055384571344, -0.8960553845713439),
+        (0.5, 0.4636476090008061, 0.46364760900080615),
+        (0.1, 0.09966865396652451, 0.09966865396652452),
+        (0.15, 0.14888995343880235, 0.14888995343880237),
+        (0.2, 0.19739556271548866, 0.1973955627154887),
+        (1.5, 0.982793723247329, 0.9827937232473292),
+        (3.125, 1.2610933822524404, 1.2610933822524406),
+        (4.75, 1.3633001003596938, 1.363300100359694),
+        (1.5703125, 1.0037452554041646, 1.0037452554041648),
+        (1.5708008, 1.0038861065181026, 1.0038861065181028),
+        (1.571289, 1.0040268609685916, 1.0040268609685918),
+        (100.0, 1.5607966601082313, 1.5607966601082315),
+        (100.5, 1.5608464064020913, 1.5608464064020915),
+        (101.0, 1.560895660206908, 1.5608956602069082),
+        (-1e5, -1.5707863267948972, -1.570786326794897),
+        (-99999.5, -1.5707863267448967, -1.5707863267448965),
+        (-99999.0, -1.570786326694896, -1.5707863266948958),
+    ];
+    #[allow(clippy::approx_constant)]
+    const LOG: [(f32, f64, f64); 13] = [
+        (0.5, -0.6931471805599454, -0.6931471805599453),
+        (0.1, -2.3025850780928847, -2.3025850780928843),
+        (0.15, -1.8971199451494523, -1.897119945149452),
+        (0.2, -1.6094378975329393, -1.609437897532939),
+        (1.5, 0.40546510810816433, 0.4054651081081644),
+        (3.125, 1.1394342831883648, 1.139434283188365),
+        (4.75, 1.5581446180465497, 1.55814461804655),
+        (1.5703125, 0.45127464413945856, 0.4512746441394586),
+        (1.5708008, 0.4515855410796279, 0.45158554107962795),
+        (1.571289, 0.45189626552573486, 0.4518962655257349),
+        (100.0, 4.605170185988091, 4.605170185988092),
+        (100.5, 4.61015772749913, 4.610157727499131),
+        (101.0, 4.615120516841259, 4.61512051684126),
+    ];
+    #[allow(clippy::approx_constant)]
+    const SQRT: [(f32, f64, f64); 13] = [
+        (0.5, 0.7071067811865475, 0.7071067811865476),
+        (0.1, 0.3162277683729183, 0.3162277683729184),
+        (0.15, 0.3872983423156681, 0.3872983423156682),
+        (0.2, 0.4472135988319588, 0.4472135988319589),
+        (1.5, 1.224744871391589, 1.2247448713915892),
+        (3.125, 1.7677669529663687, 1.7677669529663689),
+        (4.75, 2.1794494717703365, 2.179449471770337),
+        (1.5703125, 1.2531211034852137, 1.2531211034852139),
+        (1.5708008, 1.2533159143847172, 1.2533159143847175),
+        (1.571289, 1.2535106474580542, 1.2535106474580544),
+        (100.0, 10.0, 10.0),
+        (100.5, 10.02496882788171, 10.024968827881711),
+        (101.0, 10.04987562112089, 10.049875621120892),
+    ];
+    /* (t, u, below, above) for t^u */
+    #[allow(clippy::approx_constant)]
+    const POW: [(f32, f32, f64, f64); 26] = [
+        (0.5, 0.5, 0.7071067811865475, 0.7071067811865476),
+        (0.5, 1.25, 0.42044820762685725, 0.4204482076268573),
+        (0.1, 0.5, 0.3162277683729183, 0.3162277683729184),
+        (0.1, 1.25, 0.056234133566477246, 0.05623413356647725),
+        (0.15, 0.5, 0.3872983423156681, 0.3872983423156682),
+        (0.15, 1.25, 0.09334995123001377, 0.09334995123001379),
+        (0.2, 0.5, 0.4472135988319588, 0.4472135988319589),
+        (0.2, 1.25, 0.13374806348653617, 0.1337480634865362),
+        (1.5, 0.5, 1.224744871391589, 1.2247448713915892),
+        (1.5, 1.25, 1.6600228795504823, 1.6600228795504826),
+        (3.125, 0.5, 1.7677669529663687, 1.7677669529663689),
+        (3.125, 1.25, 4.154918669488272, 4.154918669488273),
+        (4.75, 0.5, 2.1794494717703365, 2.179449471770337),
+        (4.75, 1.25, 7.012405343875824, 7.012405343875825),
+        (1.5703125, 0.5, 1.2531211034852137, 1.2531211034852139),
+        (1.5703125, 1.25, 1.7578532235197015, 1.7578532235197017),
+        (1.5708008, 0.5, 1.2533159143847172, 1.2533159143847175),
+        (1.5708008, 1.25, 1.7585364952636102, 1.7585364952636104),
+        (1.571289, 0.5, 1.2535106474580542, 1.2535106474580544),
+        (1.571289, 1.25, 1.7592196532742679, 1.759219653274268),
+        (100.0, 0.5, 10.0, 10.0),
+        (100.0, 1.25, 316.2277660168379, 316.22776601683796),
+        (100.5, 0.5, 10.02496882788171, 10.024968827881711),
+        (100.5, 1.25, 318.2054232784417, 318.20542327844174),
+        (101.0, 0.5, 10.04987562112089, 10.049875621120892),
+        (101.0, 1.25, 320.1855418519927, 320.18554185199275),
+    ];
+
+    fn check_exact(r: Interval<f32>, below: f64, above: f64) {
+        assert!(
+            r.lo as f64 <= below && above <= r.hi as f64,
+            "{:?} does not contain [{:e}, {:e}]",
+            r,
+            below,
+            above
+        );
+    }
+
+    /* Checks f at each sample point, and on each case around it */
+    fn check_function(
+        f: fn(Interval<f32>) -> Interval<f32>,
+        values: &[(f32, f64, f64)],
+        cases: &[(f32, f32)],
+    ) {
+        for &(t, below, above) in values.iter() {
+            check_exact(f(Interval::<f32>::point(t)), below, above);
+            for &(lo, hi) in cases.iter().filter(|c| c.0 <= t && t <= c.1) {
+                check_exact(f(Interval::<f32>::new(lo, hi)), below, above);
+            }
+        }
+    }
+
+    #[test]
+    fn contains_exact_values() {
+        let cases = [
+            (-3.0, 0.5),
+            (0.1, 0.2),
+            (1.5, 4.75),
+            (1.5703125, 1.571289),
+            (100.0, 101.0),
+            (-1e5, -99999.0),
+        ];
+        check_function(Interval::<f32>::exp, &EXP, &cases);
+        check_function(Interval::<f32>::sin, &SIN, &cases);
+        check_function(Interval::<f32>::cos, &COS, &cases);
+        check_function(Interval::<f32>::atan, &ATAN, &cases);
+        check_function(Interval::<f32>::log, &LOG, &cases);
+        check_function(Interval::<f32>::sqrt, &SQRT, &cases);
+        let y = Interval::<f32>::new(0.5, 1.5);
+        for &(t, u, below, above) in POW.iter() {
+            let p = Interval::<f32>::point(t).pow(Interval::<f32>::point(u));
+            check_exact(p, below, above);
+            for &(lo, hi) in cases.iter().filter(|c| c.0 <= t && t <= c.1) {
+                check_exact(Interval::<f32>::new(lo, hi).pow(y), below, above);
+            }
+        }
+
+        for &(lo, hi) in cases.iter() {
+            let x = Interval::<f32>::new(lo, hi);
+            for t in points(lo, hi) {
+                let t64 = t as f64;
+                check(x * x, t64 * t64);
+                for u in points(0.5, 1.5) {
+                    check(x / y, t64 / u as f64);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn outward_rounding() {
+        let third = Interval::<f32>::point(1.0) / Interval::<f32>::point(3.0);
+        assert_eq!(third.hi(), nextupf(third.lo()));
+        check(third, 1.0 / 3.0);
+        let sum = Interval::<f32>::point(0.1) + Interval::<f32>::point(0.2);
+        check(sum, 0.1f32 as f64 + 0.2f32 as f64);
+        assert!(Interval::<f32>::new(2.0, 1.0).is_empty());
+        assert_eq!(Interval::<f32>::new(1.0, 2.0).sin().hi(), 1.0);
+    }
+}
@@ -0,0 +1,25 @@
+/// Signaling NaN test (f64)
+///
+/// Returns `true` if `x` is a signaling NaN, that is a NaN whose most
+/// significant significand bit is clear.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn issignaling(x: f64) -> bool {
+    let ix = x.to_bits() & 0x7fffffffffffffff;
+    ix > 0x7ff0000000000000 && ix & 0x0008000000000000 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert!(issignaling(f64::from_bits(0x7ff0000000000001)));
+        assert!(issignaling(f64::from_bits(0xfff4000000000000)));
+        assert!(!issignaling(NAN));
+        assert!(!issignaling(f64::from_bits(0xfff8000000000001)));
+        assert!(!issignaling(INFINITY));
+        assert!(!issignaling(0.0));
+    }
+}
@@ -0,0 +1,9 @@
+/// Signaling NaN test (f32)
+///
+/// Returns `true` if `x` is a signaling NaN, that is a NaN whose most
+/// significant significand bit is clear.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn issignalingf(x: f32) -> bool {
+    let ix = x.to_bits() & 0x7fffffff;
+    ix > 0x7f800000 && ix & 0x00400000 == 0
+}
@@ -54,6 +54,7 @@
  *      3. Special cases: y0(0)=-inf, y0(x<0)=NaN, y0(inf)=0.
  */
 
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
 use super::{cos, fabs, get_high_word, get_low_word, log, sin, sqrt};
 const INVSQRTPI: f64 = 5.64189583547756279280e-01; /* 0x3FE20DD7, 0x50429B6D */
 const TPI: f64 = 6.36619772367581382433e-01; /* 0x3FE45F30, 0x6DC9C883 */
@@ -174,10 +175,11 @@ pub fn y0(x: f64) -> f64 {
 
     /* y0(nan)=nan, y0(<0)=nan, y0(0)=-inf, y0(inf)=0 */
     if ((ix << 1) | lx) == 0 {
+        feraiseexcept(FE_DIVBYZERO);
         return -1.0 / 0.0;
     }
     if (ix >> 31) != 0 {
-        return 0.0 / 0.0;
+        return invalid(x);
     }
     if ix >= 0x7ff00000 {
         return 1.0 / x;
@@ -54,6 +54,7 @@
  *         by method mentioned above.
  */
 
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
 use super::{cos, fabs, get_high_word, get_low_word, log, sin, sqrt};
 
 const INVSQRTPI: f64 = 5.64189583547756279280e-01; /* 0x3FE20DD7, 0x50429B6D */
@@ -170,10 +171,11 @@ pub fn y1(x: f64) -> f64 {
 
     /* y1(nan)=nan, y1(<0)=nan, y1(0)=-inf, y1(inf)=0 */
     if (ix << 1 | lx) == 0 {
+        feraiseexcept(FE_DIVBYZERO);
         return -1.0 / 0.0;
     }
     if (ix >> 31) != 0 {
-        return 0.0 / 0.0;
+        return invalid(x);
     }
     if ix >= 0x7ff00000 {
         return 1.0 / x;
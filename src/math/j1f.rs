@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, invalidf, FE_DIVBYZERO};
 use super::{cosf, fabsf, logf, sinf, sqrtf};
 
 const INVSQRTPI: f32 = 5.6418961287e-01; /* 0x3f106ebb */
@@ -115,10 +116,11 @@ pub fn y1f(x: f32) -> f32 {
 
     ix = x.to_bits();
     if (ix & 0x7fffffff) == 0 {
+        feraiseexcept(FE_DIVBYZERO);
         return -1.0 / 0.0;
     }
     if (ix >> 31) != 0 {
-        return 0.0 / 0.0;
+        return invalidf(x);
     }
     if ix >= 0x7f800000 {
         return 1.0 / x;
@@ -34,6 +34,7 @@
  *      values of n>1.
  */
 
+use super::fenv::invalid;
 use super::{cos, fabs, get_high_word, get_low_word, j0, j1, log, sin, sqrt, y0, y1};
 
 const INVSQRTPI: f64 = 5.64189583547756279280e-01; /* 0x3FE20DD7, 0x50429B6D */
@@ -274,7 +275,7 @@ pub fn yn(n: i32, x: f64) -> f64 {
     }
     if sign && (ix | lx) != 0 {
         /* x < 0 */
-        return 0.0 / 0.0;
+        return invalid(x);
     }
     if ix == 0x7ff00000 {
         return 0.0;
@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::invalidf;
 use super::{fabsf, j0f, j1f, logf, y0f, y1f};
 
 pub fn jnf(n: i32, mut x: f32) -> f32 {
@@ -214,7 +215,7 @@ pub fn ynf(n: i32, x: f32) -> f32 {
     }
     if sign && ix != 0 {
         /* x < 0 */
-        return 0.0 / 0.0;
+        return invalidf(x);
     }
     if ix == 0x7f800000 {
         return 0.0;
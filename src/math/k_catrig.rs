@@ -0,0 +1,254 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/catrig.c */
+/*-
+ * Copyright (c) 2012 Stephen Montgomery-Smith <stephen@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+/*
+ * The algorithm for casinh, casin, cacos and cacosh is very close to that in
+ * "Implementing the complex arcsine and arccosine functions using exception
+ * handling" by T. E. Hull, Thomas F. Fairgrieve, and Ping Tak Peter Tang,
+ * published in ACM Transactions on Mathematical Software, Volume 23 Issue 3,
+ * 1997, Pages 299-335, http://dl.acm.org/citation.cfm?id=275324.
+ *
+ * Throughout we use the convention z = x + I*y.
+ *
+ * casinh(z) = sign(x)*log(A+sqrt(A*A-1)) + I*asin(B)
+ * where
+ * A = (|z+I| + |z-I|) / 2
+ * B = (|z+I| - |z-I|) / 2 = y/A
+ *
+ * These formulas become numerically unstable:
+ *   (a) for Re(casinh(z)) when z is close to the line segment [-I, I] (that
+ *       is, Re(casinh(z)) is close to 0);
+ *   (b) for Im(casinh(z)) when z is close to either of the intervals
+ *       [I, I*infinity) or (-I*infinity, -I] (that is, |Im(casinh(z))| is
+ *       close to PI/2).
+ *
+ * These numerical problems are overcome by defining
+ * f(a, b) = (hypot(a, b) - b) / 2 = a*a / (hypot(a, b) + b) / 2
+ * Then if A < A_CROSSOVER, we use
+ *   log(A + sqrt(A*A-1)) = log1p((A-1) + sqrt((A-1)*(A+1)))
+ *   A-1 = f(x, 1+y) + f(x, 1-y)
+ * and if B > B_CROSSOVER, we use
+ *   asin(B) = atan2(y, sqrt(A*A - y*y)) = atan2(y, sqrt((A+y)*(A-y)))
+ *   A-y = f(x, y+1) + f(x, y-1)
+ * where without loss of generality we have assumed that x and y are
+ * non-negative.
+ *
+ * Much of the difficulty comes because the intermediate computations may
+ * produce overflows or underflows.  This is dealt with in the paper by Hull
+ * et al by using exception handling.  We do this by detecting when
+ * computations risk underflow or overflow.  The hardest part is handling the
+ * underflows when computing f(a, b).
+ */
+
+use super::{atan2, fabs, hypot, log, log1p, sqrt, Complex};
+use core::f64;
+use core::f64::consts;
+
+const A_CROSSOVER: f64 = 10.0; /* Hull et al suggest 1.5, but 10 works better */
+const B_CROSSOVER: f64 = 0.6417; /* suggested by Hull et al */
+const FOUR_SQRT_MIN: f64 = 5.9666725849601654e-154; /* 0x1p-509 >= 4 * sqrt(DBL_MIN) */
+const QUARTER_SQRT_MAX: f64 = 1.6759759912428245e+153; /* 0x1p509 <= sqrt(DBL_MAX) / 4 */
+const M_E: f64 = consts::E; /*  0x15bf0a8b145769.0p-51 */
+pub(crate) const M_LN2: f64 = consts::LN_2; /*  0x162e42fefa39ef.0p-53 */
+pub(crate) const PIO2_HI: f64 = consts::FRAC_PI_2; /*  0x1921fb54442d18.0p-52 */
+pub(crate) const PIO2_LO: f64 = 6.1232339957367659e-17; /*  0x11a62633145c07.0p-106 */
+pub(crate) const RECIP_EPSILON: f64 = 1.0 / f64::EPSILON;
+pub(crate) const SQRT_3_EPSILON: f64 = 2.5809568279517849e-8; /*  0x1bb67ae8584caa.0p-78 */
+pub(crate) const SQRT_6_EPSILON: f64 = 3.6500241499888571e-8; /*  0x13988e1409212e.0p-77 */
+pub(crate) const SQRT_MIN: f64 = 1.4916681462400413e-154; /* 0x1p-511 >= sqrt(DBL_MIN) */
+
+/*
+ * Function f(a, b, hypot_a_b) = (hypot(a, b) - b) / 2.
+ * Pass hypot(a, b) as the third argument.
+ */
+#[inline]
+fn f(a: f64, b: f64, hypot_a_b: f64) -> f64 {
+    if b < 0.0 {
+        return (hypot_a_b - b) / 2.0;
+    }
+    if b == 0.0 {
+        return a / 2.0;
+    }
+    a * a / (hypot_a_b + b) / 2.0
+}
+
+/*
+ * All the hard work is contained in this function.
+ * x and y are assumed positive or zero, and less than RECIP_EPSILON.
+ * Returns (rx, b, sqrt_a2my2, new_y), where:
+ * rx = Re(casinh(z)) = -Im(cacos(y + I*x)).
+ * b is Some(B) if the value of B is usable.
+ * If b is None, sqrt_a2my2 = sqrt(A*A - y*y), and new_y = y.
+ * If returning sqrt_a2my2 has potential to result in an underflow, it is
+ * rescaled, and new_y is similarly rescaled.
+ */
+#[inline]
+pub(crate) fn do_hard_work(x: f64, y: f64) -> (f64, Option<f64>, f64, f64) {
+    let rx: f64;
+    let mut sqrt_a2my2 = 0.0;
+    let mut new_y = y;
+
+    let r = hypot(x, y + 1.0); /* |z+I| */
+    let s = hypot(x, y - 1.0); /* |z-I| */
+
+    /* A = (|z+I| + |z-I|) / 2 */
+    let mut a = (r + s) / 2.0;
+    /*
+     * Mathematically A >= 1.  There is a small chance that this will not
+     * be so because of rounding errors.  So we will make certain it is
+     * so.
+     */
+    if a < 1.0 {
+        a = 1.0;
+    }
+
+    if a < A_CROSSOVER {
+        /*
+         * Am1 = fp + fm, where fp = f(x, 1+y), and fm = f(x, 1-y).
+         * rx = log1p(Am1 + sqrt(Am1*(A+1)))
+         */
+        if y == 1.0 && x < f64::EPSILON * f64::EPSILON / 128.0 {
+            /*
+             * fp is of order x^2, and fm = x/2.
+             * A = 1 (inexactly).
+             */
+            rx = sqrt(x);
+        } else if x >= f64::EPSILON * fabs(y - 1.0) {
+            /*
+             * Underflow will not occur because
+             * x >= DBL_EPSILON^2/128 >= FOUR_SQRT_MIN
+             */
+            let am1 = f(x, 1.0 + y, r) + f(x, 1.0 - y, s);
+            rx = log1p(am1 + sqrt(am1 * (a + 1.0)));
+        } else if y < 1.0 {
+            /*
+             * fp = x*x/(1+y)/4, fm = x*x/(1-y)/4, and
+             * A = 1 (inexactly).
+             */
+            rx = x / sqrt((1.0 - y) * (1.0 + y));
+        } else {
+            /* if (y > 1) */
+            /*
+             * A-1 = y-1 (inexactly).
+             */
+            rx = log1p((y - 1.0) + sqrt((y - 1.0) * (y + 1.0)));
+        }
+    } else {
+        rx = log(a + sqrt(a * a - 1.0));
+    }
+
+    if y < FOUR_SQRT_MIN {
+        /*
+         * Avoid a possible underflow caused by y/A.  For casinh this
+         * would be legitimate, but will be picked up by invoking atan2
+         * later on.  For cacos this would not be legitimate.
+         */
+        return (rx, None, a * (2.0 / f64::EPSILON), y * (2.0 / f64::EPSILON));
+    }
+
+    /* B = (|z+I| - |z-I|) / 2 = y/A */
+    let b = y / a;
+    if b <= B_CROSSOVER {
+        return (rx, Some(b), sqrt_a2my2, new_y);
+    }
+
+    /*
+     * Amy = fp + fm, where fp = f(x, y+1), and fm = f(x, y-1).
+     * sqrt_a2my2 = sqrt(Amy*(A+y))
+     */
+    if y == 1.0 && x < f64::EPSILON / 128.0 {
+        /*
+         * fp is of order x^2, and fm = x/2.
+         * A = 1 (inexactly).
+         */
+        sqrt_a2my2 = sqrt(x) * sqrt((a + y) / 2.0);
+    } else if x >= f64::EPSILON * fabs(y - 1.0) {
+        /*
+         * Underflow will not occur because
+         * x >= DBL_EPSILON/128 >= FOUR_SQRT_MIN
+         * and
+         * x >= DBL_EPSILON^2 >= FOUR_SQRT_MIN
+         */
+        let amy = f(x, y + 1.0, r) + f(x, y - 1.0, s);
+        sqrt_a2my2 = sqrt(amy * (a + y));
+    } else if y > 1.0 {
+        /*
+         * fp = x*x/(y+1)/4, fm = x*x/(y-1)/4, and
+         * A = y (inexactly).
+         *
+         * y < RECIP_EPSILON.  So the following
+         * scaling should avoid any underflow problems.
+         */
+        sqrt_a2my2 = x * (4.0 / f64::EPSILON / f64::EPSILON) * y / sqrt((y + 1.0) * (y - 1.0));
+        new_y = y * (4.0 / f64::EPSILON / f64::EPSILON);
+    } else {
+        /* if (y < 1) */
+        /*
+         * fm = 1-y >= DBL_EPSILON, fp is of order x^2, and
+         * A = 1 (inexactly).
+         */
+        sqrt_a2my2 = sqrt((1.0 - y) * (1.0 + y));
+    }
+    (rx, None, sqrt_a2my2, new_y)
+}
+
+/*
+ * Optimized version of clog() for |z| finite and larger than ~RECIP_EPSILON.
+ */
+#[inline]
+pub(crate) fn clog_for_large_values(z: Complex<f64>) -> Complex<f64> {
+    let x = z.re;
+    let y = z.im;
+    let mut ax = fabs(x);
+    let mut ay = fabs(y);
+    if ax < ay {
+        core::mem::swap(&mut ax, &mut ay);
+    }
+
+    /*
+     * Avoid overflow in hypot() when x and y are both very large.
+     * Divide x and y by E, and then add 1 to the logarithm.  This
+     * depends on E being larger than sqrt(2), since the return value of
+     * hypot cannot overflow if neither argument is greater in magnitude
+     * than 1/sqrt(2) of the maximum value of the return type.  Likewise
+     * this determines the necessary threshold for using this method
+     * (however, actually use 1/2 instead as it is simpler).
+     */
+    if ax > f64::MAX / 2.0 {
+        return Complex::new(log(hypot(x / M_E, y / M_E)) + 1.0, atan2(y, x));
+    }
+
+    /*
+     * Avoid overflow when x or y is large.  Avoid underflow when x or
+     * y is small.
+     */
+    if ax > QUARTER_SQRT_MAX || ay < SQRT_MIN {
+        return Complex::new(log(hypot(x, y)), atan2(y, x));
+    }
+
+    Complex::new(log(ax * ax + ay * ay) / 2.0, atan2(y, x))
+}
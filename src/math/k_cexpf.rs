@@ -0,0 +1,62 @@
+/* origin: FreeBSD /usr/src/lib/msun/src/k_expf.c */
+/*-
+ * Copyright (c) 2011 David Schultz <das@FreeBSD.ORG>
+ * All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without
+ * modification, are permitted provided that the following conditions
+ * are met:
+ * 1. Redistributions of source code must retain the above copyright
+ *    notice, this list of conditions and the following disclaimer.
+ * 2. Redistributions in binary form must reproduce the above copyright
+ *    notice, this list of conditions and the following disclaimer in the
+ *    documentation and/or other materials provided with the distribution.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+ * ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+ * ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+ * OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+ * HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+ * LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+ * OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+ * SUCH DAMAGE.
+ */
+
+use super::{cosf, expf, sinf, Complex};
+
+const K: i32 = 235; /* constant for reduction */
+const KLN2: f32 = 162.88958740; /* k * ln2 */
+
+/*
+ * See k_cexp.rs for details.
+ *
+ * Input:  ln(FLT_MAX) <= x < ln(2 * FLT_MAX / FLT_MIN_DENORM) ~= 192.7
+ * Output: 2**127 <= y < 2**128
+ */
+#[inline]
+fn frexp_expf(x: f32) -> (f32, i32) {
+    let exp_x = expf(x - KLN2);
+    let hx = exp_x.to_bits();
+    let expt = (hx >> 23) as i32 - (0x7f + 127) + K;
+    let exp_x = f32::from_bits((hx & 0x7fffff) | ((0x7f + 127) << 23));
+    (exp_x, expt)
+}
+
+#[inline]
+pub(crate) fn k_ldexp_cexpf(z: Complex<f32>, expt: i32) -> Complex<f32> {
+    let (exp_x, ex_expt) = frexp_expf(z.re);
+    let expt = expt + ex_expt;
+
+    let half_expt = expt / 2;
+    let scale1 = f32::from_bits(((0x7f + half_expt) as u32) << 23);
+    let half_expt = expt - half_expt;
+    let scale2 = f32::from_bits(((0x7f + half_expt) as u32) << 23);
+
+    Complex::new(
+        cosf(z.im) * exp_x * scale1 * scale2,
+        sinf(z.im) * exp_x * scale1 * scale2,
+    )
+}
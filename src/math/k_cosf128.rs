@@ -0,0 +1,38 @@
+use super::F128;
+
+/* (-1)^n/(2n)! for n = 2..16, enough for |x| <= pi/4 */
+const C: [u128; 15] = [
+    0x3ffa5555555555555555555555555555, /* 1/4! */
+    0xbff56c16c16c16c16c16c16c16c16c17, /* -1/6! */
+    0x3fefa01a01a01a01a01a01a01a01a01a, /* 1/8! */
+    0xbfe927e4fb7789f5c72ef016d3ea6679, /* -1/10! */
+    0x3fe21eed8eff8d897b544da987acfe85, /* 1/12! */
+    0xbfda93974a8c07c9d20badf145dfa3e5, /* -1/14! */
+    0x3fd2ae7f3e733b81f11d8656b0ee8cb0, /* 1/16! */
+    0xbfca6827863b97d977bb004886a2c2ab, /* -1/18! */
+    0x3fc1e542ba402022507a9cad2bf8f0bb, /* 1/20! */
+    0xbfb90ce396db7f8529450c90b7f338ec, /* -1/22! */
+    0x3faff2cf01972f577cca4b4067ca9d8a, /* 1/24! */
+    0xbfa688e85fc6a4e59a38f2050ba6b015, /* -1/26! */
+    0x3f9d0a18a2635085d373c5c51c354a8d, /* 1/28! */
+    0xbf933932c5047d60e60caded4c2989c5, /* -1/30! */
+    0x3f89434d2e783f5bc42e1ee46fa6bfc4, /* 1/32! */
+];
+
+/* The cosine of x + y on [-pi/4, pi/4], where y is a tail of x, with the
+ * Taylor series and the evaluation order of k_cos:
+ *     cos(x + y) = w + (((1 - w) - x^2/2) + (x^4*(C2 + x^2*(C3 + ...)) - x*y))
+ * where w = 1 - x^2/2 is rounded and the first parenthesis is its error. */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_cosf128(x: F128, y: F128) -> F128 {
+    let z = x * x;
+    let mut r = F128::from_bits(C[C.len() - 1]);
+    for &c in C[..C.len() - 1].iter().rev() {
+        r = r * z + F128::from_bits(c);
+    }
+    r = r * z;
+    let hz = F128::from_bits(0x3ffe0000000000000000000000000000) * z;
+    let w = F128::ONE - hz;
+    w + (((F128::ONE - w) - hz) + (z * r - x * y))
+}
@@ -0,0 +1,121 @@
+use super::f128::{to_int, two_sum};
+use super::{roundf128, F128};
+
+/* 2^(j/32) for j = 0..31, as pairs hi, lo with hi + lo accurate to about
+ * 226 bits */
+const TBL: [u128; 64] = [
+    0x3fff0000000000000000000000000000,
+    0x00000000000000000000000000000000,
+    0x3fff059b0d31585743ae7c548eb68ca4,
+    0x3f8b7fe53e3495f7df4baf84a05e2a0d,
+    0x3fff0b5586cf9890f6298b92b71842aa,
+    0xbf8df26f5bafdd30c53d757515ea64eb,
+    0x3fff11301d0125b50a4ebbf1aed9318d,
+    0xbf8b53a33b854e9911a8bd8e8794f00a,
+    0x3fff172b83c7d517adcdf7c8c50eb14a,
+    0x3f8de480d5427fdf5d61a4fc8f09af74,
+    0x3fff1d4873168b9aa7805b8028990f08,
+    0xbf8d59d2f77e46e7a64c3eb8a3798e48,
+    0x3fff2387a6e75623866c1fadb1c15cb6,
+    0xbf8db13f35ea65bf482586c876fe1a85,
+    0x3fff29e9df51fdee12c25d15f5a24aa4,
+    0xbf8d0d5dbd4fdcb7f0049fd7e5024e5a,
+    0x3fff306fe0a31b7152de8d5a46305c86,
+    0xbf8b21343d8cbc9d60afd0e50e934682,
+    0x3fff371a7373aa9caa7145502f454798,
+    0x3f8df8f84945afe71a66f90cbb4ecd85,
+    0x3fff3dea64c12342235b41223e13d774,
+    0xbf89174d1f51f6ef660eaeef034400c2,
+    0x3fff44e086061892d03136f409df01a0,
+    0xbf8d0ac312de3d921e9b8a8d2c2c336f,
+    0x3fff4bfdad5362a271d4397afec42e21,
+    0xbf8bfc9c45d1ea63a8607d1b4ec6ceb0,
+    0x3fff5342b569d4f81df0a83c49d86a64,
+    0xbf8a6331ab837acff49f6d7368d8777f,
+    0x3fff5ab07dd48542958c93015191eb34,
+    0x3f8d76235f204a0381a7ef6d8b2f8a0c,
+    0x3fff6247eb03a5584b1f0fa06fd2da43,
+    0xbf8d138c541823376291d44341893b79,
+    0x3fff6a09e667f3bcc908b2fb1366ea95,
+    0x3f8df4f8eb7b05d449dd426768bd642c,
+    0x3fff71f75e8ec5f73dd2370f2ef0acd7,
+    0xbf8ca5e5a54e930baefa912cdd2876c1,
+    0x3fff7a11473eb0186d7d51023f6cda1f,
+    0x3f8d7bd0ad9a5de5814c7a086cd25f01,
+    0x3fff82589994cce128acf88afab34a01,
+    0x3f8aed5acb977581ea65a737bfbfb9cb,
+    0x3fff8ace5422aa0db5ba7c55a192c9bb,
+    0x3f8cf376b0f939998251a36c7686006e,
+    0x3fff93737b0cdc5e4f4501c3f2540a23,
+    0xbf8c681da853f24e0be6c880b22fdc00,
+    0x3fff9c49182a3f0901c7c46b071f2be6,
+    0xbf8dc89486bcf7a39e4dbd2ea21633a8,
+    0x3fffa5503b23e255c8b424491caf87bd,
+    0xbf8dfebd6feb1fa3f0420d402c4ce7e6,
+    0x3fffae89f995ad3ad5e8734d1773205a,
+    0x3f8dfef0eb99d7a9102c58b5ae09d6d0,
+    0x3fffb7f76f2fb5e46eaa7b081ab53c53,
+    0x3f8d532240f0d5b92d896ab309d868ee,
+    0x3fffc199bdd85529c2220cb12a091ba6,
+    0x3f8d9e51125928d998490010e5cce9f9,
+    0x3fffcb720dcef90691503cbd1e949db7,
+    0x3f8d8765566b032db74fb56677e6adb1,
+    0x3fffd5818dcfba48725da05aeb66e0dd,
+    0xbf8d5829d82a98fde240371f413970ba,
+    0x3fffdfc97337b9b5eb968cac39ed291b,
+    0x3f8dc896a513bf56ed5492e49c943842,
+    0x3fffea4afa2a490d9858f73a18f5db30,
+    0x3f8bf86dea20610ceee13eb7bb006527,
+    0x3ffff50765b6e4540674f84b762862bb,
+    0xbf859bffc80ef2b517591dbdda6b205b,
+];
+
+/* 1/n! for n = 2..14, enough for |r| <= ln2/64 */
+const P: [u128; 13] = [
+    0x3ffe0000000000000000000000000000, /* 1/2! */
+    0x3ffc5555555555555555555555555555, /* 1/3! */
+    0x3ffa5555555555555555555555555555, /* 1/4! */
+    0x3ff81111111111111111111111111111, /* 1/5! */
+    0x3ff56c16c16c16c16c16c16c16c16c17, /* 1/6! */
+    0x3ff2a01a01a01a01a01a01a01a01a01a, /* 1/7! */
+    0x3fefa01a01a01a01a01a01a01a01a01a, /* 1/8! */
+    0x3fec71de3a556c7338faac1c88e50017, /* 1/9! */
+    0x3fe927e4fb7789f5c72ef016d3ea6679, /* 1/10! */
+    0x3fe5ae64567f544e38fe747e4b837dc7, /* 1/11! */
+    0x3fe21eed8eff8d897b544da987acfe85, /* 1/12! */
+    0x3fde6124613a86d097ca38331d23af68, /* 1/13! */
+    0x3fda93974a8c07c9d20badf145dfa3e5, /* 1/14! */
+];
+
+/* ln2/32 as LN2_32_HI + LN2_32_LO, where LN2_32_HI has 20 trailing zeros so
+ * that k*LN2_32_HI is exact for |k| < 2^20 */
+const LN2_32_HI: F128 = F128::from_bits(0x3ff962e42fefa39ef35793c767300000);
+const LN2_32_LO: F128 = F128::from_bits(0x3f93f97b57a079a193394c5b16c5068c);
+const INV_LN2_32: F128 = F128::from_bits(0x400471547652b82fe1777d0ffda0d23a);
+
+/* Returns (k, a, b) with exp(hi + lo) = 2^k * (a + b), where a is a table
+ * entry and b is small, for |hi| < 11500.
+ *
+ * With x = hi + lo = (32*k + j)*ln2/32 + r and |r| <= ln2/64,
+ * exp(x) = 2^k * 2^(j/32) * (1 + expm1(r)). The relative error of a + b is
+ * below 2^-120. */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_expf128(hi: F128, lo: F128) -> (i32, F128, F128) {
+    let kf = roundf128(hi * INV_LN2_32);
+    let k = to_int(kf);
+    /* hi - kf*LN2_32_HI is exact */
+    let (r, rlo) = two_sum(hi - kf * LN2_32_HI, lo - kf * LN2_32_LO);
+
+    /* expm1(r) = r + r^2 * (1/2! + r/3! + ...) */
+    let mut p = F128::from_bits(P[P.len() - 1]);
+    for &c in P.iter().rev().skip(1) {
+        p = p * r + F128::from_bits(c);
+    }
+    let q = r + (rlo + r * r * p);
+
+    let j = (k & 31) as usize;
+    let a = F128::from_bits(TBL[2 * j]);
+    let b = F128::from_bits(TBL[2 * j + 1]) + a * q;
+    (k >> 5, a, b)
+}
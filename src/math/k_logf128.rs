@@ -0,0 +1,88 @@
+use super::f128::{
+    dd_add, dd_div, dd_mul, fast_two_sum, split, two_prod, two_sum, BIAS, SIG_BITS, SIG_MASK,
+};
+use super::F128;
+
+/* ln2 as LN2_HI + LN2_LO, where LN2_HI has 15 trailing zeros so that e*LN2_HI
+ * is exact for every exponent e */
+const LN2_HI: F128 = F128::from_bits(0x3ffe62e42fefa39ef35793c767300000);
+const LN2_LO: F128 = F128::from_bits(0x3f98f97b57a079a193394c5b16c5068c);
+const SQRT2: u128 = 0x3fff6a09e667f3bcc908b2fb1366ea95;
+
+/* 2/3 and 2/5 as pairs hi, lo */
+const C3: (F128, F128) = (
+    F128::from_bits(0x3ffe5555555555555555555555555555),
+    F128::from_bits(0x3f8c5555555555555555555555555555),
+);
+const C5: (F128, F128) = (
+    F128::from_bits(0x3ffd999999999999999999999999999a),
+    F128::from_bits(0xbf8b999999999999999999999999999a),
+);
+
+/* 2/(2n+1) for n = 3..25 */
+const TAIL: [u128; 23] = [
+    0x3ffd2492492492492492492492492492, /* 2/7 */
+    0x3ffcc71c71c71c71c71c71c71c71c71c, /* 2/9 */
+    0x3ffc745d1745d1745d1745d1745d1746, /* 2/11 */
+    0x3ffc3b13b13b13b13b13b13b13b13b14, /* 2/13 */
+    0x3ffc1111111111111111111111111111, /* 2/15 */
+    0x3ffbe1e1e1e1e1e1e1e1e1e1e1e1e1e2, /* 2/17 */
+    0x3ffbaf286bca1af286bca1af286bca1b, /* 2/19 */
+    0x3ffb8618618618618618618618618618, /* 2/21 */
+    0x3ffb642c8590b21642c8590b21642c86, /* 2/23 */
+    0x3ffb47ae147ae147ae147ae147ae147b, /* 2/25 */
+    0x3ffb2f684bda12f684bda12f684bda13, /* 2/27 */
+    0x3ffb1a7b9611a7b9611a7b9611a7b961, /* 2/29 */
+    0x3ffb0842108421084210842108421084, /* 2/31 */
+    0x3ffaf07c1f07c1f07c1f07c1f07c1f08, /* 2/33 */
+    0x3ffad41d41d41d41d41d41d41d41d41d, /* 2/35 */
+    0x3ffabacf914c1bacf914c1bacf914c1c, /* 2/37 */
+    0x3ffaa41a41a41a41a41a41a41a41a41a, /* 2/39 */
+    0x3ffa8f9c18f9c18f9c18f9c18f9c18fa, /* 2/41 */
+    0x3ffa7d05f417d05f417d05f417d05f41, /* 2/43 */
+    0x3ffa6c16c16c16c16c16c16c16c16c17, /* 2/45 */
+    0x3ffa5c9882b9310572620ae4c415c988, /* 2/47 */
+    0x3ffa4e5e0a72f05397829cbc14e5e0a7, /* 2/49 */
+    0x3ffa4141414141414141414141414141, /* 2/51 */
+];
+
+/* Returns (e, hi, lo) with log(x) = e*ln2 + hi + lo, for finite x > 0.
+ *
+ * With x = 2^e * m and m in [sqrt(2)/2, sqrt(2)), log(m) = 2*atanh(u) with
+ * u = (m-1)/(m+1) and |u| < 0.172, and
+ *     2*atanh(u) = 2u + 2u^3/3 + 2u^5/5 + u^7 * (2/7 + 2u^2/9 + ...).
+ * The first three terms are summed in double-F128 arithmetic, so the relative
+ * error of hi + lo is about 2^-220. */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_logf128(x: F128) -> (i32, F128, F128) {
+    let (mx, ex) = split(x);
+    let mut e = ex + SIG_BITS as i32;
+    let mut m = (BIAS as u128) << SIG_BITS | mx & SIG_MASK;
+    if m > SQRT2 {
+        m -= 1 << SIG_BITS;
+        e += 1;
+    }
+    let m = F128::from_bits(m);
+
+    /* m - 1 is exact */
+    let u = dd_div((m - F128::ONE, F128::ZERO), two_sum(m, F128::ONE));
+    let z = dd_mul(u, u);
+    let mut t = F128::from_bits(TAIL[TAIL.len() - 1]);
+    for &c in TAIL.iter().rev().skip(1) {
+        t = t * z.0 + F128::from_bits(c);
+    }
+    let mut s = dd_add(C5, two_prod(z.0, t));
+    s = dd_add(C3, dd_mul(z, s));
+    s = dd_add((F128::from(2), F128::ZERO), dd_mul(z, s));
+    let (hi, lo) = dd_mul(u, s);
+    (e, hi, lo)
+}
+
+/* Returns e*ln2 as a pair hi, lo */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn ln2_mul(e: i32) -> (F128, F128) {
+    let e = F128::from(e);
+    fast_two_sum(e * LN2_HI, e * LN2_LO)
+}
@@ -0,0 +1,103 @@
+/* Helpers for the functions that work in units of pi (sinpi, asinpi, ...)
+ * or of pi/180 (sind, asind, ...). */
+
+use super::fenv::underflow_if_tiny;
+use super::{floor, fmod};
+
+/* pi split so that PI_HI has 26 significant bits, and PI_LO = pi - PI_HI */
+const PI_HI: f64 = 3.1415926218032837; /* 0x400921FB, 0x50000000 */
+const PI_LO: f64 = 3.178650954705639e-08; /* 0x3E6110B4, 0x611A6263 */
+
+/* 1/pi = INV_PI_HI + INV_PI_MID + INV_PI_LO, with INV_PI_HI having 26 significant bits */
+const INV_PI: f64 = core::f64::consts::FRAC_1_PI; /* 0x3FD45F30, 0x6DC9C883 */
+const INV_PI_HI: f64 = 0.31830988079309464; /* 0x3FD45F30, 0x68000000 */
+const INV_PI_MID: f64 = 5.3906960562066786e-09; /* 0x3E372722, 0x0C000000 */
+const INV_PI_LO: f64 = -1.9678676675182486e-17; /* 0xBC76B01E, 0xC5417056 */
+
+/* pi/180 split so that D2R_HI has 26 significant bits, and D2R_LO = pi/180 - D2R_HI */
+const D2R_HI: f64 = 0.01745329238474369; /* 0x3F91DF46, 0xA0000000 */
+const D2R_LO: f64 = 1.3519960527851425e-10; /* 0x3DE294E9, 0xC8AE0EC6 */
+
+/* 180/pi = R2D_HI + R2D_MID + R2D_LO, with R2D_HI having 26 significant bits */
+const R2D: f64 = 57.29577951308232; /* 0x404CA5DC, 0x1A63C1F8 */
+const R2D_HI: f64 = 57.29577922821045; /* 0x404CA5DC, 0x18000000 */
+const R2D_MID: f64 = 2.848718736458977e-07; /* 0x3E931E0F, 0xC0000000 */
+const R2D_LO: f64 = -1.9878495670576283e-15; /* 0xBCE1E7AB, 0x456405F9 */
+
+const SPLIT: f64 = 134217729.0; /* 0x1p27 + 1 */
+
+/// Reduces `x`, with `0 <= x < 2^52`, modulo 2. Returns `(n, f)` such that
+/// `x = 2k + n/2 + f` for some integer `k`, with `n` in `0..4` and
+/// `|f| <= 1/4`. The reduction is exact.
+#[inline]
+pub(crate) fn rem_half(x: f64) -> (i32, f64) {
+    /* x mod 2; spurious inexact when x is an odd integer */
+    let r = 2.0 * (x * 0.5 - floor(x * 0.5));
+    let n = ((4.0 * r) as i32 + 1) / 2;
+    (n & 3, r - (n as f64) * 0.5)
+}
+
+/// Computes `pi * x` as an unevaluated sum `hi + lo` with a relative error
+/// of about 2^-100. `|x|` must be at most 1 and at least 2^-960.
+#[inline]
+pub(crate) fn mul_pi(x: f64) -> (f64, f64) {
+    let c = x * SPLIT;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let hi = xh * PI_HI;
+    let lo = xl * PI_HI + x * PI_LO;
+    let s = hi + lo;
+    (s, lo - (s - hi))
+}
+
+/// Computes `x / pi` with an error of little more than half an ulp.
+#[inline]
+pub(crate) fn div_pi(x: f64) -> f64 {
+    if x == 0.0 {
+        return x;
+    }
+    let c = x * SPLIT;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let p = x * INV_PI;
+    let e = ((xh * INV_PI_HI - p) + xh * INV_PI_MID + xl * INV_PI_HI) + xl * INV_PI_MID;
+    underflow_if_tiny(p + (e + x * INV_PI_LO))
+}
+
+/// Reduces a finite `x >= 0` modulo 360. Returns `(n, f)` such that
+/// `x = 360k + 90n + f` for some integer `k`, with `n` in `0..4` and
+/// `|f| <= 45`. The reduction is exact.
+#[inline]
+pub(crate) fn rem_90(x: f64) -> (i32, f64) {
+    let r = fmod(x, 360.0);
+    let n = ((r / 45.0) as i32 + 1) / 2;
+    (n & 3, r - (n as f64) * 90.0)
+}
+
+/// Computes `x * pi/180` as an unevaluated sum `hi + lo` with a relative
+/// error of about 2^-100. `|x|` must be at most 45 and at least 2^-960.
+#[inline]
+pub(crate) fn deg_to_rad(x: f64) -> (f64, f64) {
+    let c = x * SPLIT;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let hi = xh * D2R_HI;
+    let lo = xl * D2R_HI + x * D2R_LO;
+    let s = hi + lo;
+    (s, lo - (s - hi))
+}
+
+/// Computes `x * 180/pi` with an error of little more than half an ulp.
+/// `|x|` must be at most 4.
+#[inline]
+pub(crate) fn rad_to_deg(x: f64) -> f64 {
+    if x == 0.0 {
+        return x;
+    }
+    let c = x * SPLIT;
+    let xh = c - (c - x);
+    let xl = x - xh;
+    let p = x * R2D;
+    let e = ((xh * R2D_HI - p) + xh * R2D_MID + xl * R2D_HI) + xl * R2D_MID;
+    underflow_if_tiny(p + (e + x * R2D_LO))
+}
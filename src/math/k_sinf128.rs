@@ -0,0 +1,36 @@
+use super::F128;
+
+/* (-1)^n/(2n+1)! for n = 1..15, enough for |x| <= pi/4 */
+const S: [u128; 15] = [
+    0xbffc5555555555555555555555555555, /* -1/3! */
+    0x3ff81111111111111111111111111111, /* 1/5! */
+    0xbff2a01a01a01a01a01a01a01a01a01a, /* -1/7! */
+    0x3fec71de3a556c7338faac1c88e50017, /* 1/9! */
+    0xbfe5ae64567f544e38fe747e4b837dc7, /* -1/11! */
+    0x3fde6124613a86d097ca38331d23af68, /* 1/13! */
+    0xbfd6ae7f3e733b81f11d8656b0ee8cb0, /* -1/15! */
+    0x3fce952c77030ad4a6b2605197771b00, /* 1/17! */
+    0xbfc62f49b4681415724ca1ec3b7b9675, /* -1/19! */
+    0x3fbd71b8ef6dcf5718bef146fcee6e45, /* 1/21! */
+    0xbfb4761b413163819d97b8704dd7f628, /* -1/23! */
+    0x3fab3f3ccdd165fa8d4e44a419776f11, /* 1/25! */
+    0xbfa1d1ab1c2dccea320a9a18f15d4277, /* -1/27! */
+    0x3f98259f98b4358ad7abe30e7766f129, /* 1/29! */
+    0xbf8e434d2e783f5bc42e1ee46fa6bfc4, /* -1/31! */
+];
+
+/* The sine of x + y on [-pi/4, pi/4], where y is a tail of x, with the
+ * Taylor series and the evaluation order of k_sin:
+ *     sin(x + y) = x + S1*x^3 + x^5*(S2 + x^2*(S3 + ...)) + y*(1 - x^2/2) */
+#[inline]
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn k_sinf128(x: F128, y: F128) -> F128 {
+    let z = x * x;
+    let v = z * x;
+    let mut r = F128::from_bits(S[S.len() - 1]);
+    for &c in S[1..S.len() - 1].iter().rev() {
+        r = r * z + F128::from_bits(c);
+    }
+    let half = F128::from_bits(0x3ffe0000000000000000000000000000);
+    x - ((z * (half * y - v * r) - y) - v * F128::from_bits(S[0]))
+}
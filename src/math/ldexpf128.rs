@@ -0,0 +1,6 @@
+use super::F128;
+
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexpf128(x: F128, n: i32) -> F128 {
+    super::scalbnf128(x, n)
+}
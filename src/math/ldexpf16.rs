@@ -0,0 +1,36 @@
+use super::{scalbn, F16};
+
+/// Multiplies `x` by `2^n` (F16)
+///
+/// The product is exact in `f64` and rounded once, in the current rounding
+/// direction, when it is subnormal or out of range.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ldexpf16(x: F16, n: i32) -> F16 {
+    /* beyond 2^64 every finite nonzero x overflows or underflows anyway */
+    F16::from_f64(scalbn(f64::from(x), n.clamp(-64, 64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(ldexpf16(F16::ONE, 15).to_bits(), 0x7800);
+        assert_eq!(ldexpf16(F16::ONE, -24).to_bits(), 0x0001);
+        assert_eq!(ldexpf16(F16::from_bits(0x0001), 24), F16::ONE);
+        /* 1.5 * 2^-24 rounds to even, 3 * 2^-26 to nearest */
+        assert_eq!(ldexpf16(F16::from_f32(1.5), -24).to_bits(), 0x0002);
+        assert_eq!(ldexpf16(F16::from_f32(3.0), -26).to_bits(), 0x0001);
+        assert_eq!(ldexpf16(F16::MAX, i32::MIN), F16::ZERO);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(ldexpf16(F16::ONE, 16), F16::INFINITY);
+        assert_eq!(ldexpf16(-F16::ONE, i32::MAX), F16::NEG_INFINITY);
+        assert_eq!(ldexpf16(F16::INFINITY, -100), F16::INFINITY);
+        assert!(ldexpf16(-F16::ZERO, 10).is_sign_negative());
+        assert!(ldexpf16(F16::NAN, 1).is_nan());
+    }
+}
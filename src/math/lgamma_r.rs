@@ -78,6 +78,7 @@
  *
  */
 
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
 use super::{floor, k_cos, k_sin, log};
 
 const PI: f64 = 3.14159265358979311600e+00; /* 0x400921FB, 0x54442D18 */
@@ -202,6 +203,7 @@ pub fn lgamma_r(mut x: f64) -> (f64, i32) {
         t = sin_pi(x);
         if t == 0.0 {
             /* -integer */
+            feraiseexcept(FE_DIVBYZERO);
             return (1.0 / (x - x), signgam);
         }
         if t > 0.0 {
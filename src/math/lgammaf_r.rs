@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
 use super::{floorf, k_cosf, k_sinf, logf};
 
 const PI: f32 = 3.1415927410e+00; /* 0x40490fdb */
@@ -137,6 +138,7 @@ pub fn lgammaf_r(mut x: f32) -> (f32, i32) {
         t = sin_pi(x);
         if t == 0.0 {
             /* -integer */
+            feraiseexcept(FE_DIVBYZERO);
             return (1.0 / (x - x), signgam);
         }
         if t > 0.0 {
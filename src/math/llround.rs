@@ -0,0 +1,50 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::round;
+
+/// Round to nearest integer, away from zero (f64)
+///
+/// Rounds `x` to the nearest integer, with halfway cases rounded away from
+/// zero, and returns it as an `i64` (the width of `long long`). Values that
+/// are out of range saturate, and NaN is converted to 0, raising the invalid
+/// exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn llround(x: f64) -> i64 {
+    let r = round(x);
+    if !(-9223372036854775808.0..9223372036854775808.0).contains(&r) {
+        /* NaN, or a value outside the range of i64 */
+        feraiseexcept(FE_INVALID);
+    }
+    r as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::llround;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(llround(2.5), 3);
+        assert_eq!(llround(-2.5), -3);
+        assert_eq!(llround(-0.4), 0);
+        assert_eq!(llround(1e300), i64::MAX);
+        assert_eq!(llround(f64::NEG_INFINITY), i64::MIN);
+        assert_eq!(llround(f64::NAN), 0);
+    }
+
+    #[test]
+    fn exceptions() {
+        use super::super::{with_flags, Flags};
+
+        let (r, f) = with_flags(|| llround(f64::NAN));
+        assert_eq!((r, f.contains(Flags::INVALID)), (0, true));
+        let (r, f) = with_flags(|| llround(-1e300));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MIN, true));
+        /* 2^63 */
+        let (r, f) = with_flags(|| llround(9223372036854775807.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MAX, true));
+        let (r, f) = with_flags(|| llround(-9223372036854775808.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MIN, false));
+        let (r, f) = with_flags(|| llround(2.5));
+        assert_eq!((r, f.contains(Flags::INVALID)), (3, false));
+    }
+}
@@ -60,6 +60,8 @@
  * to produce the hexadecimal values shown.
  */
 
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
+
 const LN2_HI: f64 = 6.93147180369123816490e-01; /* 3fe62e42 fee00000 */
 const LN2_LO: f64 = 1.90821492927058770002e-10; /* 3dea39ef 35793c76 */
 const LG1: f64 = 6.666666666666735130e-01; /* 3FE55555 55555593 */
@@ -81,10 +83,11 @@ pub fn log(mut x: f64) -> f64 {
     if (hx < 0x00100000) || ((hx >> 31) != 0) {
         /* x < 2**-126  */
         if ui << 1 == 0 {
+            feraiseexcept(FE_DIVBYZERO);
             return -1. / (x * x); /* log(+-0)=-inf */
         }
         if hx >> 31 != 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            return invalid(x); /* log(-#) = NaN */
         }
         /* subnormal number, scale x up */
         k -= 54;
@@ -17,6 +17,7 @@
  *    log10(x) = (f - f*f/2 + r)/log(10) + k*log10(2)
  */
 
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
 use core::f64;
 
 const IVLN10HI: f64 = 4.34294481878168880939e-01; /* 0x3fdbcb7b, 0x15200000 */
@@ -57,10 +58,11 @@ pub fn log10(mut x: f64) -> f64 {
     k = 0;
     if hx < 0x00100000 || (hx >> 31) > 0 {
         if ui << 1 == 0 {
+            feraiseexcept(FE_DIVBYZERO);
             return -1. / (x * x); /* log(+-0)=-inf */
         }
         if (hx >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            return invalid(x); /* log(-#) = NaN */
         }
         /* subnormal number, scale x up */
         k -= 54;
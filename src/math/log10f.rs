@@ -13,6 +13,7 @@
  * See comments in log10.c.
  */
 
+use super::fenv::{feraiseexcept, invalidf, FE_DIVBYZERO};
 use core::f32;
 
 const IVLN10HI: f32 = 4.3432617188e-01; /* 0x3ede6000 */
@@ -49,10 +50,11 @@ pub fn log10f(mut x: f32) -> f32 {
     if ix < 0x00800000 || (ix >> 31) > 0 {
         /* x < 2**-126  */
         if ix << 1 == 0 {
+            feraiseexcept(FE_DIVBYZERO);
             return -1. / (x * x); /* log(+-0)=-inf */
         }
         if (ix >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            return invalidf(x); /* log(-#) = NaN */
         }
         /* subnormal number, scale up x */
         k -= 25;
@@ -0,0 +1,183 @@
+/* log10(x) = e*log10(2) + log(m)/ln10, in double-F128 arithmetic; within
+ * 1 ulp */
+
+use super::f128::{dd_add, dd_mul, invalid, is_zero, quiet_nan};
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::k_logf128::k_logf128;
+use super::F128;
+
+/* log10(2) and 1/ln10 as pairs hi, lo */
+const LOG10_2: (F128, F128) = (
+    F128::from_bits(0x3ffd34413509f79fef311f12b35816f9),
+    F128::from_bits(0x3f8a17826ad30c543d1f3498a5e6f26b),
+);
+const INV_LN10: (F128, F128) = (
+    F128::from_bits(0x3ffdbcb7b1526e50e32a6ab7555f5a68),
+    F128::from_bits(0xbf8b1e6e08e5cfedd1b2efee2e0695d8),
+);
+
+/// The base 10 logarithm of `x` (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10f128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if is_zero(x) {
+        feraiseexcept(FE_DIVBYZERO);
+        return F128::NEG_INFINITY;
+    }
+    if x.is_sign_negative() {
+        return invalid();
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    if x == F128::ONE {
+        return F128::ZERO;
+    }
+    let (e, hi, lo) = k_logf128(x);
+    let (hi, lo) = dd_add(
+        dd_mul((F128::from(e), F128::ZERO), LOG10_2),
+        dd_mul((hi, lo), INV_LN10),
+    );
+    hi + lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 10, 1e4 and 3 */
+        assert_eq!(log10f128(f(0x40024000000000000000000000000000)), F128::ONE);
+        assert_eq!(
+            log10f128(f(0x400c3880000000000000000000000000)),
+            F128::from(4)
+        );
+        assert_eq!(
+            log10f128(f(0x40008000000000000000000000000000)).to_bits(),
+            0x3ffde8927964fd5fd08c30343a821a24
+        );
+        assert_eq!(
+            log10f128(f(1)).to_bits(),
+            0xc00b3653051d20c18a143b801b7c5661
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(log10f128(F128::ZERO), F128::NEG_INFINITY);
+        assert_eq!(log10f128(F128::INFINITY), F128::INFINITY);
+        assert!(log10f128(-F128::ONE).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x06b7ac219562abbc27ab589ad84301eb,
+            0xc00b13e149ced59d651a62accc4f3217,
+        ),
+        (
+            0x0ee59fcf1a3a806e015d0337077f2eee,
+            0xc00ad8f7908514912791049e3fd35e1f,
+        ),
+        (
+            0x12d9ceaa0e9c666a977f64cc79198426,
+            0xc00ab2e18676632ff5cba241f2df76ee,
+        ),
+        (
+            0x177b2047c9e37042b0b81f809ce0e622,
+            0xc00a864768c63ccb2c2c0e4be99f3e7e,
+        ),
+        (
+            0x180c08872eb583665d004b42de24f1e0,
+            0xc00a80d3d338b70690acc0a4d03a7982,
+        ),
+        (
+            0x190a50fb40f1f0efd82d9cb7258dc8ce,
+            0xc00a7741b0612e946cdba63a9b049d14,
+        ),
+        (
+            0x1d9ac7e9d55f1dd554006da85fd476ae,
+            0xc00a4b4a30e6c700f3bb9ccb67530d3f,
+        ),
+        (
+            0x1f6f9dd3e4ec009a4d175ce18400be1b,
+            0xc00a39a5adc9820f1c01fdb67796d033,
+        ),
+        (
+            0x2901b44d1d9f902c8da8dd5b5a5d240c,
+            0xc009bae8626f64047df174b5872db95d,
+        ),
+        (
+            0x2b2d56ff58185edea100c04f98a219e3,
+            0xc0099117388a73acf27f1d1f0a44bff2,
+        ),
+        (
+            0x2b7c61d1d91e0e014826b598d855925d,
+            0xc0098b24598a8e80162e83626a52cd97,
+        ),
+        (
+            0x2e2fc82e8fdce465c62bb30b70ace4ed,
+            0xc009571c8955539ae66ddd3b562184a3,
+        ),
+        (
+            0x2fc280d8babd497934ea3dca96e34dab,
+            0xc00938cd18e45a1598027037585d1c5c,
+        ),
+        (
+            0x35ac67e2625a4d36b972eaa891f3742c,
+            0xc0088dbcb7af15412904d120092d1599,
+        ),
+        (
+            0x3efcca091ab48d2e034ff283eb6f6fb4,
+            0xc00536db3f8ee29edf66e2adb1e10810,
+        ),
+        (
+            0x471476ad1c1153c9408ec88c2e58a32e,
+            0x400810f765ef35c98e523231b0065be7,
+        ),
+        (
+            0x47500130f9c3098460b0e9b054ec6e97,
+            0x400819ea64b3eadec00d2f63c2b1ed4e,
+        ),
+        (
+            0x4c0ce111f275c8f42940531ac9379e65,
+            0x4008d079caeab1728e13bc7bdf278de7,
+        ),
+        (
+            0x501cb286f304bb65c93ffd7e3e06cb53,
+            0x4009367e9fe4785cad14fcca9d8c4c5a,
+        ),
+        (
+            0x634845afdc64c50c566fbbc69d40cf86,
+            0x400a53e9df52f3441f3f24e001393bb2,
+        ),
+        (
+            0x6708d10ec9b4f431e6c7811144aeb199,
+            0x400a780e76e53c7d7d169a8fb9122856,
+        ),
+        (
+            0x6b4f7b55866af034477a99b6b874a8d4,
+            0x400aa13fb95597cee58fc1e2332a24c7,
+        ),
+        (
+            0x6fe324b729df37725c0f2f51fd97159c,
+            0x400acd55f352cd821fc86acccb58d70a,
+        ),
+        (
+            0x72141c126993fbe22b7d3e069fe86495,
+            0x400ae2719fd9365413e2ff09f6b2e61c,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(log10f128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,11 @@
+use super::fenv::narrow;
+use super::log10p1;
+
+/// Base 10 logarithm of 1+x (f32)
+///
+/// Calculates `log10(1 + x)`. The computation is carried out in double precision,
+/// which keeps the error close to half an ulp.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log10p1f(x: f32) -> f32 {
+    narrow(log10p1(x as f64))
+}
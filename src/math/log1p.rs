@@ -53,6 +53,7 @@
  *       See HP-15C Advanced Functions Handbook, p.193.
  */
 
+use super::fenv::{feraiseexcept, inexact_tiny, invalid, FE_DIVBYZERO};
 use core::f64;
 
 const LN2_HI: f64 = 6.93147180369123816490e-01; /* 3fe62e42 fee00000 */
@@ -89,16 +90,15 @@ pub fn log1p(x: f64) -> f64 {
         if hx >= 0xbff00000 {
             /* x <= -1.0 */
             if x == -1. {
+                feraiseexcept(FE_DIVBYZERO);
                 return x / 0.0; /* log1p(-1) = -inf */
             }
-            return (x - x) / 0.0; /* log1p(x<-1) = NaN */
+            return invalid(x); /* log1p(x<-1) = NaN */
         }
         if hx << 1 < 0x3ca00000 << 1 {
             /* |x| < 2**-53 */
             /* underflow if subnormal */
-            if (hx & 0x7ff00000) == 0 {
-                force_eval!(x as f32);
-            }
+            inexact_tiny(x);
             return x;
         }
         if hx <= 0xbfd2bec4 {
@@ -10,6 +10,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, inexact_tinyf, invalidf, FE_DIVBYZERO};
 use core::f32;
 
 const LN2_HI: f32 = 6.9313812256e-01; /* 0x3f317180 */
@@ -44,16 +45,15 @@ pub fn log1pf(x: f32) -> f32 {
         if ix >= 0xbf800000 {
             /* x <= -1.0 */
             if x == -1. {
+                feraiseexcept(FE_DIVBYZERO);
                 return x / 0.0; /* log1p(-1)=+inf */
             }
-            return (x - x) / 0.0; /* log1p(x<-1)=NaN */
+            return invalidf(x); /* log1p(x<-1)=NaN */
         }
         if ix << 1 < 0x33800000 << 1 {
             /* |x| < 2**-24 */
             /* underflow if subnormal */
-            if (ix & 0x7f800000) == 0 {
-                force_eval!(x * x);
-            }
+            inexact_tinyf(x);
             return x;
         }
         if ix <= 0xbe95f619 {
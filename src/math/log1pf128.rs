@@ -0,0 +1,179 @@
+/* log1p(x) = log(a) + b/a, where a + b = 1 + x exactly; within 1 ulp */
+
+use super::f128::{dd_add, invalid, quiet_nan, two_sum};
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::k_logf128::{k_logf128, ln2_mul};
+use super::F128;
+
+/// The natural logarithm of 1+`x` (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log1pf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x.is_infinite() && !x.is_sign_negative() {
+        return x;
+    }
+    if x == -F128::ONE {
+        feraiseexcept(FE_DIVBYZERO);
+        return F128::NEG_INFINITY;
+    }
+    if x < -F128::ONE {
+        return invalid();
+    }
+    /* x itself for |x| < 2^-114, also keeping the sign of zero */
+    if x.to_bits() << 1 < 0x3f8d0000000000000000000000000000 << 1 {
+        return x;
+    }
+
+    /* 1 + x = a + b exactly, and log(a + b) = log(a) + b/a to 2^-226 */
+    let (a, b) = two_sum(F128::ONE, x);
+    let (e, hi, lo) = k_logf128(a);
+    let (hi, lo) = dd_add(ln2_mul(e), dd_add((hi, lo), (b / a, F128::ZERO)));
+    hi + lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 2^-100, 1e-20 and 1 */
+        assert_eq!(
+            log1pf128(f(0x3f9b0000000000000000000000000000)).to_bits(),
+            0x3f9afffffffffffffffffffffffff000
+        );
+        assert_eq!(
+            log1pf128(f(0x3fbc79ca10c9242235d511e976394d7a)).to_bits(),
+            0x3fbc79ca10c9242235d4ef1129ea5ecb
+        );
+        assert_eq!(
+            log1pf128(F128::ONE).to_bits(),
+            0x3ffe62e42fefa39ef35793c7673007e6
+        );
+        assert_eq!(
+            log1pf128(f(0xbffeffffffffffffffffffffffffffff)).to_bits(),
+            0xc005394d72518e7252d35076091866f9
+        );
+        let z = log1pf128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(log1pf128(-F128::ONE), F128::NEG_INFINITY);
+        assert_eq!(log1pf128(F128::INFINITY), F128::INFINITY);
+        assert!(log1pf128(f(0xbfff0000000000000000000000000001)).is_nan());
+        assert!(log1pf128(F128::NEG_INFINITY).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fe1749e1bb0bee32f07f26fd2c8075e,
+            0x3fe1749e1bac822b6ad8acd520d0030d,
+        ),
+        (
+            0x3fe21c81c05f8926d8de3af05a71bfb4,
+            0x3fe21c81c05a9866469dded1c9e5836e,
+        ),
+        (
+            0x3fe292beccf45f16c24d628763c8b228,
+            0x3fe292beccea78a79b0f0de8b12c796d,
+        ),
+        (
+            0x3fe2c515ba45a2e1859f82b2d7cdb1e4,
+            0x3fe2c515ba391b49e9cba26519b160dd,
+        ),
+        (
+            0x3fe482e261eaa52094e5e30afbf0386a,
+            0x3fe482e261c61a2921f5ef2e7b4a275a,
+        ),
+        (
+            0x3fe9e1816a8b2f17ee646ba796651c89,
+            0x3fe9e1816377e013d430347c960ea04c,
+        ),
+        (
+            0x3ff6a49cc8252fc4ab1443ef7430149b,
+            0x3ff6a3f061d5b1679bae5eb2da3d3aad,
+        ),
+        (
+            0x3ffa330a8ac3126b1fef502890416e8f,
+            0x3ffa2d6d4f0069197eb510a9e6ffdd94,
+        ),
+        (
+            0x3ffca7553f19df4275fa7b4790007c7c,
+            0x3ffc80ce8df99603cacd05f3d192a903,
+        ),
+        (
+            0x3fff446e891a04d0a59d04415ba104d9,
+            0x3ffea31edc8aec693706a1cc37d1cc94,
+        ),
+        (
+            0x3fffc84b2cc5ba414bbff3fd3e508175,
+            0x3fff05f7d41f578ff82cbbc9d3b19ed1,
+        ),
+        (
+            0x400048a6dcc8412699e330931b0d5824,
+            0x3fff459aa4c68fdee4a431bca1ca4618,
+        ),
+        (
+            0x4003ea97cfcfaec095f2058fe559819d,
+            0x4000ba4158c918bfe7502aabd131d6e4,
+        ),
+        (
+            0x400577490f7ab5f01cfc7ee56c413c9b,
+            0x40012353e10dc11b496d76138dea4a4f,
+        ),
+        (
+            0xbfe406b22ce5efa7c0dab7ce97432db8,
+            0xbfe406b22cf6c8bac0dd6d883861e327,
+        ),
+        (
+            0xbfe5045e5e40e4c954ec0ab180dc7740,
+            0xbfe5045e5e61fec3a22ddb9a70f064d2,
+        ),
+        (
+            0xbfe7e7c8f5f88520950049a972c23c2a,
+            0xbfe7e7c8f7c93c3dafb6a1c8bf5c1588,
+        ),
+        (
+            0xbfe8a9ef9e101a08c0a4ee4eb613b83c,
+            0xbfe8a9efa0d4c789fec3885f10242ecd,
+        ),
+        (
+            0xbfe8eb9a4e8521640c037bf79b30f226,
+            0xbfe8eb9a52352ab3b265af8faa113e8d,
+        ),
+        (
+            0xbfe90a32f42233a28cafda7a61b8df45,
+            0xbfe90a32f64bcf83827de8a132b8391c,
+        ),
+        (
+            0xbff25992c59a7086f58f98188353a5e7,
+            0xbff2599a0fc29f023c284a1cb9e75b85,
+        ),
+        (
+            0xbffb6f7ae5c4d6d019308c642d1aeb7d,
+            0xbffb8105a7c82e1a126ba2d5a27e882b,
+        ),
+        (
+            0xbffce04883d26cde8bfa7e78d4db3f87,
+            0xbffd11a8448acf6bf906a5df190eb5b6,
+        ),
+        (
+            0xbffe3303cea4b154e27dea3cac532d36,
+            0xbffed4ada15d2b47c1ef29a5f3a45c89,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(log1pf128, &REFERENCE, 1);
+    }
+}
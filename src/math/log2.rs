@@ -17,6 +17,7 @@
  *    log2(x) = (f - f*f/2 + r)/log(2) + k
  */
 
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
 use core::f64;
 
 const IVLN2HI: f64 = 1.44269504072144627571e+00; /* 0x3ff71547, 0x65200000 */
@@ -54,10 +55,11 @@ pub fn log2(mut x: f64) -> f64 {
     k = 0;
     if hx < 0x00100000 || (hx >> 31) > 0 {
         if ui << 1 == 0 {
+            feraiseexcept(FE_DIVBYZERO);
             return -1. / (x * x); /* log(+-0)=-inf */
         }
         if (hx >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            return invalid(x); /* log(-#) = NaN */
         }
         /* subnormal number, scale x up */
         k -= 54;
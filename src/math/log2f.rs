@@ -13,6 +13,7 @@
  * See comments in log2.c.
  */
 
+use super::fenv::{feraiseexcept, invalidf, FE_DIVBYZERO};
 use core::f32;
 
 const IVLN2HI: f32 = 1.4428710938e+00; /* 0x3fb8b000 */
@@ -46,10 +47,11 @@ pub fn log2f(mut x: f32) -> f32 {
     if ix < 0x00800000 || (ix >> 31) > 0 {
         /* x < 2**-126  */
         if ix << 1 == 0 {
+            feraiseexcept(FE_DIVBYZERO);
             return -1. / (x * x); /* log(+-0)=-inf */
         }
         if (ix >> 31) > 0 {
-            return (x - x) / 0.0; /* log(-#) = NaN */
+            return invalidf(x); /* log(-#) = NaN */
         }
         /* subnormal number, scale up x */
         k -= 25;
@@ -0,0 +1,176 @@
+/* log2(x) = e + log(m)/ln2, in double-F128 arithmetic; within 1 ulp */
+
+use super::f128::{dd_add, dd_mul, invalid, is_zero, quiet_nan};
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::k_logf128::k_logf128;
+use super::F128;
+
+/* 1/ln2 as a pair hi, lo */
+const INV_LN2: (F128, F128) = (
+    F128::from_bits(0x3fff71547652b82fe1777d0ffda0d23a),
+    F128::from_bits(0x3f8df4475abbd546eb4ad2c45928b367),
+);
+
+/// The base 2 logarithm of `x` (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2f128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if is_zero(x) {
+        feraiseexcept(FE_DIVBYZERO);
+        return F128::NEG_INFINITY;
+    }
+    if x.is_sign_negative() {
+        return invalid();
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    if x == F128::ONE {
+        return F128::ZERO;
+    }
+    /* e is added exactly, so powers of two give exact results */
+    let (e, hi, lo) = k_logf128(x);
+    let (hi, lo) = dd_add((F128::from(e), F128::ZERO), dd_mul((hi, lo), INV_LN2));
+    hi + lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(
+            log2f128(f(0x40020000000000000000000000000000)),
+            F128::from(3)
+        );
+        assert_eq!(log2f128(f(1)), F128::from(-16494));
+        /* log2(3) and log2(MAX) */
+        assert_eq!(
+            log2f128(f(0x40008000000000000000000000000000)).to_bits(),
+            0x3fff95c01a39fbd6879fa00b120a068c
+        );
+        assert_eq!(
+            log2f128(F128::MAX).to_bits(),
+            0x400d0000000000000000000000000000
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(log2f128(F128::ZERO), F128::NEG_INFINITY);
+        assert_eq!(log2f128(F128::INFINITY), F128::INFINITY);
+        assert!(log2f128(-F128::ONE).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x022f91f8fcd46938a88a555faac48fea,
+            0xc00cee7acad8f3e1487ee8c9ad5fc8e9,
+        ),
+        (
+            0x05fe383b20362f832285909e1835c31c,
+            0xc00cd005b54f53bfac5a1d44648aee07,
+        ),
+        (
+            0x077322161f27b63679086c546586b4da,
+            0xc00cc45e8eaa78baba1a7a8860621eaf,
+        ),
+        (
+            0x095ff017bba4990f1e412ac5092e73fd,
+            0xc00cb4f85d40f654584c472ddbc97fb5,
+        ),
+        (
+            0x132c40253835a931cca824fe964fae23,
+            0xc00c66956b59622df73960afc9909c60,
+        ),
+        (
+            0x1b9708c6ce89e457f791117bd959025f,
+            0xc00c233f9c66f3fc82fad2191c5004a8,
+        ),
+        (
+            0x1df86bd96f73e8d7b7b9dc20c9c1dac6,
+            0xc00c1033f142768cd7d4787470348e7f,
+        ),
+        (
+            0x2996ab79dd1ece769e919a48ec0dae6a,
+            0xc00b66842a30a15b5d75edc028de346e,
+        ),
+        (
+            0x30e0ff817d22b8f210d88f54626e8103,
+            0xc00ae3c00b69aeeb1ae3e015012e6ed7,
+        ),
+        (
+            0x34be317d77a6730a1830a3dfd06cbd6c,
+            0xc00a6817d7304d91df75d517422f8038,
+        ),
+        (
+            0x36a1d4d37fecbd09d218ef62b9fb0633,
+            0xc00a2ba41120865bd5945e530060c1a1,
+        ),
+        (
+            0x3d118048e2b35a453306c4f6af8a0db1,
+            0xc00876b4fce945f2e4e2d686201f3254,
+        ),
+        (
+            0x3e2367f15dba14e270afceeea38e184b,
+            0xc007db8224ee07f550da2f867f5fff25,
+        ),
+        (
+            0x571078489e23f57bb56829c0e1ef8c51,
+            0x400b7118e40d66e583887b2e2e51c4b9,
+        ),
+        (
+            0x572a87a26d741efd0fa7bf752476e980,
+            0x400b72b9d056c1775420ddb2b7d0d9cd,
+        ),
+        (
+            0x586e69eb34c2c3aee8f065b1513c6da9,
+            0x400b86f7fe0aec74ab0997b2591fb0de,
+        ),
+        (
+            0x5d5faf2534d1eeb7f60ca986764100aa,
+            0x400bd60c0851196add56846fb3b1d3f9,
+        ),
+        (
+            0x6406bf6f6b5430c75b99978d745a4966,
+            0x400c203e71bc5c3450b6ea3a058a53d9,
+        ),
+        (
+            0x66197fd6b4fba1ea8069a4958b156c29,
+            0x400c30d4acc3075ebc84525917051eaa,
+        ),
+        (
+            0x71b244617346eec463d6355b19f15219,
+            0x400c8d9abb7b6e5a51d4ac148c12712c,
+        ),
+        (
+            0x728ac05e5c9e197a77307cc6731e3bc8,
+            0x400c945e77e493000427bf57b85407a8,
+        ),
+        (
+            0x7666accef4dbd0d2bc314f09023a13f3,
+            0x400cb33df419976d6334b561fe7d64d1,
+        ),
+        (
+            0x7c203eb8b89ea4ca839d0447ecd9eb03,
+            0x400ce10a877b234e23baa83bca7a1790,
+        ),
+        (
+            0x7e53454936401744b5f7d2c5ba15c66a,
+            0x400cf2a2c3b7811c7b105cefb7da2616,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(log2f128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,36 @@
+use super::f16::from_f64_round;
+use super::{log2, Round, F16};
+
+/// The base 2 logarithm of `x` (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2f16(x: F16) -> F16 {
+    from_f64_round(log2(f64::from(x)), Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::super::frexpf16;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(log2f16(F16::from_f32(3.0)).to_bits(), 0x3e57);
+        assert_eq!(log2f16(F16::MAX).to_bits(), 0x4c00);
+        assert_eq!(log2f16(F16::from_bits(1)).to_bits(), 0xce00);
+        assert_eq!(log2f16(F16::from_f32(0.1)).to_bits(), 0xc2a5);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(log2f16(-F16::ZERO), F16::NEG_INFINITY);
+        assert!(log2f16(F16::NEG_INFINITY).is_nan());
+        assert_eq!(log2f16(F16::INFINITY), F16::INFINITY);
+        assert!(log2f16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(log2f16, log2, |x| frexpf16(x).0.to_bits() == 0x3800);
+    }
+}
@@ -0,0 +1,155 @@
+/*
+ * Return the base 2 logarithm of 1+x.
+ *
+ * Reduce 1+x to 2^k (1+f) with the correction term c as in log1p.c,
+ * calculate r = log(1+f) - f + f*f/2 as in log.c, then combine and
+ * scale in extra precision as in log2.c:
+ *    log2p1(x) = (f - f*f/2 + r + c)/log(2) + k
+ */
+
+use super::fenv::{feraiseexcept, invalid, underflow_if_tiny, FE_DIVBYZERO};
+use core::f64;
+
+const IVLN2HI: f64 = 1.44269504072144627571e+00; /* 0x3ff71547, 0x65200000 */
+const IVLN2LO: f64 = 1.67517131648865118353e-10; /* 0x3de705fc, 0x2eefa200 */
+const LG1: f64 = 6.666666666666735130e-01; /* 3FE55555 55555593 */
+const LG2: f64 = 3.999999999940941908e-01; /* 3FD99999 9997FA04 */
+const LG3: f64 = 2.857142874366239149e-01; /* 3FD24924 94229359 */
+const LG4: f64 = 2.222219843214978396e-01; /* 3FCC71C5 1D8E78AF */
+const LG5: f64 = 1.818357216161805012e-01; /* 3FC74664 96CB03DE */
+const LG6: f64 = 1.531383769920937332e-01; /* 3FC39A09 D078C69F */
+const LG7: f64 = 1.479819860511658591e-01; /* 3FC2F112 DF3E5244 */
+
+/// Base 2 logarithm of 1+x (f64)
+///
+/// Calculates `log2(1 + x)` accurately even for `x` near zero, without rounding
+/// `log1p(x)` before the change of base. The result is exact when `1 + x` is a
+/// power of two.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1(x: f64) -> f64 {
+    let mut ui: u64 = x.to_bits();
+    let hfsq: f64;
+    let mut f: f64 = 0.;
+    let mut c: f64 = 0.;
+    let s: f64;
+    let z: f64;
+    let r: f64;
+    let mut w: f64;
+    let t1: f64;
+    let t2: f64;
+    let y: f64;
+    let mut hi: f64;
+    let lo: f64;
+    let mut val_hi: f64;
+    let mut val_lo: f64;
+    let hx: u32;
+    let mut hu: u32;
+    let mut k: i32;
+
+    hx = (ui >> 32) as u32;
+    k = 1;
+    if hx < 0x3fda827a || (hx >> 31) > 0 {
+        /* 1+x < sqrt(2)+ */
+        if hx >= 0xbff00000 {
+            /* x <= -1.0 */
+            if x == -1. {
+                feraiseexcept(FE_DIVBYZERO);
+                return x / 0.0; /* log2p1(-1) = -inf */
+            }
+            return invalid(x); /* log2p1(x<-1) = NaN */
+        }
+        if hx << 1 < 0x3ca00000 << 1 {
+            /* |x| < 2**-53 */
+            if x == 0.0 {
+                return x;
+            }
+            return underflow_if_tiny(x * f64::consts::LOG2_E);
+        }
+        if hx <= 0xbfd2bec4 {
+            /* sqrt(2)/2- <= 1+x < sqrt(2)+ */
+            k = 0;
+            c = 0.;
+            f = x;
+        }
+    } else if hx >= 0x7ff00000 {
+        return x;
+    }
+    if k > 0 {
+        ui = (1. + x).to_bits();
+        hu = (ui >> 32) as u32;
+        hu += 0x3ff00000 - 0x3fe6a09e;
+        k = (hu >> 20) as i32 - 0x3ff;
+        /* correction term ~ log(1+x)-log(u), avoid underflow in c/u */
+        if k < 54 {
+            c = if k >= 2 {
+                1. - (f64::from_bits(ui) - x)
+            } else {
+                x - (f64::from_bits(ui) - 1.)
+            };
+            c /= f64::from_bits(ui);
+        } else {
+            c = 0.;
+        }
+        /* reduce u into [sqrt(2)/2, sqrt(2)] */
+        hu = (hu & 0x000fffff) + 0x3fe6a09e;
+        ui = (hu as u64) << 32 | (ui & 0xffffffff);
+        f = f64::from_bits(ui) - 1.;
+    }
+    hfsq = 0.5 * f * f;
+    s = f / (2.0 + f);
+    z = s * s;
+    w = z * z;
+    t1 = w * (LG2 + w * (LG4 + w * LG6));
+    t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    r = t2 + t1;
+
+    /* hi+lo = f - hfsq + s*(hfsq+R) + c ~ log(1+f) + c */
+    hi = f - hfsq;
+    ui = hi.to_bits();
+    ui &= (-1i64 as u64) << 32;
+    hi = f64::from_bits(ui);
+    lo = f - hi - hfsq + s * (hfsq + r) + c;
+
+    val_hi = hi * IVLN2HI;
+    val_lo = (lo + hi) * IVLN2LO + lo * IVLN2HI;
+
+    /* spadd(val_hi, val_lo, y), except for not using double_t: */
+    y = k.into();
+    w = y + val_hi;
+    val_lo += (y - w) + val_hi;
+    val_hi = w;
+
+    val_lo + val_hi
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert!(log2p1(NAN).is_nan());
+        assert!(log2p1(-2.0).is_nan());
+        assert!(log2p1(NEG_INFINITY).is_nan());
+        assert_eq!(log2p1(INFINITY), INFINITY);
+        assert_eq!(log2p1(-1.0), NEG_INFINITY);
+        assert_eq!(log2p1(0.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(log2p1(-0.0).to_bits(), (-0.0f64).to_bits());
+    }
+
+    #[test]
+    fn exact_powers_of_two() {
+        assert_eq!(log2p1(1.0), 1.0);
+        assert_eq!(log2p1(7.0), 3.0);
+        assert_eq!(log2p1(-0.5), -1.0);
+        assert_eq!(log2p1(-0.75), -2.0);
+        assert_eq!(log2p1(MAX), 1024.0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(log2p1(0.5), 0.5849625007211562);
+        assert_eq!(log2p1(1e-20), 1.4426950408889633e-20);
+    }
+}
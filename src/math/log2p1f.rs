@@ -0,0 +1,11 @@
+use super::fenv::narrow;
+use super::log2p1;
+
+/// Base 2 logarithm of 1+x (f32)
+///
+/// Calculates `log2(1 + x)`. The computation is carried out in double precision,
+/// which also makes the exact cases of `log2p1` exact here.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn log2p1f(x: f32) -> f32 {
+    narrow(log2p1(x as f64))
+}
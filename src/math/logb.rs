@@ -0,0 +1,43 @@
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::ilogb;
+
+/// Extract exponent (f64)
+///
+/// Returns the unbiased exponent of `x` as a floating-point value. Subnormal
+/// values are treated as though they were normalized.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logb(x: f64) -> f64 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0.0 {
+        feraiseexcept(FE_DIVBYZERO);
+        return -1.0 / (x * x);
+    }
+    ilogb(x) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::logb;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(logb(1.0), 0.0);
+        assert_eq!(logb(10.0), 3.0);
+        assert_eq!(logb(-0.25), -2.0);
+        assert_eq!(logb(MIN_POSITIVE), -1022.0);
+        assert_eq!(logb(f64::from_bits(1)), -1074.0);
+    }
+
+    /// The spec: https://en.cppreference.com/w/cpp/numeric/math/logb
+    #[test]
+    fn spec_tests() {
+        assert_eq!(logb(0.0), NEG_INFINITY);
+        assert_eq!(logb(-0.0), NEG_INFINITY);
+        assert_eq!(logb(INFINITY), INFINITY);
+        assert_eq!(logb(NEG_INFINITY), INFINITY);
+        assert!(logb(NAN).is_nan());
+    }
+}
@@ -0,0 +1,18 @@
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::ilogbf;
+
+/// Extract exponent (f32)
+///
+/// Returns the unbiased exponent of `x` as a floating-point value. Subnormal
+/// values are treated as though they were normalized.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logbf(x: f32) -> f32 {
+    if !x.is_finite() {
+        return x * x;
+    }
+    if x == 0.0 {
+        feraiseexcept(FE_DIVBYZERO);
+        return -1.0 / (x * x);
+    }
+    ilogbf(x) as f32
+}
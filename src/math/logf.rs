@@ -13,6 +13,8 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, invalidf, FE_DIVBYZERO};
+
 const LN2_HI: f32 = 6.9313812256e-01; /* 0x3f317180 */
 const LN2_LO: f32 = 9.0580006145e-06; /* 0x3717f7d1 */
 /* |(log(1+s)-log(1-s))/s - Lg(s)| < 2**-34.24 (~[-4.95e-11, 4.97e-11]). */
@@ -31,10 +33,11 @@ pub fn logf(mut x: f32) -> f32 {
     if (ix < 0x00800000) || ((ix >> 31) != 0) {
         /* x < 2**-126  */
         if ix << 1 == 0 {
+            feraiseexcept(FE_DIVBYZERO);
             return -1. / (x * x); /* log(+-0)=-inf */
         }
         if (ix >> 31) != 0 {
-            return (x - x) / 0.; /* log(-#) = NaN */
+            return invalidf(x); /* log(-#) = NaN */
         }
         /* subnormal number, scale up x */
         k -= 25;
@@ -0,0 +1,167 @@
+/* log(x) = e*ln2 + log(m), from k_logf128; within 1 ulp */
+
+use super::f128::{dd_add, invalid, is_zero, quiet_nan};
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::k_logf128::{k_logf128, ln2_mul};
+use super::F128;
+
+/// The natural logarithm of `x` (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if is_zero(x) {
+        feraiseexcept(FE_DIVBYZERO);
+        return F128::NEG_INFINITY;
+    }
+    if x.is_sign_negative() {
+        return invalid();
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    if x == F128::ONE {
+        return F128::ZERO;
+    }
+    let (e, hi, lo) = k_logf128(x);
+    let (hi, lo) = dd_add(ln2_mul(e), (hi, lo));
+    hi + lo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(logf128(F128::ONE), F128::ZERO);
+        /* log(2), log(MAX) and log(2^-16494) */
+        assert_eq!(
+            logf128(f(0x40000000000000000000000000000000)).to_bits(),
+            0x3ffe62e42fefa39ef35793c7673007e6
+        );
+        assert_eq!(
+            logf128(F128::MAX).to_bits(),
+            0x400c62e42fefa39ef35793c7673007e6
+        );
+        assert_eq!(logf128(f(1)).to_bits(), 0xc00c6546282207802c89d24d65e96274);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(logf128(F128::ZERO), F128::NEG_INFINITY);
+        assert_eq!(logf128(F128::INFINITY), F128::INFINITY);
+        assert!(logf128(-F128::ONE).is_nan());
+        assert!(logf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x13eb229596d1311357579c4a40f0b7b1,
+            0xc00be8d5897fb28c56281ff537dcacce,
+        ),
+        (
+            0x1823e32ff4c778bf6bbebe4206fb398e,
+            0xc00bba03d151db5c6736d451f4425487,
+        ),
+        (
+            0x1dc60c2027b261dbd588e87be0ee13d8,
+            0xc00b7b89dbd5e4ab77fcf50479d83ae3,
+        ),
+        (
+            0x205117e40282097c9a645cce8ff6d26e,
+            0xc00b5f5559c12e3f6cac5bdb2d13a5ff,
+        ),
+        (
+            0x3ffe05701e3ffe0301ccecc24c42613f,
+            0xbffe58211c31f52b7e3b3a197c5e807b,
+        ),
+        (
+            0x3ffe634081c6af68891d029954eff836,
+            0xbffd7644eb84866bce7c653456a8c994,
+        ),
+        (
+            0x3ffebe8ba43350a062d262efe9b91e01,
+            0xbffc1821e3035a240ff71e8f866098ca,
+        ),
+        (
+            0x3ffed03c3328665e370335cd4fb86112,
+            0xbffb91229cf8d25d0a421a2b3ec49efe,
+        ),
+        (
+            0x3ffee43800d604084e012045d1d7aa00,
+            0xbffac9038fdaaa68ab69804d18ae647b,
+        ),
+        (
+            0x3fff04e18c876fd8da713a66adb09707,
+            0x3ff935724c2f861b5422dcfc0e828946,
+        ),
+        (
+            0x3fff21f42103a038447f4bc2af5ea978,
+            0x3ffbfe2164a1050f00f49c5e118d860b,
+        ),
+        (
+            0x3fff2741045465f171c4ee333d0f917a,
+            0x3ffc242a1eff0953b94086c0bfb686f6,
+        ),
+        (
+            0x3fff423e49057b8cf88df578ff4e127b,
+            0x3ffcd74e16b5ba239c24de62b2114132,
+        ),
+        (
+            0x3fff77f51511b7182b473f9729595276,
+            0x3ffd89857c439835f98cbb374520a7b2,
+        ),
+        (
+            0x3fff9a4798dba24a94e12319da57c78e,
+            0x3ffde2fb437d53e083cae142b7aee484,
+        ),
+        (
+            0x3fffca7c3ec0c5c424c5ef5604aab54f,
+            0x3ffe2a5e51fd98342801b33683a6ec0d,
+        ),
+        (
+            0x3ffffd275f294ed2852eb2d22380e8cf,
+            0x3ffe600986b637f4ccd983a70421ff26,
+        ),
+        (
+            0x4e7b35629719d77e64641415580dfb50,
+            0x400a414c22097a1cd34d7338d16fe5fc,
+        ),
+        (
+            0x550ec15fd6b07dfcf59500850eb3e207,
+            0x400ad32a364e9b9093df80a0fbf8665b,
+        ),
+        (
+            0x5b0906b1a168b506020fddb6457fe5ec,
+            0x400b2bdfd97aede1762c2dac8d6fce2b,
+        ),
+        (
+            0x5d5d1ff2d108db63d9f930b889da7215,
+            0x400b45b32b72ef4e982ff9f55e19787d,
+        ),
+        (
+            0x668ec122851fd01441727564a88b6ab4,
+            0x400baba9e32f4b7706a231aedd38329f,
+        ),
+        (
+            0x6b6be9716440d0fbaeeda33493970f9a,
+            0x400be19ac11fdffbdd8e8fff428dd7b3,
+        ),
+        (
+            0x6ceb951d10c24597cd3dbcebdb7844dc,
+            0x400bf23a6cd0d93468ff33ce7e9328ec,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(logf128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,35 @@
+use super::f16::from_f64_round;
+use super::{log, Round, F16};
+
+/// The natural logarithm of `x` (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn logf16(x: F16) -> F16 {
+    from_f64_round(log(f64::from(x)), Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(logf16(F16::from_f32(2.0)).to_bits(), 0x398c);
+        assert_eq!(logf16(F16::MAX).to_bits(), 0x498c);
+        assert_eq!(logf16(F16::from_bits(1)).to_bits(), 0xcc29);
+        assert_eq!(logf16(F16::from_f32(0.5)).to_bits(), 0xb98c);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(logf16(F16::ZERO), F16::NEG_INFINITY);
+        assert!(logf16(-F16::ONE).is_nan());
+        assert_eq!(logf16(F16::INFINITY), F16::INFINITY);
+        assert!(logf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(logf16, log, |x| x == F16::ONE);
+    }
+}
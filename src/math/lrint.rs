@@ -0,0 +1,67 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::rint;
+
+/// Round to nearest integer (f64)
+///
+/// Rounds `x` to an integer using the current rounding mode and returns it as
+/// an `i64` (the width of `long` on LP64 targets). Values that are out of range
+/// saturate, and NaN is converted to 0, raising the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lrint(x: f64) -> i64 {
+    let r = rint(x);
+    if !(-9223372036854775808.0..9223372036854775808.0).contains(&r) {
+        /* NaN, or a value outside the range of i64 */
+        feraiseexcept(FE_INVALID);
+    }
+    r as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lrint;
+
+    #[test]
+    fn sanity_check() {
+        /* ties to even in the default mode */
+        assert_eq!(lrint(2.5), 2);
+        assert_eq!(lrint(-3.5), -4);
+        assert_eq!(lrint(0.5), 0);
+        assert_eq!(lrint(1e300), i64::MAX);
+        assert_eq!(lrint(-1e300), i64::MIN);
+        assert_eq!(lrint(f64::NEG_INFINITY), i64::MIN);
+        assert_eq!(lrint(f64::NAN), 0);
+
+        /* elsewhere only rounding to nearest is supported */
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        {
+            use super::super::fenv::{fegetenv, fesetenv, fesetround, FE_DOWNWARD, FE_UPWARD};
+            use core::hint::black_box;
+
+            let saved = fegetenv();
+            fesetround(FE_UPWARD);
+            let up = (lrint(black_box(2.5)), lrint(black_box(-2.5)));
+            fesetround(FE_DOWNWARD);
+            let down = (lrint(black_box(2.5)), lrint(black_box(-2.5)));
+            fesetenv(&saved);
+            assert_eq!(up, (3, -2));
+            assert_eq!(down, (2, -3));
+        }
+    }
+
+    #[test]
+    fn exceptions() {
+        use super::super::{with_flags, Flags};
+
+        let (r, f) = with_flags(|| lrint(f64::NAN));
+        assert_eq!((r, f.contains(Flags::INVALID)), (0, true));
+        let (r, f) = with_flags(|| lrint(-1e300));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MIN, true));
+        /* rounds to 2^63 */
+        let (r, f) = with_flags(|| lrint(9223372036854775807.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MAX, true));
+        let (r, f) = with_flags(|| lrint(-9223372036854775808.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MIN, false));
+        let (r, f) = with_flags(|| lrint(2.5));
+        assert_eq!((r, f.contains(Flags::INVALID)), (2, false));
+    }
+}
@@ -0,0 +1,40 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+use super::roundf;
+
+/// Round to nearest integer, away from zero (f32)
+///
+/// Rounds `x` to the nearest integer, with halfway cases rounded away from
+/// zero, and returns it as an `i64` (the width of `long` on LP64 targets).
+/// Values that are out of range saturate, and NaN is converted to 0, raising
+/// the invalid exception.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn lroundf(x: f32) -> i64 {
+    let r = roundf(x);
+    if !(-9223372036854775808.0..9223372036854775808.0).contains(&r) {
+        /* NaN, or a value outside the range of i64 */
+        feraiseexcept(FE_INVALID);
+    }
+    r as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lroundf;
+
+    #[test]
+    fn exceptions() {
+        use super::super::{with_flags, Flags};
+
+        let (r, f) = with_flags(|| lroundf(f32::NAN));
+        assert_eq!((r, f.contains(Flags::INVALID)), (0, true));
+        let (r, f) = with_flags(|| lroundf(-1e30));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MIN, true));
+        /* 2^63 */
+        let (r, f) = with_flags(|| lroundf(9223372036854775807.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MAX, true));
+        let (r, f) = with_flags(|| lroundf(-9223372036854775808.0));
+        assert_eq!((r, f.contains(Flags::INVALID)), (i64::MIN, false));
+        let (r, f) = with_flags(|| lroundf(2.5));
+        assert_eq!((r, f.contains(Flags::INVALID)), (3, false));
+    }
+}
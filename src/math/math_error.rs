@@ -0,0 +1,34 @@
+use core::fmt;
+
+/// Error reported by the functions of the [`checked`](checked/index.html)
+/// module
+///
+/// The variants follow the classification of C Annex F and POSIX: each one
+/// names the floating-point exception the operation raises and, in brackets,
+/// the `errno` value it sets under `MATH_ERRNO`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MathError {
+    /// An argument is outside the domain of the function, such as `log(-1)`
+    /// or `sqrt(-1)` (`FE_INVALID`, `EDOM`)
+    Domain,
+    /// The function has an exact infinite result at finite arguments, such as
+    /// `log(0)` or `atanh(1)` (`FE_DIVBYZERO`, `ERANGE`)
+    Pole,
+    /// The finite result is too large to be represented, such as `exp(1000)`
+    /// (`FE_OVERFLOW`, `ERANGE`)
+    Overflow,
+    /// The nonzero result is too small to be represented as a normal number,
+    /// such as `exp(-1000)` (`FE_UNDERFLOW`, `ERANGE`)
+    Underflow,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MathError::Domain => "domain error",
+            MathError::Pole => "pole error",
+            MathError::Overflow => "overflow range error",
+            MathError::Underflow => "underflow range error",
+        })
+    }
+}
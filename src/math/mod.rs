@@ -87,61 +87,213 @@ macro_rules! llvm_intrinsically_optimized {
     };
 }
 
+// Public namespaces
+pub mod checked;
+
 // Public modules
 mod acos;
+mod acosd;
+mod acosdf;
 mod acosf;
+mod acosf128;
 mod acosh;
 mod acoshf;
+mod acospi;
+mod acospif;
 mod asin;
+mod asind;
+mod asindf;
 mod asinf;
+mod asinf128;
 mod asinh;
 mod asinhf;
+mod asinpi;
+mod asinpif;
 mod atan;
 mod atan2;
+mod atan2d;
+mod atan2df;
 mod atan2f;
+mod atan2f128;
+mod atan2pi;
+mod atan2pif;
+mod atand;
+mod atandf;
 mod atanf;
+mod atanf128;
 mod atanh;
 mod atanhf;
+mod atanpi;
+mod atanpif;
+mod bf16;
+mod bf16_erf;
+mod bf16_exp;
+mod bf16_log;
+mod bf16_rsqrt;
+mod bf16_sigmoid;
+mod bf16_sqrt;
+mod bf16_tanh;
+mod cabs;
+mod cabsf;
+mod cacos;
+mod cacosf;
+mod cacosh;
+mod cacoshf;
+mod canonicalize;
+mod canonicalizef;
+mod carg;
+mod cargf;
+mod casin;
+mod casinf;
+mod casinh;
+mod casinhf;
+mod catan;
+mod catanf;
+mod catanh;
+mod catanhf;
 mod cbrt;
 mod cbrtf;
+mod cbrtf128;
+mod ccos;
+mod ccosf;
+mod ccosh;
+mod ccoshf;
 mod ceil;
 mod ceilf;
+mod ceilf128;
+mod ceilf16;
+mod cexp;
+mod cexpf;
+mod clamp;
+mod clampf;
+mod clog;
+mod clogf;
+mod complex;
+mod complexf;
+mod compoundn;
+mod compoundnf;
+mod conj;
+mod conjf;
+mod convert;
 mod copysign;
 mod copysignf;
+mod copysignf128;
+mod copysignf16;
 mod cos;
+mod cosd;
+mod cosdf;
 mod cosf;
+mod cosf128;
+mod cosf16;
 mod cosh;
 mod coshf;
+mod coshf128;
+mod cospi;
+mod cospif;
+mod cpow;
+mod cpowf;
+mod cproj;
+mod cprojf;
+mod csin;
+mod csinf;
+mod csinh;
+mod csinhf;
+mod csqrt;
+mod csqrtf;
+mod ctan;
+mod ctanf;
+mod ctanh;
+mod ctanhf;
+mod div_euclid;
+mod div_euclidf;
 mod erf;
 mod erff;
 mod exp;
 mod exp10;
 mod exp10f;
+mod exp10m1;
+mod exp10m1f;
 mod exp2;
 mod exp2f;
+mod exp2f128;
+mod exp2f16;
+mod exp2m1;
+mod exp2m1f;
 mod expf;
+mod expf128;
+mod expf16;
 mod expm1;
 mod expm1f;
+mod expm1f128;
+mod f128;
+mod f16;
 mod fabs;
 mod fabsf;
+mod fabsf128;
+mod fabsf16;
+mod fadd;
 mod fdim;
 mod fdimf;
+mod fdiv;
+mod fenv;
+mod ffma;
+mod flags;
 mod floor;
 mod floorf;
+mod floorf128;
+mod floorf16;
 mod fma;
 mod fmaf;
+mod fmaf128;
+mod fmaf16;
 mod fmax;
 mod fmaxf;
+mod fmaxf16;
+mod fmaximum;
+mod fmaximum_mag;
+mod fmaximum_mag_num;
+mod fmaximum_mag_numf;
+mod fmaximum_magf;
+mod fmaximum_num;
+mod fmaximum_numf;
+mod fmaximumf;
 mod fmin;
 mod fminf;
+mod fminf16;
+mod fminimum;
+mod fminimum_mag;
+mod fminimum_mag_num;
+mod fminimum_mag_numf;
+mod fminimum_magf;
+mod fminimum_num;
+mod fminimum_numf;
+mod fminimumf;
 mod fmod;
 mod fmodf;
+mod fmodf128;
+mod fmul;
+mod fract;
+mod fractf;
 mod frexp;
 mod frexpf;
+mod frexpf128;
+mod frexpf16;
+mod fromfp;
+mod fromfpf;
+mod fsqrt;
+mod fsub;
+mod getpayload;
+mod getpayloadf;
 mod hypot;
 mod hypotf;
+mod hypotf128;
 mod ilogb;
 mod ilogbf;
+mod ilogbf128;
+mod interval;
+mod intervalf;
+mod issignaling;
+mod issignalingf;
 mod j0;
 mod j0f;
 mod j1;
@@ -150,74 +302,269 @@ mod jn;
 mod jnf;
 mod ldexp;
 mod ldexpf;
+mod ldexpf128;
+mod ldexpf16;
 mod lgamma;
 mod lgamma_r;
 mod lgammaf;
 mod lgammaf_r;
+mod llrint;
+mod llrintf;
+mod llround;
+mod llroundf;
 mod log;
 mod log10;
 mod log10f;
+mod log10f128;
+mod log10p1;
+mod log10p1f;
 mod log1p;
 mod log1pf;
+mod log1pf128;
 mod log2;
 mod log2f;
+mod log2f128;
+mod log2f16;
+mod log2p1;
+mod log2p1f;
+mod logb;
+mod logbf;
 mod logf;
+mod logf128;
+mod logf16;
+mod lrint;
+mod lrintf;
+mod lround;
+mod lroundf;
+mod math_error;
 mod modf;
 mod modff;
+mod nan;
+mod nanf;
+mod nearbyint;
+mod nearbyintf;
 mod nextafter;
 mod nextafterf;
+mod nextafterf128;
+mod nextdown;
+mod nextdownf;
+mod nexttoward;
+mod nexttowardf;
+mod nextup;
+mod nextupf;
 mod pow;
 mod powf;
+mod powf128;
+mod powi;
+mod powif;
+mod pown;
+mod pownf;
+mod powr;
+mod powrf;
+mod recip;
+mod recipf;
+mod rem_euclid;
+mod rem_euclidf;
 mod remainder;
 mod remainderf;
 mod remquo;
 mod remquof;
+mod rint;
+mod rintf;
+mod rintf16;
+mod rootn;
+mod rootnf;
 mod round;
+mod round_mode;
+mod round_ops;
+mod round_opsf;
+mod roundeven;
+mod roundevenf;
 mod roundf;
+mod roundf128;
+mod roundf16;
+mod rsqrt;
+mod rsqrtf;
+mod scalbln;
+mod scalblnf;
 mod scalbn;
 mod scalbnf;
+mod scalbnf128;
+mod setpayload;
+mod setpayloadf;
+mod setpayloadsig;
+mod setpayloadsigf;
+mod significand;
+mod significandf;
+mod signum;
+mod signumf;
 mod sin;
 mod sincos;
 mod sincosf;
+mod sind;
+mod sindf;
 mod sinf;
+mod sinf128;
+mod sinf16;
 mod sinh;
 mod sinhf;
+mod sinhf128;
+mod sinpi;
+mod sinpif;
 mod sqrt;
 mod sqrtf;
+mod sqrtf128;
+mod sqrtf16;
 mod tan;
+mod tand;
+mod tandf;
 mod tanf;
+mod tanf128;
 mod tanh;
 mod tanhf;
+mod tanhf128;
+mod tanhf16;
+mod tanpi;
+mod tanpif;
 mod tgamma;
 mod tgammaf;
+mod to_degrees;
+mod to_degreesf;
+mod to_int;
+mod to_intf;
+mod to_radians;
+mod to_radiansf;
+mod totalorder;
+mod totalorderf;
+mod totalordermag;
+mod totalordermagf;
 mod trunc;
 mod truncf;
+mod truncf128;
+mod truncf16;
 
 // Use separated imports instead of {}-grouped imports for easier merging.
 pub use self::acos::acos;
+pub use self::acosd::acosd;
+pub use self::acosdf::acosdf;
 pub use self::acosf::acosf;
+pub use self::acosf128::acosf128;
 pub use self::acosh::acosh;
 pub use self::acoshf::acoshf;
+pub use self::acospi::acospi;
+pub use self::acospif::acospif;
 pub use self::asin::asin;
+pub use self::asind::asind;
+pub use self::asindf::asindf;
 pub use self::asinf::asinf;
+pub use self::asinf128::asinf128;
 pub use self::asinh::asinh;
 pub use self::asinhf::asinhf;
+pub use self::asinpi::asinpi;
+pub use self::asinpif::asinpif;
 pub use self::atan::atan;
 pub use self::atan2::atan2;
+pub use self::atan2d::atan2d;
+pub use self::atan2df::atan2df;
 pub use self::atan2f::atan2f;
+pub use self::atan2f128::atan2f128;
+pub use self::atan2pi::atan2pi;
+pub use self::atan2pif::atan2pif;
+pub use self::atand::atand;
+pub use self::atandf::atandf;
 pub use self::atanf::atanf;
+pub use self::atanf128::atanf128;
 pub use self::atanh::atanh;
 pub use self::atanhf::atanhf;
+pub use self::atanpi::atanpi;
+pub use self::atanpif::atanpif;
+pub use self::bf16::BF16;
+pub use self::bf16_erf::bf16_erf;
+pub use self::bf16_exp::bf16_exp;
+pub use self::bf16_log::bf16_log;
+pub use self::bf16_rsqrt::bf16_rsqrt;
+pub use self::bf16_sigmoid::bf16_sigmoid;
+pub use self::bf16_sqrt::bf16_sqrt;
+pub use self::bf16_tanh::bf16_tanh;
+pub use self::cabs::cabs;
+pub use self::cabsf::cabsf;
+pub use self::cacos::cacos;
+pub use self::cacosf::cacosf;
+pub use self::cacosh::cacosh;
+pub use self::cacoshf::cacoshf;
+pub use self::canonicalize::canonicalize;
+pub use self::canonicalizef::canonicalizef;
+pub use self::carg::carg;
+pub use self::cargf::cargf;
+pub use self::casin::casin;
+pub use self::casinf::casinf;
+pub use self::casinh::casinh;
+pub use self::casinhf::casinhf;
+pub use self::catan::catan;
+pub use self::catanf::catanf;
+pub use self::catanh::catanh;
+pub use self::catanhf::catanhf;
 pub use self::cbrt::cbrt;
 pub use self::cbrtf::cbrtf;
+pub use self::cbrtf128::cbrtf128;
+pub use self::ccos::ccos;
+pub use self::ccosf::ccosf;
+pub use self::ccosh::ccosh;
+pub use self::ccoshf::ccoshf;
 pub use self::ceil::ceil;
 pub use self::ceilf::ceilf;
+pub use self::ceilf128::ceilf128;
+pub use self::ceilf16::ceilf16;
+pub use self::cexp::cexp;
+pub use self::cexpf::cexpf;
+pub use self::clamp::clamp;
+pub use self::clampf::clampf;
+pub use self::clog::clog;
+pub use self::clogf::clogf;
+pub use self::complex::Complex;
+pub use self::compoundn::compoundn;
+pub use self::compoundnf::compoundnf;
+pub use self::conj::conj;
+pub use self::conjf::conjf;
+pub use self::convert::bf16_to_f16;
+pub use self::convert::f16_to_bf16;
+pub use self::convert::f32_to_bf16;
+pub use self::convert::f32_to_f16;
+pub use self::convert::f32_to_f64;
+pub use self::convert::f64_to_bf16;
+pub use self::convert::f64_to_f16;
+pub use self::convert::f64_to_f32;
 pub use self::copysign::copysign;
 pub use self::copysignf::copysignf;
+pub use self::copysignf128::copysignf128;
+pub use self::copysignf16::copysignf16;
 pub use self::cos::cos;
+pub use self::cosd::cosd;
+pub use self::cosdf::cosdf;
 pub use self::cosf::cosf;
+pub use self::cosf128::cosf128;
+pub use self::cosf16::cosf16;
 pub use self::cosh::cosh;
 pub use self::coshf::coshf;
+pub use self::coshf128::coshf128;
+pub use self::cospi::cospi;
+pub use self::cospif::cospif;
+pub use self::cpow::cpow;
+pub use self::cpowf::cpowf;
+pub use self::cproj::cproj;
+pub use self::cprojf::cprojf;
+pub use self::csin::csin;
+pub use self::csinf::csinf;
+pub use self::csinh::csinh;
+pub use self::csinhf::csinhf;
+pub use self::csqrt::csqrt;
+pub use self::csqrtf::csqrtf;
+pub use self::ctan::ctan;
+pub use self::ctanf::ctanf;
+pub use self::ctanh::ctanh;
+pub use self::ctanhf::ctanhf;
+pub use self::div_euclid::div_euclid;
+pub use self::div_euclidf::div_euclidf;
 pub use self::erf::erf;
 pub use self::erf::erfc;
 pub use self::erff::erfcf;
@@ -225,31 +572,116 @@ pub use self::erff::erff;
 pub use self::exp::exp;
 pub use self::exp10::exp10;
 pub use self::exp10f::exp10f;
+pub use self::exp10m1::exp10m1;
+pub use self::exp10m1f::exp10m1f;
 pub use self::exp2::exp2;
 pub use self::exp2f::exp2f;
+pub use self::exp2f128::exp2f128;
+pub use self::exp2f16::exp2f16;
+pub use self::exp2m1::exp2m1;
+pub use self::exp2m1f::exp2m1f;
 pub use self::expf::expf;
+pub use self::expf128::expf128;
+pub use self::expf16::expf16;
 pub use self::expm1::expm1;
 pub use self::expm1f::expm1f;
+pub use self::expm1f128::expm1f128;
+pub use self::f128::F128;
+pub use self::f16::F16;
 pub use self::fabs::fabs;
 pub use self::fabsf::fabsf;
+pub use self::fabsf128::fabsf128;
+pub use self::fabsf16::fabsf16;
+pub use self::fadd::fadd;
 pub use self::fdim::fdim;
 pub use self::fdimf::fdimf;
+pub use self::fdiv::fdiv;
+pub use self::fenv::feclearexcept;
+pub use self::fenv::fegetenv;
+pub use self::fenv::fegetround;
+pub use self::fenv::feraiseexcept;
+pub use self::fenv::fesetenv;
+pub use self::fenv::fesetround;
+pub use self::fenv::fetestexcept;
+pub use self::fenv::FeEnv;
+pub use self::fenv::FE_ALL_EXCEPT;
+pub use self::fenv::FE_DFL_ENV;
+pub use self::fenv::FE_DIVBYZERO;
+pub use self::fenv::FE_DOWNWARD;
+pub use self::fenv::FE_INEXACT;
+pub use self::fenv::FE_INVALID;
+pub use self::fenv::FE_OVERFLOW;
+pub use self::fenv::FE_TONEAREST;
+pub use self::fenv::FE_TOWARDZERO;
+pub use self::fenv::FE_UNDERFLOW;
+pub use self::fenv::FE_UPWARD;
+pub use self::ffma::ffma;
+pub use self::flags::clear_flags;
+pub use self::flags::raise_flags;
+pub use self::flags::test_flags;
+pub use self::flags::with_flags;
+pub use self::flags::Flags;
 pub use self::floor::floor;
 pub use self::floorf::floorf;
+pub use self::floorf128::floorf128;
+pub use self::floorf16::floorf16;
 pub use self::fma::fma;
 pub use self::fmaf::fmaf;
+pub use self::fmaf128::fmaf128;
+pub use self::fmaf16::fmaf16;
 pub use self::fmax::fmax;
 pub use self::fmaxf::fmaxf;
+pub use self::fmaxf16::fmaxf16;
+pub use self::fmaximum::fmaximum;
+pub use self::fmaximum_mag::fmaximum_mag;
+pub use self::fmaximum_mag_num::fmaximum_mag_num;
+pub use self::fmaximum_mag_numf::fmaximum_mag_numf;
+pub use self::fmaximum_magf::fmaximum_magf;
+pub use self::fmaximum_num::fmaximum_num;
+pub use self::fmaximum_numf::fmaximum_numf;
+pub use self::fmaximumf::fmaximumf;
 pub use self::fmin::fmin;
 pub use self::fminf::fminf;
+pub use self::fminf16::fminf16;
+pub use self::fminimum::fminimum;
+pub use self::fminimum_mag::fminimum_mag;
+pub use self::fminimum_mag_num::fminimum_mag_num;
+pub use self::fminimum_mag_numf::fminimum_mag_numf;
+pub use self::fminimum_magf::fminimum_magf;
+pub use self::fminimum_num::fminimum_num;
+pub use self::fminimum_numf::fminimum_numf;
+pub use self::fminimumf::fminimumf;
 pub use self::fmod::fmod;
 pub use self::fmodf::fmodf;
+pub use self::fmodf128::fmodf128;
+pub use self::fmul::fmul;
+pub use self::fract::fract;
+pub use self::fractf::fractf;
 pub use self::frexp::frexp;
 pub use self::frexpf::frexpf;
+pub use self::frexpf128::frexpf128;
+pub use self::frexpf16::frexpf16;
+pub use self::fromfp::fromfp;
+pub use self::fromfp::fromfpx;
+pub use self::fromfp::ufromfp;
+pub use self::fromfp::ufromfpx;
+pub use self::fromfpf::fromfpf;
+pub use self::fromfpf::fromfpxf;
+pub use self::fromfpf::ufromfpf;
+pub use self::fromfpf::ufromfpxf;
+pub use self::fsqrt::fsqrt;
+pub use self::fsub::fsub;
+pub use self::getpayload::getpayload;
+pub use self::getpayloadf::getpayloadf;
 pub use self::hypot::hypot;
 pub use self::hypotf::hypotf;
+pub use self::hypotf128::hypotf128;
 pub use self::ilogb::ilogb;
 pub use self::ilogbf::ilogbf;
+pub use self::ilogbf128::ilogbf128;
+pub use self::interval::Interval;
+pub use self::issignaling::issignaling;
+pub use self::issignalingf::issignalingf;
 pub use self::j0::j0;
 pub use self::j0::y0;
 pub use self::j0f::j0f;
@@ -264,63 +696,195 @@ pub use self::jnf::jnf;
 pub use self::jnf::ynf;
 pub use self::ldexp::ldexp;
 pub use self::ldexpf::ldexpf;
+pub use self::ldexpf128::ldexpf128;
+pub use self::ldexpf16::ldexpf16;
 pub use self::lgamma::lgamma;
 pub use self::lgamma_r::lgamma_r;
 pub use self::lgammaf::lgammaf;
 pub use self::lgammaf_r::lgammaf_r;
+pub use self::llrint::llrint;
+pub use self::llrintf::llrintf;
+pub use self::llround::llround;
+pub use self::llroundf::llroundf;
 pub use self::log::log;
 pub use self::log10::log10;
 pub use self::log10f::log10f;
+pub use self::log10f128::log10f128;
+pub use self::log10p1::log10p1;
+pub use self::log10p1f::log10p1f;
 pub use self::log1p::log1p;
 pub use self::log1pf::log1pf;
+pub use self::log1pf128::log1pf128;
 pub use self::log2::log2;
 pub use self::log2f::log2f;
+pub use self::log2f128::log2f128;
+pub use self::log2f16::log2f16;
+pub use self::log2p1::log2p1;
+pub use self::log2p1f::log2p1f;
+pub use self::logb::logb;
+pub use self::logbf::logbf;
 pub use self::logf::logf;
+pub use self::logf128::logf128;
+pub use self::logf16::logf16;
+pub use self::lrint::lrint;
+pub use self::lrintf::lrintf;
+pub use self::lround::lround;
+pub use self::lroundf::lroundf;
+pub use self::math_error::MathError;
 pub use self::modf::modf;
 pub use self::modff::modff;
+pub use self::nan::nan;
+pub use self::nanf::nanf;
+pub use self::nearbyint::nearbyint;
+pub use self::nearbyintf::nearbyintf;
 pub use self::nextafter::nextafter;
 pub use self::nextafterf::nextafterf;
+pub use self::nextafterf128::nextafterf128;
+pub use self::nextdown::nextdown;
+pub use self::nextdownf::nextdownf;
+pub use self::nexttoward::nexttoward;
+pub use self::nexttowardf::nexttowardf;
+pub use self::nextup::nextup;
+pub use self::nextupf::nextupf;
 pub use self::pow::pow;
 pub use self::powf::powf;
+pub use self::powf128::powf128;
+pub use self::powi::powi;
+pub use self::powif::powif;
+pub use self::pown::pown;
+pub use self::pownf::pownf;
+pub use self::powr::powr;
+pub use self::powrf::powrf;
+pub use self::recip::recip;
+pub use self::recipf::recipf;
+pub use self::rem_euclid::rem_euclid;
+pub use self::rem_euclidf::rem_euclidf;
 pub use self::remainder::remainder;
 pub use self::remainderf::remainderf;
 pub use self::remquo::remquo;
 pub use self::remquof::remquof;
+pub use self::rint::rint;
+pub use self::rintf::rintf;
+pub use self::rintf16::rintf16;
+pub use self::rootn::rootn;
+pub use self::rootnf::rootnf;
 pub use self::round::round;
+pub use self::round_mode::Round;
+pub use self::round_ops::add_round;
+pub use self::round_ops::div_round;
+pub use self::round_ops::fma_round;
+pub use self::round_ops::mul_round;
+pub use self::round_ops::sqrt_round;
+pub use self::round_ops::sub_round;
+pub use self::round_opsf::addf_round;
+pub use self::round_opsf::divf_round;
+pub use self::round_opsf::fmaf_round;
+pub use self::round_opsf::mulf_round;
+pub use self::round_opsf::sqrtf_round;
+pub use self::round_opsf::subf_round;
+pub use self::roundeven::roundeven;
+pub use self::roundevenf::roundevenf;
 pub use self::roundf::roundf;
+pub use self::roundf128::roundf128;
+pub use self::roundf16::roundf16;
+pub use self::rsqrt::rsqrt;
+pub use self::rsqrtf::rsqrtf;
+pub use self::scalbln::scalbln;
+pub use self::scalblnf::scalblnf;
 pub use self::scalbn::scalbn;
 pub use self::scalbnf::scalbnf;
+pub use self::scalbnf128::scalbnf128;
+pub use self::setpayload::setpayload;
+pub use self::setpayloadf::setpayloadf;
+pub use self::setpayloadsig::setpayloadsig;
+pub use self::setpayloadsigf::setpayloadsigf;
+pub use self::significand::significand;
+pub use self::significandf::significandf;
+pub use self::signum::signum;
+pub use self::signumf::signumf;
 pub use self::sin::sin;
 pub use self::sincos::sincos;
 pub use self::sincosf::sincosf;
+pub use self::sind::sind;
+pub use self::sindf::sindf;
 pub use self::sinf::sinf;
+pub use self::sinf128::sinf128;
+pub use self::sinf16::sinf16;
 pub use self::sinh::sinh;
 pub use self::sinhf::sinhf;
+pub use self::sinhf128::sinhf128;
+pub use self::sinpi::sinpi;
+pub use self::sinpif::sinpif;
 pub use self::sqrt::sqrt;
 pub use self::sqrtf::sqrtf;
+pub use self::sqrtf128::sqrtf128;
+pub use self::sqrtf16::sqrtf16;
 pub use self::tan::tan;
+pub use self::tand::tand;
+pub use self::tandf::tandf;
 pub use self::tanf::tanf;
+pub use self::tanf128::tanf128;
 pub use self::tanh::tanh;
 pub use self::tanhf::tanhf;
+pub use self::tanhf128::tanhf128;
+pub use self::tanhf16::tanhf16;
+pub use self::tanpi::tanpi;
+pub use self::tanpif::tanpif;
 pub use self::tgamma::tgamma;
 pub use self::tgammaf::tgammaf;
+pub use self::to_degrees::to_degrees;
+pub use self::to_degreesf::to_degreesf;
+pub use self::to_int::ceil_to_i32;
+pub use self::to_int::ceil_to_i64;
+pub use self::to_int::floor_to_i32;
+pub use self::to_int::floor_to_i64;
+pub use self::to_int::round_to_i32;
+pub use self::to_int::round_to_i64;
+pub use self::to_int::trunc_to_i32;
+pub use self::to_int::trunc_to_i64;
+pub use self::to_intf::ceilf_to_i32;
+pub use self::to_intf::ceilf_to_i64;
+pub use self::to_intf::floorf_to_i32;
+pub use self::to_intf::floorf_to_i64;
+pub use self::to_intf::roundf_to_i32;
+pub use self::to_intf::roundf_to_i64;
+pub use self::to_intf::truncf_to_i32;
+pub use self::to_intf::truncf_to_i64;
+pub use self::to_radians::to_radians;
+pub use self::to_radiansf::to_radiansf;
+pub use self::totalorder::totalorder;
+pub use self::totalorderf::totalorderf;
+pub use self::totalordermag::totalordermag;
+pub use self::totalordermagf::totalordermagf;
 pub use self::trunc::trunc;
 pub use self::truncf::truncf;
+pub use self::truncf128::truncf128;
+pub use self::truncf16::truncf16;
 
 // Private modules
 mod expo2;
-mod fenv;
+mod k_catrig;
+mod k_catrigf;
+mod k_cexp;
+mod k_cexpf;
 mod k_cos;
 mod k_cosf;
+mod k_cosf128;
+mod k_expf128;
 mod k_expo2;
 mod k_expo2f;
+mod k_logf128;
+mod k_pi;
 mod k_sin;
 mod k_sinf;
+mod k_sinf128;
 mod k_tan;
 mod k_tanf;
 mod rem_pio2;
 mod rem_pio2_large;
 mod rem_pio2f;
+mod rem_pio2f128;
+mod round_odd;
 
 // Private re-imports
 use self::expo2::expo2;
@@ -328,6 +892,12 @@ use self::k_cos::k_cos;
 use self::k_cosf::k_cosf;
 use self::k_expo2::k_expo2;
 use self::k_expo2f::k_expo2f;
+use self::k_pi::deg_to_rad;
+use self::k_pi::div_pi;
+use self::k_pi::mul_pi;
+use self::k_pi::rad_to_deg;
+use self::k_pi::rem_90;
+use self::k_pi::rem_half;
 use self::k_sin::k_sin;
 use self::k_sinf::k_sinf;
 use self::k_tan::k_tan;
@@ -335,6 +905,7 @@ use self::k_tanf::k_tanf;
 use self::rem_pio2::rem_pio2;
 use self::rem_pio2_large::rem_pio2_large;
 use self::rem_pio2f::rem_pio2f;
+use self::round_odd::round_odd;
 
 #[inline]
 fn get_high_word(x: f64) -> u32 {
@@ -0,0 +1,60 @@
+/// Quiet NaN with payload (f64)
+///
+/// Returns a quiet NaN whose payload is taken from `tagp`. As with glibc,
+/// `tagp` is parsed as an unsigned integer (decimal, or hexadecimal with a
+/// `0x` prefix, or octal with a leading `0`); a string that is not a valid
+/// number yields a payload of zero. Payload bits that do not fit in the
+/// significand are discarded.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nan(tagp: &str) -> f64 {
+    let payload = parse_payload(tagp) & ((1 << 52) - 1);
+    f64::from_bits(0x7ff8_0000_0000_0000 | payload)
+}
+
+/// Parses a NaN payload the way `strtoull(tagp, NULL, 0)` would, returning
+/// zero unless the whole string is consumed. Values that overflow saturate.
+pub(crate) fn parse_payload(tagp: &str) -> u64 {
+    let bytes = tagp.as_bytes();
+    let (radix, digits) = match bytes {
+        [b'0', b'x', rest @ ..] | [b'0', b'X', rest @ ..] if !rest.is_empty() => (16, rest),
+        [b'0', rest @ ..] => (8, rest),
+        _ => (10, bytes),
+    };
+
+    let mut value: u64 = 0;
+    for &b in digits {
+        let digit = match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'f' => b - b'a' + 10,
+            b'A'..=b'F' => b - b'A' + 10,
+            _ => return 0,
+        } as u64;
+        if digit >= radix {
+            return 0;
+        }
+        value = value.saturating_mul(radix).saturating_add(digit);
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nan;
+
+    #[test]
+    fn payloads() {
+        assert_eq!(nan("").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("42").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan("0x2A").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan("052").to_bits(), 0x7ff8_0000_0000_002a);
+        assert_eq!(nan("0").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("0x").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("12abc").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("09").to_bits(), 0x7ff8_0000_0000_0000);
+        assert_eq!(nan("0xfffffffffffff").to_bits(), 0x7fff_ffff_ffff_ffff);
+        assert_eq!(
+            nan("99999999999999999999999").to_bits(),
+            0x7fff_ffff_ffff_ffff
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use super::nan::parse_payload;
+
+/// Quiet NaN with payload (f32)
+///
+/// Returns a quiet NaN whose payload is taken from `tagp`, parsed as
+/// described for `nan`. Payload bits that do not fit in the significand are
+/// discarded.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nanf(tagp: &str) -> f32 {
+    let payload = parse_payload(tagp) as u32 & ((1 << 23) - 1);
+    f32::from_bits(0x7fc0_0000 | payload)
+}
@@ -0,0 +1,16 @@
+use super::fenv::{feclearexcept, fetestexcept, FE_INEXACT};
+use super::rint;
+
+/// Round to integral value without raising inexact (f64)
+///
+/// Rounds `x` to an integer value in floating-point format, using the
+/// current rounding mode. Unlike `rint`, the inexact exception is never raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyint(x: f64) -> f64 {
+    let e = fetestexcept(FE_INEXACT);
+    let x = rint(x);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+    x
+}
@@ -0,0 +1,16 @@
+use super::fenv::{feclearexcept, fetestexcept, FE_INEXACT};
+use super::rintf;
+
+/// Round to integral value without raising inexact (f32)
+///
+/// Rounds `x` to an integer value in floating-point format, using the
+/// current rounding mode. Unlike `rintf`, the inexact exception is never raised.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nearbyintf(x: f32) -> f32 {
+    let e = fetestexcept(FE_INEXACT);
+    let x = rintf(x);
+    if e == 0 {
+        feclearexcept(FE_INEXACT);
+    }
+    x
+}
@@ -1,3 +1,5 @@
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn nextafter(x: f64, y: f64) -> f64 {
     if x.is_nan() || y.is_nan() {
@@ -23,15 +25,37 @@ pub fn nextafter(x: f64, y: f64) -> f64 {
         ux_i += 1;
     }
 
-    let e = ux_i.wrapping_shr(52 & 0x7ff);
+    let e = ux_i >> 52 & 0x7ff;
     // raise overflow if ux.f is infinite and x is finite
     if e == 0x7ff {
-        force_eval!(x + x);
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
     }
     let ux_f = f64::from_bits(ux_i);
     // raise underflow if ux.f is subnormal or zero
     if e == 0 {
-        force_eval!(x * x + ux_f * ux_f);
+        feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
     }
     ux_f
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::{with_flags, Flags};
+    use super::*;
+
+    #[test]
+    fn exceptions() {
+        let (r, f) = with_flags(|| nextafter(f64::MAX, f64::INFINITY));
+        assert_eq!(r, f64::INFINITY);
+        assert!(f.contains(Flags::OVERFLOW | Flags::INEXACT));
+        /* the sign bit must not be taken for part of the exponent */
+        let (r, f) = with_flags(|| nextafter(-f64::MAX, f64::NEG_INFINITY));
+        assert_eq!(r, f64::NEG_INFINITY);
+        assert!(f.contains(Flags::OVERFLOW | Flags::INEXACT));
+        let (r, f) = with_flags(|| nextafter(-f64::MIN_POSITIVE, 0.0));
+        assert_eq!(r.to_bits(), 0x800f_ffff_ffff_ffff);
+        assert!(f.contains(Flags::UNDERFLOW | Flags::INEXACT));
+        let (_, f) = with_flags(|| nextafter(1.0, 2.0));
+        assert!(f.is_empty());
+    }
+}
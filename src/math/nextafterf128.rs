@@ -0,0 +1,70 @@
+use super::f128::{exponent, quiet_nan, EXP_MAX, SIGN_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+use super::F128;
+
+/// Next representable value after `x` in the direction of `y` (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextafterf128(x: F128, y: F128) -> F128 {
+    if x.is_nan() || y.is_nan() {
+        return quiet_nan(x, y);
+    }
+
+    let mut ux_i = x.to_bits();
+    let uy_i = y.to_bits();
+    if ux_i == uy_i {
+        return y;
+    }
+
+    let ax = ux_i & !SIGN_MASK;
+    let ay = uy_i & !SIGN_MASK;
+    if ax == 0 {
+        if ay == 0 {
+            return y;
+        }
+        ux_i = (uy_i & SIGN_MASK) | 1;
+    } else if ax > ay || ((ux_i ^ uy_i) & SIGN_MASK) != 0 {
+        ux_i -= 1;
+    } else {
+        ux_i += 1;
+    }
+
+    let ux_f = F128::from_bits(ux_i);
+    let e = exponent(ux_f);
+    // raise overflow if ux_f is infinite and x is finite
+    if e == EXP_MAX {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    }
+    // raise underflow if ux_f is subnormal or zero
+    if e == 0 {
+        feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+    }
+    ux_f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(
+            nextafterf128(F128::ONE, F128::INFINITY),
+            f(F128::ONE.to_bits() + 1)
+        );
+        assert_eq!(
+            nextafterf128(F128::ONE, F128::ZERO),
+            f(F128::ONE.to_bits() - 1)
+        );
+        assert_eq!(nextafterf128(F128::ZERO, -F128::ONE), -f(1));
+        assert_eq!(nextafterf128(-f(1), F128::ONE), -F128::ZERO);
+        assert_eq!(nextafterf128(F128::MAX, F128::INFINITY), F128::INFINITY);
+        assert_eq!(nextafterf128(F128::INFINITY, F128::ZERO), F128::MAX);
+        let z = nextafterf128(F128::ZERO, -F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        assert!(nextafterf128(F128::NAN, F128::ONE).is_nan());
+    }
+}
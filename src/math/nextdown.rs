@@ -0,0 +1,10 @@
+use super::nextup;
+
+/// Next representable value toward -inf (f64)
+///
+/// Returns the greatest floating-point number less than `x`, that is
+/// `-nextup(-x)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdown(x: f64) -> f64 {
+    -nextup(-x)
+}
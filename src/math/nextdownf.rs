@@ -0,0 +1,10 @@
+use super::nextupf;
+
+/// Next representable value toward -inf (f32)
+///
+/// Returns the greatest floating-point number less than `x`, that is
+/// `-nextupf(-x)`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextdownf(x: f32) -> f32 {
+    -nextupf(-x)
+}
@@ -0,0 +1,11 @@
+use super::nextafter;
+
+/// Next representable value toward a direction (f64)
+///
+/// Returns the next representable value after `x` in the direction of `y`.
+/// C declares `y` as `long double`; Rust has no such type, so the direction
+/// is given as an `f64` and this is equivalent to `nextafter`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttoward(x: f64, y: f64) -> f64 {
+    nextafter(x, y)
+}
@@ -0,0 +1,63 @@
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW, FE_UNDERFLOW};
+
+/// Next representable value toward a direction (f32)
+///
+/// Returns the next representable `f32` after `x` in the direction of `y`.
+/// C declares `y` as `long double`; Rust has no such type, so the direction
+/// is given as an `f64`, which can distinguish targets that lie between two
+/// adjacent `f32` values.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nexttowardf(x: f32, y: f64) -> f32 {
+    let mut ux_i = x.to_bits();
+
+    if x.is_nan() || y.is_nan() {
+        return (x as f64 + y) as f32;
+    }
+    if x as f64 == y {
+        return y as f32;
+    }
+    if x == 0.0 {
+        ux_i = 1;
+        if y.is_sign_negative() {
+            ux_i |= 0x8000_0000;
+        }
+    } else if (x as f64) < y {
+        if x.is_sign_negative() {
+            ux_i -= 1;
+        } else {
+            ux_i += 1;
+        }
+    } else if x.is_sign_negative() {
+        ux_i += 1;
+    } else {
+        ux_i -= 1;
+    }
+
+    let e = ux_i & 0x7f80_0000_u32;
+    // raise overflow if ux_f is infinite and x is finite
+    if e == 0x7f80_0000_u32 {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    }
+    let ux_f = f32::from_bits(ux_i);
+    // raise underflow if ux_f is subnormal or zero
+    if e == 0 {
+        feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+    }
+    ux_f
+}
+
+#[cfg(test)]
+mod tests {
+    use super::nexttowardf;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(nexttowardf(1.0, 2.0), 1.0 + f32::EPSILON);
+        assert_eq!(nexttowardf(1.0, 1.0 + 1e-12), 1.0 + f32::EPSILON);
+        assert_eq!(nexttowardf(1.0, 1.0 - 1e-12), 1.0 - f32::EPSILON / 2.0);
+        assert_eq!(nexttowardf(0.0, -1e-300), -f32::from_bits(1));
+        assert_eq!(nexttowardf(-0.0, 0.0).to_bits(), 0);
+        assert_eq!(nexttowardf(f32::MAX, 1e300), f32::INFINITY);
+        assert!(nexttowardf(1.0, f64::NAN).is_nan());
+    }
+}
@@ -0,0 +1,49 @@
+/// Next representable value toward +inf (f64)
+///
+/// Returns the least floating-point number greater than `x`. `nextup(+-0)` is
+/// the smallest positive subnormal, `nextup(-inf)` is `-MAX` and `nextup(inf)`
+/// is `inf`. Unlike `nextafter`, no overflow or underflow is signaled.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextup(x: f64) -> f64 {
+    let ui = x.to_bits();
+    let ix = ui & 0x7fffffffffffffff;
+
+    if ix > 0x7ff0000000000000 {
+        return x + x;
+    }
+    if ix == 0 {
+        return f64::from_bits(1);
+    }
+    if ui == 0x7ff0000000000000 {
+        return x;
+    }
+    if ui >> 63 == 0 {
+        f64::from_bits(ui + 1)
+    } else {
+        f64::from_bits(ui - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert!(nextup(NAN).is_nan());
+        assert_eq!(nextup(INFINITY), INFINITY);
+        assert_eq!(nextup(NEG_INFINITY), -MAX);
+        assert_eq!(nextup(MAX), INFINITY);
+        assert_eq!(nextup(0.0), 5e-324);
+        assert_eq!(nextup(-0.0), 5e-324);
+        assert_eq!(nextup(-5e-324).to_bits(), (-0.0f64).to_bits());
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(nextup(1.0), 1.0 + EPSILON);
+        assert_eq!(nextup(-1.0), -1.0 + EPSILON / 2.0);
+        assert_eq!(nextup(-MIN_POSITIVE), -MIN_POSITIVE + 5e-324);
+    }
+}
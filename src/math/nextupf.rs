@@ -0,0 +1,25 @@
+/// Next representable value toward +inf (f32)
+///
+/// Returns the least floating-point number greater than `x`. `nextupf(+-0)` is
+/// the smallest positive subnormal, `nextupf(-inf)` is `-MAX` and `nextupf(inf)`
+/// is `inf`. Unlike `nextafterf`, no overflow or underflow is signaled.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn nextupf(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let ix = ui & 0x7fffffff;
+
+    if ix > 0x7f800000 {
+        return x + x;
+    }
+    if ix == 0 {
+        return f32::from_bits(1);
+    }
+    if ui == 0x7f800000 {
+        return x;
+    }
+    if ui >> 31 == 0 {
+        f32::from_bits(ui + 1)
+    } else {
+        f32::from_bits(ui - 1)
+    }
+}
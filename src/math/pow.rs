@@ -57,6 +57,7 @@
 // compiler will convert from decimal to binary accurately enough
 // to produce the hexadecimal values shown.
 //
+use super::fenv::{feraiseexcept, invalid, overflow, underflow, FE_DIVBYZERO};
 use super::{fabs, get_high_word, scalbn, sqrt, with_set_high_word, with_set_low_word};
 
 const BP: [f64; 2] = [1.0, 1.5];
@@ -176,6 +177,9 @@ pub fn pow(x: f64, y: f64) -> f64 {
 
         if iy == 0x3ff00000 {
             /* y is +-1 */
+            if hy < 0 && x == 0.0 {
+                feraiseexcept(FE_DIVBYZERO);
+            }
             return if hy >= 0 { x } else { 1.0 / x };
         }
 
@@ -202,12 +206,15 @@ pub fn pow(x: f64, y: f64) -> f64 {
 
             if hy < 0 {
                 /* z = (1/|x|) */
+                if ix == 0 {
+                    feraiseexcept(FE_DIVBYZERO);
+                }
                 z = 1.0 / z;
             }
 
             if hx < 0 {
                 if ((ix - 0x3ff00000) | yisint) == 0 {
-                    z = (z - z) / (z - z); /* (-1)**non-int is NaN */
+                    z = invalid(z); /* (-1)**non-int is NaN */
                 } else if yisint == 1 {
                     z = -z; /* (x<0)**odd = -(|x|**odd) */
                 }
@@ -221,7 +228,7 @@ pub fn pow(x: f64, y: f64) -> f64 {
     if hx < 0 {
         if yisint == 0 {
             /* (x<0)**(non-int) is NaN */
-            return (x - x) / (x - x);
+            return invalid(x);
         }
 
         if yisint == 1 {
@@ -236,27 +243,35 @@ pub fn pow(x: f64, y: f64) -> f64 {
         if iy > 0x43f00000 {
             /* if |y| > 2**64, must o/uflow */
             if ix <= 0x3fefffff {
-                return if hy < 0 { HUGE * HUGE } else { TINY * TINY };
+                return if hy < 0 {
+                    overflow(HUGE * HUGE)
+                } else {
+                    underflow(TINY * TINY)
+                };
             }
 
             if ix >= 0x3ff00000 {
-                return if hy > 0 { HUGE * HUGE } else { TINY * TINY };
+                return if hy > 0 {
+                    overflow(HUGE * HUGE)
+                } else {
+                    underflow(TINY * TINY)
+                };
             }
         }
 
         /* over/underflow if x is not close to one */
         if ix < 0x3fefffff {
             return if hy < 0 {
-                s * HUGE * HUGE
+                overflow(s * HUGE * HUGE)
             } else {
-                s * TINY * TINY
+                underflow(s * TINY * TINY)
             };
         }
         if ix > 0x3ff00000 {
             return if hy > 0 {
-                s * HUGE * HUGE
+                overflow(s * HUGE * HUGE)
             } else {
-                s * TINY * TINY
+                underflow(s * TINY * TINY)
             };
         }
 
@@ -349,11 +364,11 @@ pub fn pow(x: f64, y: f64) -> f64 {
         /* z >= 1024 */
         if (j - 0x40900000) | i != 0 {
             /* if z > 1024 */
-            return s * HUGE * HUGE; /* overflow */
+            return overflow(s * HUGE * HUGE); /* overflow */
         }
 
         if p_l + OVT > z - p_h {
-            return s * HUGE * HUGE; /* overflow */
+            return overflow(s * HUGE * HUGE); /* overflow */
         }
     } else if (j & 0x7fffffff) >= 0x4090cc00 {
         /* z <= -1075 */
@@ -361,11 +376,11 @@ pub fn pow(x: f64, y: f64) -> f64 {
 
         if (((j as u32) - 0xc090cc00) | (i as u32)) != 0 {
             /* z < -1075 */
-            return s * TINY * TINY; /* underflow */
+            return underflow(s * TINY * TINY); /* underflow */
         }
 
         if p_l <= z - p_h {
-            return s * TINY * TINY; /* underflow */
+            return underflow(s * TINY * TINY); /* underflow */
         }
     }
 
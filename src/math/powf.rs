@@ -13,6 +13,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, invalidf, overflow, underflow, FE_DIVBYZERO};
 use super::{fabsf, scalbnf, sqrtf};
 
 const BP: [f32; 2] = [1.0, 1.5];
@@ -127,6 +128,9 @@ pub fn powf(x: f32, y: f32) -> f32 {
     }
     if iy == 0x3f800000 {
         /* y is +-1 */
+        if hy < 0 && x == 0.0 {
+            feraiseexcept(FE_DIVBYZERO);
+        }
         return if hy >= 0 { x } else { 1.0 / x };
     }
 
@@ -150,12 +154,15 @@ pub fn powf(x: f32, y: f32) -> f32 {
         z = ax;
         if hy < 0 {
             /* z = (1/|x|) */
+            if ix == 0 {
+                feraiseexcept(FE_DIVBYZERO);
+            }
             z = 1.0 / z;
         }
 
         if hx < 0 {
             if ((ix - 0x3f800000) | yisint) == 0 {
-                z = (z - z) / (z - z); /* (-1)**non-int is NaN */
+                z = invalidf(z); /* (-1)**non-int is NaN */
             } else if yisint == 1 {
                 z = -z; /* (x<0)**odd = -(|x|**odd) */
             }
@@ -167,7 +174,7 @@ pub fn powf(x: f32, y: f32) -> f32 {
     if hx < 0 {
         if yisint == 0 {
             /* (x<0)**(non-int) is NaN */
-            return (x - x) / (x - x);
+            return invalidf(x);
         }
 
         if yisint == 1 {
@@ -182,17 +189,17 @@ pub fn powf(x: f32, y: f32) -> f32 {
         /* over/underflow if x is not close to one */
         if ix < 0x3f7ffff8 {
             return if hy < 0 {
-                sn * HUGE * HUGE
+                overflow(sn * HUGE * HUGE)
             } else {
-                sn * TINY * TINY
+                underflow(sn * TINY * TINY)
             };
         }
 
         if ix > 0x3f800007 {
             return if hy > 0 {
-                sn * HUGE * HUGE
+                overflow(sn * HUGE * HUGE)
             } else {
-                sn * TINY * TINY
+                underflow(sn * TINY * TINY)
             };
         }
 
@@ -285,21 +292,21 @@ pub fn powf(x: f32, y: f32) -> f32 {
     j = z.to_bits() as i32;
     if j > 0x43000000 {
         /* if z > 128 */
-        return sn * HUGE * HUGE; /* overflow */
+        return overflow(sn * HUGE * HUGE); /* overflow */
     } else if j == 0x43000000 {
         /* if z == 128 */
         if p_l + OVT > z - p_h {
-            return sn * HUGE * HUGE; /* overflow */
+            return overflow(sn * HUGE * HUGE); /* overflow */
         }
     } else if (j & 0x7fffffff) > 0x43160000 {
         /* z < -150 */
         // FIXME: check should be  (uint32_t)j > 0xc3160000
-        return sn * TINY * TINY; /* underflow */
+        return underflow(sn * TINY * TINY); /* underflow */
     } else if j as u32 == 0xc3160000
               /* z == -150 */
               && p_l <= z - p_h
     {
-        return sn * TINY * TINY; /* underflow */
+        return underflow(sn * TINY * TINY); /* underflow */
     }
 
     /*
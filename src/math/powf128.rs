@@ -0,0 +1,297 @@
+/* pow(x, y) = exp(y*log(x)), in double-F128 arithmetic; within 1 ulp */
+
+use super::expf128::{EXP_MAX_ARG, EXP_MIN_ARG};
+use super::f128::{dd_add, dd_mul, exponent, invalid, is_zero, quiet_nan, BIAS, SIGN_MASK};
+use super::fenv::{feraiseexcept, FE_DIVBYZERO};
+use super::k_expf128::k_expf128;
+use super::k_logf128::{k_logf128, ln2_mul};
+use super::{scalbnf128, sqrtf128, F128};
+
+/* Classifies y: 0 if it is not an integer, 1 if it is an odd integer and
+ * 2 if it is an even integer */
+#[inline]
+fn yisint(y: F128) -> i32 {
+    let e = exponent(y);
+    if e >= BIAS + 113 {
+        /* even integer y, also inf */
+        2
+    } else if e >= BIAS {
+        /* the bit of weight 1 and the bits below it */
+        let k = (BIAS + 112 - e) as u32;
+        let m = y.to_bits() | 1 << 112;
+        if m << (127 - k) << 1 != 0 {
+            0
+        } else {
+            2 - (m >> k & 1) as i32
+        }
+    } else {
+        0
+    }
+}
+
+/* The rounded result of an overflow or underflow, with sign s */
+#[inline]
+fn overflow(s: bool) -> F128 {
+    let z = scalbnf128(F128::MAX, 1);
+    if s {
+        -z
+    } else {
+        z
+    }
+}
+
+#[inline]
+fn underflow(s: bool) -> F128 {
+    let z = scalbnf128(F128::MIN_POSITIVE, -200);
+    if s {
+        -z
+    } else {
+        z
+    }
+}
+
+/// Returns `x` to the power of `y` (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powf128(x: F128, y: F128) -> F128 {
+    let ax = F128::from_bits(x.to_bits() & !SIGN_MASK);
+    let ay = F128::from_bits(y.to_bits() & !SIGN_MASK);
+
+    /* x**0 = 1, even if x is NaN */
+    if is_zero(y) {
+        return F128::ONE;
+    }
+
+    /* 1**y = 1, even if y is NaN */
+    if x == F128::ONE {
+        return F128::ONE;
+    }
+
+    /* NaN if either arg is NaN */
+    if x.is_nan() || y.is_nan() {
+        return quiet_nan(x, y);
+    }
+
+    /* determine if y is an odd int when x < 0 */
+    let yint = if x.is_sign_negative() { yisint(y) } else { 0 };
+
+    /* special value of y */
+    if y.is_infinite() {
+        return if ax == F128::ONE {
+            /* (-1)**+-inf is 1 */
+            F128::ONE
+        } else if (ax > F128::ONE) != y.is_sign_negative() {
+            /* (|x|>1)**+inf = inf, (|x|<1)**-inf = inf */
+            F128::INFINITY
+        } else {
+            F128::ZERO
+        };
+    }
+    if ay == F128::ONE {
+        /* y is +-1 */
+        if y.is_sign_negative() {
+            if is_zero(x) {
+                feraiseexcept(FE_DIVBYZERO);
+            }
+            return F128::ONE / x;
+        }
+        return x;
+    }
+    if y == F128::from(2) {
+        return x * x;
+    }
+    if y == F128::from_bits(0x3ffe0000000000000000000000000000) && !x.is_sign_negative() {
+        /* y is 0.5 and x >= +0 */
+        return sqrtf128(x);
+    }
+
+    /* special value of x */
+    if ax.is_infinite() || is_zero(ax) || ax == F128::ONE {
+        /* x is +-0,+-inf,-1 */
+        let mut z = ax;
+        if y.is_sign_negative() {
+            /* z = (1/|x|) */
+            if is_zero(ax) {
+                feraiseexcept(FE_DIVBYZERO);
+            }
+            z = F128::ONE / z;
+        }
+        if x.is_sign_negative() {
+            if ax == F128::ONE && yint == 0 {
+                /* (-1)**non-int is NaN */
+                return invalid();
+            } else if yint == 1 {
+                /* (x<0)**odd = -(|x|**odd) */
+                z = -z;
+            }
+        }
+        return z;
+    }
+
+    if x.is_sign_negative() && yint == 0 {
+        /* (x<0)**(non-int) is NaN */
+        return invalid();
+    }
+    /* sign of result */
+    let s = yint == 1;
+
+    /* |y| >= 2^127, where |y*log(x)| > 2^14 unless |x| is 1 */
+    if exponent(y) >= BIAS + 127 {
+        return if (ax > F128::ONE) != y.is_sign_negative() {
+            overflow(s)
+        } else {
+            underflow(s)
+        };
+    }
+
+    /* y*log|x| as a pair hi, lo */
+    let (e, hi, lo) = k_logf128(ax);
+    let (hi, lo) = dd_mul((y, F128::ZERO), dd_add(ln2_mul(e), (hi, lo)));
+    if hi > F128::from_bits(EXP_MAX_ARG) {
+        return overflow(s);
+    }
+    if hi < F128::from_bits(EXP_MIN_ARG) {
+        return underflow(s);
+    }
+    let (k, a, b) = k_expf128(hi, lo);
+    let z = scalbnf128(a + b, k);
+    if s {
+        -z
+    } else {
+        z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::check_reference2;
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* exact results */
+        assert_eq!(powf128(F128::from(3), F128::from(5)), F128::from(243));
+        assert_eq!(
+            powf128(F128::from(-2), F128::from(-3)).to_bits(),
+            0xbffc0000000000000000000000000000
+        );
+        assert_eq!(
+            powf128(F128::from(10), F128::from(30)).to_bits(),
+            0x406293e5939a08ce9dbd480000000000
+        );
+        /* 2^0.5 and 10^-0.3 */
+        assert_eq!(
+            powf128(F128::from(2), f(0x3ffe0000000000000000000000000000)).to_bits(),
+            0x3fff6a09e667f3bcc908b2fb1366ea95
+        );
+        assert_eq!(
+            powf128(F128::from(10), f(0xbffd3333333333333333333333333333)).to_bits(),
+            0x3ffe009b9cf3342525ec18c6312066e9
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(powf128(F128::NAN, F128::ZERO), F128::ONE);
+        assert_eq!(powf128(F128::ONE, F128::NAN), F128::ONE);
+        assert!(powf128(F128::from(2), F128::NAN).is_nan());
+        assert_eq!(powf128(-F128::ONE, F128::INFINITY), F128::ONE);
+        assert_eq!(powf128(F128::from(2), F128::NEG_INFINITY), F128::ZERO);
+        assert_eq!(powf128(F128::ZERO, -F128::ONE), F128::INFINITY);
+        assert_eq!(powf128(-F128::ZERO, F128::from(-3)), F128::NEG_INFINITY);
+        assert!(powf128(F128::from(-2), f(0x3ffe0000000000000000000000000000)).is_nan());
+        assert_eq!(powf128(F128::from(10), F128::from(5000)), F128::INFINITY);
+        assert_eq!(powf128(F128::from(-10), F128::from(-5001)), -F128::ZERO);
+    }
+
+    const REFERENCE: [(u128, u128, u128); 16] = [
+        (
+            0x3ff709a7ba45bffd2d04a65004256bc2,
+            0xc000a8776bd91ff65fedd0e970259049,
+            0x401946bf9d4473bc69a8f7c86d05a1a6,
+        ),
+        (
+            0x3ff869b0a31c259d73a32ce934ccda96,
+            0xc003f2fa0ac1e02e86d13d962275a86e,
+            0x40c9af547b833c9be9819eefcc898114,
+        ),
+        (
+            0x3ff86cf5bb3213cb5244515714e1e52c,
+            0x4000186bcfeee5bd883439aad113f736,
+            0x3ff0b93141571f6644988d09ecd1fcc7,
+        ),
+        (
+            0x3ff8877e05f31e4b69ad04a3f3980ab7,
+            0xc0011937adbd310c59dcaa4e1b57886e,
+            0x401b0bdbe8525d0625762fa4e50044b3,
+        ),
+        (
+            0x3ff9bcc8ebc05e6cde0201680a6dc5d0,
+            0x3ffeafef4b91944fdc01c1c4caa444a7,
+            0x3ffa86e29fec68c26b29eddd3eb1e94f,
+        ),
+        (
+            0x3ffa814db6d027ba28cab77ecfa92975,
+            0x400036a46cc4c6abedb1edb9863585e6,
+            0x3ff43a870fc719533487b3eea1e12857,
+        ),
+        (
+            0x3ffb94012d6177feab3d9dfd4cbb8a91,
+            0x3ffdbb34e7e6bc372ccc0b86991e9c8f,
+            0x3ffd77bf3703e5b644680f7079ecd777,
+        ),
+        (
+            0x3ffd6141af60c06418870601ddca98e4,
+            0xbff888505442e2fc464c5bc7087f5839,
+            0x3fff0348676fc4b5f74bbe361db0124e,
+        ),
+        (
+            0x3ffd9afd0882bb2cf9ceb92b6fecf55a,
+            0x3ffbaa2bac143c4439a6098173b3853c,
+            0x3ffed19b3fc150ec834209e185f252f1,
+        ),
+        (
+            0x40000df9f05163c3b22ddcb6981951c4,
+            0xbff79ac857defc838c6e27afec2a01ba,
+            0x3ffefd9c4b629848eaa35dffdc7f88ed,
+        ),
+        (
+            0x40009a3ac9982f276d046d47b29636b9,
+            0x4003705120e332bdebb3d4962b09fb98,
+            0x40259a1ec8bddb187c8b04303ad5f26f,
+        ),
+        (
+            0x40018698e6c4608bfd459b75bf14aa8a,
+            0xbffcb64e486b885dceaf9f986f08e80d,
+            0x3ffe5ba7e1b3627b0456d8116847cb59,
+        ),
+        (
+            0x4001a75a11ed5a1e14f00b1afb8fc72a,
+            0xbffac67fa396138041ca4022c46bdfa7,
+            0x3ffecd0c7679cf8a8a0448b3ba537127,
+        ),
+        (
+            0x400276e95f46bb84906e81f724c057f3,
+            0xbff813720a63980b720733ba7e663dfb,
+            0x3ffef5846af7a07b1f46e1a0d857b792,
+        ),
+        (
+            0x40038ac9dfa25b4464a4f99972074ef2,
+            0x4002084a508c0656575abb13f0524bda,
+            0x402525982f08e7496c7c202396dd56c4,
+        ),
+        (
+            0x4005d3bb95acc82f29b40f2ce44e7a7f,
+            0xbffd4cb79b5b28b997ead3c266e090a8,
+            0x3ffcb3ee3bb1722a623e4fb41cfb5fab,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference2(powf128, &REFERENCE, 1);
+    }
+}
@@ -0,0 +1,52 @@
+use super::fenv::{feraiseexcept, overflow, FE_DIVBYZERO};
+
+/// Power with an i32 exponent (f64)
+///
+/// Raises `x` to the power `n` by repeated squaring, like `f64::powi`. This is
+/// usually faster than `pow`, but rounds at every step; use `pown` for an
+/// accurate result.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powi(x: f64, n: i32) -> f64 {
+    let mut a = x;
+    let mut b = n;
+    let recip = b < 0;
+    let mut r = 1.0;
+    loop {
+        if b & 1 != 0 {
+            r *= a;
+        }
+        b /= 2;
+        if b == 0 {
+            break;
+        }
+        a *= a;
+    }
+    if recip {
+        r = 1.0 / r;
+    }
+    if r.is_infinite() && x.is_finite() {
+        if x == 0.0 {
+            feraiseexcept(FE_DIVBYZERO);
+        } else {
+            return overflow(r);
+        }
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(powi(2.0, 10), 1024.0);
+        assert_eq!(powi(-2.0, 3), -8.0);
+        assert_eq!(powi(2.0, -2), 0.25);
+        assert_eq!(powi(NAN, 0), 1.0);
+        assert_eq!(powi(-0.0, -1), NEG_INFINITY);
+        assert_eq!(powi(2.0, i32::min_value()), 0.0);
+        assert_eq!(powi(1.5, i32::max_value()), INFINITY);
+    }
+}
@@ -0,0 +1,35 @@
+use super::fenv::{feraiseexcept, overflow, FE_DIVBYZERO};
+
+/// Power with an i32 exponent (f32)
+///
+/// Raises `x` to the power `n` by repeated squaring, like `f32::powi`. This is
+/// usually faster than `powf`, but rounds at every step; use `pownf` for an
+/// accurate result.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powif(x: f32, n: i32) -> f32 {
+    let mut a = x;
+    let mut b = n;
+    let recip = b < 0;
+    let mut r = 1.0;
+    loop {
+        if b & 1 != 0 {
+            r *= a;
+        }
+        b /= 2;
+        if b == 0 {
+            break;
+        }
+        a *= a;
+    }
+    if recip {
+        r = 1.0 / r;
+    }
+    if r.is_infinite() && x.is_finite() {
+        if x == 0.0 {
+            feraiseexcept(FE_DIVBYZERO);
+        } else {
+            return overflow(r);
+        }
+    }
+    r
+}
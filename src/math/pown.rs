@@ -0,0 +1,55 @@
+use super::{fabs, pow};
+
+/// Power with an integer exponent (f64)
+///
+/// Returns `x` raised to the power `n`. Unlike `pow(x, n as f64)`, the sign
+/// of the result follows the parity of `n` even when `n` is too large to be
+/// represented exactly as a `f64`. `pown(x, 0)` is 1 for any `x`, NaN included.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pown(x: f64, n: i64) -> f64 {
+    let r = pow(fabs(x), n as f64);
+    if n & 1 != 0 && x.is_sign_negative() {
+        -r
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert_eq!(pown(NAN, 0), 1.0);
+        assert_eq!(pown(INFINITY, 0), 1.0);
+        assert!(pown(NAN, 3).is_nan());
+        assert_eq!(pown(0.0, -3), INFINITY);
+        assert_eq!(pown(-0.0, -3), NEG_INFINITY);
+        assert_eq!(pown(-0.0, -2), INFINITY);
+        assert_eq!(pown(-0.0, 3).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(pown(-0.0, 2).to_bits(), 0.0f64.to_bits());
+        assert_eq!(pown(NEG_INFINITY, 3), NEG_INFINITY);
+        assert_eq!(pown(NEG_INFINITY, -3).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(pown(NEG_INFINITY, 2), INFINITY);
+    }
+
+    #[test]
+    fn large_exponents() {
+        /* i64::MAX rounds to the even 2^63 as a f64 */
+        assert_eq!(pown(-1.0, i64::max_value()), -1.0);
+        assert_eq!(pown(-1.0, i64::max_value() - 1), 1.0);
+        assert_eq!(pown(-1.0, i64::min_value()), 1.0);
+        assert_eq!(pown(-2.0, i64::max_value()), NEG_INFINITY);
+        assert_eq!(pown(-0.5, i64::max_value()).to_bits(), (-0.0f64).to_bits());
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(pown(-3.0, 3), -27.0);
+        assert_eq!(pown(-2.0, -3), -0.125);
+        assert_eq!(pown(10.0, 22), 1e22);
+        assert_eq!(pown(2.0, -1074), 5e-324);
+    }
+}
@@ -0,0 +1,11 @@
+use super::fenv::narrow;
+use super::pown;
+
+/// Power with an integer exponent (f32)
+///
+/// Returns `x` raised to the power `n`, with the sign following the parity of
+/// `n`. The computation is carried out in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn pownf(x: f32, n: i64) -> f32 {
+    narrow(pown(x as f64, n))
+}
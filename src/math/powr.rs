@@ -0,0 +1,64 @@
+use super::fenv::invalid;
+use super::{fabs, pow};
+
+/// Power defined by `exp(y * log(x))` (f64)
+///
+/// Returns `x` raised to the power `y` for `x >= 0`, following IEEE 754
+/// `powr`: negative `x` is a domain error and so are `powr(0, 0)`,
+/// `powr(inf, 0)` and `powr(1, inf)`, all of which give NaN. Unlike `pow`,
+/// NaN is never turned into 1 and `-0.0` behaves like `+0.0`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powr(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return x + y;
+    }
+    if x < 0.0 {
+        /* powr(x<0, y) = NaN */
+        return invalid(x);
+    }
+    if y == 0.0 {
+        if x == 0.0 || x.is_infinite() {
+            /* powr(0, 0) = powr(inf, 0) = NaN */
+            return invalid(y);
+        }
+        return 1.0;
+    }
+    if x == 1.0 && y.is_infinite() {
+        /* powr(1, inf) = NaN */
+        return invalid(y);
+    }
+    pow(fabs(x), y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert!(powr(-1.0, 2.0).is_nan());
+        assert!(powr(NEG_INFINITY, 2.0).is_nan());
+        assert!(powr(0.0, 0.0).is_nan());
+        assert!(powr(-0.0, -0.0).is_nan());
+        assert!(powr(INFINITY, 0.0).is_nan());
+        assert!(powr(1.0, INFINITY).is_nan());
+        assert!(powr(1.0, NEG_INFINITY).is_nan());
+        assert!(powr(1.0, NAN).is_nan());
+        assert!(powr(NAN, 0.0).is_nan());
+        assert_eq!(powr(1.0, 5.0), 1.0);
+        assert_eq!(powr(2.0, 0.0), 1.0);
+        assert_eq!(powr(0.0, -1.0), INFINITY);
+        assert_eq!(powr(-0.0, -3.0), INFINITY);
+        assert_eq!(powr(-0.0, 3.0).to_bits(), 0.0f64.to_bits());
+        assert_eq!(powr(0.0, NEG_INFINITY), INFINITY);
+        assert_eq!(powr(INFINITY, -1.0), 0.0);
+        assert_eq!(powr(0.5, INFINITY), 0.0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(powr(2.0, 10.0), 1024.0);
+        assert_eq!(powr(4.0, 0.5), 2.0);
+    }
+}
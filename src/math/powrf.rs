@@ -0,0 +1,11 @@
+use super::fenv::narrow;
+use super::powr;
+
+/// Power defined by `exp(y * log(x))` (f32)
+///
+/// Returns `x` raised to the power `y` for `x >= 0`, with the special cases
+/// of `powr`. The computation is carried out in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn powrf(x: f32, y: f32) -> f32 {
+    narrow(powr(x as f64, y as f64))
+}
@@ -0,0 +1,15 @@
+use super::fenv::{feraiseexcept, overflow, FE_DIVBYZERO};
+
+/// Reciprocal (f64)
+///
+/// Returns `1.0 / x`, like `f64::recip`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn recip(x: f64) -> f64 {
+    let r = 1.0 / x;
+    if x == 0.0 {
+        feraiseexcept(FE_DIVBYZERO);
+    } else if r.is_infinite() && x.is_finite() {
+        return overflow(r);
+    }
+    r
+}
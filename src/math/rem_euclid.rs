@@ -0,0 +1,15 @@
+use super::{fabs, fmod};
+
+/// Euclidean remainder (f64)
+///
+/// Returns the least nonnegative remainder of `x` divided by `y`, like
+/// `f64::rem_euclid`. Rounding can make the result equal to `|y|`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rem_euclid(x: f64, y: f64) -> f64 {
+    let r = fmod(x, y);
+    if r < 0.0 {
+        r + fabs(y)
+    } else {
+        r
+    }
+}
@@ -0,0 +1,15 @@
+use super::{fabsf, fmodf};
+
+/// Euclidean remainder (f32)
+///
+/// Returns the least nonnegative remainder of `x` divided by `y`, like
+/// `f32::rem_euclid`. Rounding can make the result equal to `|y|`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rem_euclidf(x: f32, y: f32) -> f32 {
+    let r = fmodf(x, y);
+    if r < 0.0 {
+        r + fabsf(y)
+    } else {
+        r
+    }
+}
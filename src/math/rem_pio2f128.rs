@@ -0,0 +1,76 @@
+use super::f128::{dd_add, exponent, split, to_int, two_sum, BIAS};
+use super::{rem_pio2_large, roundf128, F128};
+
+/* 2/pi, and pi/2 as PIO2_1 + PIO2_2 + PIO2_3 where the first two have 68 bits
+ * so that n*PIO2_1 and n*PIO2_2 are exact for |n| < 2^45 */
+const INV_PIO2: F128 = F128::from_bits(0x3ffe45f306dc9c882a53f84eafa3ea6a);
+const PIO2_1: F128 = F128::from_bits(0x3fff921fb54442d18469800000000000);
+const PIO2_2: F128 = F128::from_bits(0x3fba3198a2e03707344a400000000000);
+const PIO2_3: F128 = F128::from_bits(0x3f7127044533e63a0105df531d89cd91);
+
+// return the remainder of x rem pi/2 in y[0]+y[1]
+// use rem_pio2_large() for large x, or when the medium case cancels too much
+//
+// caller must handle the case when reduction is not needed: |x| ~<= pi/4
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn rem_pio2f128(x: F128) -> (i32, F128, F128) {
+    if exponent(x) < BIAS + 20 {
+        /* |x| < 2^20, medium size: x - n*PIO2_1 is exact, and pi/2 is known
+         * to about 249 bits */
+        let f_n = roundf128(x * INV_PIO2);
+        let (hi, lo) = two_sum(x - f_n * PIO2_1, -(f_n * PIO2_2));
+        let (hi, lo) = dd_add((hi, lo), (-(f_n * PIO2_3), F128::ZERO));
+        if exponent(hi) > BIAS - 100 {
+            return (to_int(f_n), hi, lo);
+        }
+    }
+
+    /* break |x| into five 24 bit pieces, the first scaled by 2^e0 */
+    let (m, e) = split(x);
+    let m = m << 7;
+    let mut tx = [0.0; 5];
+    for (i, t) in tx.iter_mut().enumerate() {
+        *t = (m >> (96 - 24 * i) & 0xffffff) as f64;
+    }
+    /* skip zero terms, first term is non-zero */
+    let mut i = 4;
+    while i != 0 && i!(tx, i) == 0.0 {
+        i -= 1;
+    }
+    let mut ty = [0.0; 3];
+    let n = rem_pio2_large(&tx[..=i], &mut ty, e + 89, 3);
+    let t = F128::from(i!(ty, 2)) + F128::from(i!(ty, 1));
+    let w = F128::from(i!(ty, 0));
+    let hi = t + w;
+    let lo = w - (hi - t);
+    if x.is_sign_negative() {
+        return (-n, -hi, -lo);
+    }
+    (n, hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn near_multiples_of_pi() {
+        /* pi rounded, 10^6 and 2^16383 */
+        let (n, hi, _) = rem_pio2f128(f(0x4000921fb54442d18469898cc51701b8));
+        assert_eq!((n, hi.to_bits()), (2, 0xbf8dcd129024e088a67cc74020bbea64));
+        let (n, hi, _) = rem_pio2f128(F128::from(1000000));
+        assert_eq!(
+            (n, hi.to_bits()),
+            (636620, 0xbffd6e254d0f6b39875a7869e42179f1)
+        );
+        let (n, hi, _) = rem_pio2f128(f(0x7ffe0000000000000000000000000000));
+        assert_eq!(
+            (n & 3, hi.to_bits()),
+            (0, 0x3ffd9989d949449af19659ee5bb475b2)
+        );
+    }
+}
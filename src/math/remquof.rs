@@ -1,3 +1,5 @@
+use super::fenv::{feraiseexcept, FE_INVALID};
+
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn remquof(mut x: f32, mut y: f32) -> (f32, i32) {
     let ux: u32 = x.to_bits();
@@ -11,6 +13,9 @@ pub fn remquof(mut x: f32, mut y: f32) -> (f32, i32) {
     let mut uxi: u32 = ux;
 
     if (uy << 1) == 0 || y.is_nan() || ex == 0xff {
+        if !x.is_nan() && !y.is_nan() {
+            feraiseexcept(FE_INVALID);
+        }
         return ((x * y) / (x * y), 0);
     }
     if (ux << 1) == 0 {
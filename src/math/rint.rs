@@ -0,0 +1,91 @@
+use super::fenv::{
+    fegetround, feraiseexcept, FE_DOWNWARD, FE_INEXACT, FE_TONEAREST, FE_TOWARDZERO, FE_UPWARD,
+};
+use super::{ceil, floor, trunc};
+use core::f64;
+
+const TOINT: f64 = 1. / f64::EPSILON;
+
+/// Round to integral value (f64)
+///
+/// Rounds `x` to an integer value in floating-point format, using the
+/// current rounding mode as reported by `fegetround`. The inexact exception
+/// is raised if the result differs in value from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rint(x: f64) -> f64 {
+    let mode = fegetround();
+    let y = if mode == FE_TONEAREST {
+        rint_nearest(x)
+    } else if mode == FE_TOWARDZERO {
+        trunc(x)
+    } else if mode == FE_UPWARD {
+        ceil(x)
+    } else if mode == FE_DOWNWARD {
+        floor(x)
+    } else {
+        rint_nearest(x)
+    };
+    if y != x && !x.is_nan() {
+        feraiseexcept(FE_INEXACT);
+    }
+    y
+}
+
+/// Rounds `x` to the nearest integer, with ties going to the even integer.
+#[inline]
+fn rint_nearest(x: f64) -> f64 {
+    let ui = x.to_bits();
+    let e = (ui >> 52 & 0x7ff) as i32;
+    let s = (ui >> 63) != 0;
+
+    if e >= 0x3ff + 52 {
+        return x;
+    }
+    let y = if s {
+        x - TOINT + TOINT
+    } else {
+        x + TOINT - TOINT
+    };
+    if y == 0. {
+        if s {
+            -0.
+        } else {
+            0.
+        }
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn negative_zero() {
+        assert_eq!(rint(-0.0_f64).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(rint(-0.25_f64).to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(rint(-1.0), -1.0);
+        assert_eq!(rint(2.8), 3.0);
+        assert_eq!(rint(-0.5), -0.0);
+        assert_eq!(rint(0.5), 0.0);
+        assert_eq!(rint(-1.5), -2.0);
+        assert_eq!(rint(1.5), 2.0);
+        assert_eq!(rint(2.5), 2.0);
+        assert_eq!(rint(4503599627370495.5), 4503599627370496.0);
+    }
+
+    /// The spec: https://en.cppreference.com/w/cpp/numeric/math/rint
+    #[test]
+    fn spec_tests() {
+        assert!(rint(NAN).is_nan());
+        for f in [0.0, -0.0, INFINITY, NEG_INFINITY].iter().copied() {
+            assert_eq!(rint(f), f);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::fenv::{
+    fegetround, feraiseexcept, FE_DOWNWARD, FE_INEXACT, FE_TONEAREST, FE_TOWARDZERO, FE_UPWARD,
+};
+use super::{ceilf, floorf, truncf};
+use core::f32;
+
+const TOINT: f32 = 1. / f32::EPSILON;
+
+/// Round to integral value (f32)
+///
+/// Rounds `x` to an integer value in floating-point format, using the
+/// current rounding mode as reported by `fegetround`. The inexact exception
+/// is raised if the result differs in value from `x`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf(x: f32) -> f32 {
+    let mode = fegetround();
+    let y = if mode == FE_TONEAREST {
+        rintf_nearest(x)
+    } else if mode == FE_TOWARDZERO {
+        truncf(x)
+    } else if mode == FE_UPWARD {
+        ceilf(x)
+    } else if mode == FE_DOWNWARD {
+        floorf(x)
+    } else {
+        rintf_nearest(x)
+    };
+    if y != x && !x.is_nan() {
+        feraiseexcept(FE_INEXACT);
+    }
+    y
+}
+
+/// Rounds `x` to the nearest integer, with ties going to the even integer.
+#[inline]
+fn rintf_nearest(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let e = (ui >> 23 & 0xff) as i32;
+    let s = (ui >> 31) != 0;
+
+    if e >= 0x7f + 23 {
+        return x;
+    }
+    let y = if s {
+        x - TOINT + TOINT
+    } else {
+        x + TOINT - TOINT
+    };
+    if y == 0. {
+        if s {
+            -0.
+        } else {
+            0.
+        }
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(rintf(-1.0), -1.0);
+        assert_eq!(rintf(2.8), 3.0);
+        assert_eq!(rintf(-0.5).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(rintf(0.5), 0.0);
+        assert_eq!(rintf(-1.5), -2.0);
+        assert_eq!(rintf(2.5), 2.0);
+        assert_eq!(rintf(8388607.5), 8388608.0);
+    }
+
+    /// The spec: https://en.cppreference.com/w/cpp/numeric/math/rint
+    #[test]
+    fn spec_tests() {
+        assert!(rintf(NAN).is_nan());
+        for f in [0.0, -0.0, INFINITY, NEG_INFINITY].iter().copied() {
+            assert_eq!(rintf(f), f);
+        }
+    }
+}
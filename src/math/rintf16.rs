@@ -0,0 +1,42 @@
+use super::{rintf, F16};
+
+/// Round to integral value in the current rounding mode (F16)
+///
+/// Rounds `x` to an integer in the current rounding direction, with halfway
+/// cases to even when rounding to nearest. The inexact exception is raised
+/// when the result differs from `x`.
+/// The integer is computed exactly in `f32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rintf16(x: F16) -> F16 {
+    F16::from_f32(rintf(f32::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(rintf16(F16::from_f32(-1.5)), F16::from_f32(-2.0));
+        assert_eq!(rintf16(F16::from_f32(0.5)), F16::from_f32(0.0));
+        assert_eq!(rintf16(F16::from_f32(1022.5)), F16::from_f32(1022.0));
+        assert_eq!(rintf16(F16::from_f32(2.5)), F16::from_f32(2.0));
+        assert_eq!(rintf16(F16::MAX), F16::MAX);
+        assert!(rintf16(-F16::ZERO).is_sign_negative());
+        assert!(rintf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        /* the same integers as in f32 */
+        for bits in 0..=u16::MAX {
+            let x = F16::from_bits(bits);
+            let z = rintf(f32::from(x));
+            if z.is_nan() {
+                assert!(rintf16(x).is_nan());
+            } else {
+                assert_eq!(f32::from(rintf16(x)).to_bits(), z.to_bits());
+            }
+        }
+    }
+}
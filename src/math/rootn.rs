@@ -0,0 +1,96 @@
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
+use super::{cbrt, fabs, frexp, pow, round, scalbn, sqrt};
+
+/// Integer root (f64)
+///
+/// Returns the `n`th root of `x`, that is `x` raised to the power `1/n`. For odd
+/// `n` the root of a negative `x` is negative; for even `n` it is NaN, as is
+/// `rootn(x, 0)`. The result stays within an ulp or so of the exact root, which
+/// `pow(x, 1.0 / n as f64)` does not manage since `1/n` is rounded.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootn(x: f64, n: i64) -> f64 {
+    let odd = n & 1 != 0;
+
+    if n == 0 || (x < 0.0 && !odd) {
+        /* rootn(x, 0) = rootn(x<0, even) = NaN */
+        return invalid(x);
+    }
+    if x == 0.0 || !x.is_finite() {
+        /* +-0, +-inf and nan: the result only depends on the sign of n */
+        if x == 0.0 && n < 0 {
+            feraiseexcept(FE_DIVBYZERO);
+        }
+        let r = if n > 0 { fabs(x) } else { 1.0 / fabs(x) };
+        return if odd && x.is_sign_negative() { -r } else { r };
+    }
+    match n {
+        1 => return x,
+        -1 => return 1.0 / x,
+        2 => return sqrt(x),
+        3 => return cbrt(x),
+        _ => {}
+    }
+
+    let a = fabs(x);
+    let r = if !(-2048..=2048).contains(&n) {
+        /* |log(a)/n| < 1/2, so rounding 1/n hardly matters */
+        pow(a, 1.0 / n as f64)
+    } else {
+        /*
+         * Scale a by 2^(-q*n) into [2^-1024, 2^1024] so that a^(1/n) is within
+         * a factor of 2 of 1. The rounding of 1/n then costs about an ulp,
+         * which one Newton step for r^n = a removes.
+         */
+        let n = n as i32;
+        let (_, e) = frexp(a);
+        let q = round(e as f64 / n as f64) as i32;
+        let a = scalbn(a, -q * n);
+        let r = pow(a, 1.0 / n as f64);
+        let r = r + r * (a / pow(r, n as f64) - 1.0) / n as f64;
+        scalbn(r, q)
+    };
+    if odd && x < 0.0 {
+        -r
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn spec_tests() {
+        assert!(rootn(1.0, 0).is_nan());
+        assert!(rootn(-8.0, 2).is_nan());
+        assert!(rootn(NEG_INFINITY, -4).is_nan());
+        assert!(rootn(NAN, 3).is_nan());
+        assert_eq!(rootn(0.0, -3), INFINITY);
+        assert_eq!(rootn(-0.0, -3), NEG_INFINITY);
+        assert_eq!(rootn(-0.0, -2), INFINITY);
+        assert_eq!(rootn(-0.0, 3).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(rootn(-0.0, 2).to_bits(), 0.0f64.to_bits());
+        assert_eq!(rootn(NEG_INFINITY, 5), NEG_INFINITY);
+        assert_eq!(rootn(NEG_INFINITY, -5).to_bits(), (-0.0f64).to_bits());
+        assert_eq!(rootn(INFINITY, -2), 0.0);
+    }
+
+    #[test]
+    fn exact_roots() {
+        assert_eq!(rootn(-27.0, 3), -3.0);
+        assert_eq!(rootn(16.0, 4), 2.0);
+        assert_eq!(rootn(-32.0, -5), -0.5);
+        assert_eq!(rootn(1e300, 100), 1000.0);
+        assert_eq!(rootn(5e-324, -1074), 2.0);
+        assert_eq!(rootn(2.0, i64::max_value()), 1.0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(rootn(2.0, 5), 1.148698354997035);
+        assert_eq!(rootn(1e300, 9), 2.1544346900318838e33);
+        assert_eq!(rootn(1e300, -7), 1.3894954943731376e-43);
+    }
+}
@@ -0,0 +1,11 @@
+use super::fenv::narrow;
+use super::rootn;
+
+/// Integer root (f32)
+///
+/// Returns the `n`th root of `x`, negative for a negative `x` and odd `n`. The
+/// computation is carried out in double precision.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rootnf(x: f32, n: i64) -> f32 {
+    narrow(rootn(x as f64, n))
+}
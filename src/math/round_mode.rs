@@ -0,0 +1,37 @@
+use super::fenv::{fegetround, FE_DOWNWARD, FE_TOWARDZERO, FE_UPWARD};
+
+/// Rounding direction
+///
+/// Selects how a value that is not exactly representable in the result is
+/// rounded. The variants correspond to the `FP_INT_*` macros of C23 and to the
+/// rounding-direction attributes of IEEE 754, except for `Odd`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Round {
+    /// Round to nearest, ties to even (`FP_INT_TONEAREST`)
+    Nearest,
+    /// Round to nearest, ties away from zero (`FP_INT_TONEARESTFROMZERO`)
+    NearestAway,
+    /// Round toward zero (`FP_INT_TOWARDZERO`)
+    TowardZero,
+    /// Round toward positive infinity (`FP_INT_UPWARD`)
+    Up,
+    /// Round toward negative infinity (`FP_INT_DOWNWARD`)
+    Down,
+    /// Round to odd: toward zero, then to the neighbor with an odd last bit
+    /// when inexact. A value rounded to odd, then to nearest in a format at
+    /// least two bits narrower, rounds as the exact value would.
+    Odd,
+}
+
+impl Round {
+    /* The direction of the rounding mode set with `fesetround`, for the
+     * functions that round in software as the hardware would */
+    pub(crate) fn current() -> Round {
+        match fegetround() {
+            FE_DOWNWARD => Round::Down,
+            FE_UPWARD => Round::Up,
+            FE_TOWARDZERO => Round::TowardZero,
+            _ => Round::Nearest,
+        }
+    }
+}
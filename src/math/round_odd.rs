@@ -0,0 +1,17 @@
+/* Rounds x, the correctly rounded result of an operation, to odd instead:
+ * of the two doubles around the exact value, the one with an odd last bit.
+ * err is the rounding error exact - x, or anything with its sign. A second
+ * rounding to nearest, to a format with at least two bits of precision less
+ * than double such as float, is then the correct rounding of the exact value. */
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub(crate) fn round_odd(x: f64, err: f64) -> f64 {
+    let ui = x.to_bits();
+    if err == 0.0 || ui & 1 == 1 || !x.is_finite() {
+        return x;
+    }
+    if (err > 0.0) == (x > 0.0) {
+        f64::from_bits(ui + 1)
+    } else {
+        f64::from_bits(ui - 1)
+    }
+}
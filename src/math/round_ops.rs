@@ -0,0 +1,444 @@
+//! Arithmetic with an explicit rounding direction.
+//!
+//! These compute the exact result in integer arithmetic and round it once in
+//! the given direction, whatever the rounding mode set with `fesetround`.
+//! They raise the exceptions of the operation, as if it had been done in
+//! hardware under that rounding mode.
+
+use super::fenv::{
+    feraiseexcept, invalid, FE_DIVBYZERO, FE_INEXACT, FE_INVALID, FE_OVERFLOW, FE_UNDERFLOW,
+};
+use super::fma::{mul_add, normalize, Num, ZEROINFNAN};
+use super::Round;
+
+/// The result of an operation, before rounding.
+pub(crate) enum Exact {
+    /// A zero, infinite or NaN result, which needs no rounding in any format.
+    Special(f64),
+    /// The finite nonzero result `m * 2^e`, negative if `sign`. The last bit
+    /// of `m` is sticky: it is set if any nonzero bit was dropped below it.
+    Finite { m: u64, e: i32, sign: bool },
+}
+
+/// Rounds the finite nonzero `(-1)^sign * m * 2^e`, where `m` has a sticky
+/// last bit, to the binary format with `p` bits of precision and exponent
+/// bias `bias`, in the direction `round`. Returns the bits of the result.
+#[inline]
+pub(crate) fn round_bits(
+    mut m: u64,
+    mut e: i32,
+    sign: bool,
+    round: Round,
+    p: i32,
+    bias: i32,
+) -> u64 {
+    let max = 2 * bias + 1; /* the all-ones exponent field */
+    let sign_bit = (sign as u64) << (p + (max as u32 + 1).trailing_zeros() as i32 - 1);
+
+    /* normalize m to have its top bit at bit 62, keeping the sticky bit */
+    let lz = m.leading_zeros() as i32 - 1;
+    if lz < 0 {
+        m = m >> 1 | (m & 1);
+        e += 1;
+    } else {
+        m <<= lz;
+        e -= lz;
+    }
+
+    /* biased exponent of the result, if normal */
+    let be = e + 62 + bias;
+    let away = match round {
+        Round::Nearest | Round::NearestAway => true,
+        Round::TowardZero | Round::Odd => false,
+        Round::Up => !sign,
+        Round::Down => sign,
+    };
+    if be >= max {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        let inf = (max as u64) << (p - 1);
+        return sign_bit | if away { inf } else { inf - 1 };
+    }
+
+    /* number of bits of m below the last bit kept */
+    let shift = 63 - p + if be < 1 { 1 - be } else { 0 };
+    let (mut kept, above, tie, inexact) = if shift < 64 {
+        let rem = m & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        (m >> shift, rem > half, rem == half, rem != 0)
+    } else {
+        /* below half of the smallest subnormal */
+        (0, false, false, true)
+    };
+    let up = match round {
+        Round::Nearest => above || (tie && kept & 1 == 1),
+        Round::NearestAway => above || tie,
+        Round::Odd => inexact && kept & 1 == 0,
+        _ => inexact && away,
+    };
+    kept += up as u64;
+
+    /* a carry out of the significand goes into the exponent */
+    let bits = if be < 1 {
+        0
+    } else {
+        ((be - 1) as u64) << (p - 1)
+    } + kept;
+    if inexact {
+        if bits >= (max as u64) << (p - 1) {
+            feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+        } else if be < 1 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        } else {
+            feraiseexcept(FE_INEXACT);
+        }
+    }
+    sign_bit | bits
+}
+
+/// Rounds `r` to `f64` in the direction `round`.
+#[inline]
+pub(crate) fn to_f64(r: Exact, round: Round) -> f64 {
+    match r {
+        Exact::Special(x) => x,
+        Exact::Finite { m, e, sign } => f64::from_bits(round_bits(m, e, sign, round, 53, 1023)),
+    }
+}
+
+/// Rounds `r` to `f32` in the direction `round`.
+#[inline]
+pub(crate) fn to_f32(r: Exact, round: Round) -> f32 {
+    match r {
+        Exact::Special(x) => x as f32,
+        Exact::Finite { m, e, sign } => {
+            f32::from_bits(round_bits(m, e, sign, round, 24, 127) as u32)
+        }
+    }
+}
+
+/// Splits a finite nonzero `x` into `(m, e)` with `|x| = m * 2^e` and `m`
+/// in `[2^52, 2^53)`.
+#[inline]
+pub(crate) fn split(x: f64) -> (u64, i32) {
+    let ix = x.to_bits() & !(1 << 63);
+    let e = (ix >> 52) as i32;
+    if e == 0 {
+        let shift = ix.leading_zeros() as i32 - 11;
+        (ix << shift, -1074 - shift)
+    } else {
+        (ix & 0x000fffffffffffff | 1 << 52, e - 1075)
+    }
+}
+
+/// Raises invalid if `r` is a NaN although none of `args` is.
+#[inline]
+fn check_nan(r: f64, args: &[f64]) -> Exact {
+    if r.is_nan() && !args.iter().any(|a| a.is_nan()) {
+        feraiseexcept(FE_INVALID);
+    }
+    Exact::Special(r)
+}
+
+/// The exact `x * y + z`.
+#[inline]
+pub(crate) fn fma_exact(x: f64, y: f64, z: f64, round: Round) -> Exact {
+    let nx = normalize(x);
+    let ny = normalize(y);
+    let mut nz = normalize(z);
+
+    if nx.e >= ZEROINFNAN || ny.e >= ZEROINFNAN {
+        /* x*y is 0, inf or nan, so the sum is exact */
+        let xy = x * y;
+        let r = xy + z;
+        if r == 0.0 && xy.is_sign_negative() != z.is_sign_negative() {
+            /* +0 - 0 is -0 when rounding down */
+            return Exact::Special(if round == Round::Down { -0.0 } else { 0.0 });
+        }
+        return check_nan(r, &[x, y, z]);
+    }
+    if nz.e >= ZEROINFNAN {
+        if nz.e == ZEROINFNAN {
+            return Exact::Special(z);
+        }
+        /* z==0 */
+        nz = Num {
+            m: 0,
+            e: nx.e + ny.e,
+            sign: nx.sign ^ ny.sign,
+        };
+    }
+
+    match mul_add(&nx, &ny, &nz) {
+        Some((m, e, sign)) => Exact::Finite {
+            m,
+            e,
+            sign: sign != 0,
+        },
+        None => Exact::Special(if round == Round::Down { -0.0 } else { 0.0 }),
+    }
+}
+
+/// The exact `x * y`.
+#[inline]
+pub(crate) fn mul_exact(x: f64, y: f64, round: Round) -> Exact {
+    if x == 0.0 || y == 0.0 || !x.is_finite() || !y.is_finite() {
+        return check_nan(x * y, &[x, y]);
+    }
+    fma_exact(x, y, 0.0, round)
+}
+
+/// The exact `x / y`.
+#[inline]
+pub(crate) fn div_exact(x: f64, y: f64) -> Exact {
+    if x == 0.0 || y == 0.0 || !x.is_finite() || !y.is_finite() {
+        if y == 0.0 && x != 0.0 && x.is_finite() {
+            feraiseexcept(FE_DIVBYZERO);
+        }
+        return check_nan(x / y, &[x, y]);
+    }
+
+    let (mx, ex) = split(x);
+    let (my, ey) = split(y);
+    /* long division, to 63 bits with the top one at bit 62 */
+    let n = if mx >= my { 62 } else { 63 };
+    let mut r = mx;
+    let mut q: u64 = 0;
+    for _ in 0..=n {
+        q <<= 1;
+        if r >= my {
+            r -= my;
+            q |= 1;
+        }
+        r <<= 1;
+    }
+    Exact::Finite {
+        m: q | (r != 0) as u64,
+        e: ex - ey - n,
+        sign: (x < 0.0) != (y < 0.0),
+    }
+}
+
+/// The exact square root of `x`.
+#[inline]
+pub(crate) fn sqrt_exact(x: f64) -> Exact {
+    if x == 0.0 || x.is_nan() || x == f64::INFINITY {
+        return Exact::Special(x);
+    }
+    if x < 0.0 {
+        return Exact::Special(invalid(x));
+    }
+
+    let (mut m, mut e) = split(x);
+    if e & 1 == 1 {
+        m <<= 1;
+        e -= 1;
+    }
+    /* integer square root of m * 2^72, in [2^62, 2^63), bit by bit */
+    let mut rem = (m as u128) << 72;
+    let mut root: u128 = 0;
+    let mut bit: u128 = 1 << 124;
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    Exact::Finite {
+        m: root as u64 | (rem != 0) as u64,
+        e: (e >> 1) - 36,
+        sign: false,
+    }
+}
+
+/// Addition with a rounding direction (f64)
+///
+/// Returns `x + y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn add_round(x: f64, y: f64, round: Round) -> f64 {
+    to_f64(fma_exact(x, 1.0, y, round), round)
+}
+
+/// Subtraction with a rounding direction (f64)
+///
+/// Returns `x - y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sub_round(x: f64, y: f64, round: Round) -> f64 {
+    to_f64(fma_exact(x, 1.0, -y, round), round)
+}
+
+/// Multiplication with a rounding direction (f64)
+///
+/// Returns `x * y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn mul_round(x: f64, y: f64, round: Round) -> f64 {
+    to_f64(mul_exact(x, y, round), round)
+}
+
+/// Division with a rounding direction (f64)
+///
+/// Returns `x / y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn div_round(x: f64, y: f64, round: Round) -> f64 {
+    to_f64(div_exact(x, y), round)
+}
+
+/// Square root with a rounding direction (f64)
+///
+/// Returns the square root of `x` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrt_round(x: f64, round: Round) -> f64 {
+    to_f64(sqrt_exact(x), round)
+}
+
+/// Floating multiply add with a rounding direction (f64)
+///
+/// Computes `(x*y)+z` rounded once in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fma_round(x: f64, y: f64, z: f64, round: Round) -> f64 {
+    to_f64(fma_exact(x, y, z, round), round)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    const ALL: [Round; 6] = [
+        Round::Nearest,
+        Round::NearestAway,
+        Round::TowardZero,
+        Round::Up,
+        Round::Down,
+        Round::Odd,
+    ];
+
+    #[test]
+    fn directions() {
+        let third = 1.0f64 / 3.0;
+        let above = f64::from_bits(third.to_bits() + 1);
+        assert_eq!(div_round(1.0, 3.0, Round::Nearest), third);
+        assert_eq!(div_round(1.0, 3.0, Round::Down), third);
+        assert_eq!(div_round(1.0, 3.0, Round::TowardZero), third);
+        assert_eq!(div_round(1.0, 3.0, Round::Up), above);
+        assert_eq!(div_round(-1.0, 3.0, Round::Down), -above);
+        assert_eq!(div_round(-1.0, 3.0, Round::TowardZero), -third);
+
+        let sqrt2 = consts::SQRT_2;
+        let below = f64::from_bits(sqrt2.to_bits() - 1);
+        assert_eq!(sqrt_round(2.0, Round::Nearest), sqrt2);
+        assert_eq!(sqrt_round(2.0, Round::Up), sqrt2);
+        assert_eq!(sqrt_round(2.0, Round::Down), below);
+        assert_eq!(sqrt_round(4.0, Round::Up), 2.0);
+
+        /* to odd: the odd one of the two neighbors, whatever their distance */
+        assert_eq!(div_round(1.0, 3.0, Round::Odd), third);
+        assert_eq!(sqrt_round(2.0, Round::Odd), sqrt2);
+        assert_eq!(sqrt_round(4.0, Round::Odd), 2.0);
+        assert_eq!(div_round(-1.0, 3.0, Round::Odd), -third);
+
+        /* 1 + 2^-53 is a tie */
+        let half_ulp = EPSILON / 2.0;
+        assert_eq!(add_round(1.0, half_ulp, Round::Nearest), 1.0);
+        assert_eq!(add_round(1.0, half_ulp, Round::NearestAway), 1.0 + EPSILON);
+        assert_eq!(add_round(1.0, half_ulp, Round::Up), 1.0 + EPSILON);
+        assert_eq!(
+            sub_round(-1.0, half_ulp, Round::NearestAway),
+            -1.0 - EPSILON
+        );
+        assert_eq!(sub_round(1.0, 1e-300, Round::Down), 1.0 - half_ulp);
+
+        let x = 1.0 + EPSILON;
+        assert_eq!(mul_round(x, x, Round::Nearest), 1.0 + 2.0 * EPSILON);
+        assert_eq!(mul_round(x, x, Round::Up), 1.0 + 3.0 * EPSILON);
+        assert_eq!(
+            fma_round(x, x, -1.0, Round::Nearest),
+            2.0 * EPSILON + EPSILON * EPSILON
+        );
+        assert_eq!(
+            fma_round(x, -x, 0.0, Round::TowardZero),
+            -1.0 - 2.0 * EPSILON
+        );
+    }
+
+    #[test]
+    fn nearest_matches_hardware() {
+        let values = [
+            1.0,
+            3.0,
+            0.1,
+            -7.5e-300,
+            1e300,
+            5e-324,
+            -MAX,
+            MIN_POSITIVE,
+            consts::PI,
+        ];
+        for &x in values.iter() {
+            for &y in values.iter() {
+                assert_eq!(add_round(x, y, Round::Nearest), x + y);
+                assert_eq!(sub_round(x, y, Round::Nearest), x - y);
+                assert_eq!(mul_round(x, y, Round::Nearest), x * y);
+                assert_eq!(div_round(x, y, Round::Nearest), x / y);
+                assert_eq!(fma_round(x, y, 0.5, Round::Nearest), crate::fma(x, y, 0.5));
+            }
+            assert_eq!(
+                sqrt_round(x, Round::Nearest).to_bits(),
+                crate::sqrt(x).to_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn range_limits() {
+        assert_eq!(mul_round(MAX, 2.0, Round::Nearest), INFINITY);
+        assert_eq!(mul_round(MAX, 2.0, Round::TowardZero), MAX);
+        assert_eq!(mul_round(MAX, -2.0, Round::Up), -MAX);
+        assert_eq!(mul_round(MAX, -2.0, Round::Down), NEG_INFINITY);
+        assert_eq!(add_round(MAX, 1.0, Round::Up), INFINITY);
+        assert_eq!(add_round(MAX, 1.0, Round::Nearest), MAX);
+
+        let tiny = f64::from_bits(1);
+        assert_eq!(mul_round(tiny, 0.5, Round::Nearest), 0.0);
+        assert_eq!(mul_round(tiny, 0.75, Round::Nearest), tiny);
+        assert_eq!(mul_round(tiny, 0.5, Round::NearestAway), tiny);
+        assert_eq!(mul_round(tiny, 1e-300, Round::Up), tiny);
+        assert_eq!(
+            mul_round(-tiny, 1e-300, Round::Up).to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert_eq!(
+            div_round(MIN_POSITIVE, 3.0, Round::Down),
+            f64::from_bits(0x0005555555555555)
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        for &r in ALL.iter() {
+            assert!(add_round(INFINITY, NEG_INFINITY, r).is_nan());
+            assert!(mul_round(INFINITY, 0.0, r).is_nan());
+            assert!(div_round(0.0, 0.0, r).is_nan());
+            assert!(sqrt_round(-1.0, r).is_nan());
+            assert!(fma_round(NAN, 1.0, 1.0, r).is_nan());
+            assert_eq!(div_round(1.0, -0.0, r), NEG_INFINITY);
+            assert_eq!(sqrt_round(-0.0, r).to_bits(), (-0.0f64).to_bits());
+            assert_eq!(mul_round(-0.0, 3.0, r).to_bits(), (-0.0f64).to_bits());
+        }
+        assert_eq!(add_round(1.0, -1.0, Round::Nearest).to_bits(), 0);
+        assert_eq!(
+            add_round(1.0, -1.0, Round::Down).to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert_eq!(
+            add_round(0.0, -0.0, Round::Down).to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert_eq!(add_round(0.0, -0.0, Round::Up).to_bits(), 0);
+        assert_eq!(
+            add_round(-0.0, -0.0, Round::Up).to_bits(),
+            (-0.0f64).to_bits()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Arithmetic on `f32` with an explicit rounding direction.
+//!
+//! The arguments are widened to `f64`, which is exact, and the exact result
+//! is rounded once to `f32`, so there is no double rounding.
+
+use super::round_ops::{div_exact, fma_exact, mul_exact, sqrt_exact, to_f32};
+use super::Round;
+
+/// Addition with a rounding direction (f32)
+///
+/// Returns `x + y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn addf_round(x: f32, y: f32, round: Round) -> f32 {
+    to_f32(fma_exact(x as f64, 1.0, y as f64, round), round)
+}
+
+/// Subtraction with a rounding direction (f32)
+///
+/// Returns `x - y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn subf_round(x: f32, y: f32, round: Round) -> f32 {
+    to_f32(fma_exact(x as f64, 1.0, -y as f64, round), round)
+}
+
+/// Multiplication with a rounding direction (f32)
+///
+/// Returns `x * y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn mulf_round(x: f32, y: f32, round: Round) -> f32 {
+    to_f32(mul_exact(x as f64, y as f64, round), round)
+}
+
+/// Division with a rounding direction (f32)
+///
+/// Returns `x / y` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn divf_round(x: f32, y: f32, round: Round) -> f32 {
+    to_f32(div_exact(x as f64, y as f64), round)
+}
+
+/// Square root with a rounding direction (f32)
+///
+/// Returns the square root of `x` rounded in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf_round(x: f32, round: Round) -> f32 {
+    to_f32(sqrt_exact(x as f64), round)
+}
+
+/// Floating multiply add with a rounding direction (f32)
+///
+/// Computes `(x*y)+z` rounded once in the direction `round`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn fmaf_round(x: f32, y: f32, z: f32, round: Round) -> f32 {
+    to_f32(fma_exact(x as f64, y as f64, z as f64, round), round)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::*;
+
+    #[test]
+    fn directions() {
+        let third = 1.0f32 / 3.0;
+        let below = f32::from_bits(third.to_bits() - 1);
+        assert_eq!(divf_round(1.0, 3.0, Round::Down), below);
+        assert_eq!(divf_round(1.0, 3.0, Round::Up), third);
+        assert_eq!(divf_round(-1.0, 3.0, Round::Down), -third);
+        assert_eq!(divf_round(-1.0, 3.0, Round::TowardZero), -below);
+
+        let sqrt2 = consts::SQRT_2;
+        assert_eq!(
+            sqrtf_round(2.0, Round::Up),
+            f32::from_bits(sqrt2.to_bits() + 1)
+        );
+        assert_eq!(sqrtf_round(2.0, Round::Down), sqrt2);
+
+        /* 1 + 2^-24 is a tie */
+        let half_ulp = EPSILON / 2.0;
+        assert_eq!(addf_round(1.0, half_ulp, Round::Nearest), 1.0);
+        assert_eq!(addf_round(1.0, half_ulp, Round::NearestAway), 1.0 + EPSILON);
+        assert_eq!(subf_round(1.0, 1e-30, Round::TowardZero), 1.0 - half_ulp);
+
+        /* just below the tie 1 + 2^-24 */
+        let x = 1.0 + EPSILON;
+        let y = 1.0 - half_ulp;
+        assert_eq!(mulf_round(x, y, Round::Nearest), 1.0);
+        assert_eq!(mulf_round(x, y, Round::NearestAway), 1.0);
+        assert_eq!(mulf_round(x, y, Round::Up), x);
+        assert_eq!(fmaf_round(x, x, -1.0, Round::Down), 2.0 * EPSILON);
+    }
+
+    #[test]
+    fn range_limits() {
+        assert_eq!(mulf_round(MAX, 2.0, Round::Nearest), INFINITY);
+        assert_eq!(mulf_round(MAX, 2.0, Round::TowardZero), MAX);
+        assert_eq!(mulf_round(MAX, -2.0, Round::Down), NEG_INFINITY);
+
+        let tiny = f32::from_bits(1);
+        assert_eq!(mulf_round(tiny, 0.5, Round::Nearest), 0.0);
+        assert_eq!(mulf_round(tiny, 0.5, Round::NearestAway), tiny);
+        assert_eq!(mulf_round(tiny, 1e-30, Round::Up), tiny);
+        assert!(sqrtf_round(-1.0, Round::Up).is_nan());
+        assert_eq!(divf_round(1.0, 0.0, Round::Down), INFINITY);
+    }
+}
@@ -0,0 +1,69 @@
+use core::f64;
+
+/// Round to nearest integer, ties to even (f64)
+///
+/// Rounds `x` to the nearest integer value in floating-point format, with
+/// halfway cases rounded to the even integer, regardless of the current
+/// rounding mode.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundeven(x: f64) -> f64 {
+    let ui = x.to_bits();
+    let e = (ui >> 52 & 0x7ff) as i32;
+
+    if e >= 0x3ff + 52 {
+        /* already integral, or inf or nan */
+        return x;
+    }
+    if e < 0x3ff - 1 {
+        /* |x| < 0.5 */
+        return f64::from_bits(ui & 1 << 63);
+    }
+    if e == 0x3ff - 1 {
+        /* 0.5 <= |x| < 1 */
+        return if ui << 1 == 0x3fe0_0000_0000_0000 << 1 {
+            f64::from_bits(ui & 1 << 63)
+        } else {
+            f64::from_bits(ui & 1 << 63 | 0x3ff0_0000_0000_0000)
+        };
+    }
+
+    let frac_bits = (0x3ff + 52 - e) as u32;
+    let one = 1_u64 << frac_bits;
+    let half = one >> 1;
+    let frac = ui & (one - 1);
+    let mut i = ui & !(one - 1);
+    if frac > half || (frac == half && i & one != 0) {
+        /* a carry out of the mantissa correctly bumps the exponent */
+        i += one;
+    }
+    f64::from_bits(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::roundeven;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(roundeven(0.5).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(roundeven(-0.5).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(roundeven(-0.25).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(roundeven(0.75), 1.0);
+        assert_eq!(roundeven(1.5), 2.0);
+        assert_eq!(roundeven(2.5), 2.0);
+        assert_eq!(roundeven(-3.5), -4.0);
+        assert_eq!(roundeven(2.5000000000000004), 3.0);
+        assert_eq!(roundeven(1.9999999999999998), 2.0);
+        assert_eq!(roundeven(4503599627370495.5), 4503599627370496.0);
+        assert_eq!(roundeven(4503599627370496.0), 4503599627370496.0);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(roundeven(NAN).is_nan());
+        for f in [0.0, -0.0, INFINITY, NEG_INFINITY].iter().copied() {
+            assert_eq!(roundeven(f).to_bits(), f.to_bits());
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use core::f32;
+
+/// Round to nearest integer, ties to even (f32)
+///
+/// Rounds `x` to the nearest integer value in floating-point format, with
+/// halfway cases rounded to the even integer, regardless of the current
+/// rounding mode.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundevenf(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let e = (ui >> 23 & 0xff) as i32;
+
+    if e >= 0x7f + 23 {
+        /* already integral, or inf or nan */
+        return x;
+    }
+    if e < 0x7f - 1 {
+        /* |x| < 0.5 */
+        return f32::from_bits(ui & 1 << 31);
+    }
+    if e == 0x7f - 1 {
+        /* 0.5 <= |x| < 1 */
+        return if ui << 1 == 0x3f00_0000 << 1 {
+            f32::from_bits(ui & 1 << 31)
+        } else {
+            f32::from_bits(ui & 1 << 31 | 0x3f80_0000)
+        };
+    }
+
+    let frac_bits = (0x7f + 23 - e) as u32;
+    let one = 1_u32 << frac_bits;
+    let half = one >> 1;
+    let frac = ui & (one - 1);
+    let mut i = ui & !(one - 1);
+    if frac > half || (frac == half && i & one != 0) {
+        /* a carry out of the mantissa correctly bumps the exponent */
+        i += one;
+    }
+    f32::from_bits(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::roundevenf;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(roundevenf(0.5).to_bits(), 0.0_f32.to_bits());
+        assert_eq!(roundevenf(-0.5).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(roundevenf(0.75), 1.0);
+        assert_eq!(roundevenf(2.5), 2.0);
+        assert_eq!(roundevenf(-3.5), -4.0);
+        assert_eq!(roundevenf(8388607.5), 8388608.0);
+        assert_eq!(roundevenf(8388606.5), 8388606.0);
+    }
+}
@@ -0,0 +1,71 @@
+use super::f128::{exponent, is_zero, quiet_nan, signed_zero, BIAS, SIGN_MASK, SIG_BITS, SIG_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::F128;
+
+/// Round (F128)
+///
+/// Rounds `x` to the nearest integer, with halfway cases away from zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundf128(x: F128) -> F128 {
+    let mut bits = x.to_bits();
+    let e = exponent(x) - BIAS;
+
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if e >= SIG_BITS as i32 || is_zero(x) {
+        return x;
+    }
+    if e < 0 {
+        feraiseexcept(FE_INEXACT);
+        /* |x| in [0.5, 1) rounds to 1 */
+        return if e == -1 {
+            F128::from_bits(bits & SIGN_MASK | F128::ONE.to_bits())
+        } else {
+            signed_zero(x.is_sign_negative())
+        };
+    }
+    let m = SIG_MASK >> e;
+    if bits & m == 0 {
+        return x;
+    }
+    feraiseexcept(FE_INEXACT);
+    /* add one half, a carry out of the fraction bits goes into the exponent */
+    bits += 1 << (SIG_BITS as i32 - 1 - e);
+    F128::from_bits(bits & !m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        let two = f(0x40000000000000000000000000000000);
+        let half = f(0x3ffe0000000000000000000000000000);
+        assert_eq!(roundf128(half), F128::ONE);
+        assert_eq!(roundf128(-half), -F128::ONE);
+        /* 1.5, 2.5 and the value just below 0.5 */
+        assert_eq!(roundf128(f(0x3fff8000000000000000000000000000)), two);
+        assert_eq!(
+            roundf128(f(0xc0004000000000000000000000000000)).to_bits(),
+            0xc0008000000000000000000000000000
+        );
+        let z = roundf128(-f(half.to_bits() - 1));
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        /* 2^111 + 0.5 and 2^110 + 0.25 */
+        assert_eq!(
+            roundf128(f(0x406e0000000000000000000000000001)).to_bits(),
+            0x406e0000000000000000000000000002
+        );
+        assert_eq!(
+            roundf128(f(0x406d0000000000000000000000000001)).to_bits(),
+            0x406d0000000000000000000000000000
+        );
+        assert!(roundf128(F128::NAN).is_nan());
+    }
+}
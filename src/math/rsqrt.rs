@@ -0,0 +1,127 @@
+/* rsqrt(x)
+ * Return correctly rounded 1/sqrt(x).
+ *
+ * Method:
+ *   Bit by bit method using integer arithmetic, as in sqrt.c.
+ *   1. Normalization
+ *      Scale x to y in [1,4) with even powers of 2:
+ *      find an integer k such that  1 <= (y=x*2^(2k)) < 4, then
+ *              rsqrt(x) = 2^k * rsqrt(y)
+ *      where rsqrt(y) is in (1/2,1].
+ *   2. Bit by bit computation
+ *      Let q  = rsqrt(y) truncated to i bits after binary point (q = 1/2)
+ *           i                                                   1
+ *      and keep the scaled remainder
+ *                     i-1          2
+ *              w  =  2    * (1 - q  * y)  >=  0.
+ *               i                 i
+ *      The bit 2^-(i+1) belongs to q    iff the remainder stays
+ *                                   i+1
+ *      non-negative, which after scaling is
+ *                               -(i+2)
+ *              d  =  q  * y  +  2      * y  <=  w ,
+ *               i     i                          i
+ *      and if so w is reduced by d. Since y has 52 bits after the
+ *      binary point and q at most 54, all of w, d and q*y are exact
+ *      with 108 bits after the binary point, and below 16.
+ *   3. Final rounding
+ *      After generating the 53 bits result, we compute one more bit.
+ *      1/sqrt(y) is irrational or has at most 53 bits unless y = 1,
+ *      so a set extra bit always means more than 1/2ulp (for y = 1
+ *      the bits are all ones, and rounding up gives 1).
+ *
+ * Special cases:
+ *      rsqrt(+-0) = +-inf      ... with divide-by-zero signal
+ *      rsqrt(+inf) = +0
+ *      rsqrt(-ve) = NaN        ... with invalid signal
+ *      rsqrt(NaN) = NaN
+ */
+
+use super::fenv::{feraiseexcept, invalid, FE_DIVBYZERO};
+
+/// Reciprocal square root (f64)
+///
+/// Returns `1 / sqrt(x)`, correctly rounded. Computing `1.0 / sqrt(x)`
+/// rounds twice and is off by an ulp for some inputs.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrt(x: f64) -> f64 {
+    let ui = x.to_bits();
+    let ix = ui & 0x7fffffffffffffff;
+
+    if ix > 0x7ff0000000000000 {
+        return x; /* rsqrt(NaN) = NaN */
+    }
+    if ix == 0 {
+        feraiseexcept(FE_DIVBYZERO);
+        return 1.0 / x; /* rsqrt(+-0) = +-inf */
+    }
+    if ui >> 63 != 0 {
+        return invalid(x); /* rsqrt(-ve) = NaN */
+    }
+    if ix == 0x7ff0000000000000 {
+        return 0.0; /* rsqrt(+inf) = +0 */
+    }
+
+    /* normalize x to mant * 2^(e-52), mant in [2^52, 2^53) */
+    let mut e = (ix >> 52) as i32;
+    let mut mant = ix & 0x000fffffffffffff;
+    if e == 0 {
+        /* subnormal x */
+        let shift = mant.leading_zeros() as i32 - 11;
+        mant <<= shift;
+        e = 1 - shift;
+    } else {
+        mant |= 0x0010000000000000;
+    }
+    e -= 1023;
+    if e & 1 == 1 {
+        /* odd e, double y to make e even */
+        mant <<= 1;
+        e -= 1;
+    }
+
+    /* generate rsqrt(y) bit by bit, y = mant * 2^-52 */
+    let y = (mant as u128) << 56; /* y * 2^108 */
+    let mut q: u64 = 1 << 53; /* q * 2^54 */
+    let mut qy = y >> 1;
+    let mut w = (2u128 << 108) - (y >> 1);
+    for i in 2..55 {
+        let d = qy + (y >> (i + 1));
+        if d <= w {
+            q += 1 << (54 - i);
+            qy += y >> i;
+            w -= d;
+        }
+        w <<= 1;
+    }
+
+    /* q * 2^-54 in [1/2, 1), the carry into the exponent is fine */
+    let hi = (1021 - (e >> 1)) as u64;
+    f64::from_bits((hi << 52) + (q >> 1) + (q & 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(rsqrt(4.0), 0.5);
+        assert_eq!(rsqrt(0.25), 2.0);
+        assert_eq!(rsqrt(1.0), 1.0);
+        assert_eq!(rsqrt(2.0), consts::FRAC_1_SQRT_2);
+        assert_eq!(rsqrt(5e-324), f64::from_bits(0x6180000000000000));
+        assert_eq!(rsqrt(MAX), 7.458340731200207e-155);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(rsqrt(-1.0).is_nan());
+        assert!(rsqrt(NEG_INFINITY).is_nan());
+        assert!(rsqrt(NAN).is_nan());
+        assert_eq!(rsqrt(0.0), INFINITY);
+        assert_eq!(rsqrt(-0.0), NEG_INFINITY);
+        assert_eq!(rsqrt(INFINITY), 0.0);
+    }
+}
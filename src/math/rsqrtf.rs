@@ -0,0 +1,68 @@
+/* rsqrtf(x)
+ * Return correctly rounded 1/sqrt(x). See rsqrt.rs for the method;
+ * here y has 23 bits after the binary point and q at most 25, so w,
+ * d and q*y are exact with 49 bits after the binary point.
+ */
+
+use super::fenv::{feraiseexcept, invalidf, FE_DIVBYZERO};
+
+/// Reciprocal square root (f32)
+///
+/// Returns `1 / sqrt(x)`, correctly rounded. Computing `1.0 / sqrtf(x)`
+/// rounds twice and is off by an ulp for some inputs.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn rsqrtf(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let ix = ui & 0x7fffffff;
+
+    if ix > 0x7f800000 {
+        return x; /* rsqrt(NaN) = NaN */
+    }
+    if ix == 0 {
+        feraiseexcept(FE_DIVBYZERO);
+        return 1.0 / x; /* rsqrt(+-0) = +-inf */
+    }
+    if ui >> 31 != 0 {
+        return invalidf(x); /* rsqrt(-ve) = NaN */
+    }
+    if ix == 0x7f800000 {
+        return 0.0; /* rsqrt(+inf) = +0 */
+    }
+
+    /* normalize x to mant * 2^(e-23), mant in [2^23, 2^24) */
+    let mut e = (ix >> 23) as i32;
+    let mut mant = ix & 0x007fffff;
+    if e == 0 {
+        /* subnormal x */
+        let shift = mant.leading_zeros() as i32 - 8;
+        mant <<= shift;
+        e = 1 - shift;
+    } else {
+        mant |= 0x00800000;
+    }
+    e -= 127;
+    if e & 1 == 1 {
+        /* odd e, double y to make e even */
+        mant <<= 1;
+        e -= 1;
+    }
+
+    /* generate rsqrt(y) bit by bit, y = mant * 2^-23 */
+    let y = (mant as u64) << 26; /* y * 2^49 */
+    let mut q: u32 = 1 << 24; /* q * 2^25 */
+    let mut qy = y >> 1;
+    let mut w = (2u64 << 49) - (y >> 1);
+    for i in 2..26 {
+        let d = qy + (y >> (i + 1));
+        if d <= w {
+            q += 1 << (25 - i);
+            qy += y >> i;
+            w -= d;
+        }
+        w <<= 1;
+    }
+
+    /* q * 2^-25 in [1/2, 1), the carry into the exponent is fine */
+    let hi = (125 - (e >> 1)) as u32;
+    f32::from_bits((hi << 23) + (q >> 1) + (q & 1))
+}
@@ -0,0 +1,32 @@
+use super::scalbn;
+
+/// Multiply by integral power of two (f64)
+///
+/// Returns `x * 2^n`, like `scalbn`, but takes the exponent as an `i64`
+/// (C's `long`).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbln(x: f64, n: i64) -> f64 {
+    let n = if n > i32::max_value() as i64 {
+        i32::max_value()
+    } else if n < i32::min_value() as i64 {
+        i32::min_value()
+    } else {
+        n as i32
+    };
+    scalbn(x, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::scalbln;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(scalbln(1.5, 3), 12.0);
+        assert_eq!(scalbln(1.0, -1074), f64::from_bits(1));
+        assert_eq!(scalbln(1.0, 1 << 40), INFINITY);
+        assert_eq!(scalbln(-1.0, -(1 << 40)).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(scalbln(0.0, i64::max_value()), 0.0);
+    }
+}
@@ -0,0 +1,17 @@
+use super::scalbnf;
+
+/// Multiply by integral power of two (f32)
+///
+/// Returns `x * 2^n`, like `scalbnf`, but takes the exponent as an `i64`
+/// (C's `long`).
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalblnf(x: f32, n: i64) -> f32 {
+    let n = if n > i32::max_value() as i64 {
+        i32::max_value()
+    } else if n < i32::min_value() as i64 {
+        i32::min_value()
+    } else {
+        n as i32
+    };
+    scalbnf(x, n)
+}
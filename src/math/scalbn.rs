@@ -1,5 +1,8 @@
+use super::fenv::{overflow, underflow};
+
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn scalbn(x: f64, mut n: i32) -> f64 {
+    let n0 = n;
     let x1p1023 = f64::from_bits(0x7fe0000000000000); // 0x1p1023 === 2 ^ 1023
     let x1p53 = f64::from_bits(0x4340000000000000); // 0x1p53 === 2 ^ 53
     let x1p_1022 = f64::from_bits(0x0010000000000000); // 0x1p-1022 === 2 ^ (-1022)
@@ -29,5 +32,23 @@ pub fn scalbn(x: f64, mut n: i32) -> f64 {
             }
         }
     }
-    y * f64::from_bits(((0x3ff + n) as u64) << 52)
+    y *= f64::from_bits(((0x3ff + n) as u64) << 52);
+
+    if y.is_infinite() && x.is_finite() {
+        return overflow(y);
+    }
+    if (y.to_bits() >> 52 & 0x7ff) == 0 && x != 0.0 && x.is_finite() {
+        /* tiny, and inexact if the lowest set bit of x fell below 2^-1074 */
+        let ix = x.to_bits() & !(1 << 63);
+        let e = (ix >> 52) as i32;
+        let low = if e == 0 {
+            -1074 + ix.trailing_zeros() as i32
+        } else {
+            e - 1075 + (ix | 1 << 52).trailing_zeros() as i32
+        };
+        if n0 < -1074 - low {
+            return underflow(y);
+        }
+    }
+    y
 }
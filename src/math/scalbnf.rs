@@ -1,5 +1,8 @@
+use super::fenv::{overflow, underflow};
+
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn scalbnf(mut x: f32, mut n: i32) -> f32 {
+    let (x0, n0) = (x, n);
     let x1p127 = f32::from_bits(0x7f000000); // 0x1p127f === 2 ^ 127
     let x1p_126 = f32::from_bits(0x800000); // 0x1p-126f === 2 ^ -126
     let x1p24 = f32::from_bits(0x4b800000); // 0x1p24f === 2 ^ 24
@@ -25,5 +28,23 @@ pub fn scalbnf(mut x: f32, mut n: i32) -> f32 {
             }
         }
     }
-    x * f32::from_bits(((0x7f + n) as u32) << 23)
+    x *= f32::from_bits(((0x7f + n) as u32) << 23);
+
+    if x.is_infinite() && x0.is_finite() {
+        return overflow(x);
+    }
+    if (x.to_bits() >> 23 & 0xff) == 0 && x0 != 0.0 && x0.is_finite() {
+        /* tiny, and inexact if the lowest set bit of x fell below 2^-149 */
+        let ix = x0.to_bits() & 0x7fffffff;
+        let e = (ix >> 23) as i32;
+        let low = if e == 0 {
+            -149 + ix.trailing_zeros() as i32
+        } else {
+            e - 150 + (ix | 1 << 23).trailing_zeros() as i32
+        };
+        if n0 < -149 - low {
+            return underflow(x);
+        }
+    }
+    x
 }
@@ -0,0 +1,54 @@
+use super::f128::{is_zero, quiet_nan, round_pack, split};
+use super::{Round, F128};
+
+/// Multiplies `x` by `2^n` (F128)
+///
+/// The result is rounded only when it is subnormal or out of range, in the
+/// current rounding direction.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn scalbnf128(x: F128, n: i32) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x.is_infinite() || is_zero(x) {
+        return x;
+    }
+    /* beyond the range of binary128 exponents the result is the same */
+    let n = n.clamp(-40000, 40000);
+    let (m, e) = split(x);
+    round_pack(m, e + n, x.is_sign_negative(), Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(
+            scalbnf128(F128::ONE, 1),
+            f(0x40000000000000000000000000000000)
+        );
+        assert_eq!(scalbnf128(F128::ONE, -16494), f(1));
+        assert_eq!(scalbnf128(f(1), 16494), F128::ONE);
+        assert_eq!(
+            scalbnf128(F128::MAX, -16383),
+            f(0x3fffffffffffffffffffffffffffffff)
+        );
+        assert_eq!(scalbnf128(-F128::MAX, 1), F128::NEG_INFINITY);
+        assert_eq!(scalbnf128(F128::MIN_POSITIVE, i32::MAX), F128::INFINITY);
+        assert_eq!(scalbnf128(F128::MAX, i32::MIN), F128::ZERO);
+    }
+
+    #[test]
+    fn subnormal_rounding() {
+        /* 3 * 2^-16495 is a tie, rounded to even */
+        assert_eq!(scalbnf128(f(3), -1), f(2));
+        assert_eq!(scalbnf128(f(5), -1), f(2));
+        assert_eq!(scalbnf128(f(7), -2), f(2));
+    }
+}
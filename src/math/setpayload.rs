@@ -0,0 +1,27 @@
+/// Quiet NaN with given payload (f64)
+///
+/// Returns a quiet NaN whose payload is `pl`, or `None` if `pl` is not a
+/// valid payload, i.e. not an integer in `[0, 2^51)`. A negative zero is not
+/// accepted either.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayload(pl: f64) -> Option<f64> {
+    payload_bits(pl).map(|p| f64::from_bits(0x7ff8000000000000 | p))
+}
+
+/// Returns the integer value of `pl` if it fits in the 51 payload bits.
+pub(crate) fn payload_bits(pl: f64) -> Option<u64> {
+    let ui = pl.to_bits();
+    if ui == 0 {
+        return Some(0);
+    }
+    /* rejects the sign bit, |pl| >= 2^51 and |pl| < 1 at once */
+    let e = (ui >> 52) as i32 - 0x3ff;
+    if !(0..51).contains(&e) {
+        return None;
+    }
+    let m = ui & 0x000fffffffffffff | 0x0010000000000000;
+    if m & ((1 << (52 - e)) - 1) != 0 {
+        return None;
+    }
+    Some(m >> (52 - e))
+}
@@ -0,0 +1,27 @@
+/// Quiet NaN with given payload (f32)
+///
+/// Returns a quiet NaN whose payload is `pl`, or `None` if `pl` is not a
+/// valid payload, i.e. not an integer in `[0, 2^22)`. A negative zero is not
+/// accepted either.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadf(pl: f32) -> Option<f32> {
+    payload_bitsf(pl).map(|p| f32::from_bits(0x7fc00000 | p))
+}
+
+/// Returns the integer value of `pl` if it fits in the 22 payload bits.
+pub(crate) fn payload_bitsf(pl: f32) -> Option<u32> {
+    let ui = pl.to_bits();
+    if ui == 0 {
+        return Some(0);
+    }
+    /* rejects the sign bit, |pl| >= 2^22 and |pl| < 1 at once */
+    let e = (ui >> 23) as i32 - 0x7f;
+    if !(0..22).contains(&e) {
+        return None;
+    }
+    let m = ui & 0x007fffff | 0x00800000;
+    if m & ((1 << (23 - e)) - 1) != 0 {
+        return None;
+    }
+    Some(m >> (23 - e))
+}
@@ -0,0 +1,14 @@
+use super::setpayload::payload_bits;
+
+/// Signaling NaN with given payload (f64)
+///
+/// Returns a signaling NaN whose payload is `pl`, or `None` if `pl` is not a
+/// valid payload, i.e. not an integer in `[1, 2^51)`. A zero payload would
+/// encode infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsig(pl: f64) -> Option<f64> {
+    match payload_bits(pl) {
+        Some(0) | None => None,
+        Some(p) => Some(f64::from_bits(0x7ff0000000000000 | p)),
+    }
+}
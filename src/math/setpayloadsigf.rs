@@ -0,0 +1,14 @@
+use super::setpayloadf::payload_bitsf;
+
+/// Signaling NaN with given payload (f32)
+///
+/// Returns a signaling NaN whose payload is `pl`, or `None` if `pl` is not a
+/// valid payload, i.e. not an integer in `[1, 2^22)`. A zero payload would
+/// encode infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn setpayloadsigf(pl: f32) -> Option<f32> {
+    match payload_bitsf(pl) {
+        Some(0) | None => None,
+        Some(p) => Some(f32::from_bits(0x7f800000 | p)),
+    }
+}
@@ -0,0 +1,26 @@
+use super::{ilogb, scalbn};
+
+/// Get mantissa of a floating-point number (f64)
+///
+/// Returns the mantissa of `x` scaled to the range `[1, 2)`, that is
+/// `scalbn(x, -ilogb(x))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn significand(x: f64) -> f64 {
+    scalbn(x, ilogb(x).wrapping_neg())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::significand;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(significand(12.0), 1.5);
+        assert_eq!(significand(-0.375), -1.5);
+        assert_eq!(significand(f64::from_bits(3)), 1.5);
+        assert_eq!(significand(0.0), 0.0);
+        assert_eq!(significand(INFINITY), INFINITY);
+        assert!(significand(NAN).is_nan());
+    }
+}
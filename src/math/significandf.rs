@@ -0,0 +1,10 @@
+use super::{ilogbf, scalbnf};
+
+/// Get mantissa of a floating-point number (f32)
+///
+/// Returns the mantissa of `x` scaled to the range `[1, 2)`, that is
+/// `scalbnf(x, -ilogbf(x))`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn significandf(x: f32) -> f32 {
+    scalbnf(x, ilogbf(x).wrapping_neg())
+}
@@ -0,0 +1,30 @@
+use super::copysign;
+
+/// Sign of x (f64)
+///
+/// Returns `1.0` if `x` is positive, `+0.0` or `+inf`, `-1.0` if `x` is
+/// negative, `-0.0` or `-inf`, and NaN if `x` is NaN, like `f64::signum`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn signum(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    copysign(1.0, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(signum(3.5), 1.0);
+        assert_eq!(signum(-3.5), -1.0);
+        assert_eq!(signum(0.0), 1.0);
+        assert_eq!(signum(-0.0), -1.0);
+        assert_eq!(signum(INFINITY), 1.0);
+        assert_eq!(signum(NEG_INFINITY), -1.0);
+        assert!(signum(NAN).is_nan());
+    }
+}
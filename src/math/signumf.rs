@@ -0,0 +1,13 @@
+use super::copysignf;
+
+/// Sign of x (f32)
+///
+/// Returns `1.0` if `x` is positive, `+0.0` or `+inf`, `-1.0` if `x` is
+/// negative, `-0.0` or `-inf`, and NaN if `x` is NaN, like `f32::signum`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn signumf(x: f32) -> f32 {
+    if x.is_nan() {
+        return x;
+    }
+    copysignf(1.0, x)
+}
@@ -9,6 +9,7 @@
 // is preserved.
 // ====================================================
 
+use super::fenv::{feraiseexcept, inexact_tiny, FE_INVALID};
 use super::{k_cos, k_sin, rem_pio2};
 
 // sin(x)
@@ -42,8 +43,6 @@ use super::{k_cos, k_sin, rem_pio2};
 //      TRIG(x) returns trig(x) nearly rounded
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sin(x: f64) -> f64 {
-    let x1p120 = f64::from_bits(0x4770000000000000); // 0x1p120f === 2 ^ 120
-
     /* High word of x. */
     let ix = (f64::to_bits(x) >> 32) as u32 & 0x7fffffff;
 
@@ -52,11 +51,7 @@ pub fn sin(x: f64) -> f64 {
         if ix < 0x3e500000 {
             /* |x| < 2**-26 */
             /* raise inexact if x != 0 and underflow if subnormal*/
-            if ix < 0x00100000 {
-                force_eval!(x / x1p120);
-            } else {
-                force_eval!(x + x1p120);
-            }
+            inexact_tiny(x);
             return x;
         }
         return k_sin(x, 0.0, 0);
@@ -64,6 +59,9 @@ pub fn sin(x: f64) -> f64 {
 
     /* sin(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         return x - x;
     }
 
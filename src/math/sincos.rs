@@ -10,8 +10,10 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, inexact_tiny, FE_INVALID};
 use super::{get_high_word, k_cos, k_sin, rem_pio2};
 
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sincos(x: f64) -> (f64, f64) {
     let s: f64;
     let c: f64;
@@ -25,12 +27,7 @@ pub fn sincos(x: f64) -> (f64, f64) {
         /* if |x| < 2**-27 * sqrt(2) */
         if ix < 0x3e46a09e {
             /* raise inexact if x!=0 and underflow if subnormal */
-            let x1p120 = f64::from_bits(0x4770000000000000); // 0x1p120 == 2^120
-            if ix < 0x00100000 {
-                force_eval!(x / x1p120);
-            } else {
-                force_eval!(x + x1p120);
-            }
+            inexact_tiny(x);
             return (x, 1.0);
         }
         return (k_sin(x, 0.0, 0), k_cos(x, 0.0));
@@ -38,6 +35,9 @@ pub fn sincos(x: f64) -> (f64, f64) {
 
     /* sincos(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         let rv = x - x;
         return (rv, rv);
     }
@@ -14,6 +14,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, inexact_tinyf, FE_INVALID};
 use super::{k_cosf, k_sinf, rem_pio2f};
 
 /* Small multiples of pi/2 rounded to double precision. */
@@ -38,13 +39,7 @@ pub fn sincosf(x: f32) -> (f32, f32) {
         /* |x| < 2**-12 */
         if ix < 0x39800000 {
             /* raise inexact if x!=0 and underflow if subnormal */
-
-            let x1p120 = f32::from_bits(0x7b800000); // 0x1p120 == 2^120
-            if ix < 0x00100000 {
-                force_eval!(x / x1p120);
-            } else {
-                force_eval!(x + x1p120);
-            }
+            inexact_tinyf(x);
             return (x, 1.0);
         }
         return (k_sinf(x as f64), k_cosf(x as f64));
@@ -102,6 +97,9 @@ pub fn sincosf(x: f32) -> (f32, f32) {
 
     /* sin(Inf or NaN) is NaN */
     if ix >= 0x7f800000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         let rv = x - x;
         return (rv, rv);
     }
@@ -0,0 +1,99 @@
+use super::fenv::{feraiseexcept, underflow_if_tiny, FE_INVALID};
+use super::{deg_to_rad, k_cos, k_sin, rem_90};
+
+/// Sine of x degrees (f64)
+///
+/// Computes `sin(x * pi/180)`, reducing `x` modulo 360 exactly first. The
+/// result is exact for multiples of 30 degrees; `sind(180 * n)` is `+0.0` for
+/// positive `n` and `-0.0` for negative ones.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sind(x: f64) -> f64 {
+    let x1p60 = f64::from_bits(0x43b0000000000000); // 0x1p60 === 2 ^ 60
+    let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+
+    let ui = x.to_bits();
+    let sign = (ui >> 63) != 0;
+    let ix = ui & 0x7fffffffffffffff;
+
+    if ix >= 0x7ff0000000000000 {
+        /* sind(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix < 0x0170000000000000 {
+        /* |x| < 2^-1000: sind(x) ~= x * pi/180, scaled to avoid subnormal intermediates */
+        if ix == 0 {
+            return x;
+        }
+        let (hi, lo) = deg_to_rad(x * x1p60);
+        return underflow_if_tiny((hi + lo) * x1p_60);
+    }
+
+    let (n, f) = rem_90(f64::from_bits(ix));
+    let y = if f == 0.0 {
+        match n {
+            1 => 1.0,
+            3 => -1.0,
+            _ => 0.0,
+        }
+    } else if n & 1 == 0 && (f == 30.0 || f == -30.0) {
+        /* sin(+-30) = +-1/2 */
+        let s = f / 60.0;
+        if n == 2 {
+            -s
+        } else {
+            s
+        }
+    } else {
+        let (hi, lo) = deg_to_rad(f);
+        match n {
+            0 => k_sin(hi, lo, 1),
+            1 => k_cos(hi, lo),
+            2 => -k_sin(hi, lo, 1),
+            _ => -k_cos(hi, lo),
+        }
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sind;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(sind(90.0), 1.0);
+        assert_eq!(sind(-90.0), -1.0);
+        assert_eq!(sind(270.0), -1.0);
+        assert_eq!(sind(180.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(sind(-180.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sind(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sind(30.0), 0.5);
+        assert_eq!(sind(150.0), 0.5);
+        assert_eq!(sind(-210.0), 0.5);
+        assert_eq!(sind(330.0), -0.5);
+        assert_eq!(sind(1e22), sind(1e22 % 360.0));
+        assert_eq!(sind(9007199254741050.0), 1.0);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(sind(45.0), consts::FRAC_1_SQRT_2);
+        assert_eq!(sind(1.0), 0.01745240643728351);
+        assert_eq!(sind(1e-300), 1.7453292519943295e-302);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(sind(NAN).is_nan());
+        assert!(sind(INFINITY).is_nan());
+        assert!(sind(NEG_INFINITY).is_nan());
+    }
+}
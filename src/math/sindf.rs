@@ -0,0 +1,55 @@
+use super::fenv::{feraiseexcept, underflow_if_tinyf, FE_INVALID};
+use super::{k_cosf, k_sinf, rem_90};
+
+/* pi/180 */
+const D2R: f64 = 0.017453292519943295; /* 0x3F91DF46, 0xA2529D39 */
+
+/// Sine of x degrees (f32)
+///
+/// Computes `sin(x * pi/180)`, reducing `x` modulo 360 exactly first. The
+/// result is exact for multiples of 30 degrees; `sindf(180 * n)` is `+0.0` for
+/// positive `n` and `-0.0` for negative ones.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sindf(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let sign = (ui >> 31) != 0;
+    let ix = ui & 0x7fffffff;
+
+    if ix >= 0x7f800000 {
+        /* sindf(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+
+    /* the reduction is exact in double precision, and so is good enough pi/180 * f */
+    let (n, f) = rem_90(f32::from_bits(ix) as f64);
+    let y = if f == 0.0 {
+        match n {
+            1 => 1.0,
+            3 => -1.0,
+            _ => 0.0,
+        }
+    } else if n & 1 == 0 && (f == 30.0 || f == -30.0) {
+        /* sin(+-30) = +-1/2 */
+        let s = (f / 60.0) as f32;
+        if n == 2 {
+            -s
+        } else {
+            s
+        }
+    } else {
+        match n {
+            0 => underflow_if_tinyf(k_sinf(D2R * f)),
+            1 => k_cosf(D2R * f),
+            2 => -k_sinf(D2R * f),
+            _ => -k_cosf(D2R * f),
+        }
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
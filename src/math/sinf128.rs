@@ -0,0 +1,192 @@
+/* Reduction by pi/2, then k_sinf128 or k_cosf128; within 1 ulp */
+
+use super::f128::{exponent, inexact_tiny, invalid, quiet_nan, BIAS, SIGN_MASK};
+use super::k_cosf128::k_cosf128;
+use super::k_sinf128::k_sinf128;
+use super::rem_pio2f128::rem_pio2f128;
+use super::F128;
+
+/* pi/4 rounded down, below which no reduction is needed */
+pub(crate) const PIO4: u128 = 0x3ffe921fb54442d18469898cc51701b8;
+
+/// The sine of `x` in radians (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf128(x: F128) -> F128 {
+    /* |x| ~< pi/4 */
+    if x.to_bits() & !SIGN_MASK <= PIO4 {
+        if exponent(x) < BIAS - 57 {
+            /* |x| < 2**-57 */
+            return inexact_tiny(x);
+        }
+        return k_sinf128(x, F128::ZERO);
+    }
+
+    /* sin(Inf or NaN) is NaN */
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x.is_infinite() {
+        return invalid();
+    }
+
+    /* argument reduction needed */
+    let (n, y0, y1) = rem_pio2f128(x);
+    match n & 3 {
+        0 => k_sinf128(y0, y1),
+        1 => k_cosf128(y0, y1),
+        2 => -k_sinf128(y0, y1),
+        _ => -k_cosf128(y0, y1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1, 0.5, 1e6, 2^16383 and pi */
+        assert!(ulps(sinf128(F128::ONE), 0x3ffeaed548f090cee0418dd3d2138a1e) <= 1);
+        assert!(
+            ulps(
+                sinf128(f(0x3ffe0000000000000000000000000000)),
+                0x3ffdeaee8744b05efe8764bc364fd838
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                sinf128(F128::from(1000000)),
+                0xbffd6664b2568d8674991ccb49ba6e52
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                sinf128(f(0x7ffe0000000000000000000000000000)),
+                0x3ffd8eb52c84db31a207c32981f1e518
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                sinf128(f(0x4000921fb54442d18469898cc51701b8)),
+                0x3f8dcd129024e088a67cc74020bbea64
+            ) <= 1
+        );
+        let z = sinf128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(sinf128(F128::INFINITY).is_nan());
+        assert!(sinf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3ff6f722ba8de244b40246a231c8e880,
+            0x3ff6f7226993abb0fa2811f4d7ef040c,
+        ),
+        (
+            0x3ffeb99fea86538fc902c52a07665ce8,
+            0x3ffe84dd877d0b21373e76ca1b50f1b3,
+        ),
+        (
+            0x3fff150460268ea92d207712237849f4,
+            0x3ffec411548e246fc805ed943571d784,
+        ),
+        (
+            0x40060b393d0e6702fde4a6da3695fb4c,
+            0x3ffefdbc0b13ad35956ad67205e35c9c,
+        ),
+        (
+            0x400c0c61409eff1bccd500bcb2096425,
+            0xbffea2c48be342346f6cda110f0ddb00,
+        ),
+        (
+            0x403022f7e05d5d1e9422839373216024,
+            0x3ffa60cd72b8b047197d51c622d18a21,
+        ),
+        (
+            0x4064690834139f2ea4b6bda63459b390,
+            0xbffe55b432f1e839737a0296025fcfd5,
+        ),
+        (
+            0x407fcaa29382dfb8651cce2905eb3407,
+            0xbffec8cd907e4dc4bafa570c14c29e8a,
+        ),
+        (
+            0xbff5a435a184ace234101ec48406dd3b,
+            0xbff5a43595b9838fa0f04c3a83ca7f41,
+        ),
+        (
+            0xbff959fff7e7bbb69e61656db4125941,
+            0xbff959f9627d666570a5d66b3007ce62,
+        ),
+        (
+            0xbffb48326e1cde8f3b0d0bb75fc2a4f2,
+            0xbffb47d88e6a44693d2f56e46c440d59,
+        ),
+        (
+            0xbffda1a983944fe602dc2a00018e5ef9,
+            0xbffd962d7d3029f1d3d3d8291b818ac0,
+        ),
+        (
+            0xc00041436dd5d9d6edbc0e25aa88c7e5,
+            0xbffe2e5aaf29d40677d863ebf3345578,
+        ),
+        (
+            0xc0016c5f9d25a9c6b6da785e78675315,
+            0x3ffe1ccaf5b655c5c7e635a0f979289d,
+        ),
+        (
+            0xc0021b0e41f9500a845414c8c97be02a,
+            0xbffe184875fdd3c971508df85e4d664e,
+        ),
+        (
+            0xc0027afa7c649b424f20b4b9873ac2f4,
+            0x3ffe52def6be8c897e10e34c1972a13e,
+        ),
+        (
+            0xc002b2be66942832472aa03aa3914ce2,
+            0xbffeb41c6092ab34f495e895c1783adc,
+        ),
+        (
+            0xc003855d9bb045fbf255a415b3493bb0,
+            0x3ffe6e5a6c366dab00922bd02ab73e16,
+        ),
+        (
+            0xc0043faffd8ef7615c1b6bd63aba12cf,
+            0xbffe8a8b36c0a595a4d061233a50ff1b,
+        ),
+        (
+            0xc004e72002e3e70b0a5f754f82f48205,
+            0x3ffedd45cbdc55bb9d91688458aff51e,
+        ),
+        (
+            0xc006572f6472f4ff1ac8bbe7e534ced6,
+            0xbffedc46ccaf383135a6734018aae1d1,
+        ),
+        (
+            0xc00666713b7aa4a2e68805fe21e7396b,
+            0x3ffc32cf5b3a9bba9a7146b6a44d1fa5,
+        ),
+        (
+            0xc039d37840b593ec761d708328bb83cb,
+            0x3ffefe7f6e8557b2874c89514ba80514,
+        ),
+        (
+            0xc071733e993735442cd087995b3c8a2e,
+            0xbffe7a5534740025428eeb6789c3878f,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(sinf128, &REFERENCE, 1);
+    }
+}
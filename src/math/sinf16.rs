@@ -0,0 +1,47 @@
+use super::f16::{from_f64_round, rem_pio2f16};
+use super::{fabs, k_cos, k_sin, sin, Round, F16};
+use core::f64::consts::FRAC_PI_4;
+
+/// Sine (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinf16(x: F16) -> F16 {
+    let y = f64::from(x);
+    if !y.is_finite() || fabs(y) <= FRAC_PI_4 {
+        return from_f64_round(sin(y), Round::current());
+    }
+    let (n, y0, y1) = rem_pio2f16(y);
+    let r = match n & 3 {
+        0 => k_sin(y0, y1, 1),
+        1 => k_cos(y0, y1),
+        2 => -k_sin(y0, y1, 1),
+        _ => -k_cos(y0, y1),
+    };
+    from_f64_round(r, Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(sinf16(F16::ONE).to_bits(), 0x3abb);
+        assert_eq!(sinf16(F16::MAX).to_bits(), 0x3bce);
+        assert_eq!(sinf16(F16::from_f32(3.140625)).to_bits(), 0x13ed);
+        assert_eq!(sinf16(F16::from_bits(0x8400)).to_bits(), 0x8400);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(sinf16(F16::INFINITY).is_nan());
+        assert!(sinf16(-F16::ZERO).is_sign_negative());
+        assert!(sinf16(F16::NAN).is_nan());
+        assert!(sinf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(sinf16, sin, |x| x == F16::ZERO);
+    }
+}
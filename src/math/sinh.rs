@@ -1,3 +1,4 @@
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW};
 use super::{expm1, expo2};
 
 // sinh(x) = (exp(x) - 1/exp(x))/2
@@ -45,5 +46,8 @@ pub fn sinh(x: f64) -> f64 {
     /* |x| > log(DBL_MAX) or nan */
     /* note: the result is stored to handle overflow */
     t = 2.0 * h * expo2(absx);
+    if t.is_infinite() && absx.is_finite() {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    }
     t
 }
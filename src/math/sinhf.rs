@@ -1,4 +1,5 @@
 use super::expm1f;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_OVERFLOW};
 use super::k_expo2f;
 
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
@@ -26,5 +27,9 @@ pub fn sinhf(x: f32) -> f32 {
     }
 
     /* |x| > logf(FLT_MAX) or nan */
-    2. * h * k_expo2f(absx)
+    let t = 2. * h * k_expo2f(absx);
+    if t.is_infinite() && absx.is_finite() {
+        feraiseexcept(FE_OVERFLOW | FE_INEXACT);
+    }
+    t
 }
@@ -0,0 +1,186 @@
+/* The cases of the f64 sinh, with expm1f128 and expf128; within 2 ulp */
+
+use super::expf128::EXP_MAX_ARG;
+use super::f128::{exponent, inexact_tiny, quiet_nan, BIAS, SIGN_MASK};
+use super::{expf128, expm1f128, F128};
+
+/// Hyperbolic sine (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinhf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    let half = F128::from_bits(0x3ffe0000000000000000000000000000);
+    let h = if x.is_sign_negative() { -half } else { half };
+    /* |x| */
+    let absx = F128::from_bits(x.to_bits() & !SIGN_MASK);
+
+    /* |x| < 40, where the 1/exp(x) still matters */
+    if absx < F128::from(40) {
+        let e = exponent(x);
+        if e < BIAS - 57 {
+            /* note: this branch avoids spurious underflow */
+            return inexact_tiny(x);
+        }
+        let t = expm1f128(absx);
+        if e < BIAS {
+            /* |x| < 1 */
+            return h * (F128::from(2) * t - t * t / (t + F128::ONE));
+        }
+        return h * (t + t / (t + F128::ONE));
+    }
+
+    /* |x| < log(MAX) */
+    if absx <= F128::from_bits(EXP_MAX_ARG) {
+        return h * expf128(absx);
+    }
+
+    /* exp(|x|/2)^2/2 does not overflow for slightly larger |x|, inf stays inf */
+    let w = expf128(half * absx);
+    (h * w) * w
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1, -0.5, 20, 2^-60 */
+        assert!(ulps(sinhf128(F128::ONE), 0x3fff2cd9fc44eb9825a80249487f0650) <= 2);
+        assert!(
+            ulps(
+                sinhf128(f(0xbffe0000000000000000000000000000)),
+                0xbffe0acd00fe63b96ca357895761ae66
+            ) <= 2
+        );
+        assert!(ulps(sinhf128(F128::from(20)), 0x401aceb088b68e803f93e546cffa0694) <= 2);
+        assert!(
+            ulps(
+                sinhf128(f(0x3fc30000000000000000000000000000)),
+                0x3fc30000000000000000000000000000
+            ) <= 2
+        );
+        /* beyond log(MAX), but still finite */
+        assert!(sinhf128(F128::from(11357)).is_finite());
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(sinhf128(F128::INFINITY), F128::INFINITY);
+        assert_eq!(sinhf128(F128::NEG_INFINITY), F128::NEG_INFINITY);
+        assert_eq!(sinhf128(F128::from(11400)), F128::INFINITY);
+        let z = sinhf128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        assert!(sinhf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fed6744ccafa31b6e5d74562f875a9d,
+            0x3fed6744ccafaa7a52cea1274025c0be,
+        ),
+        (
+            0x3fedc4026fb2244ed5de9a3ce4619386,
+            0x3fedc4026fb232fc9df6e5d0ac871160,
+        ),
+        (
+            0x3ff0bed99746c28c9a28823bf59b6c09,
+            0x3ff0bed9974a4e30a36b9e80718a06c2,
+        ),
+        (
+            0x3ff11634771ebd3b1e1a7a1752faae81,
+            0x3ff1163477222964072c150c3d6694aa,
+        ),
+        (
+            0x3ff4422cf59ecb84d2e376493c919713,
+            0x3ff4422cf6f2f936575050f994e31883,
+        ),
+        (
+            0x3ff51af7e8bacdf11bec6ce2b832e42a,
+            0x3ff51af7ec54be8fce2d94fa5687e01f,
+        ),
+        (
+            0x3ff9f8169a5bbb3c296aa2c7d072434a,
+            0x3ff9f82af6a7e0707b1bdd017b9fbe03,
+        ),
+        (
+            0x3ff9fa69eddb59933f5ca594f424ebe0,
+            0x3ff9fa7e92a0843a243ee5b657716289,
+        ),
+        (
+            0x3ffcb3f4f4b0e993e7178d9b35518c80,
+            0x3ffcb741bc31eed5cdd477b90f15d40c,
+        ),
+        (
+            0x400220b95fc13cb1619ca2189fb7b288,
+            0x400b03044a0abe28ddc3463df4b962d7,
+        ),
+        (
+            0x400314397c78e993686b5ad134881c80,
+            0x4016dff2f12cd5fd07b67a5dab3adafd,
+        ),
+        (
+            0x4006327a12b4430e94d76d0c93f02808,
+            0x40db0de7fcbce1efd121acda19d96f03,
+        ),
+        (
+            0x4007156ab2834b66fd29850c06b85470,
+            0x418e2bcaf5c0d1f9c93c8dbf6ddc0446,
+        ),
+        (
+            0x4007f598af88714f67dfcc6cd638d26d,
+            0x42d191e579d921c8c1348ef05a54bac7,
+        ),
+        (
+            0xbfeb815a1406aafbbcc19feb8607796e,
+            0xbfeb815a1406ab8d43777470355ddce2,
+        ),
+        (
+            0xbfecb4a355f5bd52012b96975ccb42a8,
+            0xbfecb4a355f5c0a0d44774b9243cbad9,
+        ),
+        (
+            0xbfed07fc93ab428d5f6956b8c69d1748,
+            0xbfed07fc93ab4579f24a0e73a4a1b71b,
+        ),
+        (
+            0xbff2d6575eb0a44d21b6b2a0a4277645,
+            0xbff2d6575ef2cb6a26fae2d0fdc83138,
+        ),
+        (
+            0xbff5050ac046fba605fc8ba0a4ae5fd1,
+            0xbff5050ac31ac90ac31f2a43f3fd5ce2,
+        ),
+        (
+            0xbff5285f33d932eaa3bdcee6a9b17a0f,
+            0xbff5285f37fc73c49287757e8d90d1c7,
+        ),
+        (
+            0xbffacb34323e1ce8d0566569a3e25ad1,
+            0xbffacb71c50e0fed1887e7f830c26bd3,
+        ),
+        (
+            0xbffc59299190a847cf134aef423f78d7,
+            0xbffc5acc7957c4af49219e00bb652577,
+        ),
+        (
+            0xc002cd645627c56c1c0da2814c1b51e6,
+            0xc012be2f29ef57995049e59e76791755,
+        ),
+        (
+            0xc00790fdfd675a117b9b8d1dd5c330e5,
+            0xc2406c66a94070a833421987f6978df0,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(sinhf128, &REFERENCE, 2);
+    }
+}
@@ -0,0 +1,93 @@
+use super::fenv::{feraiseexcept, underflow_if_tiny, FE_INVALID};
+use super::{k_cos, k_sin, mul_pi, rem_half};
+
+/// Sine of pi times x (f64)
+///
+/// Computes `sin(pi * x)` without first rounding `pi * x`. The result is exact
+/// for integer and half-integer `x`; `sinpi(n)` is `+0.0` for positive integers
+/// and `-0.0` for negative ones.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpi(x: f64) -> f64 {
+    let x1p60 = f64::from_bits(0x43b0000000000000); // 0x1p60 === 2 ^ 60
+    let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+
+    let ui = x.to_bits();
+    let sign = (ui >> 63) != 0;
+    let ix = ui & 0x7fffffffffffffff;
+
+    if ix >= 0x7ff0000000000000 {
+        /* sinpi(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix >= 0x4330000000000000 {
+        /* |x| >= 2^52: x is an integer */
+        return f64::from_bits(ui & 1 << 63);
+    }
+    if ix < 0x0170000000000000 {
+        /* |x| < 2^-1000: sinpi(x) ~= pi * x, scaled to avoid subnormal intermediates */
+        if ix == 0 {
+            return x;
+        }
+        let (hi, lo) = mul_pi(x * x1p60);
+        return underflow_if_tiny((hi + lo) * x1p_60);
+    }
+
+    let (n, f) = rem_half(f64::from_bits(ix));
+    let y = if f == 0.0 {
+        match n {
+            1 => 1.0,
+            3 => -1.0,
+            _ => 0.0,
+        }
+    } else {
+        let (hi, lo) = mul_pi(f);
+        match n {
+            0 => k_sin(hi, lo, 1),
+            1 => k_cos(hi, lo),
+            2 => -k_sin(hi, lo, 1),
+            _ => -k_cos(hi, lo),
+        }
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sinpi;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(sinpi(0.5), 1.0);
+        assert_eq!(sinpi(1.5), -1.0);
+        assert_eq!(sinpi(-2.5), -1.0);
+        assert_eq!(sinpi(1.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(sinpi(-1.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sinpi(4.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(sinpi(-1e300).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sinpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(sinpi(1.0 / 6.0), 0.5);
+    }
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(sinpi(0.25), consts::FRAC_1_SQRT_2);
+        assert_eq!(sinpi(1e-10), 3.1415926535897934e-10);
+        assert_eq!(sinpi(1e-310), 3.1415926535898e-310);
+        assert_eq!(sinpi(4503599627370495.5), -1.0);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(sinpi(NAN).is_nan());
+        assert!(sinpi(INFINITY).is_nan());
+        assert!(sinpi(NEG_INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,49 @@
+use super::fenv::{feraiseexcept, underflow_if_tinyf, FE_INVALID};
+use super::{k_cosf, k_sinf, rem_half};
+use core::f64::consts::PI;
+
+/// Sine of pi times x (f32)
+///
+/// Computes `sin(pi * x)` without first rounding `pi * x`. The result is exact
+/// for integer and half-integer `x`; `sinpif(n)` is `+0.0` for positive
+/// integers and `-0.0` for negative ones.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sinpif(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let sign = (ui >> 31) != 0;
+    let ix = ui & 0x7fffffff;
+
+    if ix >= 0x7f800000 {
+        /* sinpif(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix >= 0x4b000000 {
+        /* |x| >= 2^23: x is an integer */
+        return f32::from_bits(ui & 1 << 31);
+    }
+
+    /* the reduction is exact in double precision, and so is good enough pi * f */
+    let (n, f) = rem_half(f32::from_bits(ix) as f64);
+    let y = if f == 0.0 {
+        match n {
+            1 => 1.0,
+            3 => -1.0,
+            _ => 0.0,
+        }
+    } else {
+        match n {
+            0 => underflow_if_tinyf(k_sinf(PI * f)),
+            1 => k_cosf(PI * f),
+            2 => -k_sinf(PI * f),
+            _ => -k_cosf(PI * f),
+        }
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
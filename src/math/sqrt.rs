@@ -65,9 +65,7 @@
  *      Together with the remainder, we can decide whether the
  *      result is exact, bigger than 1/2ulp, or less than 1/2ulp
  *      (it will never equal to 1/2ulp).
- *      The rounding mode can be detected by checking whether
- *      huge + tiny is equal to huge, and whether huge - tiny is
- *      equal to huge for some floating point number "huge" and "tiny".
+ *      The rounding mode is given by fegetround.
  *
  * Special cases:
  *      sqrt(+-0) = +-0         ... exact
@@ -76,10 +74,15 @@
  *      sqrt(NaN) = NaN         ... with invalid signal for signaling NaN
  */
 
+use super::fenv::{feraiseexcept, FE_INVALID};
 use core::f64;
 
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sqrt(x: f64) -> f64 {
+    if x < 0.0 {
+        /* sqrt(-ve) = NaN, with invalid signal */
+        feraiseexcept(FE_INVALID);
+    }
     // On wasm32 we know that LLVM's intrinsic will compile to an optimized
     // `f64.sqrt` native instruction, so we can leverage this for both code size
     // and speed.
@@ -109,11 +112,9 @@ pub fn sqrt(x: f64) -> f64 {
     }
     #[cfg(not(target_feature = "sse2"))]
     {
+        use super::fenv::{fegetround, FE_INEXACT, FE_TONEAREST, FE_UPWARD};
         use core::num::Wrapping;
 
-        const TINY: f64 = 1.0e-300;
-
-        let mut z: f64;
         let sign: Wrapping<u32> = Wrapping(0x80000000);
         let mut ix0: i32;
         let mut s0: i32;
@@ -212,15 +213,15 @@ pub fn sqrt(x: f64) -> f64 {
             r >>= 1;
         }
 
-        /* use floating add to find out rounding direction */
+        /* round according to the current rounding mode */
         if (ix0 as u32 | ix1.0) != 0 {
-            z = 1.0 - TINY; /* raise inexact flag */
-            if z >= 1.0 {
-                z = 1.0 + TINY;
+            feraiseexcept(FE_INEXACT);
+            let mode = fegetround();
+            if mode == FE_TONEAREST || mode == FE_UPWARD {
                 if q1.0 == 0xffffffff {
                     q1 = Wrapping(0);
                     q += 1;
-                } else if z > 1.0 {
+                } else if mode == FE_UPWARD {
                     if q1.0 == 0xfffffffe {
                         q += 1;
                     }
@@ -13,8 +13,14 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, FE_INVALID};
+
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn sqrtf(x: f32) -> f32 {
+    if x < 0.0 {
+        /* sqrt(-ve) = NaN, with invalid signal */
+        feraiseexcept(FE_INVALID);
+    }
     // On wasm32 we know that LLVM's intrinsic will compile to an optimized
     // `f32.sqrt` native instruction, so we can leverage this for both code size
     // and speed.
@@ -44,9 +50,8 @@ pub fn sqrtf(x: f32) -> f32 {
     }
     #[cfg(not(target_feature = "sse"))]
     {
-        const TINY: f32 = 1.0e-30;
+        use super::fenv::{fegetround, FE_INEXACT, FE_TONEAREST, FE_UPWARD};
 
-        let mut z: f32;
         let sign: i32 = 0x80000000u32 as i32;
         let mut ix: i32;
         let mut s: i32;
@@ -109,16 +114,14 @@ pub fn sqrtf(x: f32) -> f32 {
             r >>= 1;
         }
 
-        /* use floating add to find out rounding direction */
+        /* round according to the current rounding mode */
         if ix != 0 {
-            z = 1.0 - TINY; /* raise inexact flag */
-            if z >= 1.0 {
-                z = 1.0 + TINY;
-                if z > 1.0 {
-                    q += 2;
-                } else {
-                    q += q & 1;
-                }
+            feraiseexcept(FE_INEXACT);
+            let mode = fegetround();
+            if mode == FE_UPWARD {
+                q += 2;
+            } else if mode == FE_TONEAREST {
+                q += q & 1;
             }
         }
 
@@ -0,0 +1,99 @@
+use super::f128::{invalid, is_zero, quiet_nan, round_pack, split};
+use super::{Round, F128};
+
+/// Square root (F128)
+///
+/// Returns the correctly rounded square root of `x`, computed one bit at a
+/// time in integer arithmetic.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if is_zero(x) {
+        /* sqrt(+-0) = +-0 */
+        return x;
+    }
+    if x.is_sign_negative() {
+        return invalid();
+    }
+    if x.is_infinite() {
+        return x;
+    }
+
+    /* make the exponent even, so that sqrt(m * 2^e) = sqrt(m) * 2^(e/2) */
+    let (mut m, mut e) = split(x);
+    if e & 1 != 0 {
+        m <<= 1;
+        e -= 1;
+    }
+
+    /* m is in [2^112, 2^114). Compute the 115 bit q = floor(sqrt(m * 2^116))
+     * two bits of the radicand at a time; the remainder stays below 2^118. */
+    let mut q: u128 = 0;
+    let mut rem: u128 = 0;
+    for i in (0..115).rev() {
+        let pair = if i >= 58 { (m >> (2 * i - 116)) & 3 } else { 0 };
+        rem = rem << 2 | pair;
+        let t = q << 2 | 1;
+        q <<= 1;
+        if rem >= t {
+            rem -= t;
+            q |= 1;
+        }
+    }
+
+    /* sqrt(x) = q * 2^(e/2 - 58), and the remainder makes the sticky bit */
+    round_pack(
+        q << 1 | (rem != 0) as u128,
+        e / 2 - 59,
+        false,
+        Round::current(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(sqrtf128(F128::ONE), F128::ONE);
+        /* sqrt(4) = 2, sqrt(2^-16382) = 2^-8191 */
+        assert_eq!(
+            sqrtf128(f(0x40010000000000000000000000000000)).to_bits(),
+            0x40000000000000000000000000000000
+        );
+        assert_eq!(
+            sqrtf128(F128::MIN_POSITIVE).to_bits(),
+            0x20000000000000000000000000000000
+        );
+        /* sqrt(9 * 2^-16494), from a subnormal */
+        assert_eq!(sqrtf128(f(9)).to_bits(), 0x1fc98000000000000000000000000000);
+    }
+
+    #[test]
+    fn rounded() {
+        /* sqrt(2) and sqrt(MAX) */
+        assert_eq!(
+            sqrtf128(f(0x40000000000000000000000000000000)).to_bits(),
+            0x3fff6a09e667f3bcc908b2fb1366ea95
+        );
+        assert_eq!(
+            sqrtf128(F128::MAX).to_bits(),
+            0x5ffeffffffffffffffffffffffffffff
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(sqrtf128(-F128::ONE).is_nan());
+        assert!(sqrtf128(F128::NEG_INFINITY).is_nan());
+        assert_eq!(sqrtf128(F128::INFINITY), F128::INFINITY);
+        assert!(sqrtf128(-F128::ZERO).is_sign_negative());
+    }
+}
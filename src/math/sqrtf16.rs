@@ -0,0 +1,43 @@
+use super::{sqrt, F16};
+
+/// Square root (F16)
+///
+/// Returns the correctly rounded square root of `x`. The square root of a
+/// binary16 value that is not exact is too far from every binary16 value
+/// and midpoint for the rounding of the `f64` square root to matter.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn sqrtf16(x: F16) -> F16 {
+    F16::from_f64(sqrt(f64::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(sqrtf16(F16::from_f32(4.0)), F16::from_f32(2.0));
+        assert_eq!(sqrtf16(F16::from_f32(2.0)).to_bits(), 0x3da8);
+        assert_eq!(sqrtf16(F16::from_bits(1)).to_bits(), 0x0c00);
+        let z = sqrtf16(-F16::ZERO);
+        assert!(z == F16::ZERO && z.is_sign_negative());
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(sqrtf16(F16::INFINITY), F16::INFINITY);
+        assert!(sqrtf16(F16::NEG_INFINITY).is_nan());
+        assert!(sqrtf16(-F16::ONE).is_nan());
+        assert!(sqrtf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        check_all(sqrtf16, sqrt, |x| {
+            let y = f64::from(x);
+            let r = sqrt(y);
+            r * r == y
+        });
+    }
+}
@@ -9,6 +9,7 @@
 // is preserved.
 // ====================================================
 
+use super::fenv::{feraiseexcept, inexact_tiny, FE_INVALID};
 use super::{k_tan, rem_pio2};
 
 // tan(x)
@@ -41,19 +42,13 @@ use super::{k_tan, rem_pio2};
 //      TRIG(x) returns trig(x) nearly rounded
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn tan(x: f64) -> f64 {
-    let x1p120 = f32::from_bits(0x7b800000); // 0x1p120f === 2 ^ 120
-
     let ix = (f64::to_bits(x) >> 32) as u32 & 0x7fffffff;
     /* |x| ~< pi/4 */
     if ix <= 0x3fe921fb {
         if ix < 0x3e400000 {
             /* |x| < 2**-27 */
             /* raise inexact if x!=0 and underflow if subnormal */
-            force_eval!(if ix < 0x00100000 {
-                x / x1p120 as f64
-            } else {
-                x + x1p120 as f64
-            });
+            inexact_tiny(x);
             return x;
         }
         return k_tan(x, 0.0, 0);
@@ -61,6 +56,9 @@ pub fn tan(x: f64) -> f64 {
 
     /* tan(Inf or NaN) is NaN */
     if ix >= 0x7ff00000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         return x - x;
     }
 
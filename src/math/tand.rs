@@ -0,0 +1,98 @@
+use super::fenv::{feraiseexcept, underflow_if_tiny, FE_DIVBYZERO, FE_INVALID};
+use super::{deg_to_rad, k_tan, rem_90};
+use core::f64;
+
+/// Tangent of x degrees (f64)
+///
+/// Computes `tan(x * pi/180)`, reducing `x` modulo 360 exactly first. The
+/// result is exact for multiples of 45 degrees. `tand(180 * n)` is `+0.0` for
+/// positive even and negative odd `n` and `-0.0` otherwise, and
+/// `tand(180 * n + 90)` is a signed infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tand(x: f64) -> f64 {
+    let x1p60 = f64::from_bits(0x43b0000000000000); // 0x1p60 === 2 ^ 60
+    let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+
+    let ui = x.to_bits();
+    let sign = (ui >> 63) != 0;
+    let ix = ui & 0x7fffffffffffffff;
+
+    if ix >= 0x7ff0000000000000 {
+        /* tand(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix < 0x0170000000000000 {
+        /* |x| < 2^-1000: tand(x) ~= x * pi/180, scaled to avoid subnormal intermediates */
+        if ix == 0 {
+            return x;
+        }
+        let (hi, lo) = deg_to_rad(x * x1p60);
+        return underflow_if_tiny((hi + lo) * x1p_60);
+    }
+
+    /* tan has period 180, so n = 0, 2 give tan(f) and n = 1, 3 give -cot(f) */
+    let (n, f) = rem_90(f64::from_bits(ix));
+    let odd = n & 1 != 0;
+    let y = if f == 0.0 {
+        match n {
+            0 => 0.0,
+            2 => -0.0,
+            _ => {
+                /* pole */
+                feraiseexcept(FE_DIVBYZERO);
+                if n == 1 {
+                    f64::INFINITY
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+        }
+    } else if f == 45.0 || f == -45.0 {
+        /* tan(+-45) = +-1 */
+        let t = f / 45.0;
+        if odd {
+            -t
+        } else {
+            t
+        }
+    } else {
+        let (hi, lo) = deg_to_rad(f);
+        k_tan(hi, lo, odd as i32)
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tand;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(tand(45.0), 1.0);
+        assert_eq!(tand(-45.0), -1.0);
+        assert_eq!(tand(135.0), -1.0);
+        assert_eq!(tand(225.0), 1.0);
+        assert_eq!(tand(90.0), INFINITY);
+        assert_eq!(tand(-90.0), NEG_INFINITY);
+        assert_eq!(tand(270.0), NEG_INFINITY);
+        assert_eq!(tand(360.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(tand(180.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(tand(-180.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(tand(-0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(tand(NAN).is_nan());
+        assert!(tand(INFINITY).is_nan());
+        assert!(tand(NEG_INFINITY).is_nan());
+    }
+}
@@ -0,0 +1,61 @@
+use super::fenv::{feraiseexcept, underflow_if_tinyf, FE_DIVBYZERO, FE_INVALID};
+use super::{k_tanf, rem_90};
+use core::f32;
+
+/* pi/180 */
+const D2R: f64 = 0.017453292519943295; /* 0x3F91DF46, 0xA2529D39 */
+
+/// Tangent of x degrees (f32)
+///
+/// Computes `tan(x * pi/180)`, reducing `x` modulo 360 exactly first. The
+/// result is exact for multiples of 45 degrees. `tandf(180 * n)` is `+0.0` for
+/// positive even and negative odd `n` and `-0.0` otherwise, and
+/// `tandf(180 * n + 90)` is a signed infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tandf(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let sign = (ui >> 31) != 0;
+    let ix = ui & 0x7fffffff;
+
+    if ix >= 0x7f800000 {
+        /* tandf(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+
+    /* tan has period 180, so n = 0, 2 give tan(f) and n = 1, 3 give -cot(f) */
+    let (n, f) = rem_90(f32::from_bits(ix) as f64);
+    let odd = n & 1 != 0;
+    let y = if f == 0.0 {
+        match n {
+            0 => 0.0,
+            2 => -0.0,
+            _ => {
+                /* pole */
+                feraiseexcept(FE_DIVBYZERO);
+                if n == 1 {
+                    f32::INFINITY
+                } else {
+                    f32::NEG_INFINITY
+                }
+            }
+        }
+    } else if f == 45.0 || f == -45.0 {
+        /* tan(+-45) = +-1 */
+        let t = (f / 45.0) as f32;
+        if odd {
+            -t
+        } else {
+            t
+        }
+    } else {
+        underflow_if_tinyf(k_tanf(D2R * f, odd))
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
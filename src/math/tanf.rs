@@ -14,6 +14,7 @@
  * ====================================================
  */
 
+use super::fenv::{feraiseexcept, inexact_tinyf, FE_INVALID};
 use super::{k_tanf, rem_pio2f};
 
 use core::f64::consts::FRAC_PI_2;
@@ -28,8 +29,6 @@ const T4_PIO2: f64 = 4. * FRAC_PI_2; /* 0x401921FB, 0x54442D18 */
 pub fn tanf(x: f32) -> f32 {
     let x64 = x as f64;
 
-    let x1p120 = f32::from_bits(0x7b800000); // 0x1p120f === 2 ^ 120
-
     let mut ix = x.to_bits();
     let sign = (ix >> 31) != 0;
     ix &= 0x7fffffff;
@@ -39,11 +38,7 @@ pub fn tanf(x: f32) -> f32 {
         if ix < 0x39800000 {
             /* |x| < 2**-12 */
             /* raise inexact if x!=0 and underflow if subnormal */
-            force_eval!(if ix < 0x00800000 {
-                x / x1p120
-            } else {
-                x + x1p120
-            });
+            inexact_tinyf(x);
             return x;
         }
         return k_tanf(x64, false);
@@ -69,6 +64,9 @@ pub fn tanf(x: f32) -> f32 {
 
     /* tan(Inf or NaN) is NaN */
     if ix >= 0x7f800000 {
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
         return x - x;
     }
 
@@ -0,0 +1,190 @@
+/* Reduction by pi/2, then the quotient of k_sinf128 and k_cosf128; within
+ * 3 ulp */
+
+use super::f128::{exponent, inexact_tiny, invalid, quiet_nan, BIAS, SIGN_MASK};
+use super::k_cosf128::k_cosf128;
+use super::k_sinf128::k_sinf128;
+use super::rem_pio2f128::rem_pio2f128;
+use super::sinf128::PIO4;
+use super::F128;
+
+/// The tangent of `x` in radians (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanf128(x: F128) -> F128 {
+    /* |x| ~< pi/4 */
+    if x.to_bits() & !SIGN_MASK <= PIO4 {
+        if exponent(x) < BIAS - 57 {
+            /* |x| < 2**-57 */
+            return inexact_tiny(x);
+        }
+        return k_sinf128(x, F128::ZERO) / k_cosf128(x, F128::ZERO);
+    }
+
+    /* tan(Inf or NaN) is NaN */
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if x.is_infinite() {
+        return invalid();
+    }
+
+    /* argument reduction, and tan = -cos/sin for odd n */
+    let (n, y0, y1) = rem_pio2f128(x);
+    let s = k_sinf128(y0, y1);
+    let c = k_cosf128(y0, y1);
+    if n & 1 == 0 {
+        s / c
+    } else {
+        -c / s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1, 0.5, 1e6, 2^16383 and pi */
+        assert!(ulps(tanf128(F128::ONE), 0x3fff8eb245cbee3a5b8acc7d41323141) <= 1);
+        assert!(
+            ulps(
+                tanf128(f(0x3ffe0000000000000000000000000000)),
+                0x3ffe17b4f5bf3474a431796480788244
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                tanf128(F128::from(1000000)),
+                0xbffd7e9768ab734c01080b09ec677213
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                tanf128(f(0x7ffe0000000000000000000000000000)),
+                0x3ffdb0de23a176a6f93ede34e7af4fcc
+            ) <= 1
+        );
+        assert!(
+            ulps(
+                tanf128(f(0x4000921fb54442d18469898cc51701b8)),
+                0xbf8dcd129024e088a67cc74020bbea64
+            ) <= 1
+        );
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(tanf128(F128::INFINITY).is_nan());
+        assert!(tanf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3ff81f3907a9d9cb63531860959998b8,
+            0x3ff81f3ae9c112d6ba4d0a557a36890c,
+        ),
+        (
+            0x3ffa9c9909a5aeb0227b04c074d149ed,
+            0x3ffa9cf271419bf52dbacab4294624ae,
+        ),
+        (
+            0x3fff226b20653a4c9a1fe8ae8e52e338,
+            0x4000127c1a8cac25c52c04b6a0d4d4df,
+        ),
+        (
+            0x40013e48f08fbef63aa9e4a60d49dc0b,
+            0xc000df982de39c77f9e813a31a171929,
+        ),
+        (
+            0x4005c5b3e8fba9ac2a268c36af0bb5ff,
+            0x3ffd5cde24c8acdafe461258383c5e54,
+        ),
+        (
+            0x401ca6b74e30d6c0ee2a4b6db89b076d,
+            0xbfff0a40143730ad7d317d29beb66c1d,
+        ),
+        (
+            0x401d6b68d18ea854b5c88aa1f6852004,
+            0x4000d8d542eefa1d8dfaee955f91c88d,
+        ),
+        (
+            0x405a0c7291eb710bc8b924986a678529,
+            0x3fff0fffc68fb68095e1d5eb7c9f0743,
+        ),
+        (
+            0x40dfde7151604e1714d7b1923485eb4b,
+            0xc00026a5ac175331c2fe44fd1c5e5e82,
+        ),
+        (
+            0xbffcd792af8def0cd0f10bf6bbecb7d2,
+            0xbffce0167d759648ef0d870cfd8d1cdc,
+        ),
+        (
+            0xbffdaefbf233f312ce1b4f8ed7a11074,
+            0xbffdca600cc3f7bf74a07eeb16b04b6a,
+        ),
+        (
+            0xbffe518d0f7d70c474d401cc126d3b25,
+            0xbffe8cc65a02d4c3ee96ee79206b51b0,
+        ),
+        (
+            0xbfff5f919584da871dab7068692d92f2,
+            0xc0013fdb8f464b2fe5d74727b8d105af,
+        ),
+        (
+            0xc001e5aa3fe58947e66388b3c4faf395,
+            0xc000d6cabb90492289d5e433dfc33316,
+        ),
+        (
+            0xc00589e7bf692a37abeb22c1a45d95a3,
+            0xbfffe72396542fdd9a82865bceb2faf8,
+        ),
+        (
+            0xc006d49c46df65c325fcc298b2a3cbc8,
+            0x4000e7e204a1c14287386c16d88cead8,
+        ),
+        (
+            0xc0166e4bde028830e527e3959a5a8120,
+            0x4000d304b0e8c24d5b00ded0739f7f4e,
+        ),
+        (
+            0xc020fc01624cea8ebbc5962e4f5510db,
+            0x3ffd847ef5804d21ebc3cce9c0db15c3,
+        ),
+        (
+            0xc04f1a3543eb9a966d29cc9cf09e2823,
+            0x3ffed0126e59e10d2a58d1af3ad96bed,
+        ),
+        (
+            0xc05c93042e35a8e221f026d06879bb33,
+            0x3fff2ecd72495c755e51f91f73f37887,
+        ),
+        (
+            0xc0801d465825c267a7b124bd85eff023,
+            0x3ffefecf3a841017a26efd07c323e2f9,
+        ),
+        (
+            0xc0b9174f76bd1cfaafaef97bd40e1843,
+            0x3fffd35a8ee588aab0967034d9962158,
+        ),
+        (
+            0xc0e67ffbb3da9b97b26f5b41b7a92b60,
+            0x3ffc73691a735eabedb6fbdd9456d4d6,
+        ),
+        (
+            0xc110ccfb2427d81cd8eba81b01c210ac,
+            0x3fffbc12498e474395194cc8f82bec02,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(tanf128, &REFERENCE, 3);
+    }
+}
@@ -1,4 +1,5 @@
 use super::expm1;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_UNDERFLOW};
 
 /* tanh(x) = (exp(x) - exp(-x))/(exp(x) + exp(-x))
  *         = (exp(2*x) - 1)/(exp(2*x) - 1 + 2)
@@ -41,7 +42,9 @@ pub fn tanh(mut x: f64) -> f64 {
     } else {
         /* |x| is subnormal */
         /* note: the branch above would not raise underflow in [0x1p-1023,0x1p-1022) */
-        force_eval!(x as f32);
+        if x != 0.0 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        }
         t = x;
     }
 
@@ -1,4 +1,5 @@
 use super::expm1f;
+use super::fenv::{feraiseexcept, FE_INEXACT, FE_UNDERFLOW};
 
 #[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
 pub fn tanhf(mut x: f32) -> f32 {
@@ -28,7 +29,9 @@ pub fn tanhf(mut x: f32) -> f32 {
         -t / (t + 2.)
     } else {
         /* |x| is subnormal */
-        force_eval!(x * x);
+        if x != 0.0 {
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
+        }
         x
     };
     if sign {
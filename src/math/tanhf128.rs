@@ -0,0 +1,187 @@
+/* The cases of the f64 tanh, with expm1f128; within 2 ulp */
+
+use super::f128::{exponent, inexact_tiny, quiet_nan, BIAS, SIGN_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::{expm1f128, F128};
+
+/// Hyperbolic tangent (F128)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhf128(x: F128) -> F128 {
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    let sign = x.is_sign_negative();
+    /* x = |x| */
+    let x = F128::from_bits(x.to_bits() & !SIGN_MASK);
+    let two = F128::from(2);
+
+    let t = if x > F128::from_bits(0x3ffe193ea7aad030a976a4198d55053b) {
+        /* |x| > log(3)/2 ~= 0.5493 */
+        if x > F128::from(40) {
+            /* |x| > 40 */
+            /* note: this branch avoids raising overflow */
+            feraiseexcept(FE_INEXACT);
+            F128::ONE
+        } else {
+            let t = expm1f128(two * x);
+            F128::ONE - two / (t + two)
+        }
+    } else if exponent(x) >= BIAS - 57 {
+        /* |x| >= 0x1p-57 */
+        let t = expm1f128(-two * x);
+        -t / (t + two)
+    } else {
+        /* |x| < 0x1p-57 */
+        /* note: the branch above would raise spurious underflow */
+        inexact_tiny(x)
+    };
+
+    if sign {
+        -t
+    } else {
+        t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f128::{check_reference, ulps};
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1, -0.25, 10, 2^-60 */
+        assert!(ulps(tanhf128(F128::ONE), 0x3ffe85efab514f394558632be293c427) <= 2);
+        assert!(
+            ulps(
+                tanhf128(f(0xbffd0000000000000000000000000000)),
+                0xbffcf597ea69a1c85f1358d71d84729d
+            ) <= 2
+        );
+        assert!(ulps(tanhf128(F128::from(10)), 0x3ffeffffffdc96f355529456c2d12d13) <= 2);
+        assert!(
+            ulps(
+                tanhf128(f(0x3fc30000000000000000000000000000)),
+                0x3fc30000000000000000000000000000
+            ) <= 2
+        );
+        assert_eq!(tanhf128(F128::from(-50)), -F128::ONE);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(tanhf128(F128::INFINITY), F128::ONE);
+        assert_eq!(tanhf128(F128::NEG_INFINITY), -F128::ONE);
+        let z = tanhf128(-F128::ZERO);
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        assert!(tanhf128(F128::NAN).is_nan());
+    }
+
+    const REFERENCE: [(u128, u128); 24] = [
+        (
+            0x3fca0e99f70b98ab64dd5235ffa2c55a,
+            0x3fca0e99f70b98ab64dd5235ffa2c541,
+        ),
+        (
+            0x3fd3766d30f40c2bf54cb5f44bf58ef3,
+            0x3fd3766d30f40c2bf54cb5f44aea90ef,
+        ),
+        (
+            0x3fdf091a1f627f8ca63312060cd4d873,
+            0x3fdf091a1f627f8ca632b342c3e1afdb,
+        ),
+        (
+            0x3fe9e8f9a0c0ee8c76acfb8ee6c94c51,
+            0x3fe9e8f9a0c0ee674c53c57ccea6aea8,
+        ),
+        (
+            0x3ff364bcb9952fac9647a4d9ddbe6ef3,
+            0x3ff364bcb8ae468a5fb01258a6e83a2b,
+        ),
+        (
+            0x3ff8b9d0dfebb3f5fb4914e5816239d2,
+            0x3ff8b9ca056dd8f9aa66bb1f56285ccc,
+        ),
+        (
+            0x3ff8fe410b909e4723f07c5ce31ecebb,
+            0x3ff8fe367cfff9890948eb6c2e3aa62f,
+        ),
+        (
+            0x3ffde5ca3686d642f5d57f70cf4a0b6e,
+            0x3ffdc45a5c0717b5ea67dbd99214ccd4,
+        ),
+        (
+            0x3fff7d95fb2b329d030069f1bb3263b8,
+            0x3ffece8e50c15ceab1fdbb9bdf06eeb0,
+        ),
+        (
+            0x4000bf9ac433903a17ee5263fc6cb99a,
+            0x3ffeff0fb1cc19dc388c6654bf11c374,
+        ),
+        (
+            0x40020d0e1ab47761c00e5125eaeee7cf,
+            0x3ffefffffca908ef0559935af219ed2d,
+        ),
+        (
+            0x4004d6fdbda6397a7aaf33733f6c6b88,
+            0x3fff0000000000000000000000000000,
+        ),
+        (
+            0xbfcc0bd949ecfba247645cca0a9f8ad5,
+            0xbfcc0bd949ecfba247645cca0a9f894e,
+        ),
+        (
+            0xbfcfc6cb729858bf3d5f1b3006e0a7ce,
+            0xbfcfc6cb729858bf3d5f1b3006dec958,
+        ),
+        (
+            0xbfd62c70c090e7a39f0cafb284844eac,
+            0xbfd62c70c090e7a39f0cafb262087a41,
+        ),
+        (
+            0xbfdb4809cb5a293f3502ed422e3b32ad,
+            0xbfdb4809cb5a293f3502ec8ea2d14fab,
+        ),
+        (
+            0xbfe3969589ea5cc35612ae0a2282d8ba,
+            0xbfe3969589ea5cc354bcd1839d37b156,
+        ),
+        (
+            0xbfe8fd316fa5b43516828246f6ab521d,
+            0xbfe8fd316fa5b42a9881f34d1053bb24,
+        ),
+        (
+            0xbfe9964f44cc6bfeffbc3c6afd72cca9,
+            0xbfe9964f44cc6be9ad05f1acdfe1d7e5,
+        ),
+        (
+            0xbff3ffae29356dee7ea349feecebbcb5,
+            0xbff3ffae268c0a6eeea62c250027bc3e,
+        ),
+        (
+            0xbff56117049c0c57988894be0f02c18f,
+            0xbff56116f69da537f7e356ec15424c5d,
+        ),
+        (
+            0xbff815418b03cd4b5d45fef6416df811,
+            0xbff8153fd96a2e23ae2e86ea23bd81b5,
+        ),
+        (
+            0xbffe07ace7f1d6518b1163677bfb81bd,
+            0xbffde5325724befc19e72bd5168d3655,
+        ),
+        (
+            0xc005af7b4c9ed60ce30597829633e8a0,
+            0xbfff0000000000000000000000000000,
+        ),
+    ];
+
+    #[test]
+    fn reference() {
+        check_reference(tanhf128, &REFERENCE, 2);
+    }
+}
@@ -0,0 +1,59 @@
+use super::f16::from_f64_round;
+use super::{copysign, fabs, tanh, Round, F16};
+
+/// Hyperbolic tangent (F16)
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanhf16(x: F16) -> F16 {
+    let y = f64::from(x);
+    if y.is_finite() && fabs(y) >= 9.0 {
+        /* |tanh(x)| < 1 is within 2^-24 of 1, where tanh may return 1 even
+         * when rounding down, so take the largest f64 below 1 instead */
+        return F16::from_f64(copysign(f64::from_bits(0x3fefffffffffffff), y));
+    }
+    from_f64_round(tanh(y), Round::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::f16::check_all;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(tanhf16(F16::ONE).to_bits(), 0x3a18);
+        assert_eq!(tanhf16(F16::from_f32(-0.25)).to_bits(), 0xb3d6);
+        assert_eq!(tanhf16(F16::from_f32(4.0)).to_bits(), 0x3bff);
+        assert_eq!(tanhf16(F16::from_bits(0x0400)).to_bits(), 0x0400);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(tanhf16(F16::INFINITY), F16::ONE);
+        assert_eq!(tanhf16(F16::NEG_INFINITY), -F16::ONE);
+        assert!(tanhf16(-F16::ZERO).is_sign_negative());
+        assert!(tanhf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn rounding_modes() {
+        use super::super::fenv::{fegetenv, fesetenv, fesetround, FE_DOWNWARD, FE_UPWARD};
+
+        let saved = fegetenv();
+        fesetround(FE_DOWNWARD);
+        let (a, b) = (tanhf16(F16::from_f32(20.0)), tanhf16(F16::from_f32(-20.0)));
+        fesetround(FE_UPWARD);
+        let (c, d) = (tanhf16(F16::from_f32(20.0)), tanhf16(F16::from_f32(-20.0)));
+        fesetenv(&saved);
+        assert_eq!((a.to_bits(), b.to_bits()), (0x3bff, 0xbc00));
+        assert_eq!((c.to_bits(), d.to_bits()), (0x3c00, 0xbbff));
+    }
+
+    #[test]
+    fn exhaustive() {
+        /* beyond 9, the rounding of a value just below 1 is checked above */
+        check_all(tanhf16, tanh, |x| {
+            x == F16::ZERO || fabs(f64::from(x)) >= 9.0
+        });
+    }
+}
@@ -0,0 +1,104 @@
+use super::fenv::{feraiseexcept, underflow_if_tiny, FE_DIVBYZERO, FE_INVALID};
+use super::{k_tan, mul_pi, rem_half};
+use core::f64;
+
+/// Tangent of pi times x (f64)
+///
+/// Computes `tan(pi * x)` without first rounding `pi * x`. The result is exact
+/// for integer, half-integer and quarter-integer `x`. `tanpi(n)` is `+0.0` for
+/// positive even and negative odd integers and `-0.0` otherwise, and
+/// `tanpi(n + 0.5)` is a signed infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpi(x: f64) -> f64 {
+    let x1p60 = f64::from_bits(0x43b0000000000000); // 0x1p60 === 2 ^ 60
+    let x1p_60 = f64::from_bits(0x3c30000000000000); // 0x1p-60 === 2 ^ -60
+
+    let ui = x.to_bits();
+    let sign = (ui >> 63) != 0;
+    let ix = ui & 0x7fffffffffffffff;
+
+    if ix >= 0x7ff0000000000000 {
+        /* tanpi(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix >= 0x4330000000000000 {
+        /* |x| >= 2^52: x is an integer, odd only if |x| < 2^53 and its last bit is set */
+        let odd = ix < 0x4340000000000000 && ix & 1 != 0;
+        return if sign != odd { -0.0 } else { 0.0 };
+    }
+    if ix < 0x0170000000000000 {
+        /* |x| < 2^-1000: tanpi(x) ~= pi * x, scaled to avoid subnormal intermediates */
+        if ix == 0 {
+            return x;
+        }
+        let (hi, lo) = mul_pi(x * x1p60);
+        return underflow_if_tiny((hi + lo) * x1p_60);
+    }
+
+    /* tan has period 1, so n = 0, 2 give tan(pi*f) and n = 1, 3 give -cot(pi*f) */
+    let (n, f) = rem_half(f64::from_bits(ix));
+    let odd = n & 1 != 0;
+    let y = if f == 0.0 {
+        match n {
+            0 => 0.0,
+            2 => -0.0,
+            _ => {
+                /* pole */
+                feraiseexcept(FE_DIVBYZERO);
+                if n == 1 {
+                    f64::INFINITY
+                } else {
+                    f64::NEG_INFINITY
+                }
+            }
+        }
+    } else if f == 0.25 || f == -0.25 {
+        /* tan(+-pi/4) = +-1 */
+        let t = f * 4.0;
+        if odd {
+            -t
+        } else {
+            t
+        }
+    } else {
+        let (hi, lo) = mul_pi(f);
+        k_tan(hi, lo, odd as i32)
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tanpi;
+    use core::f64::*;
+
+    #[test]
+    fn exact_values() {
+        assert_eq!(tanpi(0.25), 1.0);
+        assert_eq!(tanpi(0.75), -1.0);
+        assert_eq!(tanpi(-1.25), -1.0);
+        assert_eq!(tanpi(0.5), INFINITY);
+        assert_eq!(tanpi(1.5), NEG_INFINITY);
+        assert_eq!(tanpi(-0.5), NEG_INFINITY);
+        assert_eq!(tanpi(2.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(tanpi(1.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(tanpi(-1.0).to_bits(), 0.0_f64.to_bits());
+        assert_eq!(tanpi(-2.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(tanpi(4503599627370497.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(tanpi(-1e300).to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert!(tanpi(NAN).is_nan());
+        assert!(tanpi(INFINITY).is_nan());
+        assert_eq!(tanpi(-0.0).to_bits(), (-0.0_f64).to_bits());
+    }
+}
@@ -0,0 +1,64 @@
+use super::fenv::{feraiseexcept, underflow_if_tinyf, FE_DIVBYZERO, FE_INVALID};
+use super::{k_tanf, rem_half};
+use core::f32;
+use core::f64::consts::PI;
+
+/// Tangent of pi times x (f32)
+///
+/// Computes `tan(pi * x)` without first rounding `pi * x`. The result is exact
+/// for integer, half-integer and quarter-integer `x`. `tanpif(n)` is `+0.0` for
+/// positive even and negative odd integers and `-0.0` otherwise, and
+/// `tanpif(n + 0.5)` is a signed infinity.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn tanpif(x: f32) -> f32 {
+    let ui = x.to_bits();
+    let sign = (ui >> 31) != 0;
+    let ix = ui & 0x7fffffff;
+
+    if ix >= 0x7f800000 {
+        /* tanpif(nan or inf) = nan */
+        if x.is_infinite() {
+            feraiseexcept(FE_INVALID);
+        }
+        return x - x;
+    }
+    if ix >= 0x4b000000 {
+        /* |x| >= 2^23: x is an integer, odd only if |x| < 2^24 and its last bit is set */
+        let odd = ix < 0x4b800000 && ix & 1 != 0;
+        return if sign != odd { -0.0 } else { 0.0 };
+    }
+
+    /* tan has period 1, so n = 0, 2 give tan(pi*f) and n = 1, 3 give -cot(pi*f) */
+    let (n, f) = rem_half(f32::from_bits(ix) as f64);
+    let odd = n & 1 != 0;
+    let y = if f == 0.0 {
+        match n {
+            0 => 0.0,
+            2 => -0.0,
+            _ => {
+                /* pole */
+                feraiseexcept(FE_DIVBYZERO);
+                if n == 1 {
+                    f32::INFINITY
+                } else {
+                    f32::NEG_INFINITY
+                }
+            }
+        }
+    } else if f == 0.25 || f == -0.25 {
+        /* tan(+-pi/4) = +-1 */
+        let t = (f * 4.0) as f32;
+        if odd {
+            -t
+        } else {
+            t
+        }
+    } else {
+        underflow_if_tinyf(k_tanf(PI * f, odd))
+    };
+    if sign {
+        -y
+    } else {
+        y
+    }
+}
@@ -23,6 +23,9 @@ Gamma(x)*Gamma(-x) = -pi/(x sin(pi x))
 most ideas and constants are from boost and python
 */
 extern crate core;
+use super::fenv::{
+    feraiseexcept, invalid, overflow, FE_DIVBYZERO, FE_INEXACT, FE_INVALID, FE_UNDERFLOW,
+};
 use super::{exp, floor, k_cos, k_sin, pow};
 
 const PI: f64 = 3.141592653589793238462643383279502884;
@@ -130,7 +133,7 @@ fn s(x: f64) -> f64 {
     return num / den;
 }
 
-pub fn tgamma(mut x: f64) -> f64 {
+pub fn tgamma(x: f64) -> f64 {
     let u: u64 = x.to_bits();
     let absx: f64;
     let mut y: f64;
@@ -143,10 +146,16 @@ pub fn tgamma(mut x: f64) -> f64 {
     /* special cases */
     if ix >= 0x7ff00000 {
         /* tgamma(nan)=nan, tgamma(inf)=inf, tgamma(-inf)=nan with invalid */
+        if x == core::f64::NEG_INFINITY {
+            feraiseexcept(FE_INVALID);
+        }
         return x + core::f64::INFINITY;
     }
     if ix < ((0x3ff - 54) << 20) {
         /* |x| < 2^-54: tgamma(x) ~ 1/x, +-0 raises div-by-zero */
+        if x == 0.0 {
+            feraiseexcept(FE_DIVBYZERO);
+        }
         return 1.0 / x;
     }
 
@@ -154,7 +163,7 @@ pub fn tgamma(mut x: f64) -> f64 {
     /* raise inexact when non-integer */
     if x == floor(x) {
         if sign {
-            return 0.0 / 0.0;
+            return invalid(x);
         }
         if x <= FACT.len() as f64 {
             return FACT[(x as usize) - 1];
@@ -166,8 +175,7 @@ pub fn tgamma(mut x: f64) -> f64 {
     if ix >= 0x40670000 {
         /* |x| >= 184 */
         if sign {
-            let x1p_126 = f64::from_bits(0x3810000000000000); // 0x1p-126 == 2^-126
-            force_eval!((x1p_126 / x) as f32);
+            feraiseexcept(FE_UNDERFLOW | FE_INEXACT);
             if floor(x) * 0.5 == floor(x * 0.5) {
                 return 0.0;
             } else {
@@ -175,8 +183,7 @@ pub fn tgamma(mut x: f64) -> f64 {
             }
         }
         let x1p1023 = f64::from_bits(0x7fe0000000000000); // 0x1p1023 == 2^1023
-        x *= x1p1023;
-        return x;
+        return overflow(x * x1p1023);
     }
 
     absx = if sign { -x } else { x };
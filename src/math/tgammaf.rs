@@ -1,5 +1,6 @@
+use super::fenv::narrow;
 use super::tgamma;
 
 pub fn tgammaf(x: f32) -> f32 {
-    tgamma(x as f64) as f32
+    narrow(tgamma(x as f64))
 }
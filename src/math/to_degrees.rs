@@ -0,0 +1,23 @@
+use core::f64::consts::PI;
+
+/// Radians to degrees (f64)
+///
+/// Converts the angle `x` from radians to degrees, like `f64::to_degrees`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn to_degrees(x: f64) -> f64 {
+    x * (180.0f64 / PI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::to_radians;
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(to_degrees(PI), 180.0);
+        assert_eq!(to_degrees(-PI / 2.0), -90.0);
+        assert_eq!(to_radians(180.0), PI);
+        assert_eq!(to_radians(-90.0), -PI / 2.0);
+    }
+}
@@ -0,0 +1,9 @@
+/// Radians to degrees (f32)
+///
+/// Converts the angle `x` from radians to degrees, like `f32::to_degrees`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn to_degreesf(x: f32) -> f32 {
+    /* use a constant for better precision, as std does */
+    const PIS_IN_180: f32 = 57.2957795130823208767981548141051703_f32;
+    x * PIS_IN_180
+}
@@ -0,0 +1,133 @@
+//! Rounding conversions from `f64` to integer types.
+//!
+//! Unlike `lround` and a plain `as` cast, these return `None` when `x` is NaN
+//! or when the rounded value does not fit in the target type, instead of
+//! silently saturating.
+
+use super::{ceil, floor, round, trunc};
+
+/// Converts an integral `x` to `i32`, or `None` if it is NaN or out of range.
+#[inline]
+fn to_i32(x: f64) -> Option<i32> {
+    if (-2147483648.0..2147483648.0).contains(&x) {
+        Some(x as i32)
+    } else {
+        None
+    }
+}
+
+/// Converts an integral `x` to `i64`, or `None` if it is NaN or out of range.
+#[inline]
+fn to_i64(x: f64) -> Option<i64> {
+    if (-9223372036854775808.0..9223372036854775808.0).contains(&x) {
+        Some(x as i64)
+    } else {
+        None
+    }
+}
+
+/// Floor to `i32` (f64)
+///
+/// Returns the largest integer less than or equal to `x`, or `None` if `x` is
+/// NaN or the result does not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floor_to_i32(x: f64) -> Option<i32> {
+    to_i32(floor(x))
+}
+
+/// Floor to `i64` (f64)
+///
+/// Returns the largest integer less than or equal to `x`, or `None` if `x` is
+/// NaN or the result does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floor_to_i64(x: f64) -> Option<i64> {
+    to_i64(floor(x))
+}
+
+/// Ceil to `i32` (f64)
+///
+/// Returns the smallest integer greater than or equal to `x`, or `None` if `x`
+/// is NaN or the result does not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceil_to_i32(x: f64) -> Option<i32> {
+    to_i32(ceil(x))
+}
+
+/// Ceil to `i64` (f64)
+///
+/// Returns the smallest integer greater than or equal to `x`, or `None` if `x`
+/// is NaN or the result does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceil_to_i64(x: f64) -> Option<i64> {
+    to_i64(ceil(x))
+}
+
+/// Truncate to `i32` (f64)
+///
+/// Returns the integer part of `x`, or `None` if `x` is NaN or the result does
+/// not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn trunc_to_i32(x: f64) -> Option<i32> {
+    to_i32(trunc(x))
+}
+
+/// Truncate to `i64` (f64)
+///
+/// Returns the integer part of `x`, or `None` if `x` is NaN or the result does
+/// not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn trunc_to_i64(x: f64) -> Option<i64> {
+    to_i64(trunc(x))
+}
+
+/// Round to `i32` (f64)
+///
+/// Returns the nearest integer to `x`, with halfway cases rounded away from
+/// zero, or `None` if `x` is NaN or the result does not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn round_to_i32(x: f64) -> Option<i32> {
+    to_i32(round(x))
+}
+
+/// Round to `i64` (f64)
+///
+/// Returns the nearest integer to `x`, with halfway cases rounded away from
+/// zero, or `None` if `x` is NaN or the result does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn round_to_i64(x: f64) -> Option<i64> {
+    to_i64(round(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(floor_to_i32(-1.5), Some(-2));
+        assert_eq!(ceil_to_i32(-1.5), Some(-1));
+        assert_eq!(trunc_to_i32(-1.5), Some(-1));
+        assert_eq!(round_to_i32(-1.5), Some(-2));
+        assert_eq!(round_to_i64(2.5), Some(3));
+    }
+
+    #[test]
+    fn range_limits() {
+        assert_eq!(floor_to_i32(-2147483648.5), None);
+        assert_eq!(ceil_to_i32(-2147483648.5), Some(i32::min_value()));
+        assert_eq!(floor_to_i32(2147483647.5), Some(i32::max_value()));
+        assert_eq!(ceil_to_i32(2147483647.5), None);
+        assert_eq!(trunc_to_i64(-9223372036854775808.0), Some(i64::min_value()));
+        assert_eq!(trunc_to_i64(9223372036854775808.0), None);
+    }
+
+    #[test]
+    fn spec_tests() {
+        assert_eq!(round_to_i32(NAN), None);
+        assert_eq!(round_to_i64(NAN), None);
+        assert_eq!(floor_to_i64(INFINITY), None);
+        assert_eq!(ceil_to_i64(NEG_INFINITY), None);
+        assert_eq!(trunc_to_i32(-0.0), Some(0));
+    }
+}
@@ -0,0 +1,126 @@
+//! Rounding conversions from `f32` to integer types.
+//!
+//! Unlike `lroundf` and a plain `as` cast, these return `None` when `x` is NaN
+//! or when the rounded value does not fit in the target type, instead of
+//! silently saturating.
+
+use super::{ceilf, floorf, roundf, truncf};
+
+/// Converts an integral `x` to `i32`, or `None` if it is NaN or out of range.
+#[inline]
+fn to_i32(x: f32) -> Option<i32> {
+    if (-2147483648.0..2147483648.0).contains(&x) {
+        Some(x as i32)
+    } else {
+        None
+    }
+}
+
+/// Converts an integral `x` to `i64`, or `None` if it is NaN or out of range.
+#[inline]
+fn to_i64(x: f32) -> Option<i64> {
+    if (-9223372036854775808.0..9223372036854775808.0).contains(&x) {
+        Some(x as i64)
+    } else {
+        None
+    }
+}
+
+/// Floor to `i32` (f32)
+///
+/// Returns the largest integer less than or equal to `x`, or `None` if `x` is
+/// NaN or the result does not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floorf_to_i32(x: f32) -> Option<i32> {
+    to_i32(floorf(x))
+}
+
+/// Floor to `i64` (f32)
+///
+/// Returns the largest integer less than or equal to `x`, or `None` if `x` is
+/// NaN or the result does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn floorf_to_i64(x: f32) -> Option<i64> {
+    to_i64(floorf(x))
+}
+
+/// Ceil to `i32` (f32)
+///
+/// Returns the smallest integer greater than or equal to `x`, or `None` if `x`
+/// is NaN or the result does not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceilf_to_i32(x: f32) -> Option<i32> {
+    to_i32(ceilf(x))
+}
+
+/// Ceil to `i64` (f32)
+///
+/// Returns the smallest integer greater than or equal to `x`, or `None` if `x`
+/// is NaN or the result does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn ceilf_to_i64(x: f32) -> Option<i64> {
+    to_i64(ceilf(x))
+}
+
+/// Truncate to `i32` (f32)
+///
+/// Returns the integer part of `x`, or `None` if `x` is NaN or the result does
+/// not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf_to_i32(x: f32) -> Option<i32> {
+    to_i32(truncf(x))
+}
+
+/// Truncate to `i64` (f32)
+///
+/// Returns the integer part of `x`, or `None` if `x` is NaN or the result does
+/// not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf_to_i64(x: f32) -> Option<i64> {
+    to_i64(truncf(x))
+}
+
+/// Round to `i32` (f32)
+///
+/// Returns the nearest integer to `x`, with halfway cases rounded away from
+/// zero, or `None` if `x` is NaN or the result does not fit in an `i32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundf_to_i32(x: f32) -> Option<i32> {
+    to_i32(roundf(x))
+}
+
+/// Round to `i64` (f32)
+///
+/// Returns the nearest integer to `x`, with halfway cases rounded away from
+/// zero, or `None` if `x` is NaN or the result does not fit in an `i64`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn roundf_to_i64(x: f32) -> Option<i64> {
+    to_i64(roundf(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(floorf_to_i32(-1.5), Some(-2));
+        assert_eq!(ceilf_to_i32(-1.5), Some(-1));
+        assert_eq!(truncf_to_i32(-1.5), Some(-1));
+        assert_eq!(roundf_to_i64(2.5), Some(3));
+    }
+
+    #[test]
+    fn range_limits() {
+        assert_eq!(floorf_to_i32(-2147483648.0), Some(i32::min_value()));
+        assert_eq!(floorf_to_i32(2147483648.0), None);
+        assert_eq!(
+            roundf_to_i64(-9223372036854775808.0),
+            Some(i64::min_value())
+        );
+        assert_eq!(roundf_to_i64(9223372036854775808.0), None);
+        assert_eq!(roundf_to_i32(NAN), None);
+        assert_eq!(ceilf_to_i64(INFINITY), None);
+    }
+}
@@ -0,0 +1,9 @@
+use core::f64::consts::PI;
+
+/// Degrees to radians (f64)
+///
+/// Converts the angle `x` from degrees to radians, like `f64::to_radians`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn to_radians(x: f64) -> f64 {
+    x * (PI / 180.0)
+}
@@ -0,0 +1,9 @@
+use core::f32::consts::PI;
+
+/// Degrees to radians (f32)
+///
+/// Converts the angle `x` from degrees to radians, like `f32::to_radians`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn to_radiansf(x: f32) -> f32 {
+    x * (PI / 180.0)
+}
@@ -0,0 +1,47 @@
+/// IEEE 754 total order (f64)
+///
+/// Returns `true` if `x` is ordered before or equal to `y` in the total order
+/// `-NaN < -inf < ... < -0 < +0 < ... < +inf < +NaN`, in which NaNs are
+/// further ordered by their quiet bit and payload and `x` and `y` are equal
+/// only if their encodings are. Suitable as the comparison for sorting.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorder(x: f64, y: f64) -> bool {
+    let mut ix = x.to_bits() as i64;
+    let mut iy = y.to_bits() as i64;
+    /* map sign-magnitude to two's complement order */
+    ix ^= (((ix >> 63) as u64) >> 1) as i64;
+    iy ^= (((iy >> 63) as u64) >> 1) as i64;
+    ix <= iy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::*;
+
+    #[test]
+    fn sanity_check() {
+        let snan = f64::from_bits(0x7ff0000000000001);
+        let ordered = [
+            -NAN,
+            -snan,
+            NEG_INFINITY,
+            -MAX,
+            -1.0,
+            -5e-324,
+            -0.0,
+            0.0,
+            5e-324,
+            1.0,
+            MAX,
+            INFINITY,
+            snan,
+            NAN,
+        ];
+        for (i, &x) in ordered.iter().enumerate() {
+            for (j, &y) in ordered.iter().enumerate() {
+                assert_eq!(totalorder(x, y), i <= j, "{:?} {:?}", x, y);
+            }
+        }
+    }
+}
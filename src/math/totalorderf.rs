@@ -0,0 +1,15 @@
+/// IEEE 754 total order (f32)
+///
+/// Returns `true` if `x` is ordered before or equal to `y` in the total order
+/// `-NaN < -inf < ... < -0 < +0 < ... < +inf < +NaN`, in which NaNs are
+/// further ordered by their quiet bit and payload and `x` and `y` are equal
+/// only if their encodings are. Suitable as the comparison for sorting.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalorderf(x: f32, y: f32) -> bool {
+    let mut ix = x.to_bits() as i32;
+    let mut iy = y.to_bits() as i32;
+    /* map sign-magnitude to two's complement order */
+    ix ^= (((ix >> 31) as u32) >> 1) as i32;
+    iy ^= (((iy >> 31) as u32) >> 1) as i32;
+    ix <= iy
+}
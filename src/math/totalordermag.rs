@@ -0,0 +1,8 @@
+/// IEEE 754 total order of magnitudes (f64)
+///
+/// Returns `true` if `|x|` is ordered before or equal to `|y|` in the order of
+/// `totalorder`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermag(x: f64, y: f64) -> bool {
+    x.to_bits() & 0x7fffffffffffffff <= y.to_bits() & 0x7fffffffffffffff
+}
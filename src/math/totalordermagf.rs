@@ -0,0 +1,8 @@
+/// IEEE 754 total order of magnitudes (f32)
+///
+/// Returns `true` if `|x|` is ordered before or equal to `|y|` in the order of
+/// `totalorderf`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn totalordermagf(x: f32, y: f32) -> bool {
+    x.to_bits() & 0x7fffffff <= y.to_bits() & 0x7fffffff
+}
@@ -0,0 +1,55 @@
+use super::f128::{exponent, is_zero, quiet_nan, signed_zero, BIAS, SIG_BITS, SIG_MASK};
+use super::fenv::{feraiseexcept, FE_INEXACT};
+use super::F128;
+
+/// Truncate (F128)
+///
+/// Rounds `x` to the nearest integer toward zero.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf128(x: F128) -> F128 {
+    let bits = x.to_bits();
+    let e = exponent(x) - BIAS;
+
+    if x.is_nan() {
+        return quiet_nan(x, x);
+    }
+    if e >= SIG_BITS as i32 {
+        return x;
+    }
+    if e < 0 {
+        if !is_zero(x) {
+            feraiseexcept(FE_INEXACT);
+        }
+        return signed_zero(x.is_sign_negative());
+    }
+    let m = SIG_MASK >> e;
+    if bits & m == 0 {
+        return x;
+    }
+    feraiseexcept(FE_INEXACT);
+    F128::from_bits(bits & !m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(bits: u128) -> F128 {
+        F128::from_bits(bits)
+    }
+
+    #[test]
+    fn sanity_check() {
+        /* 1.5, 2.75 and 2^112 + 0.5 */
+        assert_eq!(truncf128(f(0x3fff8000000000000000000000000000)), F128::ONE);
+        assert_eq!(
+            truncf128(f(0xc0006000000000000000000000000000)).to_bits(),
+            0xc0000000000000000000000000000000
+        );
+        let z = truncf128(f(0xbffe0000000000000000000000000000));
+        assert!(z == F128::ZERO && z.is_sign_negative());
+        assert_eq!(truncf128(F128::MAX), F128::MAX);
+        assert_eq!(truncf128(F128::NEG_INFINITY), F128::NEG_INFINITY);
+        assert!(truncf128(F128::NAN).is_nan());
+    }
+}
@@ -0,0 +1,40 @@
+use super::{truncf, F16};
+
+/// Rounds toward zero (F16)
+///
+/// Returns the integer part of `x`, rounded toward zero.
+/// The integer is computed exactly in `f32`.
+#[cfg_attr(all(test, assert_no_panic), no_panic::no_panic)]
+pub fn truncf16(x: F16) -> F16 {
+    F16::from_f32(truncf(f32::from(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(truncf16(F16::from_f32(-1.5)), F16::from_f32(-1.0));
+        assert_eq!(truncf16(F16::from_f32(0.75)), F16::from_f32(0.0));
+        assert_eq!(truncf16(F16::from_f32(1023.5)), F16::from_f32(1023.0));
+        assert_eq!(truncf16(F16::from_f32(-0.25)), F16::from_f32(-0.0));
+        assert_eq!(truncf16(F16::MAX), F16::MAX);
+        assert!(truncf16(-F16::ZERO).is_sign_negative());
+        assert!(truncf16(F16::NAN).is_nan());
+    }
+
+    #[test]
+    fn exhaustive() {
+        /* the same integers as in f32 */
+        for bits in 0..=u16::MAX {
+            let x = F16::from_bits(bits);
+            let z = truncf(f32::from(x));
+            if z.is_nan() {
+                assert!(truncf16(x).is_nan());
+            } else {
+                assert_eq!(f32::from(truncf16(x)).to_bits(), z.to_bits());
+            }
+        }
+    }
+}